@@ -3,7 +3,7 @@
 //! data store in here - but for a larger app, you'd likely do something else.
 
 use cacao::listview::{
-    ListView, ListViewDelegate, ListViewRow,
+    IndexPath, ListView, ListViewDelegate, ListViewRow,
     RowAnimation, RowEdge, RowAction, RowActionStyle
 };
 
@@ -29,7 +29,7 @@ impl TodosListView {
             Message::MarkTodoComplete(row) => {
                 self.todos.with_mut(row, |todo| todo.status = TodoStatus::Complete);
                 if let Some(view) = &self.view {
-                    view.reload_rows(&[row]);
+                    view.reload_rows(&[IndexPath::new(0, row)]);
                     view.set_row_actions_visible(false);
                 }
             },
@@ -38,7 +38,7 @@ impl TodosListView {
                 self.todos.with_mut(row, |todo| todo.status = TodoStatus::Incomplete);
                 
                 if let Some(view) = &self.view {
-                    view.reload_rows(&[row]);
+                    view.reload_rows(&[IndexPath::new(0, row)]);
                     view.set_row_actions_visible(false);
                 }
             },
@@ -48,7 +48,7 @@ impl TodosListView {
                 self.view.as_ref().unwrap().perform_batch_updates(|listview| {
                     // We know we always insert at the 0 index, so this is a simple calculation.
                     // You'd need to diff yourself for anything more complicated.
-                    listview.insert_rows(0..1, RowAnimation::SlideDown);
+                    listview.insert_rows(vec![IndexPath::new(0, 0)], RowAnimation::SlideDown);
                 });
             },
 
@@ -68,17 +68,17 @@ impl ListViewDelegate for TodosListView {
     }
 
     /// The number of todos we currently have.
-    fn number_of_items(&self) -> usize {
+    fn items_in_section(&self, _section: usize) -> usize {
         self.todos.len()
     }
 
     /// For a given row, dequeues a view from the system and passes the appropriate `Transfer` for
     /// configuration.
-    fn item_for(&self, row: usize) -> ListViewRow {
+    fn item_for(&self, index: IndexPath) -> ListViewRow {
         let mut view = self.view.as_ref().unwrap().dequeue::<TodoViewRow>(TODO_ROW);
             
         if let Some(view) = &mut view.delegate {
-            self.todos.with(row, |todo| view.configure_with(todo));
+            self.todos.with(index.row, |todo| view.configure_with(todo));
         }
 
         view.into_row()
@@ -87,22 +87,24 @@ impl ListViewDelegate for TodosListView {
     /// Provides support for _swipe-to-reveal_ actions. After a user has completed one of these
     /// actions, we make sure to mark the tableview as done (see the message handlers in this
     /// file).
-    fn actions_for(&self, row: usize, edge: RowEdge) -> Vec<RowAction> {
+    fn actions_for(&self, index: IndexPath, edge: RowEdge) -> Vec<RowAction> {
         if let RowEdge::Leading = edge {
             return vec![];
         }
 
         let mut actions = vec![];
 
+        let row = index.row;
+
         self.todos.with(row, |todo| match todo.status {
             TodoStatus::Complete => {
-                actions.push(RowAction::new("Mark Incomplete", RowActionStyle::Destructive, move |_action, row| {
+                actions.push(RowAction::new("Mark Incomplete", RowActionStyle::Destructive, move |_action, _| {
                     dispatch_ui(Message::MarkTodoIncomplete(row));
                 }));
             },
 
             TodoStatus::Incomplete => {
-                actions.push(RowAction::new("Mark Complete", RowActionStyle::Regular, move |_action, row| {
+                actions.push(RowAction::new("Mark Complete", RowActionStyle::Regular, move |_action, _| {
                     dispatch_ui(Message::MarkTodoComplete(row));
                 }));
             }
@@ -13,7 +13,7 @@ use crate::color::Color;
 use crate::foundation::{id, nil, BOOL, YES, NO, NSString, NSUInteger};
 use crate::invoker::TargetActionHandler;
 use crate::layout::{Layout, LayoutAnchorX, LayoutAnchorY, LayoutAnchorDimension};
-use crate::text::Font;
+use crate::text::{AttributedString, Font};
 use crate::utils::load;
 
 #[cfg(feature = "macos")]
@@ -122,6 +122,14 @@ impl Button {
         }
     }
 
+    /// Sets a styled title. Note that this replaces the title wholesale, so call
+    /// `set_text_color()` afterwards if you want to recolor it.
+    pub fn set_attributed_title(&self, title: &AttributedString) {
+        unsafe {
+            let _: () = msg_send![&*self.objc, setAttributedTitle:title.to_objc()];
+        }
+    }
+
     pub fn set_text_color(&self, color: Color) {
         let bg = color.into_platform_specific_color();
         
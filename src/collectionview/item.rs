@@ -0,0 +1,64 @@
+//! The items that `CollectionView` vends - an `NSCollectionViewItem` (which `NSCollectionView`
+//! deals in), hosting a `ListViewRow` with your content.
+
+use std::sync::Once;
+
+use objc_id::ShareId;
+use objc::declare::ClassDecl;
+use objc::runtime::{Class, Object, Sel};
+use objc::{class, msg_send, sel, sel_impl};
+
+use crate::foundation::id;
+use crate::listview::ListViewRow;
+use crate::panic::AddGuardedMethod;
+
+/// An item in a `CollectionView`. You get these from `CollectionView::dequeue()`, configure the
+/// `content` delegate, and hand them back (via `into_item()`) from
+/// `CollectionViewDelegate::item_for()`.
+#[derive(Debug)]
+pub struct CollectionViewItem<T = ()> {
+    /// A pointer to the Objective-C runtime `NSCollectionViewItem`.
+    pub objc: ShareId<Object>,
+
+    /// The view displayed by this item, which fills it.
+    pub content: ListViewRow<T>
+}
+
+impl<T> CollectionViewItem<T> {
+    /// Consumes this item and returns a type-erased one, suitable for returning from
+    /// `CollectionViewDelegate::item_for()`. Much like `ListViewRow::into_row()`, the content
+    /// delegate is kept alive by the backing view.
+    pub fn into_item(self) -> CollectionViewItem {
+        CollectionViewItem {
+            objc: self.objc,
+            content: self.content.into_row()
+        }
+    }
+}
+
+/// Gives each item a plain container view, rather than trying to load one from a nib.
+fn load_view(this: &mut Object, _: Sel) {
+    unsafe {
+        let view: id = msg_send![class!(NSView), new];
+        let _: () = msg_send![this, setView:view];
+        let _: () = msg_send![view, release];
+    }
+}
+
+/// Injects an `NSCollectionViewItem` subclass that builds its own view. This is registered with
+/// the collection view for every identifier you register a vendor for.
+pub(crate) fn register_collection_view_item_class() -> *const Class {
+    static mut ITEM_CLASS: *const Class = 0 as *const Class;
+    static INIT: Once = Once::new();
+
+    INIT.call_once(|| unsafe {
+        let superclass = class!(NSCollectionViewItem);
+        let mut decl = ClassDecl::new("RSTCollectionViewItem", superclass).unwrap();
+        decl.add_guarded_method(sel!(loadView), load_view);
+        ITEM_CLASS = decl.register();
+    });
+
+    unsafe {
+        ITEM_CLASS
+    }
+}
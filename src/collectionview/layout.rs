@@ -0,0 +1,147 @@
+//! Layouts for `CollectionView`, wrapping `NSCollectionViewFlowLayout` and
+//! `NSCollectionViewGridLayout`.
+
+use core_graphics::base::CGFloat;
+use objc::{class, msg_send, sel, sel_impl};
+
+use crate::foundation::{id, NSInteger, NSUInteger};
+use crate::utils::CGSize;
+
+/// The direction a flow layout fills, and thus scrolls, in.
+#[derive(Copy, Clone, Debug, PartialEq)]
+pub enum ScrollDirection {
+    /// Items fill rows left to right, and the view scrolls vertically.
+    Vertical,
+
+    /// Items fill columns top to bottom, and the view scrolls horizontally.
+    Horizontal
+}
+
+impl From<ScrollDirection> for NSInteger {
+    fn from(direction: ScrollDirection) -> Self {
+        match direction {
+            ScrollDirection::Vertical => 0,
+            ScrollDirection::Horizontal => 1
+        }
+    }
+}
+
+/// Lays items out in lines, fitting as many fixed-size items on each line as there's room for.
+/// This supports multiple sections, and is the default.
+#[derive(Copy, Clone, Debug, PartialEq)]
+pub struct FlowLayout {
+    /// The width of each item.
+    pub item_width: CGFloat,
+
+    /// The height of each item.
+    pub item_height: CGFloat,
+
+    /// The minimum spacing between items on the same line.
+    pub spacing: CGFloat,
+
+    /// The minimum spacing between lines.
+    pub line_spacing: CGFloat,
+
+    /// The direction items fill in.
+    pub direction: ScrollDirection
+}
+
+impl Default for FlowLayout {
+    fn default() -> Self {
+        FlowLayout {
+            item_width: 50.,
+            item_height: 50.,
+            spacing: 10.,
+            line_spacing: 10.,
+            direction: ScrollDirection::Vertical
+        }
+    }
+}
+
+/// Lays items out in a grid of evenly sized cells, stretching items (within the given bounds) to
+/// fill the available width. Grid layouts only display the first section.
+#[derive(Copy, Clone, Debug, PartialEq)]
+pub struct GridLayout {
+    /// The minimum width of each item.
+    pub min_item_width: CGFloat,
+
+    /// The minimum height of each item.
+    pub min_item_height: CGFloat,
+
+    /// The maximum width of each item. `0` means no maximum.
+    pub max_item_width: CGFloat,
+
+    /// The maximum height of each item. `0` means no maximum.
+    pub max_item_height: CGFloat,
+
+    /// The maximum number of columns. `0` means no limit.
+    pub max_columns: usize,
+
+    /// The minimum spacing between items in a row.
+    pub spacing: CGFloat,
+
+    /// The minimum spacing between rows.
+    pub line_spacing: CGFloat
+}
+
+impl Default for GridLayout {
+    fn default() -> Self {
+        GridLayout {
+            min_item_width: 50.,
+            min_item_height: 50.,
+            max_item_width: 0.,
+            max_item_height: 0.,
+            max_columns: 0,
+            spacing: 10.,
+            line_spacing: 10.
+        }
+    }
+}
+
+/// How a `CollectionView` arranges its items.
+#[derive(Copy, Clone, Debug, PartialEq)]
+pub enum CollectionViewLayout {
+    /// A flow layout.
+    Flow(FlowLayout),
+
+    /// A grid layout.
+    Grid(GridLayout)
+}
+
+impl Default for CollectionViewLayout {
+    fn default() -> Self {
+        CollectionViewLayout::Flow(FlowLayout::default())
+    }
+}
+
+impl CollectionViewLayout {
+    /// Builds the backing `NSCollectionViewLayout`. The returned object is autoreleased.
+    pub(crate) fn to_objc(&self) -> id {
+        unsafe {
+            match self {
+                CollectionViewLayout::Flow(flow) => {
+                    let layout: id = msg_send![class!(NSCollectionViewFlowLayout), new];
+                    let _: () = msg_send![layout, setItemSize:CGSize::new(flow.item_width, flow.item_height)];
+                    let _: () = msg_send![layout, setMinimumInteritemSpacing:flow.spacing];
+                    let _: () = msg_send![layout, setMinimumLineSpacing:flow.line_spacing];
+
+                    let direction: NSInteger = flow.direction.into();
+                    let _: () = msg_send![layout, setScrollDirection:direction];
+                    msg_send![layout, autorelease]
+                },
+
+                CollectionViewLayout::Grid(grid) => {
+                    let layout: id = msg_send![class!(NSCollectionViewGridLayout), new];
+                    let _: () = msg_send![layout, setMinimumItemSize:CGSize::new(grid.min_item_width, grid.min_item_height)];
+                    let _: () = msg_send![layout, setMaximumItemSize:CGSize::new(grid.max_item_width, grid.max_item_height)];
+                    let _: () = msg_send![layout, setMinimumInteritemSpacing:grid.spacing];
+                    let _: () = msg_send![layout, setMinimumLineSpacing:grid.line_spacing];
+
+                    let columns = grid.max_columns as NSUInteger;
+                    let _: () = msg_send![layout, setMaximumNumberOfColumns:columns];
+                    msg_send![layout, autorelease]
+                }
+            }
+        }
+    }
+}
@@ -0,0 +1,100 @@
+//! Registers the `NSCollectionView` subclasses that back `CollectionView`, and forwards data
+//! source and delegate calls on to the Rust side.
+
+use std::sync::Once;
+
+use objc::declare::ClassDecl;
+use objc::runtime::{Class, Object, Sel, BOOL};
+use objc::{class, sel, sel_impl, msg_send};
+
+use crate::foundation::{load_or_register_class, id, YES, NSInteger};
+use crate::collectionview::{
+    COLLECTIONVIEW_DELEGATE_PTR, COLLECTIONVIEW_CELL_VENDOR_PTR,
+    CollectionViewDelegate, index_path_from_objc, index_paths_from_set, index_paths_to_set
+};
+use crate::utils::load;
+use crate::panic::AddGuardedMethod;
+
+/// Returns the number of sections.
+fn number_of_sections<T: CollectionViewDelegate>(this: &Object, _: Sel, _: id) -> NSInteger {
+    let view = load::<T>(this, COLLECTIONVIEW_DELEGATE_PTR);
+    view.number_of_sections() as NSInteger
+}
+
+/// Returns the number of items in a section.
+fn number_of_items<T: CollectionViewDelegate>(this: &Object, _: Sel, _: id, section: NSInteger) -> NSInteger {
+    let view = load::<T>(this, COLLECTIONVIEW_DELEGATE_PTR);
+    view.items_in_section(section as usize) as NSInteger
+}
+
+/// Vends the item for a given index path.
+fn item_for_index_path<T: CollectionViewDelegate>(this: &Object, _: Sel, _: id, index_path: id) -> id {
+    let view = load::<T>(this, COLLECTIONVIEW_DELEGATE_PTR);
+    let item = view.item_for(index_path_from_objc(index_path));
+
+    // As with `ListView`, the collection view retains the item we hand back.
+    unsafe {
+        msg_send![&*item.objc, self]
+    }
+}
+
+/// Filters a proposed selection down to the items the delegate allows.
+fn should_select_items<T: CollectionViewDelegate>(this: &Object, _: Sel, _: id, index_paths: id) -> id {
+    let view = load::<T>(this, COLLECTIONVIEW_DELEGATE_PTR);
+
+    let allowed: Vec<_> = index_paths_from_set(index_paths).into_iter()
+        .filter(|index| view.should_select(*index))
+        .collect();
+
+    index_paths_to_set(&allowed)
+}
+
+/// Called when items are selected or deselected.
+fn selection_did_change<T: CollectionViewDelegate>(this: &Object, _: Sel, _: id, _: id) {
+    let selected = index_paths_from_set(unsafe { msg_send![this, selectionIndexPaths] });
+    let view = load::<T>(this, COLLECTIONVIEW_DELEGATE_PTR);
+    view.selection_did_change(selected);
+}
+
+/// Enforces normalcy, or: a needlessly cruel method in terms of the name. You get the idea though.
+fn enforce_normalcy(_: &Object, _: Sel) -> BOOL {
+    return YES;
+}
+
+/// Injects an `NSCollectionView` subclass. This is used for the default views that don't use
+/// delegates.
+pub(crate) fn register_collectionview_class() -> *const Class {
+    static mut VIEW_CLASS: *const Class = 0 as *const Class;
+    static INIT: Once = Once::new();
+
+    INIT.call_once(|| unsafe {
+        let superclass = class!(NSCollectionView);
+        let decl = ClassDecl::new("RSTCollectionView", superclass).unwrap();
+        VIEW_CLASS = decl.register();
+    });
+
+    unsafe {
+        VIEW_CLASS
+    }
+}
+
+/// Injects an `NSCollectionView` subclass, with some callback and pointer ivars for what we
+/// need to do.
+pub(crate) fn register_collectionview_class_with_delegate<T: CollectionViewDelegate>(instance: &T) -> *const Class {
+    load_or_register_class("NSCollectionView", instance.subclass_name(), |decl| unsafe {
+        decl.add_ivar::<usize>(COLLECTIONVIEW_DELEGATE_PTR);
+        decl.add_ivar::<usize>(COLLECTIONVIEW_CELL_VENDOR_PTR);
+
+        decl.add_guarded_method(sel!(isFlipped), enforce_normalcy);
+
+        // Data source
+        decl.add_guarded_method(sel!(numberOfSectionsInCollectionView:), number_of_sections::<T>);
+        decl.add_guarded_method(sel!(collectionView:numberOfItemsInSection:), number_of_items::<T>);
+        decl.add_guarded_method(sel!(collectionView:itemForRepresentedObjectAtIndexPath:), item_for_index_path::<T>);
+
+        // Selection
+        decl.add_guarded_method(sel!(collectionView:shouldSelectItemsAtIndexPaths:), should_select_items::<T>);
+        decl.add_guarded_method(sel!(collectionView:didSelectItemsAtIndexPaths:), selection_did_change::<T>);
+        decl.add_guarded_method(sel!(collectionView:didDeselectItemsAtIndexPaths:), selection_did_change::<T>);
+    })
+}
@@ -0,0 +1,472 @@
+//! Wraps `NSCollectionView`, for grids of reusable items - icon grids, photo galleries, and so on.
+//!
+//! This follows the same register/dequeue pattern as `ListView`: register a vendor for each kind
+//! of item, then dequeue and configure one in `CollectionViewDelegate::item_for()`.
+//!
+//! ```rust,no_run
+//! use cacao::collectionview::{
+//!     CollectionView, CollectionViewDelegate, CollectionViewItem,
+//!     CollectionViewLayout, FlowLayout
+//! };
+//! use cacao::listview::IndexPath;
+//! use cacao::view::{View, ViewDelegate};
+//!
+//! #[derive(Default)]
+//! struct PhotoCell;
+//!
+//! impl ViewDelegate for PhotoCell {
+//!     const NAME: &'static str = "PhotoCell";
+//!     fn did_load(&mut self, _view: View) {}
+//! }
+//!
+//! #[derive(Default)]
+//! struct Gallery {
+//!     view: Option<CollectionView>,
+//!     photos: Vec<String>
+//! }
+//!
+//! impl CollectionViewDelegate for Gallery {
+//!     const NAME: &'static str = "Gallery";
+//!
+//!     fn did_load(&mut self, view: CollectionView) {
+//!         view.register("photo", PhotoCell::default);
+//!         view.set_layout(CollectionViewLayout::Flow(FlowLayout {
+//!             item_width: 120.,
+//!             item_height: 120.,
+//!             ..FlowLayout::default()
+//!         }));
+//!         self.view = Some(view);
+//!     }
+//!
+//!     fn items_in_section(&self, _section: usize) -> usize {
+//!         self.photos.len()
+//!     }
+//!
+//!     fn item_for(&self, index: IndexPath) -> CollectionViewItem {
+//!         let item = self.view.as_ref().unwrap().dequeue::<PhotoCell>("photo", index);
+//!         item.into_item()
+//!     }
+//! }
+//! ```
+
+use std::cell::RefCell;
+
+use block::ConcreteBlock;
+use objc_id::ShareId;
+use objc::runtime::{Class, Object};
+use objc::{class, msg_send, sel, sel_impl};
+
+use crate::foundation::{id, nil, YES, NO, NSArray, NSString, NSInteger, NSUInteger};
+use crate::color::Color;
+use crate::layout::{Layout, LayoutAnchorX, LayoutAnchorY, LayoutAnchorDimension, LayoutConstraint};
+use crate::listview::{CellFactory, CellFactoryMap, IndexPath, ListViewRow, SelectionMode};
+use crate::panic::guard;
+use crate::scrollview::ScrollView;
+use crate::view::ViewDelegate;
+
+mod item;
+pub use item::CollectionViewItem;
+use item::register_collection_view_item_class;
+
+mod layout;
+pub use layout::{CollectionViewLayout, FlowLayout, GridLayout, ScrollDirection};
+
+mod macos;
+use macos::{register_collectionview_class, register_collectionview_class_with_delegate};
+
+mod traits;
+pub use traits::CollectionViewDelegate;
+
+pub(crate) static COLLECTIONVIEW_DELEGATE_PTR: &str = "rstCollectionViewDelegatePtr";
+pub(crate) static COLLECTIONVIEW_CELL_VENDOR_PTR: &str = "rstCollectionViewCellVendorPtr";
+
+/// Returns an autoreleased `NSIndexPath` for an `IndexPath`.
+pub(crate) fn index_path_to_objc(index: IndexPath) -> id {
+    unsafe {
+        let item = index.row as NSInteger;
+        let section = index.section as NSInteger;
+        msg_send![class!(NSIndexPath), indexPathForItem:item inSection:section]
+    }
+}
+
+/// Reads an `IndexPath` out of an `NSIndexPath`.
+pub(crate) fn index_path_from_objc(index_path: id) -> IndexPath {
+    unsafe {
+        let item: NSInteger = msg_send![index_path, item];
+        let section: NSInteger = msg_send![index_path, section];
+        IndexPath::new(section as usize, item as usize)
+    }
+}
+
+/// Reads the `IndexPath`s out of an `NSSet` of `NSIndexPath`s, in ascending order.
+pub(crate) fn index_paths_from_set(set: id) -> Vec<IndexPath> {
+    let objects = NSArray::wrap(unsafe { msg_send![set, allObjects] });
+    let mut indexes = objects.map(index_path_from_objc);
+    indexes.sort();
+    indexes
+}
+
+/// Returns an autoreleased `NSSet` of `NSIndexPath`s.
+pub(crate) fn index_paths_to_set(indexes: &[IndexPath]) -> id {
+    unsafe {
+        let set: id = msg_send![class!(NSMutableSet), set];
+
+        for index in indexes {
+            let _: () = msg_send![set, addObject:index_path_to_objc(*index)];
+        }
+
+        set
+    }
+}
+
+/// A helper method for instantiating view classes and applying default settings to them.
+fn common_init(class: *const Class) -> id {
+    unsafe {
+        let view: id = msg_send![class, new];
+        let _: () = msg_send![view, setTranslatesAutoresizingMaskIntoConstraints:NO];
+        let _: () = msg_send![view, setWantsLayer:YES];
+        let _: () = msg_send![view, setSelectable:YES];
+        let _: () = msg_send![view, setAllowsEmptySelection:YES];
+        let _: () = msg_send![view, setAllowsMultipleSelection:NO];
+        let _: () = msg_send![view, setCollectionViewLayout:CollectionViewLayout::default().to_objc()];
+        view
+    }
+}
+
+/// A grid of reusable items, backed by `NSCollectionView` (inside an `NSScrollView`).
+#[derive(Debug)]
+pub struct CollectionView<T = ()> {
+    /// Internal map of item identifers/vendors. These are used for handling dynamic item
+    /// allocation and reuse.
+    cell_factory: CellFactory,
+
+    /// A pointer to the Objective-C runtime view controller.
+    pub objc: ShareId<Object>,
+
+    /// The `NSScrollView` that hosts the collection view. Layout anchors refer to this.
+    pub scrollview: ScrollView,
+
+    /// A pointer to the delegate for this view.
+    pub delegate: Option<Box<T>>,
+
+    /// A pointer to the Objective-C runtime top layout constraint.
+    pub top: LayoutAnchorY,
+
+    /// A pointer to the Objective-C runtime leading layout constraint.
+    pub leading: LayoutAnchorX,
+
+    /// A pointer to the Objective-C runtime trailing layout constraint.
+    pub trailing: LayoutAnchorX,
+
+    /// A pointer to the Objective-C runtime bottom layout constraint.
+    pub bottom: LayoutAnchorY,
+
+    /// A pointer to the Objective-C runtime width layout constraint.
+    pub width: LayoutAnchorDimension,
+
+    /// A pointer to the Objective-C runtime height layout constraint.
+    pub height: LayoutAnchorDimension,
+
+    /// A pointer to the Objective-C runtime center X layout constraint.
+    pub center_x: LayoutAnchorX,
+
+    /// A pointer to the Objective-C runtime center Y layout constraint.
+    pub center_y: LayoutAnchorY
+}
+
+impl Default for CollectionView {
+    fn default() -> Self {
+        CollectionView::new()
+    }
+}
+
+impl CollectionView {
+    /// Returns a default `CollectionView`, with no delegate.
+    pub fn new() -> Self {
+        let view = common_init(register_collectionview_class());
+        let scrollview = ScrollView::new();
+
+        unsafe {
+            let _: () = msg_send![&*scrollview.objc, setDocumentView:view];
+        }
+
+        CollectionView::init(view, CellFactory::new(), scrollview)
+    }
+}
+
+impl<T> CollectionView<T> where T: CollectionViewDelegate + 'static {
+    /// Initializes a new CollectionView with a given `CollectionViewDelegate`. This enables you
+    /// to respond to events and vend items.
+    pub fn with(delegate: T) -> CollectionView<T> {
+        let class = register_collectionview_class_with_delegate::<T>(&delegate);
+        let view = common_init(class);
+        let mut delegate = Box::new(delegate);
+        let cell = CellFactory::new();
+
+        unsafe {
+            let delegate_ptr: *const T = &*delegate;
+            let cell_vendor_ptr: *const RefCell<CellFactoryMap> = &*cell.0;
+            (&mut *view).set_ivar(COLLECTIONVIEW_DELEGATE_PTR, delegate_ptr as usize);
+            (&mut *view).set_ivar(COLLECTIONVIEW_CELL_VENDOR_PTR, cell_vendor_ptr as usize);
+            let _: () = msg_send![view, setDelegate:view];
+            let _: () = msg_send![view, setDataSource:view];
+        }
+
+        let scrollview = ScrollView::new();
+
+        unsafe {
+            let _: () = msg_send![&*scrollview.objc, setDocumentView:view];
+        }
+
+        let mut view = CollectionView::init(view, cell, scrollview);
+        (&mut delegate).did_load(view.clone_as_handle());
+        view.delegate = Some(delegate);
+        view
+    }
+}
+
+impl<T> CollectionView<T> {
+    /// Wires up the layout anchors (which, as with `ListView`, come from the `NSScrollView`).
+    fn init(view: id, cell_factory: CellFactory, scrollview: ScrollView) -> Self {
+        let anchor_view = &*scrollview.objc;
+
+        CollectionView {
+            cell_factory: cell_factory,
+            delegate: None,
+            top: LayoutAnchorY::new(unsafe { msg_send![anchor_view, topAnchor] }),
+            leading: LayoutAnchorX::new(unsafe { msg_send![anchor_view, leadingAnchor] }),
+            trailing: LayoutAnchorX::new(unsafe { msg_send![anchor_view, trailingAnchor] }),
+            bottom: LayoutAnchorY::new(unsafe { msg_send![anchor_view, bottomAnchor] }),
+            width: LayoutAnchorDimension::new(unsafe { msg_send![anchor_view, widthAnchor] }),
+            height: LayoutAnchorDimension::new(unsafe { msg_send![anchor_view, heightAnchor] }),
+            center_x: LayoutAnchorX::new(unsafe { msg_send![anchor_view, centerXAnchor] }),
+            center_y: LayoutAnchorY::new(unsafe { msg_send![anchor_view, centerYAnchor] }),
+            objc: unsafe { ShareId::from_ptr(view) },
+            scrollview: scrollview
+        }
+    }
+
+    /// An internal method that returns a clone of this object, sans references to the delegate or
+    /// callback pointer. We use this in calling `did_load()` - implementing delegates get a way to
+    /// reference, customize and use the view but without the trickery of holding pieces of the
+    /// delegate - the `CollectionView` is the only true holder of those.
+    pub(crate) fn clone_as_handle(&self) -> CollectionView {
+        CollectionView {
+            cell_factory: self.cell_factory.clone(),
+            delegate: None,
+            top: self.top.clone(),
+            leading: self.leading.clone(),
+            trailing: self.trailing.clone(),
+            bottom: self.bottom.clone(),
+            width: self.width.clone(),
+            height: self.height.clone(),
+            center_x: self.center_x.clone(),
+            center_y: self.center_y.clone(),
+            objc: self.objc.clone(),
+            scrollview: self.scrollview.clone_as_handle()
+        }
+    }
+
+    /// Register an item vendor function with an identifier. This is stored internally and used
+    /// for item-reuse.
+    pub fn register<F, R>(&self, identifier: &'static str, vendor: F)
+    where
+        F: Fn() -> R + 'static,
+        R: ViewDelegate + 'static
+    {
+        self.cell_factory.insert(identifier, vendor);
+
+        unsafe {
+            let key = NSString::new(identifier).into_inner();
+            let _: () = msg_send![&*self.objc, registerClass:register_collection_view_item_class() forItemWithIdentifier:key];
+        }
+    }
+
+    /// Dequeue a reusable item for the given index path. If one is not in the queue, will create
+    /// and cache one for reuse. The identifier must have been registered with `register()`.
+    pub fn dequeue<R: ViewDelegate + 'static>(&self, identifier: &'static str, index: IndexPath) -> CollectionViewItem<R> {
+        unsafe {
+            let key = NSString::new(identifier).into_inner();
+            let item: id = msg_send![&*self.objc, makeItemWithIdentifier:key forIndexPath:index_path_to_objc(index)];
+            let container: id = msg_send![item, view];
+            let subviews: id = msg_send![container, subviews];
+            let count: NSUInteger = msg_send![subviews, count];
+
+            // Reused items already host their content view.
+            let content = match count {
+                0 => {
+                    let delegate: Box<R> = self.cell_factory.get(identifier);
+                    let content = ListViewRow::with_boxed(delegate);
+
+                    let _: () = msg_send![container, addSubview:&**content.objc.borrow()];
+
+                    LayoutConstraint::activate(&[
+                        content.top.constraint_equal_to(&LayoutAnchorY::new(msg_send![container, topAnchor])),
+                        content.leading.constraint_equal_to(&LayoutAnchorX::new(msg_send![container, leadingAnchor])),
+                        content.trailing.constraint_equal_to(&LayoutAnchorX::new(msg_send![container, trailingAnchor])),
+                        content.bottom.constraint_equal_to(&LayoutAnchorY::new(msg_send![container, bottomAnchor]))
+                    ]);
+
+                    content
+                },
+
+                _ => {
+                    let index: NSUInteger = 0;
+                    ListViewRow::from_cached(msg_send![subviews, objectAtIndex:index])
+                }
+            };
+
+            CollectionViewItem {
+                objc: ShareId::from_ptr(item),
+                content: content
+            }
+        }
+    }
+
+    /// Sets the layout, which determines how items are sized and arranged.
+    pub fn set_layout(&self, layout: CollectionViewLayout) {
+        unsafe {
+            let _: () = msg_send![&*self.objc, setCollectionViewLayout:layout.to_objc()];
+        }
+    }
+
+    /// Reloads every item.
+    pub fn reload(&self) {
+        unsafe {
+            let _: () = msg_send![&*self.objc, reloadData];
+        }
+    }
+
+    /// Groups item updates so that they animate together. The handle passed to `update` is what
+    /// you should call `insert_items()` and friends on; index paths work the same as they do
+    /// for `ListView::perform_batch_updates()`.
+    pub fn perform_batch_updates<F: Fn(CollectionView) + 'static>(&self, animated: bool, update: F) {
+        let handle = self.clone_as_handle();
+
+        let block = ConcreteBlock::new(move || {
+            guard(|| update(handle.clone_as_handle()));
+        });
+        let block = block.copy();
+
+        unsafe {
+            let target: id = match animated {
+                true => msg_send![&*self.objc, animator],
+                false => &*self.objc as *const Object as id
+            };
+
+            let _: () = msg_send![target, performBatchUpdates:&*block completionHandler:nil];
+        }
+    }
+
+    /// Inserts items at the given index paths, which refer to positions once the insert is done.
+    /// Your delegate should already reflect the new items when this is called.
+    pub fn insert_items(&self, indexes: &[IndexPath]) {
+        unsafe {
+            let _: () = msg_send![&*self.objc, insertItemsAtIndexPaths:index_paths_to_set(indexes)];
+        }
+    }
+
+    /// Removes the items at the given index paths, which refer to positions before the removal.
+    pub fn remove_items(&self, indexes: &[IndexPath]) {
+        unsafe {
+            let _: () = msg_send![&*self.objc, deleteItemsAtIndexPaths:index_paths_to_set(indexes)];
+        }
+    }
+
+    /// Moves the item at `from` so that it ends up at `to`, possibly in another section.
+    pub fn move_item(&self, from: IndexPath, to: IndexPath) {
+        unsafe {
+            let _: () = msg_send![&*self.objc, moveItemAtIndexPath:index_path_to_objc(from) toIndexPath:index_path_to_objc(to)];
+        }
+    }
+
+    /// Reloads the items at the given index paths.
+    pub fn reload_items(&self, indexes: &[IndexPath]) {
+        unsafe {
+            let _: () = msg_send![&*self.objc, reloadItemsAtIndexPaths:index_paths_to_set(indexes)];
+        }
+    }
+
+    /// Sets how many items the user can select at once. Changing this clears the current
+    /// selection.
+    pub fn set_selection_mode(&self, mode: SelectionMode) {
+        self.deselect_all();
+
+        unsafe {
+            let _: () = msg_send![&*self.objc, setSelectable:match mode {
+                SelectionMode::None => NO,
+                _ => YES
+            }];
+
+            let _: () = msg_send![&*self.objc, setAllowsMultipleSelection:match mode {
+                SelectionMode::Multiple => YES,
+                _ => NO
+            }];
+        }
+    }
+
+    /// Selects the items at the given index paths, adding them to the current selection. This
+    /// does not consult `CollectionViewDelegate::should_select()`.
+    pub fn select_items(&self, indexes: &[IndexPath]) {
+        unsafe {
+            // NSCollectionViewScrollPositionNone
+            let position: NSInteger = 0;
+            let _: () = msg_send![&*self.objc, selectItemsAtIndexPaths:index_paths_to_set(indexes) scrollPosition:position];
+        }
+    }
+
+    /// Clears the selection.
+    pub fn deselect_all(&self) {
+        unsafe {
+            let _: () = msg_send![&*self.objc, deselectAll:nil];
+        }
+    }
+
+    /// Returns the index paths of the selected items, in ascending order.
+    pub fn selected_items(&self) -> Vec<IndexPath> {
+        index_paths_from_set(unsafe { msg_send![&*self.objc, selectionIndexPaths] })
+    }
+
+    /// Call this to set the background color for the collection view. Passing `None` makes it
+    /// transparent.
+    pub fn set_background_color(&self, color: Option<Color>) {
+        unsafe {
+            let colors: NSArray = match color {
+                Some(color) => NSArray::new(&[color.into_platform_specific_color()]),
+                None => NSArray::new(&[msg_send![class!(NSColor), clearColor]])
+            };
+
+            let _: () = msg_send![&*self.objc, setBackgroundColors:colors.into_inner()];
+        }
+    }
+}
+
+impl<T> Layout for CollectionView<T> {
+    /// This returns the NSScrollView, not the NSCollectionView.
+    fn get_backing_node(&self) -> ShareId<Object> {
+        self.scrollview.objc.clone()
+    }
+
+    fn add_subview<V: Layout>(&self, view: &V) {
+        let backing_node = view.get_backing_node();
+
+        unsafe {
+            let _: () = msg_send![&*self.scrollview.objc, addSubview:backing_node];
+        }
+    }
+}
+
+impl<T> Drop for CollectionView<T> {
+    /// If the originating `CollectionView` is being dropped, remove it from the view heirarchy,
+    /// to match the semantics of how Rust handles things.
+    fn drop(&mut self) {
+        if self.delegate.is_some() {
+            unsafe {
+                let superview: id = msg_send![&*self.scrollview.objc, superview];
+                if superview != nil {
+                    let _: () = msg_send![&*self.scrollview.objc, removeFromSuperview];
+                }
+            }
+        }
+    }
+}
@@ -0,0 +1,39 @@
+//! Various traits used for CollectionViews.
+
+use crate::collectionview::{CollectionView, CollectionViewItem};
+use crate::listview::IndexPath;
+
+#[allow(unused_variables)]
+pub trait CollectionViewDelegate {
+    /// Used to cache subclass creations on the Objective-C side.
+    /// You can just set this to be the name of your view type. This
+    /// value *must* be unique per-type.
+    const NAME: &'static str;
+
+    /// You should rarely (read: probably never) need to implement this yourself.
+    /// It simply acts as a getter for the associated `NAME` const on this trait.
+    fn subclass_name(&self) -> &'static str {
+        Self::NAME
+    }
+
+    /// Called when the CollectionView is ready to work with. You're passed a `CollectionView` -
+    /// this is safe to store and use repeatedly, but it's not thread safe - any UI calls must be
+    /// made from the main thread!
+    fn did_load(&mut self, view: CollectionView);
+
+    /// Returns the number of sections in the collection view. Defaults to a single section.
+    fn number_of_sections(&self) -> usize { 1 }
+
+    /// Returns the number of items in the given section.
+    fn items_in_section(&self, section: usize) -> usize;
+
+    /// Returns the item for the given index path. Use `CollectionView::dequeue()` to reuse
+    /// items as the view scrolls.
+    fn item_for(&self, index: IndexPath) -> CollectionViewItem;
+
+    /// Called when the user is about to select an item; return `false` to prevent it.
+    fn should_select(&self, index: IndexPath) -> bool { true }
+
+    /// Called whenever the selection changes, with the now-selected items in ascending order.
+    fn selection_did_change(&self, selected: Vec<IndexPath>) {}
+}
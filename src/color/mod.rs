@@ -94,7 +94,7 @@ impl Property {
 /// colors will automatically switch to the "correct" colors/shades depending on whether
 /// the user is in light or dark mode; to support this with custom colors, be sure
 /// to call the `.dark()` method after initializing.
-#[derive(Clone)]
+#[derive(Clone, Debug)]
 pub enum Color {
     /// Represents an `NSColor` on macOS, and a `UIColor` everywhere else. You typically
     /// don't create this variant yourself; use the initializers found on this enum.
@@ -183,44 +183,7 @@ impl UserDefaults {
             return None;
         }
 
-        if NSData::is(result) {
-            let data = NSData::wrap(result);
-            return Some(Value::Data(data.into_vec()));
-        }
-
-        if NSString::is(result) {
-            let s = NSString::wrap(result).to_str().to_string();
-            return Some(Value::String(s));
-        }
-
-        // This works, but might not be the best approach. We basically need to inspect the
-        // `NSNumber` returned and see what the wrapped encoding type is. `q` and `d` represent
-        // `NSInteger` (platform specific) and `double` (f64) respectively, but conceivably we
-        // might need others.
-        //
-        // BOOL returns as "c", which... something makes me feel weird there, but testing it seems
-        // reliable.
-        //
-        // For context: https://nshipster.com/type-encodings/
-        if NSNumber::is(result) {
-            let number = NSNumber::wrap(result);
-            
-            return match number.objc_type() {
-                "c" => Some(Value::Bool(number.as_bool())),
-                "d" => Some(Value::Float(number.as_f64())),
-                "q" => Some(Value::Integer(number.as_i64())),
-
-                x => {
-                    // Debugging code that should be removed at some point.
-                    #[cfg(debug_assertions)]
-                    println!("Code: {}", x);
-
-                    None
-                }
-            };
-        }
-
-        None
+        Value::from_objc(result)
     }
 
     /// Returns a boolean value if the object stored for the specified key is managed by an
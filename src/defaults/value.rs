@@ -1,6 +1,6 @@
 use std::collections::HashMap;
 
-use crate::foundation::{id, NSData, NSDictionary, NSString, NSNumber};
+use crate::foundation::{id, nil, NSData, NSDictionary, NSString, NSNumber};
 
 /// Represents a Value that can be stored or queried with `UserDefaults`.
 ///
@@ -130,6 +130,49 @@ impl Value {
     }
 }
 
+impl Value {
+    /// Attempts to pull a `Value` out of an `NSObject` vended to us by the system. Returns `None`
+    /// for `nil`, or for any type we don't (yet) support.
+    pub(crate) fn from_objc(object: id) -> Option<Self> {
+        if object == nil {
+            return None;
+        }
+
+        if NSData::is(object) {
+            let data = NSData::wrap(object);
+            return Some(Value::Data(data.into_vec()));
+        }
+
+        if NSString::is(object) {
+            let s = NSString::wrap(object).to_str().to_string();
+            return Some(Value::String(s));
+        }
+
+        // This works, but might not be the best approach. We basically need to inspect the
+        // `NSNumber` returned and see what the wrapped encoding type is. `q` and `d` represent
+        // `NSInteger` (platform specific) and `double` (f64) respectively, but conceivably we
+        // might need others.
+        //
+        // BOOL returns as "c", which... something makes me feel weird there, but testing it seems
+        // reliable.
+        //
+        // For context: https://nshipster.com/type-encodings/
+        if NSNumber::is(object) {
+            let number = NSNumber::wrap(object);
+            
+            return match number.objc_type() {
+                "c" => Some(Value::Bool(number.as_bool())),
+                "d" => Some(Value::Float(number.as_f64())),
+                "q" => Some(Value::Integer(number.as_i64())),
+
+                _ => None
+            };
+        }
+
+        None
+    }
+}
+
 impl From<Value> for id {
     /// Shepherds `Value` types into `NSObject`s that can be stored in `NSUserDefaults`.
     // These currently work, but may not be exhaustive and should be looked over past the preview
@@ -0,0 +1,225 @@
+//! A small futures executor that runs on top of Grand Central Dispatch.
+//!
+//! There's no need for a separate reactor here: the system is already running a loop for us (the
+//! main run loop, which drains the main dispatch queue), and GCD already has a thread pool for
+//! background work. Wakers simply schedule another poll onto the appropriate queue.
+//!
+//! - Futures spawned via `spawn_local` are polled exclusively on the main queue. They don't need
+//! to be `Send`, which means they're free to hold and update views when whatever they're awaiting
+//! resolves.
+//! - Futures spawned via `spawn_background` are polled on the global concurrent queue.
+//!
+//! You generally don't use this module directly; see `App::spawn_local()` and
+//! `App::spawn_background()`.
+
+use std::cell::RefCell;
+use std::collections::HashMap;
+use std::future::Future;
+use std::pin::Pin;
+use std::sync::{Arc, Mutex};
+use std::sync::atomic::{AtomicUsize, Ordering};
+use std::task::{Context, Poll, Wake, Waker};
+
+use dispatch::{Queue, QueuePriority};
+
+use objc::{class, msg_send, sel, sel_impl};
+
+use crate::foundation::{to_bool, BOOL};
+
+type LocalFuture = Pin<Box<dyn Future<Output = ()> + 'static>>;
+type BackgroundFuture = Pin<Box<dyn Future<Output = ()> + Send + 'static>>;
+
+static NEXT_LOCAL_TASK_ID: AtomicUsize = AtomicUsize::new(0);
+
+// Main-thread futures aren't `Send`, so they can't travel inside a waker. Instead, they're parked
+// here (only ever touched from the main thread), and wakers carry just the task id.
+thread_local! {
+    static LOCAL_TASKS: RefCell<HashMap<usize, LocalFuture>> = RefCell::new(HashMap::new());
+}
+
+/// Returns whether we're currently on the main thread.
+fn is_main_thread() -> bool {
+    let result: BOOL = unsafe { msg_send![class!(NSThread), isMainThread] };
+    to_bool(result)
+}
+
+/// A waker for main-thread tasks. Waking schedules a poll on the main queue, which may happen from
+/// any thread.
+struct LocalWaker(usize);
+
+impl Wake for LocalWaker {
+    fn wake(self: Arc<Self>) {
+        let task_id = self.0;
+        Queue::main().exec_async(move || poll_local(task_id));
+    }
+}
+
+/// Pulls the task out of the local task map, polls it, and places it back if it's still pending.
+///
+/// The task is removed for the duration of the poll so that the future is free to spawn further
+/// local tasks without tripping over the `RefCell`. Any wakeups that come in while polling are
+/// queued behind us on the (serial) main queue, so by the time they run the task is back in place.
+fn poll_local(task_id: usize) {
+    let future = LOCAL_TASKS.with(|tasks| tasks.borrow_mut().remove(&task_id));
+
+    if let Some(mut future) = future {
+        let waker = Waker::from(Arc::new(LocalWaker(task_id)));
+        let mut context = Context::from_waker(&waker);
+
+        if let Poll::Pending = future.as_mut().poll(&mut context) {
+            LOCAL_TASKS.with(|tasks| {
+                tasks.borrow_mut().insert(task_id, future);
+            });
+        }
+    }
+}
+
+/// Spawns a future that will be polled to completion on the main thread. The first poll happens on
+/// the next pass of the main run loop.
+///
+/// This must be called from the main thread, and will panic otherwise - if you're on a background
+/// thread, dispatch to the main queue first.
+pub fn spawn_local<F>(future: F)
+where
+    F: Future<Output = ()> + 'static
+{
+    if !is_main_thread() {
+        panic!("spawn_local() must be called from the main thread; dispatch to the main queue first.");
+    }
+
+    let task_id = NEXT_LOCAL_TASK_ID.fetch_add(1, Ordering::Relaxed);
+
+    LOCAL_TASKS.with(|tasks| {
+        tasks.borrow_mut().insert(task_id, Box::pin(future));
+    });
+
+    Queue::main().exec_async(move || poll_local(task_id));
+}
+
+/// A background task. The future lives behind a `Mutex`, as a wakeup may cause it to be scheduled
+/// on another GCD worker while it's still being polled; that second poll simply waits its turn.
+struct BackgroundTask {
+    future: Mutex<Option<BackgroundFuture>>
+}
+
+impl BackgroundTask {
+    /// Schedules a poll of this task on the global concurrent queue.
+    fn schedule(self: Arc<Self>) {
+        Queue::global(QueuePriority::Default).exec_async(move || self.poll());
+    }
+
+    /// Polls the underlying future, dropping it once it's complete.
+    fn poll(self: Arc<Self>) {
+        let waker = Waker::from(self.clone());
+        let mut context = Context::from_waker(&waker);
+        let mut slot = self.future.lock().unwrap();
+
+        if let Some(future) = slot.as_mut() {
+            if let Poll::Ready(()) = future.as_mut().poll(&mut context) {
+                *slot = None;
+            }
+        }
+    }
+}
+
+impl Wake for BackgroundTask {
+    fn wake(self: Arc<Self>) {
+        self.schedule();
+    }
+}
+
+/// Spawns a future that will be polled to completion on the global concurrent GCD queue. This can
+/// be called from any thread.
+pub fn spawn_background<F>(future: F)
+where
+    F: Future<Output = ()> + Send + 'static
+{
+    let task = Arc::new(BackgroundTask {
+        future: Mutex::new(Some(Box::pin(future)))
+    });
+
+    task.schedule();
+}
+
+#[cfg(test)]
+mod tests {
+    use std::future::Future;
+    use std::pin::Pin;
+    use std::sync::mpsc;
+    use std::task::{Context, Poll};
+    use std::thread;
+    use std::time::Duration;
+
+    use super::{spawn_background, spawn_local};
+
+    /// Returns `Pending` (after waking itself) the given number of times before completing, so
+    /// that the executor has to reschedule it.
+    struct YieldTimes(usize);
+
+    impl Future for YieldTimes {
+        type Output = ();
+
+        fn poll(mut self: Pin<&mut Self>, context: &mut Context) -> Poll<()> {
+            if self.0 == 0 {
+                return Poll::Ready(());
+            }
+
+            self.0 -= 1;
+            context.waker().wake_by_ref();
+            Poll::Pending
+        }
+    }
+
+    #[test]
+    fn background_future_runs_to_completion() {
+        let (sender, receiver) = mpsc::channel();
+
+        spawn_background(async move {
+            sender.send(42).unwrap();
+        });
+
+        assert_eq!(receiver.recv_timeout(Duration::from_secs(5)), Ok(42));
+    }
+
+    #[test]
+    fn background_future_is_repolled_when_woken() {
+        let (sender, receiver) = mpsc::channel();
+
+        spawn_background(async move {
+            YieldTimes(3).await;
+            sender.send(()).unwrap();
+        });
+
+        assert_eq!(receiver.recv_timeout(Duration::from_secs(5)), Ok(()));
+    }
+
+    #[test]
+    fn background_futures_can_be_spawned_from_any_thread() {
+        let (sender, receiver) = mpsc::channel();
+
+        for i in 0..4 {
+            let sender = sender.clone();
+
+            thread::spawn(move || {
+                spawn_background(async move {
+                    sender.send(i).unwrap();
+                });
+            });
+        }
+
+        let mut received: Vec<usize> = (0..4).map(|_| {
+            receiver.recv_timeout(Duration::from_secs(5)).unwrap()
+        }).collect();
+
+        received.sort();
+        assert_eq!(received, vec![0, 1, 2, 3]);
+    }
+
+    #[test]
+    #[should_panic(expected = "must be called from the main thread")]
+    fn spawn_local_panics_off_the_main_thread() {
+        if let Err(panic) = thread::spawn(|| spawn_local(async {})).join() {
+            std::panic::resume_unwind(panic);
+        }
+    }
+}
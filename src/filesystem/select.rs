@@ -12,6 +12,7 @@ use objc_id::ShareId;
 
 use crate::foundation::{id, YES, NO, NSInteger, NSString};
 use crate::filesystem::enums::ModalResponse;
+use crate::panic::guard;
 
 #[derive(Debug)]
 pub struct FileSelectPanel {
@@ -125,11 +126,13 @@ impl FileSelectPanel {
     pub fn show<F: Fn(Vec<PathBuf>) + 'static>(&self, handler: F) {
         let panel = self.panel.clone();
         let completion = ConcreteBlock::new(move |result: NSInteger| {
-            let response: ModalResponse = result.into();
+            guard(|| {
+                let response: ModalResponse = result.into();
 
-            handler(match response {
-                ModalResponse::Ok => get_urls(&panel),
-                _ => Vec::new()
+                handler(match response {
+                    ModalResponse::Ok => get_urls(&panel),
+                    _ => Vec::new()
+                });
             });
         });
 
@@ -71,6 +71,10 @@ impl NSArray {
         let mut ret: Vec<T> = Vec::with_capacity(count);
         let mut index = 0;
 
+        if count == 0 {
+            return ret;
+        }
+
         loop {
             let item: id = unsafe { msg_send![&*self.0, objectAtIndex:index] };
             ret.push(transform(item));
@@ -46,6 +46,10 @@ mod point;
 
 pub use point::NSPoint;
 
+mod range;
+
+pub use range::NSRange;
+
 mod rect;
 
 pub use rect::NSRect;
@@ -0,0 +1,122 @@
+use std::ops::Range;
+
+use crate::foundation::NSUInteger;
+
+#[repr(C)]
+#[derive(Copy, Clone, Debug, Default, PartialEq)]
+pub struct NSRange {
+    pub location: NSUInteger,
+    pub length: NSUInteger,
+}
+
+impl NSRange {
+    /// Creates a new `NSRange`.
+    pub fn new(location: usize, length: usize) -> Self {
+        Self {
+            location: location as NSUInteger,
+            length: length as NSUInteger,
+        }
+    }
+
+    /// Converts a byte range into `text` to the UTF-16 range AppKit works in.
+    ///
+    /// # Panics
+    ///
+    /// Panics if the range is out of bounds, or doesn't fall on `char` boundaries.
+    pub(crate) fn from_byte_range(text: &str, range: Range<usize>) -> Self {
+        assert!(
+            range.start <= range.end && text.get(range.clone()).is_some(),
+            "NSRange: range {:?} is not valid for text of length {}", range, text.len()
+        );
+
+        let location = text[..range.start].encode_utf16().count();
+        let length = text[range].encode_utf16().count();
+        NSRange::new(location, length)
+    }
+
+    /// Converts this UTF-16 range into a byte range into `text`. Offsets that land inside a
+    /// surrogate pair are moved past it, and anything past the end is clamped to it.
+    pub(crate) fn to_byte_range(&self, text: &str) -> Range<usize> {
+        let start = self.location as usize;
+        let end = start + self.length as usize;
+        utf16_to_byte(text, start)..utf16_to_byte(text, end)
+    }
+}
+
+/// Returns the byte offset in `text` of a UTF-16 offset, rounding up to a `char` boundary.
+fn utf16_to_byte(text: &str, offset: usize) -> usize {
+    let mut units = 0;
+
+    for (index, c) in text.char_indices() {
+        if units >= offset {
+            return index;
+        }
+
+        units += c.len_utf16();
+    }
+
+    text.len()
+}
+
+impl From<Range<usize>> for NSRange {
+    fn from(range: Range<usize>) -> Self {
+        NSRange::new(range.start, range.end.saturating_sub(range.start))
+    }
+}
+
+impl From<NSRange> for Range<usize> {
+    fn from(range: NSRange) -> Self {
+        let start = range.location as usize;
+        start..(start + range.length as usize)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    // 'é' is two bytes and one UTF-16 unit; '😀' is four bytes and two units.
+    const TEXT: &str = "aé😀b";
+
+    #[test]
+    fn byte_ranges_become_utf16_ranges() {
+        assert_eq!(NSRange::from_byte_range(TEXT, 0..0), NSRange::new(0, 0));
+        assert_eq!(NSRange::from_byte_range(TEXT, 0..3), NSRange::new(0, 2));
+        assert_eq!(NSRange::from_byte_range(TEXT, 3..7), NSRange::new(2, 2));
+        assert_eq!(NSRange::from_byte_range(TEXT, 7..8), NSRange::new(4, 1));
+        assert_eq!(NSRange::from_byte_range(TEXT, 8..8), NSRange::new(5, 0));
+    }
+
+    #[test]
+    fn utf16_ranges_become_byte_ranges() {
+        assert_eq!(NSRange::new(0, 2).to_byte_range(TEXT), 0..3);
+        assert_eq!(NSRange::new(2, 2).to_byte_range(TEXT), 3..7);
+        assert_eq!(NSRange::new(4, 1).to_byte_range(TEXT), 7..8);
+        assert_eq!(NSRange::new(5, 0).to_byte_range(TEXT), 8..8);
+    }
+
+    #[test]
+    fn round_trips() {
+        for (start, _) in TEXT.char_indices().chain(Some((TEXT.len(), ' '))) {
+            for (end, _) in TEXT.char_indices().chain(Some((TEXT.len(), ' '))).filter(|(end, _)| *end >= start) {
+                assert_eq!(NSRange::from_byte_range(TEXT, start..end).to_byte_range(TEXT), start..end);
+            }
+        }
+    }
+
+    #[test]
+    fn offsets_inside_a_surrogate_pair_round_up() {
+        assert_eq!(NSRange::new(3, 0).to_byte_range(TEXT), 7..7);
+    }
+
+    #[test]
+    fn offsets_past_the_end_are_clamped() {
+        assert_eq!(NSRange::new(4, 10).to_byte_range(TEXT), 7..8);
+    }
+
+    #[test]
+    #[should_panic]
+    fn byte_ranges_must_fall_on_char_boundaries() {
+        NSRange::from_byte_range(TEXT, 0..2);
+    }
+}
@@ -12,6 +12,7 @@ use core_graphics::{
 use core_graphics::context::{CGContext, CGContextRef};
 
 use crate::foundation::{id, YES, NO, NSString};
+use crate::panic::guard;
 use crate::utils::os;
 use super::icons::*;
 
@@ -161,7 +162,8 @@ impl Image {
                 resized_frame.size.height / config.source.1
             );
 
-            let result = handler(resized_frame, &context);
+            // Guarded on its own, so that the graphics state is restored even if the handler panics.
+            let result = guard(|| handler(resized_frame, &context));
             
             let _: () = msg_send![class!(NSGraphicsContext), restoreGraphicsState];
 
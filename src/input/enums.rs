@@ -0,0 +1,47 @@
+/// Keyboard commands a `TextField` can intercept while it's being edited. See
+/// `TextFieldDelegate::text_command()`.
+#[derive(Copy, Clone, Debug, PartialEq)]
+pub enum TextFieldCommand {
+    /// The Return/Enter key.
+    Enter,
+
+    /// The Escape key (or Command-Period).
+    Escape,
+
+    /// The Tab key.
+    Tab,
+
+    /// Shift-Tab.
+    BackTab
+}
+
+/// The kinds of text field available. These all share the `TextField` API; some additional
+/// methods only apply to a given kind, and are documented as such.
+#[derive(Copy, Clone, Debug, PartialEq)]
+pub enum TextFieldKind {
+    /// A standard text field.
+    Plain,
+
+    /// A password field, which masks its contents (`NSSecureTextField`).
+    Secure,
+
+    /// A search field, with a magnifying glass, a cancel button and optional recent searches
+    /// (`NSSearchField`).
+    Search,
+
+    /// A token field, which turns input into tokens (e.g, for tags) as the user types
+    /// (`NSTokenField`).
+    Token
+}
+
+impl TextFieldKind {
+    /// The name of the AppKit class backing this kind of field.
+    pub(crate) fn superclass_name(&self) -> &'static str {
+        match self {
+            TextFieldKind::Plain => "NSTextField",
+            TextFieldKind::Secure => "NSSecureTextField",
+            TextFieldKind::Search => "NSSearchField",
+            TextFieldKind::Token => "NSTokenField"
+        }
+    }
+}
@@ -0,0 +1,135 @@
+//! Bridges Rust formatting and parsing to a custom `NSFormatter` subclass.
+
+use std::ffi::c_void;
+use std::sync::Once;
+
+use objc::declare::ClassDecl;
+use objc::runtime::{Class, Object, Sel, BOOL};
+use objc::{class, msg_send, sel, sel_impl};
+use objc_id::ShareId;
+
+use crate::defaults::Value;
+use crate::foundation::{id, nil, YES, NO, NSString};
+use crate::input::formatter::Formatter;
+use crate::panic::AddGuardedMethod;
+
+static CUSTOM_FORMATTER_PTR: &str = "rstCustomFormatterPtr";
+
+/// Implement this to control formatting and parsing yourself - e.g, for a value that isn't a
+/// plain number or date. Values are passed around as `defaults::Value`s, and can be read back
+/// from a field with `TextField::get_object_value()`.
+pub trait CustomFormatter {
+    /// Returns the string to display for a value.
+    fn format(&self, value: &Value) -> String;
+
+    /// Parses what the user typed into a value. Return an `Err` with a description of the
+    /// problem to reject the input.
+    fn parse(&self, input: &str) -> Result<Value, String>;
+}
+
+impl<F> Formatter for F where F: CustomFormatter + 'static {
+    /// Hands the formatter over to a new `RSTCustomFormatter`, which drops it when it's
+    /// deallocated.
+    fn into_formatter(self) -> ShareId<Object> {
+        let formatter: Box<Box<dyn CustomFormatter>> = Box::new(Box::new(self));
+
+        unsafe {
+            let objc: id = msg_send![register_custom_formatter_class(), new];
+            let ptr = Box::into_raw(formatter);
+            (&mut *objc).set_ivar(CUSTOM_FORMATTER_PTR, ptr as usize);
+            ShareId::from_retained_ptr(objc)
+        }
+    }
+}
+
+/// Loads the Rust formatter backing an `RSTCustomFormatter`.
+fn load_formatter(this: &Object) -> &dyn CustomFormatter {
+    unsafe {
+        let ptr: usize = *this.get_ivar(CUSTOM_FORMATTER_PTR);
+        let formatter = ptr as *const Box<dyn CustomFormatter>;
+        &**formatter
+    }
+}
+
+/// Formats an object value for display.
+fn string_for_object_value(this: &Object, _: Sel, value: id) -> id {
+    match Value::from_objc(value) {
+        Some(value) => {
+            let string = NSString::new(&load_formatter(this).format(&value));
+            unsafe { msg_send![string.into_inner(), autorelease] }
+        },
+
+        None => nil
+    }
+}
+
+/// Parses user input into an object value, or describes why it couldn't be.
+fn get_object_value(this: &Object, _: Sel, value: *mut c_void, string: id, error: *mut c_void) -> BOOL {
+    let input = NSString::wrap(string).to_string();
+
+    match load_formatter(this).parse(&input) {
+        Ok(parsed) => {
+            if !value.is_null() {
+                unsafe { *(value as *mut id) = autoreleased(parsed); }
+            }
+
+            YES
+        },
+
+        Err(message) => {
+            if !error.is_null() {
+                let message = NSString::new(&message);
+                unsafe { *(error as *mut id) = msg_send![message.into_inner(), autorelease]; }
+            }
+
+            NO
+        }
+    }
+}
+
+/// Converts a parsed value into an autoreleased object, for handing back through an out-pointer.
+///
+/// Numbers come back from `Value::into()` already autoreleased (`numberWith...`), while strings
+/// and data come back owned (`alloc`/`init`), so only the latter need an `autorelease`.
+fn autoreleased(value: Value) -> id {
+    match value {
+        Value::Bool(_) | Value::Integer(_) | Value::Float(_) => value.into(),
+
+        Value::String(_) | Value::Data(_) => {
+            let object: id = value.into();
+            unsafe { msg_send![object, autorelease] }
+        }
+    }
+}
+
+/// Drops the Rust formatter along with the Objective-C one.
+fn dealloc(this: &Object, _: Sel) {
+    unsafe {
+        let ptr: usize = *this.get_ivar(CUSTOM_FORMATTER_PTR);
+        let _formatter = Box::from_raw(ptr as *mut Box<dyn CustomFormatter>);
+
+        let _: () = msg_send![super(this, class!(NSFormatter)), dealloc];
+    }
+}
+
+/// Injects an `NSFormatter` subclass that forwards to a `CustomFormatter`.
+fn register_custom_formatter_class() -> *const Class {
+    static mut FORMATTER_CLASS: *const Class = 0 as *const Class;
+    static INIT: Once = Once::new();
+
+    INIT.call_once(|| unsafe {
+        let superclass = class!(NSFormatter);
+        let mut decl = ClassDecl::new("RSTCustomFormatter", superclass).unwrap();
+        decl.add_ivar::<usize>(CUSTOM_FORMATTER_PTR);
+
+        decl.add_guarded_method(sel!(stringForObjectValue:), string_for_object_value);
+        decl.add_guarded_method(sel!(getObjectValue:forString:errorDescription:), get_object_value);
+        decl.add_guarded_method(sel!(dealloc), dealloc);
+
+        FORMATTER_CLASS = decl.register();
+    });
+
+    unsafe {
+        FORMATTER_CLASS
+    }
+}
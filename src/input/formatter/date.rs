@@ -0,0 +1,107 @@
+//! Wraps `NSDateFormatter`.
+
+use objc::runtime::Object;
+use objc::{class, msg_send, sel, sel_impl};
+use objc_id::ShareId;
+
+use crate::foundation::{id, YES, NO, NSString, NSUInteger};
+use crate::input::formatter::Formatter;
+
+/// How much detail a `DateFormatter` shows for the date or time portion of a value.
+#[derive(Copy, Clone, Debug, PartialEq)]
+pub enum DateStyle {
+    /// Omit this portion entirely.
+    None,
+
+    /// Numeric only (e.g, `11/23/37`, or `3:30 PM`).
+    Short,
+
+    /// Abbreviated (e.g, `Nov 23, 1937`).
+    Medium,
+
+    /// Spelled out (e.g, `November 23, 1937`).
+    Long,
+
+    /// Everything (e.g, `Tuesday, April 12, 1952 AD`).
+    Full
+}
+
+impl From<DateStyle> for NSUInteger {
+    fn from(style: DateStyle) -> Self {
+        match style {
+            DateStyle::None => 0,
+            DateStyle::Short => 1,
+            DateStyle::Medium => 2,
+            DateStyle::Long => 3,
+            DateStyle::Full => 4
+        }
+    }
+}
+
+/// Formats and parses dates. Fields using this hold an `NSDate`, which you can read with
+/// `TextField::get_date()`.
+#[derive(Clone, Debug)]
+pub struct DateFormatter {
+    /// The underlying `NSDateFormatter`.
+    pub objc: ShareId<Object>
+}
+
+impl DateFormatter {
+    /// Returns a new formatter with the given date and time styles, using the current locale.
+    pub fn new(date_style: DateStyle, time_style: DateStyle) -> Self {
+        let formatter = DateFormatter {
+            objc: unsafe { ShareId::from_retained_ptr(msg_send![class!(NSDateFormatter), new]) }
+        };
+
+        unsafe {
+            let date_style: NSUInteger = date_style.into();
+            let time_style: NSUInteger = time_style.into();
+            let _: () = msg_send![&*formatter.objc, setDateStyle:date_style];
+            let _: () = msg_send![&*formatter.objc, setTimeStyle:time_style];
+        }
+
+        formatter
+    }
+
+    /// Returns a new formatter using a fixed format string (e.g, `"yyyy-MM-dd"`). See Unicode
+    /// Technical Standard #35 for the syntax.
+    pub fn with_format(format: &str) -> Self {
+        let formatter = DateFormatter {
+            objc: unsafe { ShareId::from_retained_ptr(msg_send![class!(NSDateFormatter), new]) }
+        };
+
+        unsafe {
+            let format = NSString::new(format);
+            let _: () = msg_send![&*formatter.objc, setDateFormat:format.into_inner()];
+        }
+
+        formatter
+    }
+
+    /// Sets the locale to format for, by identifier (e.g, `"de_DE"`). This defaults to the
+    /// user's current locale.
+    pub fn set_locale(&self, identifier: &str) {
+        unsafe {
+            let identifier = NSString::new(identifier);
+            let locale: id = msg_send![class!(NSLocale), localeWithLocaleIdentifier:identifier.into_inner()];
+            let _: () = msg_send![&*self.objc, setLocale:locale];
+        }
+    }
+
+    /// Sets whether parsing uses heuristics to make sense of input that doesn't match the
+    /// format exactly. This defaults to `false`.
+    pub fn set_lenient(&self, lenient: bool) {
+        unsafe {
+            let _: () = msg_send![&*self.objc, setLenient:match lenient {
+                true => YES,
+                false => NO
+            }];
+        }
+    }
+}
+
+impl Formatter for DateFormatter {
+    fn into_formatter(self) -> ShareId<Object> {
+        self.objc.clone()
+    }
+}
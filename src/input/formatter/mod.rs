@@ -0,0 +1,38 @@
+//! Formatters control how a `TextField` displays its value, and how what the user types is
+//! turned back into one. They wrap `NSFormatter` subclasses: `NumberFormatter` and
+//! `DateFormatter` use the system implementations, and `CustomFormatter` lets you supply your
+//! own parsing and formatting in Rust.
+//!
+//! ```rust,no_run
+//! use cacao::input::TextField;
+//! use cacao::input::formatter::{NumberFormatter, NumberStyle};
+//!
+//! let price = TextField::new();
+//!
+//! let formatter = NumberFormatter::new(NumberStyle::Currency);
+//! formatter.set_currency_code("EUR");
+//! formatter.set_minimum(0.);
+//! price.set_formatter(formatter);
+//!
+//! // Later on...
+//! let amount: Option<f64> = price.get_number();
+//! ```
+
+use objc::runtime::Object;
+use objc_id::ShareId;
+
+mod number;
+pub use number::{NumberFormatter, NumberStyle};
+
+mod date;
+pub use date::{DateFormatter, DateStyle};
+
+mod custom;
+pub use custom::CustomFormatter;
+
+/// Anything that can be attached to a `TextField` with `TextField::set_formatter()`.
+pub trait Formatter {
+    /// Returns the backing `NSFormatter`. This consumes the formatter, as custom formatters hand
+    /// ownership of themselves over to the Objective-C side.
+    fn into_formatter(self) -> ShareId<Object>;
+}
@@ -0,0 +1,143 @@
+//! Wraps `NSNumberFormatter`.
+
+use objc::runtime::Object;
+use objc::{class, msg_send, sel, sel_impl};
+use objc_id::ShareId;
+
+use crate::foundation::{id, YES, NO, NSNumber, NSString, NSUInteger};
+use crate::input::formatter::Formatter;
+
+/// How a `NumberFormatter` presents numbers.
+#[derive(Copy, Clone, Debug, PartialEq)]
+pub enum NumberStyle {
+    /// Plain numbers, with grouping separators (e.g, `1,234.5`).
+    Decimal,
+
+    /// Monetary amounts, in the formatter's currency (e.g, `$1,234.50`).
+    Currency,
+
+    /// Percentages, where `1.0` is `100%`.
+    Percent,
+
+    /// Scientific notation (e.g, `1.2345E3`).
+    Scientific
+}
+
+impl From<NumberStyle> for NSUInteger {
+    fn from(style: NumberStyle) -> Self {
+        match style {
+            NumberStyle::Decimal => 1,
+            NumberStyle::Currency => 2,
+            NumberStyle::Percent => 3,
+            NumberStyle::Scientific => 4
+        }
+    }
+}
+
+/// Formats and parses numbers, according to a style and locale. Fields using this hold an
+/// `NSNumber`, which you can read with `TextField::get_number()`.
+#[derive(Clone, Debug)]
+pub struct NumberFormatter {
+    /// The underlying `NSNumberFormatter`.
+    pub objc: ShareId<Object>
+}
+
+impl NumberFormatter {
+    /// Returns a new formatter for the given style, using the current locale.
+    pub fn new(style: NumberStyle) -> Self {
+        let formatter = NumberFormatter {
+            objc: unsafe { ShareId::from_retained_ptr(msg_send![class!(NSNumberFormatter), new]) }
+        };
+
+        unsafe {
+            let style: NSUInteger = style.into();
+            let _: () = msg_send![&*formatter.objc, setNumberStyle:style];
+        }
+
+        formatter
+    }
+
+    /// Sets the smallest value that will be accepted.
+    pub fn set_minimum(&self, minimum: f64) {
+        unsafe {
+            let _: () = msg_send![&*self.objc, setMinimum:NSNumber::float(minimum).into_inner()];
+        }
+    }
+
+    /// Sets the largest value that will be accepted.
+    pub fn set_maximum(&self, maximum: f64) {
+        unsafe {
+            let _: () = msg_send![&*self.objc, setMaximum:NSNumber::float(maximum).into_inner()];
+        }
+    }
+
+    /// Sets the minimum number of digits shown after the decimal separator.
+    pub fn set_minimum_fraction_digits(&self, digits: usize) {
+        unsafe {
+            let digits = digits as NSUInteger;
+            let _: () = msg_send![&*self.objc, setMinimumFractionDigits:digits];
+        }
+    }
+
+    /// Sets the maximum number of digits shown after the decimal separator. Input with more
+    /// digits is rounded.
+    pub fn set_maximum_fraction_digits(&self, digits: usize) {
+        unsafe {
+            let digits = digits as NSUInteger;
+            let _: () = msg_send![&*self.objc, setMaximumFractionDigits:digits];
+        }
+    }
+
+    /// Sets whether grouping separators (e.g, the commas in `1,000,000`) are shown.
+    pub fn set_uses_grouping_separator(&self, uses: bool) {
+        unsafe {
+            let _: () = msg_send![&*self.objc, setUsesGroupingSeparator:match uses {
+                true => YES,
+                false => NO
+            }];
+        }
+    }
+
+    /// Sets the ISO 4217 currency code (e.g, `"USD"`) used by the `Currency` style. This
+    /// defaults to the locale's currency.
+    pub fn set_currency_code(&self, code: &str) {
+        unsafe {
+            let code = NSString::new(code);
+            let _: () = msg_send![&*self.objc, setCurrencyCode:code.into_inner()];
+        }
+    }
+
+    /// Sets the locale to format for, by identifier (e.g, `"de_DE"`). This defaults to the
+    /// user's current locale.
+    pub fn set_locale(&self, identifier: &str) {
+        unsafe {
+            let identifier = NSString::new(identifier);
+            let locale: id = msg_send![class!(NSLocale), localeWithLocaleIdentifier:identifier.into_inner()];
+            let _: () = msg_send![&*self.objc, setLocale:locale];
+        }
+    }
+
+    /// Sets whether parsing is forgiving about input that doesn't quite match the format (e.g,
+    /// a currency amount typed without its symbol). This defaults to `false`.
+    pub fn set_lenient(&self, lenient: bool) {
+        unsafe {
+            let _: () = msg_send![&*self.objc, setLenient:match lenient {
+                true => YES,
+                false => NO
+            }];
+        }
+    }
+
+    /// Formats a number, as it would be displayed in a field.
+    pub fn format(&self, number: f64) -> String {
+        NSString::wrap(unsafe {
+            msg_send![&*self.objc, stringFromNumber:NSNumber::float(number).into_inner()]
+        }).to_string()
+    }
+}
+
+impl Formatter for NumberFormatter {
+    fn into_formatter(self) -> ShareId<Object> {
+        self.objc.clone()
+    }
+}
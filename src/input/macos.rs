@@ -7,52 +7,132 @@
 //! for in the modern era. It also implements a few helpers for things like setting a background
 //! color, and enforcing layer backing by default.
 
-use std::sync::Once;
-
-use objc::declare::ClassDecl;
 use objc::runtime::{Class, Object, Sel, BOOL};
-use objc::{class, sel, sel_impl};
-use objc_id::Id;
+use objc::{sel, sel_impl, msg_send};
 
-use crate::foundation::{id, YES, NO, NSUInteger};
-use crate::dragdrop::DragInfo;
-use crate::input::{TEXTFIELD_DELEGATE_PTR, TextFieldDelegate};
+use crate::foundation::{load_or_register_class, id, YES, NO, NSString};
+use crate::input::{TEXTFIELD_DELEGATE_PTR, TextFieldCommand, TextFieldDelegate, TextFieldKind, set_error_state};
 use crate::utils::load;
+use crate::panic::AddGuardedMethod;
+
+/// Returns the current value of the field.
+fn value_of(this: &Object) -> String {
+    NSString::wrap(unsafe {
+        msg_send![this, stringValue]
+    }).to_string()
+}
+
+/// Runs the delegate's validation against the current value, updating the error state to match.
+/// Returns whether the value passed.
+fn validate<T: TextFieldDelegate>(this: &Object, value: &str) -> bool {
+    let view = load::<T>(this, TEXTFIELD_DELEGATE_PTR);
+
+    match view.validate(value) {
+        Ok(()) => {
+            set_error_state(this, None);
+            true
+        },
+
+        Err(message) => {
+            set_error_state(this, Some(&message));
+            false
+        }
+    }
+}
+
+/// Called when the user is about to start editing.
+fn text_should_begin_editing<T: TextFieldDelegate>(this: &Object, _: Sel, _control: id, _editor: id) -> BOOL {
+    let view = load::<T>(this, TEXTFIELD_DELEGATE_PTR);
+
+    match view.text_should_begin_editing() {
+        true => YES,
+        false => NO
+    }
+}
+
+/// Called when the user has started editing.
+fn text_did_begin_editing<T: TextFieldDelegate>(this: &Object, _: Sel, _notification: id) {
+    let view = load::<T>(this, TEXTFIELD_DELEGATE_PTR);
+    view.text_did_begin_editing();
+}
+
+/// Called whenever the text changes; validates live, then passes the new value on.
+fn text_did_change<T: TextFieldDelegate>(this: &Object, _: Sel, _notification: id) {
+    let value = value_of(this);
+    validate::<T>(this, &value);
 
-/// Injects an `NSTextField` subclass. This is used for the default views that don't use delegates - we
-/// have separate classes here since we don't want to waste cycles on methods that will never be
-/// used if there's no delegates.
-pub(crate) fn register_view_class() -> *const Class {
-    static mut VIEW_CLASS: *const Class = 0 as *const Class;
-    static INIT: Once = Once::new();
+    let view = load::<T>(this, TEXTFIELD_DELEGATE_PTR);
+    view.text_did_change(&value);
+}
+
+/// Called when editing is about to end. Invalid values keep the user editing.
+fn text_should_end_editing<T: TextFieldDelegate>(this: &Object, _: Sel, _control: id, _editor: id) -> BOOL {
+    let value = value_of(this);
+
+    if !validate::<T>(this, &value) {
+        return NO;
+    }
 
-    INIT.call_once(|| unsafe {
-        let superclass = class!(NSTextField);
-        let decl = ClassDecl::new("RSTTextInputField", superclass).unwrap();
-        VIEW_CLASS = decl.register();
-    });
+    let view = load::<T>(this, TEXTFIELD_DELEGATE_PTR);
 
-    unsafe { VIEW_CLASS }
+    match view.text_should_end_editing(&value) {
+        true => YES,
+        false => NO
+    }
+}
+
+/// Called when the user has finished editing.
+fn text_did_end_editing<T: TextFieldDelegate>(this: &Object, _: Sel, _notification: id) {
+    let view = load::<T>(this, TEXTFIELD_DELEGATE_PTR);
+    view.text_did_end_editing(&value_of(this));
 }
 
-/// Injects an `NSTextField` subclass, with some callback and pointer ivars for what we
-/// need to do.
-pub(crate) fn register_view_class_with_delegate<T: TextFieldDelegate>() -> *const Class {
-    static mut VIEW_CLASS: *const Class = 0 as *const Class;
-    static INIT: Once = Once::new();
+/// Called when the field sends its action (see `TextFieldDelegate::text_did_submit()`).
+fn text_did_submit<T: TextFieldDelegate>(this: &Object, _: Sel, _sender: id) {
+    let view = load::<T>(this, TEXTFIELD_DELEGATE_PTR);
+    view.text_did_submit(&value_of(this));
+}
 
-    INIT.call_once(|| unsafe {
-        let superclass = class!(NSView);
-        let mut decl = ClassDecl::new("RSTTextInputFieldWithDelegate", superclass).unwrap();
+/// Maps the key commands we care about through to the delegate. Returning `YES` means the
+/// command was handled, and the field editor won't act on it.
+fn do_command_by_selector<T: TextFieldDelegate>(this: &Object, _: Sel, _control: id, _editor: id, command: Sel) -> BOOL {
+    let command = match command {
+        c if c == sel!(insertNewline:) => TextFieldCommand::Enter,
+        c if c == sel!(cancelOperation:) => TextFieldCommand::Escape,
+        c if c == sel!(insertTab:) => TextFieldCommand::Tab,
+        c if c == sel!(insertBacktab:) => TextFieldCommand::BackTab,
+        _ => { return NO; }
+    };
 
+    let view = load::<T>(this, TEXTFIELD_DELEGATE_PTR);
+
+    match view.text_command(command, &value_of(this)) {
+        true => YES,
+        false => NO
+    }
+}
+
+/// Injects a subclass of the `NSTextField` (sub)class for `kind`. This is used for the default
+/// views that don't use delegates - we have separate classes here since we don't want to waste
+/// cycles on methods that will never be used if there's no delegates.
+pub(crate) fn register_view_class(kind: TextFieldKind) -> *const Class {
+    load_or_register_class(kind.superclass_name(), "RSTTextInputField", |_| {})
+}
+
+/// Injects a subclass of the `NSTextField` (sub)class for `kind`, with some callback and pointer
+/// ivars for what we need to do. The field acts as its own delegate, and is its own target.
+pub(crate) fn register_view_class_with_delegate<T: TextFieldDelegate>(kind: TextFieldKind, instance: &T) -> *const Class {
+    load_or_register_class(kind.superclass_name(), instance.subclass_name(), |decl| unsafe {
         // A pointer to the "view controller" on the Rust side. It's expected that this doesn't
         // move.
-        decl.add_ivar::<usize>(TEXTFIELD_DELEGATE_PTR);        
-       
-        VIEW_CLASS = decl.register();
-    });
+        decl.add_ivar::<usize>(TEXTFIELD_DELEGATE_PTR);
 
-    unsafe {
-        VIEW_CLASS
-    }
+        decl.add_guarded_method(sel!(control:textShouldBeginEditing:), text_should_begin_editing::<T>);
+        decl.add_guarded_method(sel!(controlTextDidBeginEditing:), text_did_begin_editing::<T>);
+        decl.add_guarded_method(sel!(controlTextDidChange:), text_did_change::<T>);
+        decl.add_guarded_method(sel!(control:textShouldEndEditing:), text_should_end_editing::<T>);
+        decl.add_guarded_method(sel!(controlTextDidEndEditing:), text_did_end_editing::<T>);
+        decl.add_guarded_method(sel!(rstTextFieldAction:), text_did_submit::<T>);
+        decl.add_guarded_method(sel!(control:textView:doCommandBySelector:), do_command_by_selector::<T>);
+    })
 }
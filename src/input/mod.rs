@@ -40,14 +40,18 @@
 //!
 //! For more information on Autolayout, view the module or check out the examples folder.
 
+use std::time::{Duration, SystemTime, UNIX_EPOCH};
+
+use core_graphics::base::CGFloat;
 use objc_id::ShareId;
-use objc::runtime::{Class, Object};
-use objc::{msg_send, sel, sel_impl};
+use objc::runtime::{Class, Object, BOOL};
+use objc::{class, msg_send, sel, sel_impl};
 
-use crate::foundation::{id, nil, YES, NO, NSArray, NSInteger, NSString};
+use crate::foundation::{id, nil, to_bool, YES, NO, NSArray, NSInteger, NSNumber, NSString};
+use crate::defaults::Value;
 use crate::color::Color;
 use crate::layout::{Layout, LayoutAnchorX, LayoutAnchorY, LayoutAnchorDimension};
-use crate::text::{Font, TextAlign};
+use crate::text::{AttributedString, Font, TextAlign};
 
 #[cfg(target_os = "macos")]
 mod macos;
@@ -64,15 +68,21 @@ use ios::{register_view_class, register_view_class_with_delegate};
 //mod controller;
 //pub use controller::TextFieldController;
 
+mod enums;
+pub use enums::{TextFieldCommand, TextFieldKind};
+
 mod traits;
 pub use traits::TextFieldDelegate;
 
+pub mod formatter;
+use formatter::Formatter;
+
 pub(crate) static TEXTFIELD_DELEGATE_PTR: &str = "rstTextFieldDelegatePtr";
 
 /// A helper method for instantiating view classes and applying default settings to them.
-fn allocate_view(registration_fn: fn() -> *const Class) -> id { 
+fn allocate_view(class: *const Class) -> id { 
     unsafe {
-        let view: id = msg_send![registration_fn(), new];
+        let view: id = msg_send![class, new];
 
         let _: () = msg_send![view, setTranslatesAutoresizingMaskIntoConstraints:NO];
 
@@ -83,6 +93,35 @@ fn allocate_view(registration_fn: fn() -> *const Class) -> id {
     }
 }
 
+/// Puts a field into (or takes it out of) an error state. This is shared with the delegate
+/// callbacks, which drive it from `TextFieldDelegate::validate()`.
+pub(crate) fn set_error_state(view: &Object, message: Option<&str>) {
+    unsafe {
+        let layer: id = msg_send![view, layer];
+
+        match message {
+            Some(message) => {
+                let color = Color::SystemRed.into_platform_specific_color();
+                let cg: id = msg_send![color, CGColor];
+                let width: CGFloat = 1.;
+                let radius: CGFloat = 3.;
+                let _: () = msg_send![layer, setBorderColor:cg];
+                let _: () = msg_send![layer, setBorderWidth:width];
+                let _: () = msg_send![layer, setCornerRadius:radius];
+
+                let message = NSString::new(message);
+                let _: () = msg_send![view, setToolTip:message.into_inner()];
+            },
+
+            None => {
+                let width: CGFloat = 0.;
+                let _: () = msg_send![layer, setBorderWidth:width];
+                let _: () = msg_send![view, setToolTip:nil];
+            }
+        }
+    }
+}
+
 /// A clone-able handler to an `NSTextField/UITextField` reference in the 
 /// Objective-C runtime.
 #[derive(Debug)]
@@ -127,7 +166,27 @@ impl Default for TextField {
 impl TextField {
     /// Returns a default `TextField`, suitable for 
     pub fn new() -> Self {
-        let view = allocate_view(register_view_class);
+        TextField::of_kind(TextFieldKind::Plain)
+    }
+
+    /// Returns a password field, which masks what's typed into it.
+    pub fn secure() -> Self {
+        TextField::of_kind(TextFieldKind::Secure)
+    }
+
+    /// Returns a search field.
+    pub fn search() -> Self {
+        TextField::of_kind(TextFieldKind::Search)
+    }
+
+    /// Returns a token field.
+    pub fn token() -> Self {
+        TextField::of_kind(TextFieldKind::Token)
+    }
+
+    /// Returns a `TextField` of the given kind.
+    pub fn of_kind(kind: TextFieldKind) -> Self {
+        let view = allocate_view(register_view_class(kind));
 
         TextField {
             delegate: None,
@@ -148,14 +207,24 @@ impl<T> TextField<T> where T: TextFieldDelegate + 'static {
     /// Initializes a new TextField with a given `TextFieldDelegate`. This enables you to respond to events
     /// and customize the view as a module, similar to class-based systems.
     pub fn with(delegate: T) -> TextField<T> {
-        let delegate = Box::new(delegate);
+        TextField::with_kind(TextFieldKind::Plain, delegate)
+    }
+
+    /// Initializes a new TextField of the given kind (e.g, a search field), with a given
+    /// `TextFieldDelegate`.
+    pub fn with_kind(kind: TextFieldKind, delegate: T) -> TextField<T> {
+        let class = register_view_class_with_delegate::<T>(kind, &delegate);
+        let mut delegate = Box::new(delegate);
         
-        let label = allocate_view(register_view_class_with_delegate::<T>);
+        let label = allocate_view(class);
         unsafe {
             //let view: id = msg_send![register_view_class_with_delegate::<T>(), new];
             //let _: () = msg_send![view, setTranslatesAutoresizingMaskIntoConstraints:NO];
             let ptr: *const T = &*delegate;
             (&mut *label).set_ivar(TEXTFIELD_DELEGATE_PTR, ptr as usize);
+            let _: () = msg_send![label, setDelegate:label];
+            let _: () = msg_send![label, setTarget:label];
+            let _: () = msg_send![label, setAction:sel!(rstTextFieldAction:)];
         };
 
         let mut label = TextField {
@@ -171,7 +240,7 @@ impl<T> TextField<T> where T: TextFieldDelegate + 'static {
             objc: unsafe { ShareId::from_ptr(label) },
         };
 
-        //(&mut delegate).did_load(label.clone_as_handle()); 
+        (&mut delegate).did_load(label.clone_as_handle()); 
         label.delegate = Some(delegate);
         label
     }
@@ -206,6 +275,180 @@ impl<T> TextField<T> {
         value.to_str().to_string()
     }
 
+    /// Returns the field's value as a number, if it has one. With a `NumberFormatter` attached,
+    /// this is the parsed value; otherwise, the text is parsed as a plain number.
+    pub fn get_number(&self) -> Option<f64> {
+        let value: id = unsafe { msg_send![&*self.objc, objectValue] };
+
+        if value == nil {
+            return None;
+        }
+
+        if NSNumber::is(value) {
+            return Some(NSNumber::wrap(value).as_f64());
+        }
+
+        self.get_value().trim().parse().ok()
+    }
+
+    /// Returns the field's value as a date, if it has one. This requires a `DateFormatter`.
+    pub fn get_date(&self) -> Option<SystemTime> {
+        unsafe {
+            let value: id = msg_send![&*self.objc, objectValue];
+            let is_date: BOOL = match value == nil {
+                true => NO,
+                false => msg_send![value, isKindOfClass:class!(NSDate)]
+            };
+
+            if !to_bool(is_date) {
+                return None;
+            }
+
+            let interval: f64 = msg_send![value, timeIntervalSince1970];
+
+            Some(match interval >= 0. {
+                true => UNIX_EPOCH + Duration::from_secs_f64(interval),
+                false => UNIX_EPOCH - Duration::from_secs_f64(-interval)
+            })
+        }
+    }
+
+    /// Returns the field's underlying object value - e.g, whatever a `CustomFormatter` parsed
+    /// the input into.
+    pub fn get_object_value(&self) -> Option<Value> {
+        Value::from_objc(unsafe { msg_send![&*self.objc, objectValue] })
+    }
+
+    /// Sets the placeholder text, shown (dimmed) while the field is empty.
+    pub fn set_placeholder(&self, placeholder: &str) {
+        let s = NSString::new(placeholder);
+
+        unsafe {
+            let _: () = msg_send![&*self.objc, setPlaceholderString:s.into_inner()];
+        }
+    }
+
+    /// For search fields: sets whether the action is sent on every keystroke, rather than after
+    /// a short pause in typing. This defaults to `false`.
+    pub fn set_sends_search_string_immediately(&self, immediately: bool) {
+        unsafe {
+            let _: () = msg_send![&*self.objc, setSendsSearchStringImmediately:match immediately {
+                true => YES,
+                false => NO
+            }];
+        }
+    }
+
+    /// For search fields: sets whether the action is only sent when the user presses Enter,
+    /// rather than as they type. This defaults to `false`.
+    pub fn set_sends_whole_search_string(&self, whole: bool) {
+        unsafe {
+            let _: () = msg_send![&*self.objc, setSendsWholeSearchString:match whole {
+                true => YES,
+                false => NO
+            }];
+        }
+    }
+
+    /// For search fields: sets whether the cancel (clear) button is shown while there's text in
+    /// the field. This defaults to `true`.
+    pub fn set_shows_cancel_button(&self, shows: bool) {
+        unsafe {
+            let cell: id = msg_send![&*self.objc, cell];
+            let button: id = msg_send![cell, cancelButtonCell];
+            let _: () = msg_send![button, setTransparent:match shows {
+                true => NO,
+                false => YES
+            }];
+            let _: () = msg_send![button, setEnabled:match shows {
+                true => YES,
+                false => NO
+            }];
+        }
+    }
+
+    /// For search fields: keeps up to `maximum` recent searches, shown in a menu under the
+    /// magnifying glass. If `autosave_name` is given, they're saved to user defaults under it and
+    /// survive relaunches.
+    pub fn set_recent_searches(&self, maximum: usize, autosave_name: Option<&str>) {
+        unsafe {
+            // These tags tell AppKit where to put the recents (and related items) in the menu. The
+            // menu and its items each hold their own retain, so ours are released as we go.
+            let menu: id = msg_send![class!(NSMenu), new];
+            let items: [(&str, NSInteger); 4] = [
+                ("Recent Searches", 1000),
+                ("", 1001),
+                ("Clear Recent Searches", 1002),
+                ("No Recent Searches", 1003)
+            ];
+
+            for (title, tag) in items.iter() {
+                let title = NSString::new(title);
+                let empty = NSString::new("");
+                let item: id = msg_send![class!(NSMenuItem), alloc];
+                let item: id = msg_send![item, initWithTitle:title.into_inner() action:nil keyEquivalent:empty.into_inner()];
+                let _: () = msg_send![item, setTag:*tag];
+                let _: () = msg_send![menu, addItem:item];
+                let _: () = msg_send![item, release];
+            }
+
+            let maximum = maximum as NSInteger;
+            let _: () = msg_send![&*self.objc, setMaximumRecents:maximum];
+            let _: () = msg_send![&*self.objc, setSearchMenuTemplate:menu];
+            let _: () = msg_send![menu, release];
+
+            if let Some(name) = autosave_name {
+                let name = NSString::new(name);
+                let _: () = msg_send![&*self.objc, setRecentsAutosaveName:name.into_inner()];
+            }
+        }
+    }
+
+    /// For token fields: sets the characters that end a token as they're typed. AppKit defaults
+    /// to a comma.
+    pub fn set_tokenizing_characters(&self, characters: &str) {
+        unsafe {
+            let characters = NSString::new(characters);
+            let set: id = msg_send![class!(NSCharacterSet), characterSetWithCharactersInString:characters.into_inner()];
+            let _: () = msg_send![&*self.objc, setTokenizingCharacterSet:set];
+        }
+    }
+
+    /// For token fields: returns the current tokens.
+    pub fn get_tokens(&self) -> Vec<String> {
+        let value: id = unsafe { msg_send![&*self.objc, objectValue] };
+
+        if value == nil {
+            return Vec::new();
+        }
+
+        let is_array: BOOL = unsafe { msg_send![value, isKindOfClass:class!(NSArray)] };
+
+        match to_bool(is_array) {
+            true => NSArray::wrap(value).map(|token| NSString::wrap(token).to_string()),
+            false => Vec::new()
+        }
+    }
+
+    /// For token fields: replaces the current tokens.
+    pub fn set_tokens(&self, tokens: &[&str]) {
+        let tokens: NSArray = tokens.iter().map(|token| NSString::new(token).into_inner()).collect::<Vec<id>>().into();
+
+        unsafe {
+            let _: () = msg_send![&*self.objc, setObjectValue:tokens.into_inner()];
+        }
+    }
+
+    /// Attaches a formatter, which controls how the value is displayed and how input is parsed.
+    /// Input the formatter rejects can't be committed.
+    pub fn set_formatter<F: Formatter>(&self, formatter: F) {
+        let formatter = formatter.into_formatter();
+
+        unsafe {
+            let _: () = msg_send![&*self.objc, setFormatter:&*formatter];
+        }
+    }
+
     /// Call this to set the background color for the backing layer.
     pub fn set_background_color(&self, color: Color) {
         let bg = color.into_platform_specific_color();
@@ -226,6 +469,21 @@ impl<T> TextField<T> {
         }
     }
 
+    /// Sets styled text. This also lets the field keep attributes while it's being edited;
+    /// otherwise, AppKit strips them the moment editing begins.
+    pub fn set_attributed_text(&self, text: &AttributedString) {
+        unsafe {
+            #[cfg(target_os = "macos")]
+            {
+                let _: () = msg_send![&*self.objc, setAllowsEditingTextAttributes:YES];
+                let _: () = msg_send![&*self.objc, setAttributedStringValue:text.to_objc()];
+            }
+
+            #[cfg(target_os = "ios")]
+            let _: () = msg_send![&*self.objc, setAttributedText:text.to_objc()];
+        }
+    }
+
     pub fn set_text_alignment(&self, alignment: TextAlign) {
         unsafe {
             let alignment: NSInteger = alignment.into();
@@ -233,6 +491,13 @@ impl<T> TextField<T> {
         }
     }
 
+    /// Puts the field into an error state - a red border, with `message` as its tooltip - or
+    /// clears it, with `None`. Fields with a delegate manage this automatically, via
+    /// `TextFieldDelegate::validate()`.
+    pub fn set_error(&self, message: Option<&str>) {
+        set_error_state(&*self.objc, message);
+    }
+
     pub fn set_font(&self, font: &Font) {
         unsafe {
             let _: () = msg_send![&*self.objc, setFont:&*font.objc];
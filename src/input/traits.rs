@@ -1,4 +1,54 @@
-//! Various traits used for Labels.
+//! Various traits used for TextFields.
 
+use crate::input::{TextField, TextFieldCommand};
+
+#[allow(unused_variables)]
 pub trait TextFieldDelegate {
+    /// Used to cache subclass creations on the Objective-C side.
+    /// You can just set this to be the name of your view type. This
+    /// value *must* be unique per-type.
+    const NAME: &'static str;
+
+    /// You should rarely (read: probably never) need to implement this yourself.
+    /// It simply acts as a getter for the associated `NAME` const on this trait.
+    fn subclass_name(&self) -> &'static str {
+        Self::NAME
+    }
+
+    /// Called when the TextField is ready to work with. You're passed a `TextField` - this is
+    /// safe to store and use repeatedly, but it's not thread safe - any UI calls must be made
+    /// from the main thread!
+    fn did_load(&mut self, view: TextField) {}
+
+    /// Called when the user is about to start editing; return `false` to prevent it.
+    fn text_should_begin_editing(&self) -> bool { true }
+
+    /// Called when the user has started editing.
+    fn text_did_begin_editing(&self) {}
+
+    /// Called whenever the text changes while the user is editing it.
+    fn text_did_change(&self, value: &str) {}
+
+    /// Called when editing is about to end (e.g, focus is moving elsewhere); return `false` to
+    /// keep the user editing. This isn't called if `validate()` rejects the value.
+    fn text_should_end_editing(&self, value: &str) -> bool { true }
+
+    /// Called when the user has finished editing.
+    fn text_did_end_editing(&self, value: &str) {}
+
+    /// Called when the field sends its action: when the user presses Enter, for most fields.
+    /// Search fields send it as the user types - after a short pause by default, on every
+    /// keystroke with `TextField::set_sends_search_string_immediately()`, or only on Enter with
+    /// `TextField::set_sends_whole_search_string()`.
+    fn text_did_submit(&self, value: &str) {}
+
+    /// Called when the user presses Enter, Escape or Tab while editing. Return `true` if you've
+    /// handled the command, which stops the field from doing what it normally would (e.g,
+    /// moving focus on Tab).
+    fn text_command(&self, command: TextFieldCommand, value: &str) -> bool { false }
+
+    /// Validates the current value. This is called as the user types, and again before editing
+    /// ends. Returning an `Err` puts the field into an error state (see `TextField::set_error()`)
+    /// with the message as its tooltip, and keeps the user editing until it's fixed.
+    fn validate(&self, value: &str) -> Result<(), String> { Ok(()) }
 }
@@ -19,6 +19,7 @@ use block::{Block, ConcreteBlock, RcBlock};
 
 use crate::foundation::{id, nil, NSString};
 use crate::utils::load;
+use crate::panic::AddGuardedMethod;
 
 pub static ACTION_CALLBACK_PTR: &str = "rstTargetActionPtr";
 
@@ -78,7 +79,7 @@ impl TargetActionHandler {
 }
 
 /// This will fire for an NSButton callback.
-extern fn perform<F: Fn() + 'static>(this: &mut Object, _: Sel, _sender: id) {
+fn perform<F: Fn() + 'static>(this: &mut Object, _: Sel, _sender: id) {
     let action = load::<Action>(this, ACTION_CALLBACK_PTR);
     (action.0)();
 }
@@ -104,7 +105,7 @@ pub(crate) fn register_invoker_class<F: Fn() + 'static>() -> *const Class {
         let mut decl = ClassDecl::new("RSTTargetActionHandler", superclass).unwrap();
 
         decl.add_ivar::<usize>(ACTION_CALLBACK_PTR);
-        decl.add_method(sel!(perform:), perform::<F> as extern fn (&mut Object, _, id));
+        decl.add_guarded_method(sel!(perform:), perform::<F>);
         
         VIEW_CLASS = decl.register();
     });
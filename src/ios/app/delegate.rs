@@ -20,6 +20,7 @@ use crate::foundation::{id, nil, BOOL, YES, NO, NSUInteger, NSArray, NSString};
 
 use crate::ios::app::{AppDelegate, APP_DELEGATE};
 use crate::ios::scene::{SceneConfig, SceneConnectionOptions, SceneSession};
+use crate::panic::AddGuardedMethod;
 
 #[cfg(feature = "cloudkit")]
 use crate::cloudkit::share::CKShareMetaData;
@@ -35,12 +36,12 @@ fn app<T>(this: &Object) -> &T {
 }
 
 /// Fires when the Application Delegate receives a `applicationDidFinishLaunching` notification.
-extern fn did_finish_launching<T: AppDelegate>(this: &Object, _: Sel, _: id, _: id) -> BOOL {
+fn did_finish_launching<T: AppDelegate>(this: &Object, _: Sel, _: id, _: id) -> BOOL {
     app::<T>(this).did_finish_launching();
     YES
 }
 
-extern fn configuration_for_scene_session<T: AppDelegate>(this: &Object, _: Sel, _: id, session: id, opts: id) -> id {
+fn configuration_for_scene_session<T: AppDelegate>(this: &Object, _: Sel, _: id, session: id, opts: id) -> id {
     app::<T>(this).config_for_scene_session(
         SceneSession::with(session),
         SceneConnectionOptions::with(opts)
@@ -58,15 +59,15 @@ pub(crate) fn register_app_delegate_class<T: AppDelegate>() -> *const Class {
         let mut decl = ClassDecl::new("RSTAppDelegate", superclass).unwrap();
 
         // Launching Applications
-        decl.add_method(
+        decl.add_guarded_method(
             sel!(application:didFinishLaunchingWithOptions:),
-            did_finish_launching::<T> as extern fn(&Object, _, _, id) -> BOOL
+            did_finish_launching::<T>
         );
 
         // Scenes
-        decl.add_method(
+        decl.add_guarded_method(
             sel!(application:configurationForConnectingSceneSession:options:),
-            configuration_for_scene_session::<T> as extern fn(&Object, _, _, id, id) -> id
+            configuration_for_scene_session::<T>
         );
         /*decl.add_method(
             sel!(application:didDiscardSceneSessions:),
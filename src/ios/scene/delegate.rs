@@ -21,6 +21,7 @@ use crate::utils::load;
 
 use crate::ios::app::{SCENE_DELEGATE_VENDOR};
 use crate::ios::scene::{Scene, SceneConfig, SceneConnectionOptions, SceneSession, WindowSceneDelegate};
+use crate::panic::AddGuardedMethod;
 
 #[cfg(feature = "cloudkit")]
 use crate::cloudkit::share::CKShareMetaData;
@@ -28,7 +29,7 @@ use crate::cloudkit::share::CKShareMetaData;
 pub(crate) static WINDOW_SCENE_PTR: &str = "rstWindowSceneDelegatePtr";
 
 /// 
-extern fn init<
+fn init<
     T: WindowSceneDelegate,
     F: Fn() -> Box<T>
 >(this: &mut Object, _: Sel) -> id {
@@ -48,7 +49,7 @@ extern fn init<
     x
 }
 
-extern fn scene_will_connect_to_session_with_options<
+fn scene_will_connect_to_session_with_options<
     T: WindowSceneDelegate
 >(this: &Object, _: Sel, scene: id, session: id, options: id) {
     let delegate = load::<T>(this, WINDOW_SCENE_PTR);
@@ -81,12 +82,12 @@ pub(crate) fn register_window_scene_delegate_class<
         decl.add_protocol(p);
 
         // Override the `init` call to handle creating and attaching a WindowSceneDelegate.
-        decl.add_method(sel!(init), init::<T, F> as extern fn(&mut Object, _) -> id);
+        decl.add_guarded_method(sel!(init), init::<T, F>);
 
         // UIWindowSceneDelegate API
-        decl.add_method(
+        decl.add_guarded_method(
             sel!(scene:willConnectToSession:options:),
-            scene_will_connect_to_session_with_options::<T> as extern fn(&Object, _, _, _, _)
+            scene_will_connect_to_session_with_options::<T>
         );
 
         // Launching Applications       
@@ -90,11 +90,15 @@ pub mod button;
 #[cfg(feature = "cloudkit")]
 pub mod cloudkit;
 
+#[cfg(feature = "macos")]
+pub mod collectionview;
+
 pub mod color;
 pub mod dragdrop;
 pub mod error;
 pub mod events;
 pub mod defaults;
+pub(crate) mod executor;
 pub mod filesystem;
 pub mod foundation;
 pub mod geometry;
@@ -105,12 +109,29 @@ pub mod layout;
 pub mod listview;
 pub mod networking;
 pub mod notification_center;
+
+#[cfg(feature = "macos")]
+pub mod outlineview;
+
+pub mod panic;
 pub mod pasteboard;
 pub mod progress;
+pub mod routing;
 pub mod scrollview;
+pub mod slider;
+pub mod stepper;
 pub mod switch;
+
+#[cfg(feature = "macos")]
+pub mod tableview;
+
 pub mod text;
 
+#[cfg(feature = "macos")]
+pub mod textview;
+
+pub mod timer;
+
 #[cfg(feature = "quicklook")]
 pub mod quicklook;
 
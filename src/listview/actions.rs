@@ -7,6 +7,7 @@ use block::ConcreteBlock;
 use crate::color::Color;
 use crate::foundation::{id, NSString, NSUInteger};
 use crate::image::Image;
+use crate::panic::guard;
 
 /// Represents the "type" or "style" of row action. A `Regular` action is
 /// nothing special; do whatever you want. A `Destructive` action will have
@@ -53,11 +54,13 @@ impl RowAction {
     {
         let title = NSString::new(title);
         let block = ConcreteBlock::new(move |action: id, row: NSUInteger| {
-            let action = RowAction(unsafe {
-                Id::from_ptr(action)
+            guard(|| {
+                let action = RowAction(unsafe {
+                    Id::from_ptr(action)
+                });
+
+                handler(action, row as usize);
             });
-            
-            handler(action, row as usize);
         });
         let block = block.copy();
         let style = style as NSUInteger;
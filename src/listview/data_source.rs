@@ -0,0 +1,133 @@
+//! A snapshot-based data source for `ListView`, which figures out row changes for you.
+
+use std::cell::{Ref, RefCell};
+
+use crate::listview::{Changeset, Identifiable, IndexPath, ListView, RowAnimation};
+
+/// Holds the items backing a `ListView`, and animates the view whenever they're replaced.
+///
+/// Rather than computing which rows to insert, remove or reload by hand, keep one of these in
+/// your `ListViewDelegate`, read from it in `items_in_section()` and `item_for()`, and hand it new
+/// snapshots via `apply()`. Each data source drives a single section of the list (the first, by
+/// default); for a sectioned list, keep one per section.
+///
+/// ```rust,no_run
+/// use cacao::listview::{Identifiable, ListDataSource, ListView, RowAnimation};
+///
+/// #[derive(Clone, PartialEq)]
+/// struct Todo {
+///     id: u64,
+///     title: String
+/// }
+///
+/// impl Identifiable for Todo {
+///     type Id = u64;
+///     fn id(&self) -> u64 { self.id }
+/// }
+///
+/// fn refresh(view: &ListView, todos: &ListDataSource<Todo>, latest: Vec<Todo>) {
+///     todos.apply(view, latest, RowAnimation::Fade);
+/// }
+/// ```
+#[derive(Debug, Default)]
+pub struct ListDataSource<T> {
+    section: usize,
+    items: RefCell<Vec<T>>
+}
+
+impl<T> ListDataSource<T>
+where
+    T: Identifiable + PartialEq
+{
+    /// Creates a new data source with an initial snapshot. No view updates are performed for
+    /// this; call `ListView::reload()` if the view has already loaded.
+    pub fn new(items: Vec<T>) -> Self {
+        ListDataSource::for_section(0, items)
+    }
+
+    /// Creates a new data source that drives the given section of the list.
+    pub fn for_section(section: usize, items: Vec<T>) -> Self {
+        ListDataSource {
+            section: section,
+            items: RefCell::new(items)
+        }
+    }
+
+    /// Returns the section this data source drives.
+    pub fn section(&self) -> usize {
+        self.section
+    }
+
+    /// Returns the number of items in the current snapshot. Return this from
+    /// `ListViewDelegate::items_in_section()`.
+    pub fn len(&self) -> usize {
+        self.items.borrow().len()
+    }
+
+    /// Returns whether the current snapshot is empty.
+    pub fn is_empty(&self) -> bool {
+        self.items.borrow().is_empty()
+    }
+
+    /// Returns the item at `index` in the current snapshot, if there is one.
+    pub fn get(&self, index: usize) -> Option<Ref<'_, T>> {
+        let items = self.items.borrow();
+
+        match index < items.len() {
+            true => Some(Ref::map(items, |items| &items[index])),
+            false => None
+        }
+    }
+
+    /// Returns the current snapshot.
+    pub fn items(&self) -> Ref<'_, Vec<T>> {
+        self.items.borrow()
+    }
+
+    /// Replaces the current snapshot with `items` without touching the view. You'll need to
+    /// reload the view yourself.
+    pub fn replace(&self, items: Vec<T>) {
+        *self.items.borrow_mut() = items;
+    }
+
+    /// Replaces the current snapshot with `items`, and animates `view` to match: removed rows
+    /// animate out, new rows animate in, moved rows slide to their new position, and rows whose
+    /// contents changed are reloaded. Returns the changes that were applied.
+    pub fn apply<V>(&self, view: &ListView<V>, items: Vec<T>, animation: RowAnimation) -> Changeset {
+        let changes = Changeset::between(&self.items.borrow(), &items);
+
+        // The new snapshot needs to be in place before touching the view, as the view will call
+        // back into the delegate (and thus, here) for row counts and cells.
+        self.replace(items);
+
+        if changes.is_empty() {
+            return changes;
+        }
+
+        let section = self.section;
+        let path = |row: usize| IndexPath::new(section, row);
+
+        if !changes.deletes.is_empty() || !changes.moves.is_empty() || !changes.inserts.is_empty() {
+            view.perform_batch_updates(|view| {
+                if !changes.deletes.is_empty() {
+                    view.remove_rows(changes.deletes.iter().map(|row| path(*row)), animation);
+                }
+
+                for (from, to) in &changes.moves {
+                    view.move_row(path(*from), path(*to));
+                }
+
+                if !changes.inserts.is_empty() {
+                    view.insert_rows(changes.inserts.iter().map(|row| path(*row)), animation);
+                }
+            });
+        }
+
+        if !changes.reloads.is_empty() {
+            let reloads: Vec<IndexPath> = changes.reloads.iter().map(|row| path(*row)).collect();
+            view.reload_rows(&reloads);
+        }
+
+        changes
+    }
+}
@@ -0,0 +1,381 @@
+//! A pure-Rust diff between two snapshots of list items, producing the row operations needed to
+//! animate from one to the other.
+
+use std::collections::HashMap;
+use std::hash::Hash;
+
+/// Implemented by items that have a stable identity across snapshots. Two items with the same
+/// `id()` are considered to be the same row (possibly with updated contents, which `PartialEq`
+/// determines), even if they've moved.
+pub trait Identifiable {
+    /// The type of the identifier; commonly an integer or string key.
+    type Id: Eq + Hash;
+
+    /// Returns this item's identifier. Identifiers should be unique within a snapshot.
+    fn id(&self) -> Self::Id;
+}
+
+/// The row operations needed to go from one snapshot to another.
+///
+/// As `NSTableView` applies updates sequentially (rather than all at once, like `UITableView`),
+/// these are meant to be applied in order:
+///
+/// 1. Remove `deletes` (indexes into the old snapshot).
+/// 2. Apply each of `moves` in turn, as `(from, to)` pairs. Each is relative to the list as it
+///    stands after the previous step - i.e, remove the row at `from`, then insert it at `to`.
+/// 3. Insert `inserts` (indexes into the new snapshot).
+/// 4. Reload `reloads` (indexes into the new snapshot), which are rows that kept their identity
+///    but whose contents changed.
+///
+/// `ListDataSource` handles all of this for you.
+#[derive(Clone, Debug, Default, PartialEq)]
+pub struct Changeset {
+    /// Rows to remove, as indexes into the old snapshot, in ascending order.
+    pub deletes: Vec<usize>,
+
+    /// Rows to insert, as indexes into the new snapshot, in ascending order.
+    pub inserts: Vec<usize>,
+
+    /// Rows to move, applied in order after deletes; see the type-level docs.
+    pub moves: Vec<(usize, usize)>,
+
+    /// Rows to reload, as indexes into the new snapshot, in ascending order.
+    pub reloads: Vec<usize>
+}
+
+impl Changeset {
+    /// Computes the changes needed to go from `old` to `new`.
+    ///
+    /// Moves are kept to a minimum: rows that form the longest run still in the same relative
+    /// order stay put, and everything else is moved around them. If identifiers aren't unique,
+    /// the first occurrence in `old` is matched with the first in `new`, and any further
+    /// duplicates are treated as deletes and inserts respectively.
+    pub fn between<T: Identifiable + PartialEq>(old: &[T], new: &[T]) -> Self {
+        let mut old_positions: HashMap<T::Id, usize> = HashMap::with_capacity(old.len());
+        for (index, item) in old.iter().enumerate() {
+            old_positions.entry(item.id()).or_insert(index);
+        }
+
+        // For every new item, the index in `old` that it came from, if any.
+        let mut matched = vec![false; old.len()];
+        let sources: Vec<Option<usize>> = new.iter().map(|item| {
+            let source = old_positions.get(&item.id()).copied().filter(|&index| !matched[index]);
+
+            if let Some(index) = source {
+                matched[index] = true;
+            }
+
+            source
+        }).collect();
+
+        let deletes = (0..old.len()).filter(|&index| !matched[index]).collect();
+
+        let inserts = sources.iter().enumerate()
+            .filter(|(_, source)| source.is_none())
+            .map(|(index, _)| index)
+            .collect();
+
+        let reloads = sources.iter().enumerate()
+            .filter(|(index, source)| match source {
+                Some(old_index) => old[*old_index] != new[*index],
+                None => false
+            })
+            .map(|(index, _)| index)
+            .collect();
+
+        Changeset {
+            deletes: deletes,
+            inserts: inserts,
+            moves: moves(&matched, &sources),
+            reloads: reloads
+        }
+    }
+
+    /// Returns whether there's nothing to do.
+    pub fn is_empty(&self) -> bool {
+        self.deletes.is_empty() && self.inserts.is_empty() && self.moves.is_empty() && self.reloads.is_empty()
+    }
+}
+
+/// Computes the sequential moves that reorder the surviving rows (post-delete, pre-insert) from
+/// their old order into their new order.
+fn moves(matched: &[bool], sources: &[Option<usize>]) -> Vec<(usize, usize)> {
+    // Map old indexes to their position once deleted rows are gone...
+    let mut compacted = vec![0; matched.len()];
+    let mut count = 0;
+    for (index, is_matched) in matched.iter().enumerate() {
+        if *is_matched {
+            compacted[index] = count;
+            count += 1;
+        }
+    }
+
+    // ...and then list those positions in the order the rows should end up in.
+    let target: Vec<usize> = sources.iter()
+        .filter_map(|source| source.map(|index| compacted[index]))
+        .collect();
+
+    let stable = longest_increasing_subsequence(&target);
+    let mut working: Vec<usize> = (0..target.len()).collect();
+    let mut moves = vec![];
+
+    // Place each out-of-order row directly after the row that precedes it in the target order.
+    // Working left to right, everything before it is already in its final relative order.
+    for (index, item) in target.iter().enumerate() {
+        if stable[index] {
+            continue;
+        }
+
+        let from = working.iter().position(|x| x == item).unwrap();
+        working.remove(from);
+
+        let to = match index {
+            0 => 0,
+            _ => working.iter().position(|x| *x == target[index - 1]).unwrap() + 1
+        };
+        working.insert(to, *item);
+
+        if from != to {
+            moves.push((from, to));
+        }
+    }
+
+    moves
+}
+
+/// Returns a mask marking the members of one longest strictly increasing subsequence of `values`.
+fn longest_increasing_subsequence(values: &[usize]) -> Vec<bool> {
+    // `tails[k]` holds the index of the smallest value that ends an increasing run of length
+    // `k + 1`; `previous` links each index back to the one before it in its run.
+    let mut tails: Vec<usize> = vec![];
+    let mut previous: Vec<Option<usize>> = vec![None; values.len()];
+
+    for (index, value) in values.iter().enumerate() {
+        let position = match tails.binary_search_by(|&tail| values[tail].cmp(value)) {
+            Ok(position) | Err(position) => position
+        };
+
+        if position > 0 {
+            previous[index] = Some(tails[position - 1]);
+        }
+
+        if position == tails.len() {
+            tails.push(index);
+        } else {
+            tails[position] = index;
+        }
+    }
+
+    let mut mask = vec![false; values.len()];
+    let mut current = tails.last().copied();
+
+    while let Some(index) = current {
+        mask[index] = true;
+        current = previous[index];
+    }
+
+    mask
+}
+
+#[cfg(test)]
+mod tests {
+    use super::{Changeset, Identifiable};
+
+    #[derive(Clone, Debug, PartialEq)]
+    struct Item {
+        id: u32,
+        title: &'static str
+    }
+
+    impl Identifiable for Item {
+        type Id = u32;
+
+        fn id(&self) -> u32 {
+            self.id
+        }
+    }
+
+    /// Items with the given ids, all with the same contents.
+    fn items(ids: &[u32]) -> Vec<Item> {
+        ids.iter().map(|&id| Item { id: id, title: "" }).collect()
+    }
+
+    /// Applies a changeset to `old` the way `ListDataSource` does, returning the resulting ids.
+    fn apply(old: &[Item], new: &[Item], changeset: &Changeset) -> Vec<u32> {
+        let mut rows: Vec<u32> = old.iter().map(|item| item.id).collect();
+
+        for &index in changeset.deletes.iter().rev() {
+            rows.remove(index);
+        }
+
+        for &(from, to) in &changeset.moves {
+            let row = rows.remove(from);
+            rows.insert(to, row);
+        }
+
+        for &index in &changeset.inserts {
+            rows.insert(index, new[index].id);
+        }
+
+        rows
+    }
+
+    /// Diffs `old` against `new`, checks that applying the result gets from one to the other, and
+    /// returns it.
+    fn diff(old: &[Item], new: &[Item]) -> Changeset {
+        let changeset = Changeset::between(old, new);
+        let expected: Vec<u32> = new.iter().map(|item| item.id).collect();
+        assert_eq!(apply(old, new, &changeset), expected, "{:?}", changeset);
+        changeset
+    }
+
+    #[test]
+    fn identical_snapshots_are_empty() {
+        assert!(diff(&items(&[]), &items(&[])).is_empty());
+        assert!(diff(&items(&[1, 2, 3]), &items(&[1, 2, 3])).is_empty());
+    }
+
+    #[test]
+    fn empty_to_non_empty_inserts_everything() {
+        let changeset = diff(&items(&[]), &items(&[1, 2, 3]));
+
+        assert_eq!(changeset.inserts, vec![0, 1, 2]);
+        assert!(changeset.deletes.is_empty());
+        assert!(changeset.moves.is_empty());
+    }
+
+    #[test]
+    fn non_empty_to_empty_deletes_everything() {
+        let changeset = diff(&items(&[1, 2, 3]), &items(&[]));
+
+        assert_eq!(changeset.deletes, vec![0, 1, 2]);
+        assert!(changeset.inserts.is_empty());
+        assert!(changeset.moves.is_empty());
+    }
+
+    #[test]
+    fn moving_one_row_is_a_single_move() {
+        let changeset = diff(&items(&[1, 2, 3, 4]), &items(&[2, 3, 4, 1]));
+
+        assert_eq!(changeset.moves.len(), 1);
+        assert!(changeset.deletes.is_empty());
+        assert!(changeset.inserts.is_empty());
+        assert!(changeset.reloads.is_empty());
+    }
+
+    #[test]
+    fn swapping_two_rows_is_a_single_move() {
+        let changeset = diff(&items(&[1, 2, 3]), &items(&[1, 3, 2]));
+        assert_eq!(changeset.moves.len(), 1);
+    }
+
+    #[test]
+    fn reversing_moves_all_but_one_row() {
+        let changeset = diff(&items(&[1, 2, 3, 4, 5]), &items(&[5, 4, 3, 2, 1]));
+        assert_eq!(changeset.moves.len(), 4);
+    }
+
+    #[test]
+    fn moves_mixed_with_inserts_and_deletes() {
+        let changeset = diff(&items(&[1, 2, 3, 4, 5]), &items(&[6, 4, 1, 3, 7]));
+
+        assert_eq!(changeset.deletes, vec![1, 4]);
+        assert_eq!(changeset.inserts, vec![0, 4]);
+        assert_eq!(changeset.moves.len(), 1);
+    }
+
+    #[test]
+    fn changed_contents_with_the_same_id_reload() {
+        let old = vec![Item { id: 1, title: "a" }, Item { id: 2, title: "b" }, Item { id: 3, title: "c" }];
+        let new = vec![Item { id: 1, title: "a" }, Item { id: 2, title: "B" }, Item { id: 3, title: "c" }];
+        let changeset = diff(&old, &new);
+
+        assert_eq!(changeset.reloads, vec![1]);
+        assert!(changeset.deletes.is_empty());
+        assert!(changeset.inserts.is_empty());
+        assert!(changeset.moves.is_empty());
+    }
+
+    #[test]
+    fn reloads_are_indexed_into_the_new_snapshot() {
+        let old = vec![Item { id: 1, title: "a" }, Item { id: 2, title: "b" }];
+        let new = vec![Item { id: 3, title: "c" }, Item { id: 2, title: "B" }, Item { id: 1, title: "a" }];
+        let changeset = diff(&old, &new);
+
+        assert_eq!(changeset.reloads, vec![1]);
+        assert_eq!(changeset.inserts, vec![0]);
+    }
+
+    #[test]
+    fn duplicate_ids_after_the_first_are_deleted_and_inserted() {
+        let changeset = diff(&items(&[1, 1, 2]), &items(&[1, 2, 1, 1]));
+
+        assert_eq!(changeset.deletes, vec![1]);
+        assert_eq!(changeset.inserts, vec![2, 3]);
+        assert!(changeset.moves.is_empty());
+    }
+
+    #[test]
+    fn every_permutation_of_a_small_list_round_trips() {
+        let old = items(&[1, 2, 3, 4, 5]);
+        let mut ids = vec![1, 2, 3, 4, 5];
+
+        // Heap's algorithm, iteratively.
+        let mut counters = vec![0; ids.len()];
+        let mut index = 0;
+        diff(&old, &items(&ids));
+
+        while index < ids.len() {
+            if counters[index] < index {
+                match index % 2 {
+                    0 => ids.swap(0, index),
+                    _ => ids.swap(counters[index], index)
+                }
+
+                let changeset = diff(&old, &items(&ids));
+                assert!(changeset.deletes.is_empty() && changeset.inserts.is_empty());
+
+                counters[index] += 1;
+                index = 0;
+            } else {
+                counters[index] = 0;
+                index += 1;
+            }
+        }
+    }
+
+    #[test]
+    fn mixed_edits_round_trip() {
+        // A small deterministic generator, so that this doesn't need a `rand` dependency.
+        let mut seed: u32 = 0x2545_f491;
+        let mut next = move |bound: u32| {
+            seed ^= seed << 13;
+            seed ^= seed >> 17;
+            seed ^= seed << 5;
+            seed % bound
+        };
+
+        for _ in 0..200 {
+            let old: Vec<u32> = (0..12).filter(|_| next(3) != 0).collect();
+            let mut new: Vec<u32> = old.iter().copied().filter(|_| next(4) != 0).collect();
+
+            for id in 12..16 {
+                if next(2) == 0 {
+                    let at = next(new.len() as u32 + 1) as usize;
+                    new.insert(at, id);
+                }
+            }
+
+            for _ in 0..3 {
+                if new.len() > 1 {
+                    let from = next(new.len() as u32) as usize;
+                    let to = next(new.len() as u32) as usize;
+                    let id = new.remove(from);
+                    new.insert(to, id);
+                }
+            }
+
+            diff(&items(&old), &items(&new));
+        }
+    }
+}
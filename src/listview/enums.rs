@@ -3,6 +3,7 @@ use crate::foundation::{NSInteger, NSUInteger};
 /// This enum represents the different stock animations possible
 /// for ListView row operations. You can pass it to `insert_rows`
 /// and `remove_rows` - reloads don't get animations.
+#[derive(Copy, Clone, Debug)]
 pub enum RowAnimation {
     /// No animation.
     None,
@@ -41,6 +42,67 @@ impl Into<NSUInteger> for RowAnimation {
     }
 }
 
+/// Controls how many rows a user can select in a `ListView`.
+#[derive(Copy, Clone, Debug, PartialEq)]
+pub enum SelectionMode {
+    /// Rows can't be selected.
+    None,
+
+    /// At most one row can be selected at a time. This is the default.
+    Single,
+
+    /// Any number of rows can be selected, using the standard Shift and Command modifiers.
+    Multiple
+}
+
+impl From<SelectionMode> for NSUInteger {
+    fn from(mode: SelectionMode) -> Self {
+        match mode {
+            SelectionMode::None => 0,
+            SelectionMode::Single => 1,
+            SelectionMode::Multiple => 2
+        }
+    }
+}
+
+impl From<NSUInteger> for SelectionMode {
+    fn from(mode: NSUInteger) -> Self {
+        match mode {
+            0 => SelectionMode::None,
+            2 => SelectionMode::Multiple,
+            _ => SelectionMode::Single
+        }
+    }
+}
+
+/// Where a proposed drop would land, relative to the row it targets.
+#[derive(Copy, Clone, Debug, PartialEq)]
+pub enum DropPosition {
+    /// The drop is onto the row itself.
+    On,
+
+    /// The drop is between rows, immediately above the row.
+    Above
+}
+
+impl From<DropPosition> for NSInteger {
+    fn from(position: DropPosition) -> Self {
+        match position {
+            DropPosition::On => 0,
+            DropPosition::Above => 1
+        }
+    }
+}
+
+impl From<NSInteger> for DropPosition {
+    fn from(position: NSInteger) -> Self {
+        match position {
+            0 => DropPosition::On,
+            _ => DropPosition::Above
+        }
+    }
+}
+
 #[derive(Debug)]
 pub enum RowEdge {
     Leading,
@@ -0,0 +1,122 @@
+//! Caches row heights for a `ListView`, so that the delegate is only asked for each row's height
+//! once - `NSTableView` asks for heights a *lot* (e.g, every time it re-tiles), and for large
+//! lists that adds up quickly.
+
+use std::collections::HashMap;
+
+use core_graphics::base::CGFloat;
+
+/// Heights for (flat) table rows, along with the estimate used for rows that haven't been
+/// measured yet.
+#[derive(Debug, Default)]
+pub(crate) struct HeightCache {
+    heights: HashMap<usize, CGFloat>,
+    estimate: Option<CGFloat>
+}
+
+impl HeightCache {
+    /// Returns the cached height for `row`, if there is one.
+    pub fn get(&self, row: usize) -> Option<CGFloat> {
+        self.heights.get(&row).copied()
+    }
+
+    /// Caches the height for `row`.
+    pub fn insert(&mut self, row: usize, height: CGFloat) {
+        self.heights.insert(row, height);
+    }
+
+    /// Drops the cached heights for the given rows.
+    pub fn invalidate(&mut self, rows: &[usize]) {
+        for row in rows {
+            self.heights.remove(row);
+        }
+    }
+
+    /// Accounts for a row being inserted at `row`: cached heights from there on move down a row.
+    pub fn insert_row(&mut self, row: usize) {
+        self.heights = self.heights.drain()
+            .map(|(cached, height)| match cached >= row {
+                true => (cached + 1, height),
+                false => (cached, height)
+            })
+            .collect();
+    }
+
+    /// Accounts for `row` being removed: its cached height is dropped, and those after it move up
+    /// a row.
+    pub fn remove_row(&mut self, row: usize) {
+        self.heights.remove(&row);
+
+        self.heights = self.heights.drain()
+            .map(|(cached, height)| match cached > row {
+                true => (cached - 1, height),
+                false => (cached, height)
+            })
+            .collect();
+    }
+
+    /// Drops every cached height. The estimate is kept.
+    pub fn clear(&mut self) {
+        self.heights.clear();
+    }
+
+    /// Returns the estimated row height, if one has been set.
+    pub fn estimate(&self) -> Option<CGFloat> {
+        self.estimate
+    }
+
+    /// Sets (or clears) the estimated row height.
+    pub fn set_estimate(&mut self, estimate: Option<CGFloat>) {
+        self.estimate = estimate;
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::HeightCache;
+
+    fn cache(heights: &[(usize, f64)]) -> HeightCache {
+        let mut cache = HeightCache::default();
+
+        for (row, height) in heights {
+            cache.insert(*row, *height as _);
+        }
+
+        cache
+    }
+
+    #[test]
+    fn inserting_a_row_shifts_later_heights_down() {
+        let mut cache = cache(&[(0, 10.), (1, 20.), (2, 30.)]);
+        cache.insert_row(1);
+
+        assert_eq!(cache.get(0), Some(10.));
+        assert_eq!(cache.get(1), None);
+        assert_eq!(cache.get(2), Some(20.));
+        assert_eq!(cache.get(3), Some(30.));
+    }
+
+    #[test]
+    fn removing_a_row_drops_it_and_shifts_later_heights_up() {
+        let mut cache = cache(&[(0, 10.), (1, 20.), (2, 30.)]);
+        cache.remove_row(1);
+
+        assert_eq!(cache.get(0), Some(10.));
+        assert_eq!(cache.get(1), Some(30.));
+        assert_eq!(cache.get(2), None);
+    }
+
+    #[test]
+    fn invalidate_and_clear_keep_the_estimate() {
+        let mut cache = cache(&[(0, 10.), (1, 20.)]);
+        cache.set_estimate(Some(44.));
+
+        cache.invalidate(&[0]);
+        assert_eq!(cache.get(0), None);
+        assert_eq!(cache.get(1), Some(20.));
+
+        cache.clear();
+        assert_eq!(cache.get(1), None);
+        assert_eq!(cache.estimate(), Some(44.));
+    }
+}
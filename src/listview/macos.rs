@@ -7,40 +7,61 @@
 //! for in the modern era. It also implements a few helpers for things like setting a background
 //! color, and enforcing layer backing by default.
 
+use std::cell::RefCell;
+use std::ops::Range;
 use std::sync::Once;
 
+use core_graphics::base::CGFloat;
+
 use objc::declare::ClassDecl;
 use objc::runtime::{Class, Object, Sel, BOOL};
 use objc::{class, sel, sel_impl, msg_send};
 use objc_id::Id;
 
-use crate::foundation::{load_or_register_class, id, YES, NO, NSArray, NSInteger, NSUInteger};
-use crate::dragdrop::DragInfo;
+use crate::foundation::{load_or_register_class, id, nil, to_bool, YES, NO, NSArray, NSInteger, NSRange, NSRect, NSSize, NSString, NSUInteger};
+use crate::dragdrop::{DragInfo, DragOperation};
 use crate::listview::{
-    LISTVIEW_DELEGATE_PTR, LISTVIEW_CELL_VENDOR_PTR,
-    ListViewDelegate, RowEdge
+    LISTVIEW_DELEGATE_PTR, LISTVIEW_CELL_VENDOR_PTR, LISTVIEW_SECTIONS_PTR, LISTVIEW_SELECTION_MODE,
+    LISTVIEW_REORDERING, LISTVIEW_ROW_DRAG_TYPE, DropPosition, IndexPath, ListViewDelegate, RowEdge,
+    SelectionMode, index_set_to_vec
 };
+use crate::listview::prefetch::PrefetchState;
+use crate::pasteboard::PasteboardItem;
+use crate::listview::sections::{RowKind, SectionLayout};
 use crate::utils::load;
+use crate::panic::{guard, AddGuardedMethod};
 
-/// Determines the number of items by way of the backing data source (the Rust struct).
-extern fn number_of_items<T: ListViewDelegate>(
+/// Determines the number of rows from the section layout. AppKit asks for this often, so the
+/// delegate is only queried (and headers and footers vended) when the layout has been marked as
+/// out of date - i.e, on the first load, and after `ListView::reload()`.
+fn number_of_items<T: ListViewDelegate>(
     this: &Object,
     _: Sel,
     _: id
 ) -> NSInteger {
-    let view = load::<T>(this, LISTVIEW_DELEGATE_PTR);
-    view.number_of_items() as NSInteger
+    let sections = load::<RefCell<SectionLayout>>(this, LISTVIEW_SECTIONS_PTR);
+
+    let needs_rebuild = sections.borrow().needs_rebuild();
+    if needs_rebuild {
+        let view = load::<T>(this, LISTVIEW_DELEGATE_PTR);
+        let layout = SectionLayout::query(view);
+        return sections.borrow_mut().rebuild(layout) as NSInteger;
+    }
+
+    let rows = sections.borrow().total_rows();
+    rows as NSInteger
 }
 
-extern fn view_for_column<T: ListViewDelegate>(
+fn view_for_column<T: ListViewDelegate>(
     this: &Object,
     _: Sel,
     _table_view: id,
     _: id,
-    item: NSInteger
+    row: NSInteger
 ) -> id {
     let view = load::<T>(this, LISTVIEW_DELEGATE_PTR);
-    let item = view.item_for(item as usize);
+    let sections = load::<RefCell<SectionLayout>>(this, LISTVIEW_SECTIONS_PTR);
+    let kind = sections.borrow().kind_for_row(row as usize);
 
     // A hacky method of returning the underlying pointer
     // without Rust annoying us.
@@ -51,13 +72,171 @@ extern fn view_for_column<T: ListViewDelegate>(
     //
     // @TODO: Finish investing the `Rc` approach, might be able to just take
     // ownership and rely on Rust being correct.
-    let objc = item.objc.borrow();
-    unsafe {
-        msg_send![&**objc, self]
+    match kind {
+        Some(RowKind::Item(index)) => {
+            let item = view.item_for(index);
+            let objc = item.objc.borrow();
+
+            unsafe {
+                msg_send![&**objc, self]
+            }
+        },
+
+        Some(RowKind::Header(section)) => match sections.borrow().header(section) {
+            Some(header) => unsafe { msg_send![&**header.objc.borrow(), self] },
+            None => nil
+        },
+
+        Some(RowKind::Footer(section)) => match sections.borrow().footer(section) {
+            Some(footer) => unsafe { msg_send![&**footer.objc.borrow(), self] },
+            None => nil
+        },
+
+        Some(RowKind::LoadMore) => match sections.borrow().load_more() {
+            Some(footer) => unsafe { msg_send![&**footer, self] },
+            None => nil
+        },
+
+        None => nil
+    }
+}
+
+/// Called when a row view is added to the table. When it's the "load more" footer coming into
+/// view, the delegate is asked to load more.
+fn did_add_row_view<T: ListViewDelegate>(
+    this: &Object,
+    _: Sel,
+    _table_view: id,
+    _row_view: id,
+    row: NSInteger
+) {
+    let sections = load::<RefCell<SectionLayout>>(this, LISTVIEW_SECTIONS_PTR);
+    let kind = sections.borrow().kind_for_row(row as usize);
+
+    if let Some(RowKind::LoadMore) = kind {
+        let view = load::<T>(this, LISTVIEW_DELEGATE_PTR);
+        view.load_more();
+    }
+}
+
+/// Called whenever the list scrolls; moves the prefetch window along with the visible rows, and
+/// lets the delegate know which items have come into (and gone out of) range.
+pub(crate) fn did_scroll<T: ListViewDelegate>(this: &Object, state: &RefCell<PrefetchState>) {
+    guard(|| {
+        let visible: Range<usize> = unsafe {
+            let rect: NSRect = msg_send![this, visibleRect];
+            let range: NSRange = msg_send![this, rowsInRect:rect];
+            range.into()
+        };
+
+        let (prefetch, cancel) = {
+            let sections = load::<RefCell<SectionLayout>>(this, LISTVIEW_SECTIONS_PTR);
+            let sections = sections.borrow();
+            let (prefetch, cancel) = state.borrow_mut().update(visible, sections.total_rows());
+
+            let items = |rows: Vec<usize>| -> Vec<IndexPath> {
+                rows.into_iter().filter_map(|row| match sections.kind_for_row(row) {
+                    Some(RowKind::Item(index)) => Some(index),
+                    _ => None
+                }).collect()
+            };
+
+            (items(prefetch), items(cancel))
+        };
+
+        let view = load::<T>(this, LISTVIEW_DELEGATE_PTR);
+
+        if !cancel.is_empty() {
+            view.cancel_prefetch(cancel);
+        }
+
+        if !prefetch.is_empty() {
+            view.prefetch_rows(prefetch);
+        }
+    })
+}
+
+/// Returns the height a view would like to be, going by its constraints.
+fn fitting_height(view: &Object) -> Option<CGFloat> {
+    let size: NSSize = unsafe { msg_send![view, fittingSize] };
+
+    match size.height > 0. {
+        true => Some(size.height),
+        false => None
+    }
+}
+
+/// Returns the height for a row. With automatic row heights, `NSTableView` measures rows itself
+/// and only uses this as an estimate until it has; otherwise, heights come from the delegate
+/// (for items) or the view's constraints (for everything else), and are cached.
+fn height_of_row<T: ListViewDelegate>(
+    this: &Object,
+    _: Sel,
+    _table_view: id,
+    row: NSInteger
+) -> CGFloat {
+    let row = row as usize;
+    let view = load::<T>(this, LISTVIEW_DELEGATE_PTR);
+    let sections = load::<RefCell<SectionLayout>>(this, LISTVIEW_SECTIONS_PTR);
+
+    let (automatic, default): (BOOL, CGFloat) = unsafe {
+        (msg_send![this, usesAutomaticRowHeights], msg_send![this, rowHeight])
+    };
+
+    let (kind, cached, estimate) = {
+        let sections = sections.borrow();
+        (sections.kind_for_row(row), sections.heights().get(row), sections.heights().estimate())
+    };
+
+    if to_bool(automatic) {
+        let estimated = match kind {
+            Some(RowKind::Item(index)) => view.estimated_height_for_row(index),
+            _ => None
+        };
+
+        return estimated.or(estimate).unwrap_or(default);
+    }
+
+    if let Some(height) = cached {
+        return height;
+    }
+
+    let height = match kind {
+        Some(RowKind::Item(index)) => view.height_for_row(index),
+
+        Some(RowKind::Header(section)) => sections.borrow().header(section)
+            .and_then(|header| fitting_height(&**header.objc.borrow())),
+
+        Some(RowKind::Footer(section)) => sections.borrow().footer(section)
+            .and_then(|footer| fitting_height(&**footer.objc.borrow())),
+
+        Some(RowKind::LoadMore) => sections.borrow().load_more()
+            .and_then(|footer| fitting_height(&**footer)),
+
+        None => None
+    }.unwrap_or(default);
+
+    sections.borrow_mut().heights_mut().insert(row, height);
+    height
+}
+
+/// Marks section headers as group rows, which gives them the group row style and lets them float
+/// at the top of the list.
+fn is_group_row<T: ListViewDelegate>(
+    this: &Object,
+    _: Sel,
+    _table_view: id,
+    row: NSInteger
+) -> BOOL {
+    let sections = load::<RefCell<SectionLayout>>(this, LISTVIEW_SECTIONS_PTR);
+
+    match sections.borrow().kind_for_row(row as usize) {
+        Some(RowKind::Header(_)) => YES,
+        _ => NO
     }
 }
 
-extern fn row_actions_for_row<T: ListViewDelegate>(
+fn row_actions_for_row<T: ListViewDelegate>(
     this: &Object,
     _: Sel,
     _table_view: id,
@@ -66,46 +245,356 @@ extern fn row_actions_for_row<T: ListViewDelegate>(
 ) -> id {
     let edge: RowEdge = edge.into();
     let view = load::<T>(this, LISTVIEW_DELEGATE_PTR);
-    
-    let actions = view.actions_for(row as usize, edge);
+    let sections = load::<RefCell<SectionLayout>>(this, LISTVIEW_SECTIONS_PTR);
+    let kind = sections.borrow().kind_for_row(row as usize);
+
+    let actions = match kind {
+        Some(RowKind::Item(index)) => view.actions_for(index, edge),
+        _ => Vec::new()
+    };
+
+    let ids: Vec<&Object> = actions.iter().map(|action| &*action.0).collect();
+    NSArray::from(ids).into_inner()
+}
+
+/// Determines whether a row can be selected. Headers and footers never can; items defer to the
+/// selection mode and then the delegate.
+fn should_select_row<T: ListViewDelegate>(
+    this: &Object,
+    _: Sel,
+    _table_view: id,
+    row: NSInteger
+) -> BOOL {
+    let mode: SelectionMode = unsafe {
+        let mode: NSUInteger = *this.get_ivar(LISTVIEW_SELECTION_MODE);
+        mode.into()
+    };
+
+    if mode == SelectionMode::None {
+        return NO;
+    }
+
+    let sections = load::<RefCell<SectionLayout>>(this, LISTVIEW_SECTIONS_PTR);
+    let kind = sections.borrow().kind_for_row(row as usize);
+
+    match kind {
+        Some(RowKind::Item(index)) => {
+            let view = load::<T>(this, LISTVIEW_DELEGATE_PTR);
+
+            match view.should_select(index) {
+                true => YES,
+                false => NO
+            }
+        },
+
+        _ => NO
+    }
+}
+
+/// Called when the selection has changed; passes the selected index paths to the delegate.
+fn selection_did_change<T: ListViewDelegate>(this: &Object, _: Sel, _: id) {
+    let rows = unsafe {
+        let index_set: id = msg_send![this, selectedRowIndexes];
+        index_set_to_vec(index_set)
+    };
+
+    let selected = {
+        let sections = load::<RefCell<SectionLayout>>(this, LISTVIEW_SECTIONS_PTR);
+        let sections = sections.borrow();
+
+        rows.into_iter().filter_map(|row| match sections.kind_for_row(row) {
+            Some(RowKind::Item(index)) => Some(index),
+            _ => None
+        }).collect()
+    };
+
+    let view = load::<T>(this, LISTVIEW_DELEGATE_PTR);
+    view.selection_did_change(selected);
+}
+
+/// Called when a row is double clicked; this is the table's `doubleAction`.
+fn row_double_clicked<T: ListViewDelegate>(this: &Object, _: Sel, _: id) {
+    let row: NSInteger = unsafe { msg_send![this, clickedRow] };
+    if row < 0 {
+        return;
+    }
 
-    //if actions.len() > 0 {
-        let ids: Vec<&Object> = actions.iter().map(|action| &*action.0).collect();
-        NSArray::from(ids).into_inner()
-    //} else {
-    //    NSArray::new(&[]).into_inner()
-    //}
+    let sections = load::<RefCell<SectionLayout>>(this, LISTVIEW_SECTIONS_PTR);
+    let kind = sections.borrow().kind_for_row(row as usize);
+
+    if let Some(RowKind::Item(index)) = kind {
+        let view = load::<T>(this, LISTVIEW_DELEGATE_PTR);
+        view.row_double_clicked(index);
+    }
+}
+
+/// Returns the string used for type-select for a given row.
+fn type_select_string<T: ListViewDelegate>(
+    this: &Object,
+    _: Sel,
+    _table_view: id,
+    _column: id,
+    row: NSInteger
+) -> id {
+    let sections = load::<RefCell<SectionLayout>>(this, LISTVIEW_SECTIONS_PTR);
+    let kind = sections.borrow().kind_for_row(row as usize);
+
+    let value = match kind {
+        Some(RowKind::Item(index)) => {
+            let view = load::<T>(this, LISTVIEW_DELEGATE_PTR);
+            view.type_select_string_for(index)
+        },
+
+        _ => None
+    };
+
+    match value {
+        Some(value) => NSString::new(&value).into_inner(),
+        None => nil
+    }
+}
+
+/// Returns whether the user is currently dragging rows around inside this list, as opposed to
+/// dragging something in from elsewhere.
+fn is_reordering(this: &Object, info: id) -> bool {
+    let allows: BOOL = unsafe { *this.get_ivar(LISTVIEW_REORDERING) };
+    let source: id = unsafe { msg_send![info, draggingSource] };
+    to_bool(allows) && source == this as *const Object as id
+}
+
+/// Reads the (flat) rows being reordered off of the dragging pasteboard, in ascending order.
+fn dragged_rows(info: id) -> Vec<usize> {
+    let items = unsafe {
+        let pasteboard: id = msg_send![info, draggingPasteboard];
+        NSArray::wrap(msg_send![pasteboard, pasteboardItems])
+    };
+
+    let mut rows: Vec<usize> = items.map(|item| PasteboardItem::string_for(item, LISTVIEW_ROW_DRAG_TYPE))
+        .into_iter()
+        .filter_map(|row| row.and_then(|row| row.parse().ok()))
+        .collect();
+
+    rows.sort();
+    rows
+}
+
+/// Vends the pasteboard writer for a row being dragged. When reordering is enabled, the row
+/// number is written under a private type so we can pick it back up on drop.
+fn pasteboard_writer_for_row<T: ListViewDelegate>(
+    this: &Object,
+    _: Sel,
+    _table_view: id,
+    row: NSInteger
+) -> id {
+    let sections = load::<RefCell<SectionLayout>>(this, LISTVIEW_SECTIONS_PTR);
+    let kind = sections.borrow().kind_for_row(row as usize);
+
+    let index = match kind {
+        Some(RowKind::Item(index)) => index,
+        _ => { return nil; }
+    };
+
+    let view = load::<T>(this, LISTVIEW_DELEGATE_PTR);
+    let allows: BOOL = unsafe { *this.get_ivar(LISTVIEW_REORDERING) };
+
+    let item = match (view.pasteboard_writer_for(index), to_bool(allows)) {
+        (Some(item), _) => item,
+        (None, true) => PasteboardItem::new(),
+        (None, false) => { return nil; }
+    };
+
+    if to_bool(allows) {
+        item.set_string_for(&row.to_string(), NSString::new(LISTVIEW_ROW_DRAG_TYPE));
+    }
+
+    unsafe {
+        let item: id = msg_send![&*item.0, retain];
+        msg_send![item, autorelease]
+    }
+}
+
+/// Validates a proposed drop. Internal reorders are only allowed between items, and drops onto
+/// a row are retargeted to sit above it; anything else is handed to the delegate.
+fn validate_drop<T: ListViewDelegate>(
+    this: &Object,
+    _: Sel,
+    _table_view: id,
+    info: id,
+    row: NSInteger,
+    operation: NSInteger
+) -> NSUInteger {
+    let sections = load::<RefCell<SectionLayout>>(this, LISTVIEW_SECTIONS_PTR);
+    let position: DropPosition = operation.into();
+
+    if is_reordering(this, info) {
+        if position == DropPosition::On {
+            let above: NSInteger = DropPosition::Above.into();
+            let _: () = unsafe { msg_send![this, setDropRow:row dropOperation:above] };
+        }
+
+        return match sections.borrow().insertion_index_for_row(row as usize) {
+            Some(_) => DragOperation::Move.into(),
+            None => DragOperation::None.into()
+        };
+    }
+
+    let index = match position {
+        DropPosition::On => match sections.borrow().kind_for_row(row as usize) {
+            Some(RowKind::Item(index)) => Some(index),
+            _ => None
+        },
+
+        DropPosition::Above => sections.borrow().insertion_index_for_row(row as usize)
+    };
+
+    match index {
+        Some(index) => {
+            let view = load::<T>(this, LISTVIEW_DELEGATE_PTR);
+
+            view.validate_drop(DragInfo {
+                info: unsafe { Id::from_ptr(info) }
+            }, index, position).into()
+        },
+
+        None => DragOperation::None.into()
+    }
+}
+
+/// Accepts a drop. Internal reorders are reported to the delegate and then animated in place;
+/// anything else is handed to the delegate.
+fn accept_drop<T: ListViewDelegate>(
+    this: &Object,
+    _: Sel,
+    _table_view: id,
+    info: id,
+    row: NSInteger,
+    operation: NSInteger
+) -> BOOL {
+    let sections = load::<RefCell<SectionLayout>>(this, LISTVIEW_SECTIONS_PTR);
+    let view = load::<T>(this, LISTVIEW_DELEGATE_PTR);
+    let position: DropPosition = operation.into();
+
+    if !is_reordering(this, info) {
+        let index = match position {
+            DropPosition::On => match sections.borrow().kind_for_row(row as usize) {
+                Some(RowKind::Item(index)) => Some(index),
+                _ => None
+            },
+
+            DropPosition::Above => sections.borrow().insertion_index_for_row(row as usize)
+        };
+
+        return match index {
+            Some(index) => match view.accept_drop(DragInfo {
+                info: unsafe { Id::from_ptr(info) }
+            }, index, position) {
+                true => YES,
+                false => NO
+            },
+
+            None => NO
+        };
+    }
+
+    let (to, moves) = {
+        let sections = sections.borrow();
+
+        let to = match sections.insertion_index_for_row(row as usize) {
+            Some(to) => to,
+            None => { return NO; }
+        };
+
+        let moves: Vec<(usize, _)> = dragged_rows(info).into_iter().filter_map(|row| {
+            match sections.kind_for_row(row) {
+                Some(RowKind::Item(index)) => Some((row, index)),
+                _ => None
+            }
+        }).collect();
+
+        (to, moves)
+    };
+
+    if moves.is_empty() {
+        return NO;
+    }
+
+    view.move_rows(moves.iter().map(|(_, index)| *index).collect(), to);
+
+    // The usual dance for moving several rows at once: rows above the drop point shift
+    // everything before it up by one as they leave, and rows below it stack up after it.
+    unsafe {
+        let _: () = msg_send![this, beginUpdates];
+
+        let mut old_offset: NSInteger = 0;
+        let mut new_offset: NSInteger = 0;
+
+        for (from, _) in moves.iter() {
+            let from = *from as NSInteger;
+
+            if from < row {
+                let _: () = msg_send![this, moveRowAtIndex:(from + old_offset) toIndex:(row - 1)];
+                old_offset -= 1;
+            } else {
+                let _: () = msg_send![this, moveRowAtIndex:from toIndex:(row + new_offset)];
+                new_offset += 1;
+            }
+        }
+
+        let _: () = msg_send![this, endUpdates];
+    }
+
+    let from: Vec<IndexPath> = moves.iter().map(|(_, index)| *index).collect();
+    sections.borrow_mut().move_items(&from, to.section);
+
+    YES
 }
 
 /// Enforces normalcy, or: a needlessly cruel method in terms of the name. You get the idea though.
-extern fn enforce_normalcy(_: &Object, _: Sel) -> BOOL {
+fn enforce_normalcy(_: &Object, _: Sel) -> BOOL {
     return YES;
 }
 
 /// Called when a drag/drop operation has entered this view.
-extern fn dragging_entered<T: ListViewDelegate>(this: &mut Object, _: Sel, info: id) -> NSUInteger {
+fn dragging_entered<T: ListViewDelegate>(this: &mut Object, _: Sel, info: id) -> NSUInteger {
+    // NSTableView drives its own row-level drop handling (`validate_drop`) from here; that
+    // takes precedence over whatever the delegate says for the view as a whole.
+    let operation: NSUInteger = unsafe { msg_send![super(this, class!(NSTableView)), draggingEntered:info] };
+
     let view = load::<T>(this, LISTVIEW_DELEGATE_PTR);
-    view.dragging_entered(DragInfo {
+    let fallback: NSUInteger = view.dragging_entered(DragInfo {
         info: unsafe { Id::from_ptr(info) }
-    }).into()
+    }).into();
+
+    match operation {
+        0 => fallback,
+        operation => operation
+    }
 }
 
 /// Called when a drag/drop operation has entered this view.
-extern fn prepare_for_drag_operation<T: ListViewDelegate>(this: &mut Object, _: Sel, info: id) -> BOOL {
+fn prepare_for_drag_operation<T: ListViewDelegate>(this: &mut Object, _: Sel, info: id) -> BOOL {
+    let prepared: BOOL = unsafe { msg_send![super(this, class!(NSTableView)), prepareForDragOperation:info] };
+
     let view = load::<T>(this, LISTVIEW_DELEGATE_PTR);
-    
+
     match view.prepare_for_drag_operation(DragInfo {
         info: unsafe { Id::from_ptr(info) }
-    }) {
+    }) || to_bool(prepared) {
         true => YES,
         false => NO
     }
 }
 
 /// Called when a drag/drop operation has entered this view.
-extern fn perform_drag_operation<T: ListViewDelegate>(this: &mut Object, _: Sel, info: id) -> BOOL {
+fn perform_drag_operation<T: ListViewDelegate>(this: &mut Object, _: Sel, info: id) -> BOOL {
+    // This is where NSTableView calls through to `tableView:acceptDrop:row:dropOperation:`.
+    let performed: BOOL = unsafe { msg_send![super(this, class!(NSTableView)), performDragOperation:info] };
+    if to_bool(performed) {
+        return YES;
+    }
+
     let view = load::<T>(this, LISTVIEW_DELEGATE_PTR);
-        
+
     match view.perform_drag_operation(DragInfo {
         info: unsafe { Id::from_ptr(info) }
     }) {
@@ -115,18 +604,22 @@ extern fn perform_drag_operation<T: ListViewDelegate>(this: &mut Object, _: Sel,
 }
 
 /// Called when a drag/drop operation has entered this view.
-extern fn conclude_drag_operation<T: ListViewDelegate>(this: &mut Object, _: Sel, info: id) {
+fn conclude_drag_operation<T: ListViewDelegate>(this: &mut Object, _: Sel, info: id) {
+    let _: () = unsafe { msg_send![super(this, class!(NSTableView)), concludeDragOperation:info] };
+
     let view = load::<T>(this, LISTVIEW_DELEGATE_PTR);
-    
+
     view.conclude_drag_operation(DragInfo {
         info: unsafe { Id::from_ptr(info) }
     });           
 }
 
 /// Called when a drag/drop operation has entered this view.
-extern fn dragging_exited<T: ListViewDelegate>(this: &mut Object, _: Sel, info: id) {
+fn dragging_exited<T: ListViewDelegate>(this: &mut Object, _: Sel, info: id) {
+    let _: () = unsafe { msg_send![super(this, class!(NSTableView)), draggingExited:info] };
+
     let view = load::<T>(this, LISTVIEW_DELEGATE_PTR);
-        
+
     view.dragging_exited(DragInfo {
         info: unsafe { Id::from_ptr(info) }
     });
@@ -142,7 +635,9 @@ pub(crate) fn register_listview_class() -> *const Class {
 
     INIT.call_once(|| unsafe {
         let superclass = class!(NSTableView);
-        let decl = ClassDecl::new("RSTListView", superclass).unwrap();
+        let mut decl = ClassDecl::new("RSTListView", superclass).unwrap();
+        decl.add_ivar::<NSUInteger>(LISTVIEW_SELECTION_MODE);
+        decl.add_ivar::<BOOL>(LISTVIEW_REORDERING);
         VIEW_CLASS = decl.register();
     });
 
@@ -156,22 +651,45 @@ pub(crate) fn register_listview_class() -> *const Class {
 /// `UITableView` semantics; if `NSTableView`'s multi column behavior is needed, then it can
 /// be added in.
 pub(crate) fn register_listview_class_with_delegate<T: ListViewDelegate>(instance: &T) -> *const Class {
-    load_or_register_class("NSTableView", instance.subclass_name(), |decl| unsafe {
+    let custom_type_select = instance.uses_custom_type_select();
+
+    load_or_register_class("NSTableView", instance.subclass_name(), move |decl| unsafe {
         decl.add_ivar::<usize>(LISTVIEW_DELEGATE_PTR);
         decl.add_ivar::<usize>(LISTVIEW_CELL_VENDOR_PTR);
+        decl.add_ivar::<usize>(LISTVIEW_SECTIONS_PTR);
+        decl.add_ivar::<NSUInteger>(LISTVIEW_SELECTION_MODE);
+        decl.add_ivar::<BOOL>(LISTVIEW_REORDERING);
         
-        decl.add_method(sel!(isFlipped), enforce_normalcy as extern fn(&Object, _) -> BOOL);
+        decl.add_guarded_method(sel!(isFlipped), enforce_normalcy);
 
         // Tableview-specific
-        decl.add_method(sel!(numberOfRowsInTableView:), number_of_items::<T> as extern fn(&Object, _, id) -> NSInteger);
-        decl.add_method(sel!(tableView:viewForTableColumn:row:), view_for_column::<T> as extern fn(&Object, _, id, id, NSInteger) -> id);
-        decl.add_method(sel!(tableView:rowActionsForRow:edge:), row_actions_for_row::<T> as extern fn(&Object, _, id, NSInteger, NSInteger) -> id);
+        decl.add_guarded_method(sel!(numberOfRowsInTableView:), number_of_items::<T>);
+        decl.add_guarded_method(sel!(tableView:viewForTableColumn:row:), view_for_column::<T>);
+        decl.add_guarded_method(sel!(tableView:heightOfRow:), height_of_row::<T>);
+        decl.add_guarded_method(sel!(tableView:isGroupRow:), is_group_row::<T>);
+        decl.add_guarded_method(sel!(tableView:didAddRowView:forRow:), did_add_row_view::<T>);
+        decl.add_guarded_method(sel!(tableView:rowActionsForRow:edge:), row_actions_for_row::<T>);
+
+        // Selection
+        decl.add_guarded_method(sel!(tableView:shouldSelectRow:), should_select_row::<T>);
+        decl.add_guarded_method(sel!(tableViewSelectionDidChange:), selection_did_change::<T>);
+        decl.add_guarded_method(sel!(rstRowDoubleClicked:), row_double_clicked::<T>);
+
+        // Implementing this at all replaces AppKit's own type-select, so it's opt-in.
+        if custom_type_select {
+            decl.add_guarded_method(sel!(tableView:typeSelectStringForTableColumn:row:), type_select_string::<T>);
+        }
+
+        // Dragging rows out, dropping onto rows, and reordering
+        decl.add_guarded_method(sel!(tableView:pasteboardWriterForRow:), pasteboard_writer_for_row::<T>);
+        decl.add_guarded_method(sel!(tableView:validateDrop:proposedRow:proposedDropOperation:), validate_drop::<T>);
+        decl.add_guarded_method(sel!(tableView:acceptDrop:row:dropOperation:), accept_drop::<T>);
 
         // Drag and drop operations (e.g, accepting files)
-        decl.add_method(sel!(draggingEntered:), dragging_entered::<T> as extern fn (&mut Object, _, _) -> NSUInteger);
-        decl.add_method(sel!(prepareForDragOperation:), prepare_for_drag_operation::<T> as extern fn (&mut Object, _, _) -> BOOL);
-        decl.add_method(sel!(performDragOperation:), perform_drag_operation::<T> as extern fn (&mut Object, _, _) -> BOOL);
-        decl.add_method(sel!(concludeDragOperation:), conclude_drag_operation::<T> as extern fn (&mut Object, _, _));
-        decl.add_method(sel!(draggingExited:), dragging_exited::<T> as extern fn (&mut Object, _, _));
+        decl.add_guarded_method(sel!(draggingEntered:), dragging_entered::<T>);
+        decl.add_guarded_method(sel!(prepareForDragOperation:), prepare_for_drag_operation::<T>);
+        decl.add_guarded_method(sel!(performDragOperation:), perform_drag_operation::<T>);
+        decl.add_guarded_method(sel!(concludeDragOperation:), conclude_drag_operation::<T>);
+        decl.add_guarded_method(sel!(draggingExited:), dragging_exited::<T>);
     })
 }
@@ -45,13 +45,15 @@ use std::collections::HashMap;
 
 use core_graphics::base::CGFloat;
 use objc_id::ShareId;
-use objc::runtime::{Class, Object};
+use objc::runtime::{Class, Object, BOOL};
 use objc::{class, msg_send, sel, sel_impl};
 
-use crate::foundation::{id, nil, YES, NO, NSArray, NSString, NSUInteger};
+use crate::foundation::{id, nil, to_bool, YES, NO, NSArray, NSRange, NSRect, NSString, NSInteger, NSUInteger};
 use crate::color::Color;
-use crate::layout::{Layout, LayoutAnchorX, LayoutAnchorY, LayoutAnchorDimension};
+use crate::layout::{Layout, LayoutConstraint, LayoutAnchorX, LayoutAnchorY, LayoutAnchorDimension};
+use crate::notification_center::{NotificationCenter, NotificationName, ObserverToken};
 use crate::pasteboard::PasteboardType;
+use crate::progress::{ProgressIndicator, ProgressIndicatorStyle};
 use crate::scrollview::ScrollView;
 use crate::utils::CGSize;
 
@@ -59,7 +61,7 @@ use crate::utils::CGSize;
 mod macos;
 
 #[cfg(target_os = "macos")]
-use macos::{register_listview_class, register_listview_class_with_delegate};
+use macos::{register_listview_class, register_listview_class_with_delegate, did_scroll};
 
 #[cfg(target_os = "ios")]
 mod ios;
@@ -68,7 +70,7 @@ mod ios;
 use ios::{register_view_class, register_view_class_with_delegate};
 
 mod enums;
-pub use enums::{RowAnimation, RowEdge};
+pub use enums::{DropPosition, RowAnimation, RowEdge, SelectionMode};
 
 mod traits;
 pub use traits::ListViewDelegate;
@@ -79,8 +81,29 @@ pub use row::ListViewRow;
 mod actions;
 pub use actions::{RowAction, RowActionStyle};
 
+mod diff;
+pub use diff::{Changeset, Identifiable};
+
+mod sections;
+pub use sections::IndexPath;
+
+mod prefetch;
+use prefetch::PrefetchState;
+
+mod heights;
+use sections::{RowKind, SectionLayout};
+
+mod data_source;
+pub use data_source::ListDataSource;
+
 pub(crate) static LISTVIEW_DELEGATE_PTR: &str = "rstListViewDelegatePtr";
 pub(crate) static LISTVIEW_CELL_VENDOR_PTR: &str = "rstListViewCellVendorPtr";
+pub(crate) static LISTVIEW_SECTIONS_PTR: &str = "rstListViewSectionsPtr";
+pub(crate) static LISTVIEW_SELECTION_MODE: &str = "rstListViewSelectionMode";
+pub(crate) static LISTVIEW_REORDERING: &str = "rstListViewReordering";
+
+/// The private pasteboard type used to carry rows being reordered within a `ListView`.
+pub(crate) static LISTVIEW_ROW_DRAG_TYPE: &str = "com.cacao.listview.row";
 
 use std::any::Any;
 use std::sync::{Arc, RwLock};
@@ -88,7 +111,7 @@ use std::sync::{Arc, RwLock};
 use std::rc::Rc;
 use std::cell::RefCell;
 
-use crate::view::ViewDelegate;
+use crate::view::{View, ViewDelegate};
 
 pub(crate) type CellFactoryMap = HashMap<&'static str, Box<dyn Fn() -> Box<dyn Any>>>;
 
@@ -139,11 +162,48 @@ impl CellFactory {
     }
 }
 
+/// Reads the indexes out of an `NSIndexSet`, in ascending order.
+pub(crate) fn index_set_to_vec(index_set: id) -> Vec<usize> {
+    let mut indexes = vec![];
+
+    unsafe {
+        let not_found = NSInteger::max_value() as NSUInteger;
+        let mut index: NSUInteger = msg_send![index_set, firstIndex];
+
+        while index != not_found {
+            indexes.push(index as usize);
+            index = msg_send![index_set, indexGreaterThanIndex:index];
+        }
+    }
+
+    indexes
+}
+
+/// Builds the "load more" footer: a spinning progress indicator, centered in a row.
+fn load_more_footer() -> ShareId<Object> {
+    let view = View::new();
+    let spinner = ProgressIndicator::new();
+    spinner.set_style(ProgressIndicatorStyle::Spinner);
+    spinner.set_indeterminate(true);
+    spinner.start_animation();
+    view.add_subview(&spinner);
+
+    LayoutConstraint::activate(&[
+        spinner.center_x.constraint_equal_to(&view.center_x),
+        spinner.top.constraint_equal_to(&view.top).offset(8.),
+        spinner.bottom.constraint_equal_to(&view.bottom).offset(-8.)
+    ]);
+
+    view.get_backing_node()
+}
+
 /// A helper method for instantiating view classes and applying default settings to them.
 fn common_init(class: *const Class) -> id { 
     unsafe {
         let tableview: id = msg_send![class, new];
         let _: () = msg_send![tableview, setTranslatesAutoresizingMaskIntoConstraints:NO];
+        (&mut *tableview).set_ivar::<NSUInteger>(LISTVIEW_SELECTION_MODE, SelectionMode::Single.into());
+        (&mut *tableview).set_ivar::<BOOL>(LISTVIEW_REORDERING, NO);
 
         // Let's... make NSTableView into UITableView-ish.
         #[cfg(target_os = "macos")]
@@ -177,6 +237,17 @@ pub struct ListView<T = ()> {
     /// allocation and reuse, which is necessary for an "infinite" listview.
     cell_factory: CellFactory,
 
+    /// The current section layout, shared with any handles so that `IndexPath`s can be mapped to
+    /// rows from anywhere.
+    sections: Rc<RefCell<SectionLayout>>,
+
+    /// The rows the delegate has been asked to prefetch, shared with the scroll observer.
+    prefetch: Rc<RefCell<PrefetchState>>,
+
+    /// Observes the scroll view's clip view, so prefetching can follow the scroll position.
+    #[cfg(target_os = "macos")]
+    scroll_observer: Option<ObserverToken>,
+
     /// A pointer to the Objective-C runtime view controller.
     pub objc: ShareId<Object>,
 
@@ -245,6 +316,8 @@ impl ListView {
 
         ListView {
             cell_factory: CellFactory::new(),
+            sections: Rc::new(RefCell::new(SectionLayout::default())),
+            prefetch: Rc::new(RefCell::new(PrefetchState::default())),
             delegate: None,
             top: LayoutAnchorY::new(unsafe { msg_send![anchor_view, topAnchor] }),
             leading: LayoutAnchorX::new(unsafe { msg_send![anchor_view, leadingAnchor] }),
@@ -256,6 +329,9 @@ impl ListView {
             center_y: LayoutAnchorY::new(unsafe { msg_send![anchor_view, centerYAnchor] }),
             objc: unsafe { ShareId::from_ptr(view) },
 
+            #[cfg(target_os = "macos")]
+            scroll_observer: None,
+
             #[cfg(target_os = "macos")]
             scrollview: scrollview
         }
@@ -270,6 +346,7 @@ impl<T> ListView<T> where T: ListViewDelegate + 'static {
         let view = common_init(class);
         let mut delegate = Box::new(delegate);
         let cell = CellFactory::new();
+        let sections = Rc::new(RefCell::new(SectionLayout::default()));
         
         unsafe {
             //let view: id = msg_send![register_view_class_with_delegate::<T>(), new];
@@ -278,8 +355,16 @@ impl<T> ListView<T> where T: ListViewDelegate + 'static {
             let cell_vendor_ptr: *const RefCell<CellFactoryMap> = &*cell.0;
             (&mut *view).set_ivar(LISTVIEW_DELEGATE_PTR, delegate_ptr as usize);
             (&mut *view).set_ivar(LISTVIEW_CELL_VENDOR_PTR, cell_vendor_ptr as usize);
+            let sections_ptr: *const RefCell<SectionLayout> = &*sections;
+            (&mut *view).set_ivar(LISTVIEW_SECTIONS_PTR, sections_ptr as usize);
             let _: () = msg_send![view, setDelegate:view];
             let _: () = msg_send![view, setDataSource:view];
+
+            #[cfg(target_os = "macos")]
+            {
+                let _: () = msg_send![view, setTarget:view];
+                let _: () = msg_send![view, setDoubleAction:sel!(rstRowDoubleClicked:)];
+            }
         };
 
         #[cfg(target_os = "macos")]
@@ -302,6 +387,8 @@ impl<T> ListView<T> where T: ListViewDelegate + 'static {
 
         let mut view = ListView {
             cell_factory: cell,
+            sections: sections,
+            prefetch: Rc::new(RefCell::new(PrefetchState::default())),
             delegate: None,
             top: LayoutAnchorY::new(unsafe { msg_send![anchor_view, topAnchor] }),
             leading: LayoutAnchorX::new(unsafe { msg_send![anchor_view, leadingAnchor] }),
@@ -312,11 +399,33 @@ impl<T> ListView<T> where T: ListViewDelegate + 'static {
             center_x: LayoutAnchorX::new(unsafe { msg_send![anchor_view, centerXAnchor] }),
             center_y: LayoutAnchorY::new(unsafe { msg_send![anchor_view, centerYAnchor] }),
             objc: unsafe { ShareId::from_ptr(view) },
+
+            #[cfg(target_os = "macos")]
+            scroll_observer: None,
             
             #[cfg(target_os = "macos")]
             scrollview: scrollview
         };
 
+        // Follow the scroll position, so we know when to ask the delegate to prefetch.
+        #[cfg(target_os = "macos")]
+        {
+            let objc = view.objc.clone();
+            let prefetch = Rc::clone(&view.prefetch);
+
+            let clip_view: id = unsafe {
+                let clip_view: id = msg_send![&*view.scrollview.objc, contentView];
+                let _: () = msg_send![clip_view, setPostsBoundsChangedNotifications:YES];
+                clip_view
+            };
+
+            view.scroll_observer = Some(NotificationCenter::default().observe(
+                NotificationName::NSViewBoundsDidChange,
+                Some(unsafe { &*clip_view }),
+                move |_| did_scroll::<T>(&objc, &prefetch)
+            ));
+        }
+
         (&mut delegate).did_load(view.clone_as_handle()); 
         view.delegate = Some(delegate);
         view
@@ -331,6 +440,8 @@ impl<T> ListView<T> {
     pub(crate) fn clone_as_handle(&self) -> ListView {
         ListView {
             cell_factory: CellFactory::new(),
+            sections: Rc::clone(&self.sections),
+            prefetch: Rc::clone(&self.prefetch),
             delegate: None,
             top: self.top.clone(),
             leading: self.leading.clone(),
@@ -342,6 +453,9 @@ impl<T> ListView<T> {
             center_y: self.center_y.clone(),
             objc: self.objc.clone(),
 
+            #[cfg(target_os = "macos")]
+            scroll_observer: None,
+
             #[cfg(target_os = "macos")]
             scrollview: self.scrollview.clone_as_handle()
         }
@@ -398,13 +512,28 @@ impl<T> ListView<T> {
         }
     }
 
-    pub fn insert_rows<I: IntoIterator<Item = usize>>(&self, indexes: I, animation: RowAnimation) {
+    /// Inserts rows at the given index paths, which refer to positions once the insert is done.
+    /// Your delegate should already reflect the new items when this is called.
+    pub fn insert_rows<I: IntoIterator<Item = IndexPath>>(&self, indexes: I, animation: RowAnimation) {
+        let mut indexes: Vec<IndexPath> = indexes.into_iter().collect();
+        indexes.sort();
+
+        let rows: Vec<usize> = {
+            let mut sections = self.sections.borrow_mut();
+
+            for index in &indexes {
+                sections.insert_item(*index);
+            }
+
+            indexes.iter().map(|index| sections.row_for(*index)).collect()
+        };
+
         #[cfg(target_os = "macos")]
         unsafe {
             let index_set: id = msg_send![class!(NSMutableIndexSet), new];
             
-            for index in indexes {
-                let x: NSUInteger = index as NSUInteger;
+            for row in rows {
+                let x: NSUInteger = row as NSUInteger;
                 let _: () = msg_send![index_set, addIndex:x];
             }
 
@@ -417,13 +546,22 @@ impl<T> ListView<T> {
         }
     }
 
-    pub fn reload_rows(&self, indexes: &[usize]) {
+    /// Reloads the rows at the given index paths. Any cached heights for them are thrown away,
+    /// and re-measured.
+    pub fn reload_rows(&self, indexes: &[IndexPath]) {
+        let rows: Vec<usize> = {
+            let mut sections = self.sections.borrow_mut();
+            let rows: Vec<usize> = indexes.iter().map(|index| sections.row_for(*index)).collect();
+            sections.heights_mut().invalidate(&rows);
+            rows
+        };
+
         #[cfg(target_os = "macos")]
         unsafe {
             let index_set: id = msg_send![class!(NSMutableIndexSet), new];
             
-            for index in indexes {
-                let x: NSUInteger = *index as NSUInteger;
+            for row in rows {
+                let x: NSUInteger = row as NSUInteger;
                 let _: () = msg_send![index_set, addIndex:x];
             }
 
@@ -432,16 +570,60 @@ impl<T> ListView<T> {
             let ye: id = msg_send![class!(NSIndexSet), indexSetWithIndex:0];
             let y = ShareId::from_ptr(ye);
             let _: () = msg_send![&*self.objc, reloadDataForRowIndexes:&*x columnIndexes:&*y];
+            let _: () = msg_send![&*self.objc, noteHeightOfRowsWithIndexesChanged:&*x];
+        }
+    }
+
+    /// Lets the list know that the rows at the given index paths have changed height - e.g,
+    /// because they expand in place to show more content. Cached heights for them are thrown
+    /// away, and the change is animated.
+    pub fn note_height_changed(&self, indexes: &[IndexPath]) {
+        let rows: Vec<usize> = {
+            let mut sections = self.sections.borrow_mut();
+            let rows: Vec<usize> = indexes.iter().map(|index| sections.row_for(*index)).collect();
+            sections.heights_mut().invalidate(&rows);
+            rows
+        };
+
+        #[cfg(target_os = "macos")]
+        unsafe {
+            let index_set: id = msg_send![class!(NSMutableIndexSet), new];
+
+            for row in rows {
+                let x: NSUInteger = row as NSUInteger;
+                let _: () = msg_send![index_set, addIndex:x];
+            }
+
+            let x = ShareId::from_retained_ptr(index_set);
+            let _: () = msg_send![&*self.objc, noteHeightOfRowsWithIndexesChanged:&*x];
         }
     }
 
-    pub fn remove_rows<I: IntoIterator<Item = usize>>(&self, indexes: I, animations: RowAnimation) {
+    /// Removes the rows at the given index paths, which refer to positions before the removal.
+    pub fn remove_rows<I: IntoIterator<Item = IndexPath>>(&self, indexes: I, animations: RowAnimation) {
+        let indexes: Vec<IndexPath> = indexes.into_iter().collect();
+
+        let rows: Vec<usize> = {
+            let mut sections = self.sections.borrow_mut();
+            let rows = indexes.iter().map(|index| sections.row_for(*index)).collect();
+
+            // Indexes refer to positions before the removal, so work from the end backwards.
+            let mut removals = indexes.clone();
+            removals.sort();
+
+            for index in removals.iter().rev() {
+                sections.remove_item(*index);
+            }
+
+            rows
+        };
+
         #[cfg(target_os = "macos")]
         unsafe {
             let index_set: id = msg_send![class!(NSMutableIndexSet), new];
             
-            for index in indexes {
-                let x: NSUInteger = index as NSUInteger;
+            for row in rows {
+                let x: NSUInteger = row as NSUInteger;
                 let _: () = msg_send![index_set, addIndex:x];
             }
 
@@ -454,17 +636,202 @@ impl<T> ListView<T> {
         }
     }
 
+    /// Moves the row at `from` so that it ends up at `to`, possibly in another section. Inside
+    /// `perform_batch_updates()`, index paths are relative to any changes made earlier in the
+    /// batch.
+    pub fn move_row(&self, from: IndexPath, to: IndexPath) {
+        let (from, to) = {
+            let mut sections = self.sections.borrow_mut();
+            let from_row = sections.row_for(from);
+            sections.remove_item(from);
+            sections.insert_item(to);
+            (from_row, sections.row_for(to))
+        };
+
+        #[cfg(target_os = "macos")]
+        unsafe {
+            let from = from as NSInteger;
+            let to = to as NSInteger;
+            let _: () = msg_send![&*self.objc, moveRowAtIndex:from toIndex:to];
+        }
+    }
+
+    /// Returns the index path for a given table row, or `None` if the row is out of range or is
+    /// a section header or footer. This is useful for mapping raw row numbers you may receive
+    /// (e.g, in a `RowAction` handler) back to your data.
+    pub fn index_path_for_row(&self, row: usize) -> Option<IndexPath> {
+        match self.sections.borrow().kind_for_row(row) {
+            Some(RowKind::Item(index)) => Some(index),
+            _ => None
+        }
+    }
+
+    /// Returns the index paths of the items currently on screen, in ascending order. Section
+    /// headers and footers aren't included.
+    pub fn visible_rows(&self) -> Vec<IndexPath> {
+        let rows: std::ops::Range<usize> = unsafe {
+            let rect: NSRect = msg_send![&*self.objc, visibleRect];
+            let range: NSRange = msg_send![&*self.objc, rowsInRect:rect];
+            range.into()
+        };
+
+        rows.filter_map(|row| self.index_path_for_row(row)).collect()
+    }
+
+    /// Sets how many rows either side of the visible area are passed to
+    /// `ListViewDelegate::prefetch_rows()` as the list scrolls. This defaults to `20`.
+    pub fn set_prefetch_distance(&self, rows: usize) {
+        self.prefetch.borrow_mut().set_distance(rows);
+    }
+
+    /// Shows or hides a "load more" footer - a spinner, below every section - at the end of the
+    /// list. Whenever it comes into view, `ListViewDelegate::load_more()` is called. Show this
+    /// while there are more results to page in, and hide it once everything has been loaded.
+    pub fn set_shows_load_more_footer(&self, shows: bool) {
+        let row = {
+            let mut sections = self.sections.borrow_mut();
+
+            if sections.load_more().is_some() == shows {
+                return;
+            }
+
+            match shows {
+                true => {
+                    sections.set_load_more(Some(load_more_footer()));
+                    sections.load_more_row()
+                },
+
+                false => {
+                    let row = sections.load_more_row();
+                    sections.set_load_more(None);
+                    row
+                }
+            }
+        };
+
+        #[cfg(target_os = "macos")]
+        unsafe {
+            if let Some(row) = row {
+                let row = row as NSUInteger;
+                let index_set: id = msg_send![class!(NSIndexSet), indexSetWithIndex:row];
+                let animation: NSUInteger = RowAnimation::Fade.into();
+
+                match shows {
+                    true => {
+                        let _: () = msg_send![&*self.objc, insertRowsAtIndexes:index_set withAnimation:animation];
+                    },
+
+                    false => {
+                        let _: () = msg_send![&*self.objc, removeRowsAtIndexes:index_set withAnimation:animation];
+                    }
+                }
+            }
+        }
+    }
+
+    /// Returns whether the "load more" footer is being shown.
+    pub fn shows_load_more_footer(&self) -> bool {
+        self.sections.borrow().load_more().is_some()
+    }
+
+    /// Sets whether section headers stick to the top of the list while their section is being
+    /// scrolled. This defaults to `true`.
+    pub fn set_sticky_headers(&self, sticky: bool) {
+        #[cfg(target_os = "macos")]
+        unsafe {
+            let _: () = msg_send![&*self.objc, setFloatsGroupRows:match sticky {
+                true => YES,
+                false => NO
+            }];
+        }
+    }
+
+    /// Sets how many rows the user can select at once. Changing this clears the current
+    /// selection.
+    pub fn set_selection_mode(&self, mode: SelectionMode) {
+        #[cfg(target_os = "macos")]
+        unsafe {
+            let _: () = msg_send![&*self.objc, deselectAll:nil];
+            let _: () = msg_send![&*self.objc, setAllowsEmptySelection:YES];
+            let _: () = msg_send![&*self.objc, setAllowsMultipleSelection:match mode {
+                SelectionMode::Multiple => YES,
+                _ => NO
+            }];
+
+            let objc = &*self.objc as *const Object as *mut Object;
+            (&mut *objc).set_ivar::<NSUInteger>(LISTVIEW_SELECTION_MODE, mode.into());
+        }
+    }
+
+    /// Returns the current selection mode.
+    pub fn selection_mode(&self) -> SelectionMode {
+        unsafe {
+            let mode: NSUInteger = *self.objc.get_ivar(LISTVIEW_SELECTION_MODE);
+            mode.into()
+        }
+    }
+
+    /// Selects the rows at the given index paths. If `extend` is `true`, they're added to the
+    /// current selection; otherwise, they replace it. This does not consult
+    /// `ListViewDelegate::should_select()`.
+    pub fn select_rows(&self, indexes: &[IndexPath], extend: bool) {
+        let rows: Vec<usize> = {
+            let sections = self.sections.borrow();
+            indexes.iter().map(|index| sections.row_for(*index)).collect()
+        };
+
+        #[cfg(target_os = "macos")]
+        unsafe {
+            let index_set: id = msg_send![class!(NSMutableIndexSet), new];
+
+            for row in rows {
+                let x: NSUInteger = row as NSUInteger;
+                let _: () = msg_send![index_set, addIndex:x];
+            }
+
+            let x = ShareId::from_retained_ptr(index_set);
+            let _: () = msg_send![&*self.objc, selectRowIndexes:&*x byExtendingSelection:match extend {
+                true => YES,
+                false => NO
+            }];
+        }
+    }
+
+    /// Clears the selection.
+    pub fn deselect_all(&self) {
+        #[cfg(target_os = "macos")]
+        unsafe {
+            let _: () = msg_send![&*self.objc, deselectAll:nil];
+        }
+    }
+
+    /// Returns the index paths of the selected rows, in ascending order.
+    pub fn selected_rows(&self) -> Vec<IndexPath> {
+        let rows = unsafe {
+            let index_set: id = msg_send![&*self.objc, selectedRowIndexes];
+            index_set_to_vec(index_set)
+        };
+
+        rows.into_iter().filter_map(|row| self.index_path_for_row(row)).collect()
+    }
+
     /// Sets an enforced row-height; if you need dynamic rows, you'll want to
-    /// look at ListViewDelegate methods, or use AutoLayout.
+    /// look at `ListViewDelegate::height_for_row()`, or use AutoLayout.
     pub fn set_row_height(&self, height: CGFloat) {
         unsafe {
             let _: () = msg_send![&*self.objc, setRowHeight:height];
         }
     }
 
+    /// Sets the height used for rows that haven't been laid out yet, when using automatic row
+    /// heights. `ListViewDelegate::estimated_height_for_row()` takes precedence over this.
+    pub fn set_estimated_row_height(&self, height: CGFloat) {
+        self.sections.borrow_mut().heights_mut().set_estimate(Some(height));
+    }
+
     /// This defaults to true. If you're using manual heights, you may want to set this to `false`,
     /// as it will tell AppKit internally to just use the number instead of trying to judge
-    /// heights.
+    /// heights. This also needs to be `false` for `ListViewDelegate::height_for_row()` to be used.
     ///
     /// It can make some scrolling situations much smoother.
     pub fn set_uses_automatic_row_heights(&self, uses: bool) {
@@ -501,21 +868,75 @@ impl<T> ListView<T> {
         }
     }
 
-    /// Register this view for drag and drop operations.
+    /// Register this view for drag and drop operations. This replaces any types registered
+    /// before (the row type used for reordering is kept, if reordering is allowed).
     pub fn register_for_dragged_types(&self, types: &[PasteboardType]) {
+        let mut types: Vec<NSString> = types.into_iter().map(|t| {
+            // This clone probably doesn't need to be here, but it should also be cheap as
+            // this is just an enum... and this is not an oft called method.
+            t.clone().into()
+        }).collect();
+
+        // Keep reordering working.
+        if self.allows_reordering() {
+            types.push(NSString::new(LISTVIEW_ROW_DRAG_TYPE));
+        }
+
         unsafe {
-            let types: NSArray = types.into_iter().map(|t| {
-                // This clone probably doesn't need to be here, but it should also be cheap as
-                // this is just an enum... and this is not an oft called method.
-                let x: NSString = t.clone().into();
-                x.into_inner()
-            }).collect::<Vec<id>>().into();
+            // `registerForDraggedTypes:` adds to the existing types, so clear them out first.
+            let _: () = msg_send![&*self.objc, unregisterDraggedTypes];
 
+            let types: NSArray = types.into_iter().map(|t| t.into_inner()).collect::<Vec<id>>().into();
             let _: () = msg_send![&*self.objc, registerForDraggedTypes:types.into_inner()];
         }
     }
 
+    /// Sets whether the user can reorder rows by dragging them. Rows can only be moved between
+    /// items (not onto them), and section headers and footers can't be dragged. When a reorder
+    /// happens, `ListViewDelegate::move_rows()` is called and the move is animated.
+    ///
+    /// This defaults to `false`.
+    pub fn set_allows_reordering(&self, allows: bool) {
+        #[cfg(target_os = "macos")]
+        unsafe {
+            let objc = &*self.objc as *const Object as *mut Object;
+            (&mut *objc).set_ivar::<BOOL>(LISTVIEW_REORDERING, match allows {
+                true => YES,
+                false => NO
+            });
+
+            let existing = NSArray::wrap(msg_send![&*self.objc, registeredDraggedTypes]);
+            let mut types: Vec<id> = existing.map(|t| t).into_iter()
+                .filter(|t| NSString::wrap(*t).to_str() != LISTVIEW_ROW_DRAG_TYPE)
+                .collect();
+
+            if allows {
+                types.push(NSString::new(LISTVIEW_ROW_DRAG_TYPE).into_inner());
+            }
+
+            // `registerForDraggedTypes:` only ever adds types, so the row type has to be
+            // unregistered before the rest are registered again. `existing` keeps them alive.
+            let _: () = msg_send![&*self.objc, unregisterDraggedTypes];
+
+            let types: NSArray = types.into();
+            let _: () = msg_send![&*self.objc, registerForDraggedTypes:types.into_inner()];
+        }
+    }
+
+    /// Returns whether the user can reorder rows by dragging them.
+    pub fn allows_reordering(&self) -> bool {
+        unsafe {
+            let allows: BOOL = *self.objc.get_ivar(LISTVIEW_REORDERING);
+            to_bool(allows)
+        }
+    }
+
+    /// Reloads every row. The delegate is asked for the shape of each section again, and for
+    /// fresh header and footer views.
     pub fn reload(&self) {
+        self.prefetch.borrow_mut().reset();
+        self.sections.borrow_mut().set_needs_rebuild();
+
         unsafe {
             let _: () = msg_send![&*self.objc, reloadData];
         }
@@ -0,0 +1,187 @@
+//! Tracks which rows are close to the visible area of a `ListView` as it scrolls, so that the
+//! delegate can be told when to start (and stop) fetching data for them.
+
+use std::collections::BTreeSet;
+use std::ops::Range;
+
+/// How many rows either side of the visible area are prefetched, by default.
+const DEFAULT_PREFETCH_DISTANCE: usize = 20;
+
+/// The prefetch window for a `ListView`: the rows either side of the visible area that the
+/// delegate has been asked to prefetch.
+#[derive(Debug)]
+pub(crate) struct PrefetchState {
+    distance: usize,
+    window: Range<usize>,
+
+    /// Rows passed to `prefetch_rows` that haven't since been shown or cancelled.
+    prefetched: BTreeSet<usize>
+}
+
+impl Default for PrefetchState {
+    fn default() -> Self {
+        PrefetchState {
+            distance: DEFAULT_PREFETCH_DISTANCE,
+            window: 0..0,
+            prefetched: BTreeSet::new()
+        }
+    }
+}
+
+impl PrefetchState {
+    /// Sets how many rows either side of the visible area should be prefetched. This resets the
+    /// window, so the next scroll reports everything around the visible rows again.
+    pub fn set_distance(&mut self, distance: usize) {
+        self.distance = distance;
+        self.reset();
+    }
+
+    /// Forgets the current window; used whenever row numbers stop meaning what they used to
+    /// (e.g, on a reload).
+    pub fn reset(&mut self) {
+        self.window = 0..0;
+        self.prefetched.clear();
+    }
+
+    /// Moves the window to surround `visible` (clamped to `total` rows), returning the rows that
+    /// have newly come within range and should be prefetched, and the prefetched rows that have
+    /// fallen out of range and can be cancelled. Rows that are (or were) visible are never
+    /// prefetched, and a prefetched row that scrolls into view is never cancelled.
+    pub fn update(&mut self, visible: Range<usize>, total: usize) -> (Vec<usize>, Vec<usize>) {
+        let window = visible.start.saturating_sub(self.distance)..(visible.end + self.distance).min(total);
+
+        let prefetch: Vec<usize> = window.clone()
+            .filter(|row| !self.window.contains(row) && !visible.contains(row))
+            .collect();
+
+        // Once a prefetched row has been shown, it's been used; it's not ours to cancel anymore.
+        self.prefetched.retain(|row| !visible.contains(row));
+
+        let cancel: Vec<usize> = self.prefetched.iter()
+            .filter(|row| !window.contains(row))
+            .cloned()
+            .collect();
+
+        for row in &cancel {
+            self.prefetched.remove(row);
+        }
+
+        self.prefetched.extend(prefetch.iter().cloned());
+        self.window = window;
+        (prefetch, cancel)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn state(distance: usize) -> PrefetchState {
+        let mut state = PrefetchState::default();
+        state.set_distance(distance);
+        state
+    }
+
+    #[test]
+    fn first_update_prefetches_around_the_visible_rows() {
+        let mut state = state(2);
+        let (prefetch, cancel) = state.update(10..15, 100);
+        assert_eq!(prefetch, vec![8, 9, 15, 16]);
+        assert!(cancel.is_empty());
+    }
+
+    #[test]
+    fn scrolling_down() {
+        let mut state = state(2);
+        state.update(10..15, 100);
+
+        let (prefetch, cancel) = state.update(12..17, 100);
+        assert_eq!(prefetch, vec![17, 18]);
+        assert_eq!(cancel, vec![8, 9]);
+    }
+
+    #[test]
+    fn scrolling_up() {
+        let mut state = state(2);
+        state.update(10..15, 100);
+
+        let (prefetch, cancel) = state.update(7..12, 100);
+        assert_eq!(prefetch, vec![5, 6]);
+        assert_eq!(cancel, vec![15, 16]);
+    }
+
+    #[test]
+    fn jumping_away_cancels_everything_prefetched() {
+        let mut state = state(2);
+        state.update(10..15, 100);
+
+        let (prefetch, cancel) = state.update(50..55, 100);
+        assert_eq!(prefetch, vec![48, 49, 55, 56]);
+        assert_eq!(cancel, vec![8, 9, 15, 16]);
+    }
+
+    #[test]
+    fn visible_rows_are_never_cancelled() {
+        let mut state = state(2);
+        state.update(10..15, 100);
+
+        // Everything that was on screen falls out of range; none of it was ever prefetched.
+        let (_, cancel) = state.update(30..35, 100);
+        assert_eq!(cancel, vec![8, 9, 15, 16]);
+
+        // 28 and 29 were prefetched, then shown; they aren't cancelled when they leave. Nor are
+        // 31 and 32, which were on screen all along.
+        let (_, cancel) = state.update(26..31, 100);
+        assert_eq!(cancel, vec![35, 36]);
+
+        let (_, cancel) = state.update(60..65, 100);
+        assert_eq!(cancel, vec![24, 25]);
+    }
+
+    #[test]
+    fn clamps_at_the_start() {
+        let mut state = state(5);
+        let (prefetch, _) = state.update(0..3, 100);
+        assert_eq!(prefetch, vec![3, 4, 5, 6, 7]);
+
+        let (prefetch, cancel) = state.update(2..5, 100);
+        assert_eq!(prefetch, vec![8, 9]);
+        assert!(cancel.is_empty());
+    }
+
+    #[test]
+    fn clamps_at_the_end() {
+        let mut state = state(5);
+        let (prefetch, _) = state.update(95..98, 100);
+        assert_eq!(prefetch, vec![90, 91, 92, 93, 94, 98, 99]);
+
+        let (prefetch, cancel) = state.update(97..100, 100);
+        assert!(prefetch.is_empty());
+        assert_eq!(cancel, vec![90, 91]);
+    }
+
+    #[test]
+    fn reset_forgets_the_window() {
+        let mut state = state(2);
+        state.update(10..15, 100);
+        state.reset();
+
+        let (prefetch, cancel) = state.update(10..15, 100);
+        assert_eq!(prefetch, vec![8, 9, 15, 16]);
+        assert!(cancel.is_empty());
+    }
+
+    #[test]
+    fn changing_the_distance_starts_over() {
+        let mut state = state(2);
+        state.update(10..15, 100);
+        state.set_distance(4);
+
+        let (prefetch, cancel) = state.update(10..15, 100);
+        assert_eq!(prefetch, vec![6, 7, 8, 9, 15, 16, 17, 18]);
+        assert!(cancel.is_empty());
+
+        let (_, cancel) = state.update(40..45, 100);
+        assert_eq!(cancel, vec![6, 7, 8, 9, 15, 16, 17, 18]);
+    }
+}
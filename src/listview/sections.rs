@@ -0,0 +1,435 @@
+//! Maps sectioned content (`IndexPath`s, headers and footers) onto the flat list of rows that
+//! `NSTableView` actually works with.
+
+use objc::runtime::Object;
+use objc_id::ShareId;
+
+use crate::listview::{ListViewDelegate, ListViewRow};
+use crate::listview::heights::HeightCache;
+
+/// Addresses an item in a `ListView`: the section it's in, and its row within that section.
+/// Header and footer rows don't count towards `row`.
+#[derive(Copy, Clone, Debug, Default, PartialEq, Eq, Hash, PartialOrd, Ord)]
+pub struct IndexPath {
+    /// The section index.
+    pub section: usize,
+
+    /// The row index, within the section.
+    pub row: usize
+}
+
+impl IndexPath {
+    /// Returns a new `IndexPath`.
+    pub fn new(section: usize, row: usize) -> Self {
+        IndexPath { section, row }
+    }
+}
+
+/// What a given (flat) table row displays.
+#[derive(Copy, Clone, Debug, PartialEq)]
+pub(crate) enum RowKind {
+    /// The header for a section.
+    Header(usize),
+
+    /// An item.
+    Item(IndexPath),
+
+    /// The footer for a section.
+    Footer(usize),
+
+    /// The "load more" footer, shown after every section.
+    LoadMore
+}
+
+/// The shape of a single section.
+#[derive(Debug, Default)]
+pub(crate) struct Section {
+    header: Option<ListViewRow>,
+    footer: Option<ListViewRow>,
+    items: usize
+}
+
+impl Section {
+    /// The number of header rows (0 or 1) that precede the items.
+    fn header_rows(&self) -> usize {
+        match self.header.is_some() {
+            true => 1,
+            false => 0
+        }
+    }
+
+    /// The total number of table rows this section occupies.
+    fn len(&self) -> usize {
+        self.header_rows() + self.items + match self.footer.is_some() {
+            true => 1,
+            false => 0
+        }
+    }
+
+    /// Whether `other` occupies the same rows as this section, i.e whether swapping one for the
+    /// other leaves every item where it was.
+    fn same_shape(&self, other: &Section) -> bool {
+        self.header.is_some() == other.header.is_some()
+            && self.footer.is_some() == other.footer.is_some()
+            && self.items == other.items
+    }
+}
+
+/// The current section layout for a `ListView`. This is built from the delegate when the table
+/// first loads and on every `ListView::reload()`, and adjusted in place as rows are inserted,
+/// removed or moved, so that `IndexPath`s can be translated to table rows (and back) at any point.
+///
+/// Header and footer views are vended when the layout is rebuilt, and retained here until the
+/// next rebuild. Cached row heights live here too, so that they can be shifted around (or
+/// thrown away) as the layout changes.
+#[derive(Debug, Default)]
+pub(crate) struct SectionLayout {
+    sections: Vec<Section>,
+    load_more: Option<ShareId<Object>>,
+    heights: HeightCache,
+
+    /// Whether the layout reflects the delegate. Until it does, the next row count query from
+    /// the table rebuilds it.
+    loaded: bool
+}
+
+impl SectionLayout {
+    /// Queries the delegate for the shape of every section. This doesn't touch the current
+    /// layout, so it can be done outside of a borrow - the delegate may well call back into the
+    /// `ListView` (e.g, to dequeue header views).
+    pub fn query<T: ListViewDelegate>(delegate: &T) -> Vec<Section> {
+        (0..delegate.number_of_sections()).map(|section| Section {
+            header: delegate.header_for(section),
+            footer: delegate.footer_for(section),
+            items: delegate.items_in_section(section)
+        }).collect()
+    }
+
+    /// Rebuilds the layout from freshly queried sections, returning the total number of table
+    /// rows. The "load more" footer isn't tied to any section, and is kept.
+    ///
+    /// If every section has the same shape as before, items keep their cached heights; only the
+    /// (freshly vended) headers and footers are re-measured. Otherwise, every height goes.
+    pub fn rebuild(&mut self, sections: Vec<Section>) -> usize {
+        let unchanged = self.sections.len() == sections.len() && self.sections.iter()
+            .zip(sections.iter())
+            .all(|(old, new)| old.same_shape(new));
+
+        self.sections = sections;
+        self.loaded = true;
+
+        match unchanged {
+            true => {
+                let rows = self.header_and_footer_rows();
+                self.heights.invalidate(&rows);
+            },
+
+            false => {
+                self.heights.clear();
+            }
+        }
+
+        self.total_rows()
+    }
+
+    /// Returns the table rows that hold section headers and footers.
+    fn header_and_footer_rows(&self) -> Vec<usize> {
+        let mut rows = vec![];
+        let mut start = 0;
+
+        for section in &self.sections {
+            if section.header.is_some() {
+                rows.push(start);
+            }
+
+            if section.footer.is_some() {
+                rows.push(start + section.len() - 1);
+            }
+
+            start += section.len();
+        }
+
+        rows
+    }
+
+    /// Returns whether the layout needs to be rebuilt from the delegate before it can be used.
+    pub fn needs_rebuild(&self) -> bool {
+        !self.loaded
+    }
+
+    /// Marks the layout as out of date, so that it's rebuilt (and headers and footers vended
+    /// again) the next time the table asks for its row count.
+    pub fn set_needs_rebuild(&mut self) {
+        self.loaded = false;
+    }
+
+    /// The total number of table rows.
+    pub fn total_rows(&self) -> usize {
+        self.sections.iter().map(|section| section.len()).sum::<usize>() + match self.load_more.is_some() {
+            true => 1,
+            false => 0
+        }
+    }
+
+    /// Returns the table row that `path` corresponds to.
+    ///
+    /// Sections past the end of the layout are treated as empty and header-less, which keeps a
+    /// single-section list working before it's ever been laid out.
+    pub fn row_for(&self, path: IndexPath) -> usize {
+        let start: usize = self.sections.iter().take(path.section).map(|section| section.len()).sum();
+
+        let header_rows = match self.sections.get(path.section) {
+            Some(section) => section.header_rows(),
+            None => 0
+        };
+
+        start + header_rows + path.row
+    }
+
+    /// Returns what the given table row displays, if it's in range.
+    pub fn kind_for_row(&self, row: usize) -> Option<RowKind> {
+        let mut start = 0;
+
+        for (index, section) in self.sections.iter().enumerate() {
+            if row < start + section.len() {
+                let offset = row - start;
+                let header_rows = section.header_rows();
+
+                return Some(match offset {
+                    offset if offset < header_rows => RowKind::Header(index),
+                    offset if offset - header_rows < section.items => {
+                        RowKind::Item(IndexPath::new(index, offset - header_rows))
+                    },
+                    _ => RowKind::Footer(index)
+                });
+            }
+
+            start += section.len();
+        }
+
+        match row == start && self.load_more.is_some() {
+            true => Some(RowKind::LoadMore),
+            false => None
+        }
+    }
+
+    /// Returns the insertion point for a drop *above* the given table row: the `IndexPath` an
+    /// item dropped there would end up at. Rows between a header and its first item, or between
+    /// the last item and the footer, resolve to that section; drops above a header resolve to
+    /// the end of the previous section. Returns `None` if there's nowhere to insert (e.g, above
+    /// the very first header).
+    pub fn insertion_index_for_row(&self, row: usize) -> Option<IndexPath> {
+        if self.sections.is_empty() {
+            return match row {
+                0 => Some(IndexPath::new(0, 0)),
+                _ => None
+            };
+        }
+
+        let mut start = 0;
+
+        for (index, section) in self.sections.iter().enumerate() {
+            let items_start = start + section.header_rows();
+            let items_end = items_start + section.items;
+
+            if row >= items_start && row <= items_end {
+                return Some(IndexPath::new(index, row - items_start));
+            }
+
+            start += section.len();
+        }
+
+        None
+    }
+
+    /// Returns the retained header view for `section`, if it has one.
+    pub fn header(&self, section: usize) -> Option<&ListViewRow> {
+        self.sections.get(section).and_then(|section| section.header.as_ref())
+    }
+
+    /// Returns the retained footer view for `section`, if it has one.
+    pub fn footer(&self, section: usize) -> Option<&ListViewRow> {
+        self.sections.get(section).and_then(|section| section.footer.as_ref())
+    }
+
+    /// Returns the "load more" footer view, if it's being shown.
+    pub fn load_more(&self) -> Option<&ShareId<Object>> {
+        self.load_more.as_ref()
+    }
+
+    /// Returns the table row the "load more" footer is in, if it's being shown.
+    pub fn load_more_row(&self) -> Option<usize> {
+        match self.load_more.is_some() {
+            true => Some(self.total_rows() - 1),
+            false => None
+        }
+    }
+
+    /// Sets (or clears) the "load more" footer view. Unlike section headers and footers, this
+    /// survives rebuilds.
+    pub fn set_load_more(&mut self, view: Option<ShareId<Object>>) {
+        if let Some(row) = self.load_more_row() {
+            self.heights.invalidate(&[row]);
+        }
+
+        self.load_more = view;
+
+        if let Some(row) = self.load_more_row() {
+            self.heights.invalidate(&[row]);
+        }
+    }
+
+    /// Returns the row height cache.
+    pub fn heights(&self) -> &HeightCache {
+        &self.heights
+    }
+
+    /// Returns the row height cache, for updating.
+    pub fn heights_mut(&mut self) -> &mut HeightCache {
+        &mut self.heights
+    }
+
+    /// Accounts for an item being inserted at `path`. Cached heights for the rows after it are
+    /// shifted along with them.
+    pub fn insert_item(&mut self, path: IndexPath) {
+        while self.sections.len() <= path.section {
+            self.sections.push(Section::default());
+        }
+
+        self.sections[path.section].items += 1;
+
+        let row = self.row_for(path);
+        self.heights.insert_row(row);
+    }
+
+    /// Accounts for the item at `path` being removed. Its cached height is dropped, and those for
+    /// the rows after it are shifted along with them.
+    pub fn remove_item(&mut self, path: IndexPath) {
+        let row = self.row_for(path);
+
+        if let Some(section) = self.sections.get_mut(path.section) {
+            if section.items > 0 {
+                section.items -= 1;
+                self.heights.remove_row(row);
+            }
+        }
+    }
+
+    /// Accounts for the items at `from` being moved into `to_section` in one go (e.g, by a drag
+    /// reorder). Every row between the old and new positions shifts, so cached heights are
+    /// simply thrown away.
+    pub fn move_items(&mut self, from: &[IndexPath], to_section: usize) {
+        for path in from {
+            if let Some(section) = self.sections.get_mut(path.section) {
+                section.items = section.items.saturating_sub(1);
+            }
+        }
+
+        while self.sections.len() <= to_section {
+            self.sections.push(Section::default());
+        }
+
+        self.sections[to_section].items += from.len();
+        self.heights.clear();
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::{IndexPath, RowKind, Section, SectionLayout};
+
+    /// Header- and footer-less sections with the given item counts.
+    fn sections(items: &[usize]) -> Vec<Section> {
+        items.iter().map(|&items| Section {
+            header: None,
+            footer: None,
+            items: items
+        }).collect()
+    }
+
+    /// A layout with every row's height cached as 10 times its row number.
+    fn measured(items: &[usize]) -> SectionLayout {
+        let mut layout = SectionLayout::default();
+        let rows = layout.rebuild(sections(items));
+
+        for row in 0..rows {
+            layout.heights_mut().insert(row, (row * 10) as _);
+        }
+
+        layout
+    }
+
+    #[test]
+    fn starts_out_needing_a_rebuild() {
+        let mut layout = SectionLayout::default();
+        assert!(layout.needs_rebuild());
+
+        layout.rebuild(sections(&[2]));
+        assert!(!layout.needs_rebuild());
+
+        layout.set_needs_rebuild();
+        assert!(layout.needs_rebuild());
+    }
+
+    #[test]
+    fn maps_index_paths_to_rows_and_back() {
+        let mut layout = SectionLayout::default();
+        assert_eq!(layout.rebuild(sections(&[2, 0, 3])), 5);
+
+        assert_eq!(layout.row_for(IndexPath::new(0, 1)), 1);
+        assert_eq!(layout.row_for(IndexPath::new(2, 0)), 2);
+        assert_eq!(layout.kind_for_row(4), Some(RowKind::Item(IndexPath::new(2, 2))));
+        assert_eq!(layout.kind_for_row(5), None);
+    }
+
+    #[test]
+    fn rebuilding_with_the_same_shape_keeps_heights() {
+        let mut layout = measured(&[2, 3]);
+        layout.rebuild(sections(&[2, 3]));
+
+        assert_eq!(layout.heights().get(0), Some(0.));
+        assert_eq!(layout.heights().get(4), Some(40.));
+    }
+
+    #[test]
+    fn rebuilding_with_a_new_shape_drops_heights() {
+        let mut layout = measured(&[2, 3]);
+        layout.rebuild(sections(&[3, 2]));
+
+        assert_eq!(layout.heights().get(0), None);
+        assert_eq!(layout.heights().get(4), None);
+    }
+
+    #[test]
+    fn inserting_an_item_shifts_heights_after_it() {
+        let mut layout = measured(&[2, 3]);
+        layout.insert_item(IndexPath::new(1, 0));
+
+        assert_eq!(layout.total_rows(), 6);
+        assert_eq!(layout.heights().get(1), Some(10.));
+        assert_eq!(layout.heights().get(2), None);
+        assert_eq!(layout.heights().get(3), Some(20.));
+        assert_eq!(layout.heights().get(5), Some(40.));
+    }
+
+    #[test]
+    fn removing_an_item_shifts_heights_after_it() {
+        let mut layout = measured(&[2, 3]);
+        layout.remove_item(IndexPath::new(0, 1));
+
+        assert_eq!(layout.total_rows(), 4);
+        assert_eq!(layout.heights().get(0), Some(0.));
+        assert_eq!(layout.heights().get(1), Some(20.));
+        assert_eq!(layout.heights().get(3), Some(40.));
+    }
+
+    #[test]
+    fn removing_from_an_empty_section_changes_nothing() {
+        let mut layout = measured(&[0, 2]);
+        layout.remove_item(IndexPath::new(0, 0));
+
+        assert_eq!(layout.total_rows(), 2);
+        assert_eq!(layout.heights().get(0), Some(0.));
+        assert_eq!(layout.heights().get(1), Some(10.));
+    }
+}
@@ -1,8 +1,11 @@
 //! Various traits used for Views.
 
+use core_graphics::base::CGFloat;
+
 use crate::Node;
 use crate::dragdrop::{DragInfo, DragOperation};
-use crate::listview::{ListView, ListViewRow, RowAction, RowEdge};
+use crate::listview::{DropPosition, IndexPath, ListView, ListViewRow, RowAction, RowEdge};
+use crate::pasteboard::PasteboardItem;
 use crate::layout::Layout;
 use crate::view::View;
 
@@ -24,18 +27,103 @@ pub trait ListViewDelegate {
     /// main thread!
     fn did_load(&mut self, view: ListView);
 
-    /// Returns the number of items in the list view.
-    fn number_of_items(&self) -> usize;
+    /// Returns the number of sections in the list view. Defaults to a single section.
+    fn number_of_sections(&self) -> usize { 1 }
+
+    /// Returns the number of items in the given section.
+    fn items_in_section(&self, section: usize) -> usize;
 
     /// This is temporary and you should not rely on this signature if you
     /// choose to try and work with this. NSTableView & such associated delegate patterns
     /// are tricky to support in Rust, and while I have a few ideas about them, I haven't
     /// had time to sit down and figure them out properly yet.
-    fn item_for(&self, row: usize) -> ListViewRow;
+    fn item_for(&self, index: IndexPath) -> ListViewRow;
+
+    /// Returns a header view for the given section, or `None` for no header. Headers are
+    /// displayed as group rows - on macOS, they stick to the top of the list while their section
+    /// is scrolled (see `ListView::set_sticky_headers()`).
+    ///
+    /// This is called whenever the list reloads, and the view is kept until the next reload.
+    fn header_for(&self, section: usize) -> Option<ListViewRow> { None }
+
+    /// Returns a footer view for the given section, or `None` for no footer.
+    ///
+    /// This is called whenever the list reloads, and the view is kept until the next reload.
+    fn footer_for(&self, section: usize) -> Option<ListViewRow> { None }
     
+    /// Returns the height of the item at `index`, or `None` to use the list's row height (see
+    /// `ListView::set_row_height()`). This is only consulted with automatic row heights turned
+    /// off (see `ListView::set_uses_automatic_row_heights()`). Each row is asked once, and the
+    /// height is cached until the row reloads, or `ListView::note_height_changed()` is called.
+    fn height_for_row(&self, index: IndexPath) -> Option<CGFloat> { None }
+
+    /// Returns an estimated height for the item at `index`, which is used with automatic row
+    /// heights until the row has actually been laid out. Good estimates keep the scroller accurate
+    /// when flicking through large lists. Return `None` to fall back to the estimate set with
+    /// `ListView::set_estimated_row_height()`.
+    fn estimated_height_for_row(&self, index: IndexPath) -> Option<CGFloat> { None }
+
     /// An optional delegate method; implement this if you'd like swipe-to-reveal to be
     /// supported for a given row by returning a vector of actions to show.
-    fn actions_for(&self, row: usize, edge: RowEdge) -> Vec<RowAction> { Vec::new() }
+    fn actions_for(&self, index: IndexPath, edge: RowEdge) -> Vec<RowAction> { Vec::new() }
+
+    /// Called when the user is about to select a row; return `false` to prevent it. Section
+    /// headers and footers can never be selected, and this isn't consulted if the selection mode
+    /// is `SelectionMode::None`.
+    fn should_select(&self, index: IndexPath) -> bool { true }
+
+    /// Called whenever the selection changes, with the now-selected rows in ascending order.
+    fn selection_did_change(&self, selected: Vec<IndexPath>) {}
+
+    /// Called when a row is double clicked.
+    fn row_double_clicked(&self, index: IndexPath) {}
+
+    /// Return `true` to supply type-select strings via `type_select_string_for()`. When `false`
+    /// (the default), AppKit's built-in type-select is left as-is.
+    ///
+    /// This is checked once, when the list's class is registered.
+    fn uses_custom_type_select(&self) -> bool { false }
+
+    /// Returns the string to match against when the user types to select a row (i.e, type-select).
+    /// Return `None` to exclude a row from type-select. This is only consulted if
+    /// `uses_custom_type_select()` returns `true`.
+    fn type_select_string_for(&self, index: IndexPath) -> Option<String> { None }
+
+    /// Called as the list scrolls, with items that have come close to the visible area. This is
+    /// a good place to start loading data for them (e.g, the next page of results from a server),
+    /// so it's ready by the time they're displayed. How far ahead this looks can be configured
+    /// with `ListView::set_prefetch_distance()`.
+    fn prefetch_rows(&self, indexes: Vec<IndexPath>) {}
+
+    /// Called as the list scrolls, with items previously passed to `prefetch_rows` that are no
+    /// longer close to the visible area. Any loading still in flight for them can be cancelled.
+    fn cancel_prefetch(&self, indexes: Vec<IndexPath>) {}
+
+    /// Called when the "load more" footer (see `ListView::set_shows_load_more_footer()`) comes
+    /// into view. Fetch the next page, insert the new rows, and hide the footer once there's
+    /// nothing left to load.
+    fn load_more(&self) {}
+
+    /// Returns the pasteboard item to write when the given row is dragged, or `None` if the
+    /// row can't be dragged out of the list. With reordering enabled (see
+    /// `ListView::set_allows_reordering()`), rows can be dragged regardless.
+    fn pasteboard_writer_for(&self, index: IndexPath) -> Option<PasteboardItem> { None }
+
+    /// Called as a drag moves over the list, to validate a proposed drop. `position` says whether
+    /// the drop is onto the item at `index`, or between rows - in which case `index` is where a
+    /// dropped item would be inserted. Return `DragOperation::None` to refuse the drop.
+    ///
+    /// Internal reorders are handled for you, and won't call this.
+    fn validate_drop(&self, info: DragInfo, index: IndexPath, position: DropPosition) -> DragOperation { DragOperation::None }
+
+    /// Called when a drop that passed `validate_drop` is released over the list. Return `true`
+    /// if the drop was accepted.
+    fn accept_drop(&self, info: DragInfo, index: IndexPath, position: DropPosition) -> bool { false }
+
+    /// Called when the user reorders rows by dragging them. `from` holds the dragged rows in
+    /// ascending order, and `to` is the insertion point, addressed as it was *before* the move.
+    /// You should update your data to match; the list animates the move itself.
+    fn move_rows(&self, from: Vec<IndexPath>, to: IndexPath) {}
 
     /// Called when this is about to be added to the view heirarchy.
     fn will_appear(&self, animated: bool) {}
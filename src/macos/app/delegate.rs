@@ -18,6 +18,7 @@ use crate::foundation::{id, nil, to_bool, BOOL, YES, NO, NSUInteger, NSArray, NS
 use crate::macos::app::{APP_PTR, AppDelegate};
 use crate::macos::printing::PrintSettings;
 use crate::user_activity::UserActivity;
+use crate::panic::AddGuardedMethod;
 
 #[cfg(feature = "cloudkit")]
 use crate::cloudkit::share::CKShareMetaData;
@@ -33,78 +34,78 @@ fn app<T>(this: &Object) -> &T {
 }
 
 /// Fires when the Application Delegate receives a `applicationWillFinishLaunching` notification.
-extern fn will_finish_launching<T: AppDelegate>(this: &Object, _: Sel, _: id) {
+fn will_finish_launching<T: AppDelegate>(this: &Object, _: Sel, _: id) {
     app::<T>(this).will_finish_launching();
 }
 
 /// Fires when the Application Delegate receives a `applicationDidFinishLaunching` notification.
-extern fn did_finish_launching<T: AppDelegate>(this: &Object, _: Sel, _: id) {
+fn did_finish_launching<T: AppDelegate>(this: &Object, _: Sel, _: id) {
     app::<T>(this).did_finish_launching();
 }
 
 /// Fires when the Application Delegate receives a `applicationWillBecomeActive` notification.
-extern fn will_become_active<T: AppDelegate>(this: &Object, _: Sel, _: id) {
+fn will_become_active<T: AppDelegate>(this: &Object, _: Sel, _: id) {
     app::<T>(this).will_become_active();
 }
 
 /// Fires when the Application Delegate receives a `applicationDidBecomeActive` notification.
-extern fn did_become_active<T: AppDelegate>(this: &Object, _: Sel, _: id) {
+fn did_become_active<T: AppDelegate>(this: &Object, _: Sel, _: id) {
     app::<T>(this).did_become_active();
 }
 
 /// Fires when the Application Delegate receives a `applicationWillResignActive` notification.
-extern fn will_resign_active<T: AppDelegate>(this: &Object, _: Sel, _: id) {
+fn will_resign_active<T: AppDelegate>(this: &Object, _: Sel, _: id) {
     app::<T>(this).will_resign_active();
 }
 
 /// Fires when the Application Delegate receives a `applicationDidResignActive` notification.
-extern fn did_resign_active<T: AppDelegate>(this: &Object, _: Sel, _: id) {
+fn did_resign_active<T: AppDelegate>(this: &Object, _: Sel, _: id) {
     app::<T>(this).did_resign_active();
 }
 
 /// Fires when the Application Delegate receives a 'applicationShouldTerminate:` notification.
-extern fn should_terminate<T: AppDelegate>(this: &Object, _: Sel, _: id) -> NSUInteger {
+fn should_terminate<T: AppDelegate>(this: &Object, _: Sel, _: id) -> NSUInteger {
     app::<T>(this).should_terminate().into()
 }
 
 /// Fires when the Application Delegate receives a `applicationWillTerminate:` notification.
-extern fn will_terminate<T: AppDelegate>(this: &Object, _: Sel, _: id) {
+fn will_terminate<T: AppDelegate>(this: &Object, _: Sel, _: id) {
     app::<T>(this).will_terminate();
 }
 
 /// Fires when the Application Delegate receives a `applicationWillHide:` notification.
-extern fn will_hide<T: AppDelegate>(this: &Object, _: Sel, _: id) {
+fn will_hide<T: AppDelegate>(this: &Object, _: Sel, _: id) {
     app::<T>(this).will_hide();
 }
 
 /// Fires when the Application Delegate receives a `applicationDidHide:` notification.
-extern fn did_hide<T: AppDelegate>(this: &Object, _: Sel, _: id) {
+fn did_hide<T: AppDelegate>(this: &Object, _: Sel, _: id) {
     app::<T>(this).did_hide();
 }
 
 /// Fires when the Application Delegate receives a `applicationWillUnhide:` notification.
-extern fn will_unhide<T: AppDelegate>(this: &Object, _: Sel, _: id) {
+fn will_unhide<T: AppDelegate>(this: &Object, _: Sel, _: id) {
     app::<T>(this).will_unhide();
 }
 
 /// Fires when the Application Delegate receives a `applicationDidUnhide:` notification.
-extern fn did_unhide<T: AppDelegate>(this: &Object, _: Sel, _: id) {
+fn did_unhide<T: AppDelegate>(this: &Object, _: Sel, _: id) {
     app::<T>(this).did_unhide();
 }
 
 /// Fires when the Application Delegate receives a `applicationWillUpdate:` notification.
-extern fn will_update<T: AppDelegate>(this: &Object, _: Sel, _: id) {
+fn will_update<T: AppDelegate>(this: &Object, _: Sel, _: id) {
     app::<T>(this).will_update();
 }
 
 /// Fires when the Application Delegate receives a `applicationDidUpdate:` notification.
-extern fn did_update<T: AppDelegate>(this: &Object, _: Sel, _: id) {
+fn did_update<T: AppDelegate>(this: &Object, _: Sel, _: id) {
     app::<T>(this).did_update();
 }
 
 /// Fires when the Application Delegate receives a
 /// `applicationShouldHandleReopen:hasVisibleWindows:` notification.
-extern fn should_handle_reopen<T: AppDelegate>(this: &Object, _: Sel, _: id, has_visible_windows: BOOL) -> BOOL {
+fn should_handle_reopen<T: AppDelegate>(this: &Object, _: Sel, _: id, has_visible_windows: BOOL) -> BOOL {
     match app::<T>(this).should_handle_reopen(to_bool(has_visible_windows)) {
         true => YES,
         false => NO
@@ -112,7 +113,7 @@ extern fn should_handle_reopen<T: AppDelegate>(this: &Object, _: Sel, _: id, has
 }
 
 /// Fires when the application delegate receives a `applicationDockMenu:` request.
-extern fn dock_menu<T: AppDelegate>(this: &Object, _: Sel, _: id) -> id {
+fn dock_menu<T: AppDelegate>(this: &Object, _: Sel, _: id) -> id {
     match app::<T>(this).dock_menu() {
         Some(mut menu) => &mut *menu.inner,
         None => nil
@@ -120,19 +121,19 @@ extern fn dock_menu<T: AppDelegate>(this: &Object, _: Sel, _: id) -> id {
 }
 
 /// Fires when the application delegate receives a `application:willPresentError:` notification.
-extern fn will_present_error<T: AppDelegate>(this: &Object, _: Sel, _: id, error: id) -> id {
+fn will_present_error<T: AppDelegate>(this: &Object, _: Sel, _: id, error: id) -> id {
     let error = Error::new(error);
     app::<T>(this).will_present_error(error).into_nserror()
 }
 
 /// Fires when the application receives a `applicationDidChangeScreenParameters:` notification.
-extern fn did_change_screen_parameters<T: AppDelegate>(this: &Object, _: Sel, _: id) {
+fn did_change_screen_parameters<T: AppDelegate>(this: &Object, _: Sel, _: id) {
     app::<T>(this).did_change_screen_parameters();
 }
 
 /// Fires when the application receives a `application:willContinueUserActivityWithType:`
 /// notification.
-extern fn will_continue_user_activity_with_type<T: AppDelegate>(this: &Object, _: Sel, _: id, activity_type: id) -> BOOL {
+fn will_continue_user_activity_with_type<T: AppDelegate>(this: &Object, _: Sel, _: id, activity_type: id) -> BOOL {
     let activity = NSString::wrap(activity_type);
 
     match app::<T>(this).will_continue_user_activity(activity.to_str()) {
@@ -142,7 +143,7 @@ extern fn will_continue_user_activity_with_type<T: AppDelegate>(this: &Object, _
 }
 
 /// Fires when the application receives a `application:continueUserActivity:restorationHandler:` notification.
-extern fn continue_user_activity<T: AppDelegate>(this: &Object, _: Sel, _: id, activity: id, handler: id) -> BOOL {
+fn continue_user_activity<T: AppDelegate>(this: &Object, _: Sel, _: id, activity: id, handler: id) -> BOOL {
     // @TODO: This needs to support restorable objects, but it involves a larger question about how
     // much `NSObject` wrapping we want to do here. For now, pass the handler for whenever it's
     // useful. 
@@ -159,7 +160,7 @@ extern fn continue_user_activity<T: AppDelegate>(this: &Object, _: Sel, _: id, a
 
 /// Fires when the application receives a
 /// `application:didFailToContinueUserActivityWithType:error:` message.
-extern fn failed_to_continue_user_activity<T: AppDelegate>(this: &Object, _: Sel, _: id, activity_type: id, error: id) {
+fn failed_to_continue_user_activity<T: AppDelegate>(this: &Object, _: Sel, _: id, activity_type: id, error: id) {
     app::<T>(this).failed_to_continue_user_activity(
         NSString::wrap(activity_type).to_str(),
         Error::new(error)
@@ -167,41 +168,41 @@ extern fn failed_to_continue_user_activity<T: AppDelegate>(this: &Object, _: Sel
 }
 
 /// Fires when the application receives a `application:didUpdateUserActivity:` message.
-extern fn did_update_user_activity<T: AppDelegate>(this: &Object, _: Sel, _: id, activity: id) {
+fn did_update_user_activity<T: AppDelegate>(this: &Object, _: Sel, _: id, activity: id) {
     let activity = UserActivity::with_inner(activity);
     app::<T>(this).updated_user_activity(activity);
 }
 
 /// Fires when the application receives a `application:didRegisterForRemoteNotificationsWithDeviceToken:` message.
-extern fn registered_for_remote_notifications<T: AppDelegate>(_this: &Object, _: Sel, _: id, _: id) {
+fn registered_for_remote_notifications<T: AppDelegate>(_this: &Object, _: Sel, _: id, _: id) {
 
 }
 
 /// Fires when the application receives a `application:didFailToRegisterForRemoteNotificationsWithError:` message.
-extern fn failed_to_register_for_remote_notifications<T: AppDelegate>(this: &Object, _: Sel, _: id, error: id) {
+fn failed_to_register_for_remote_notifications<T: AppDelegate>(this: &Object, _: Sel, _: id, error: id) {
     app::<T>(this).failed_to_register_for_remote_notifications(Error::new(error));
 }
 
 /// Fires when the application receives a `application:didReceiveRemoteNotification:` message.
-extern fn did_receive_remote_notification<T: AppDelegate>(_this: &Object, _: Sel, _: id, _: id) {
+fn did_receive_remote_notification<T: AppDelegate>(_this: &Object, _: Sel, _: id, _: id) {
 
 }
 
 /// Fires when the application receives a `application:userDidAcceptCloudKitShareWithMetadata:`
 /// message.
 #[cfg(feature = "cloudkit")]
-extern fn accepted_cloudkit_share<T: AppDelegate>(this: &Object, _: Sel, _: id, metadata: id) {
+fn accepted_cloudkit_share<T: AppDelegate>(this: &Object, _: Sel, _: id, metadata: id) {
     let share = CKShareMetaData::with_inner(metadata);
     app::<T>(this).user_accepted_cloudkit_share(share);
 }
 
 /// Fires when the application receives an `application:openURLs` message.
-extern fn open_urls<T: AppDelegate>(this: &Object, _: Sel, _: id, file_urls: id) {
+fn open_urls<T: AppDelegate>(this: &Object, _: Sel, _: id, file_urls: id) {
     let urls = NSArray::wrap(file_urls).map(|url| {
         let uri = NSString::wrap(unsafe {
             msg_send![url, absoluteString]
         });
-        
+
         Url::parse(uri.to_str())
     }).into_iter().filter_map(|url| url.ok()).collect();
 
@@ -209,7 +210,7 @@ extern fn open_urls<T: AppDelegate>(this: &Object, _: Sel, _: id, file_urls: id)
 }
 
 /// Fires when the application receives an `application:openFileWithoutUI:` message.
-extern fn open_file_without_ui<T: AppDelegate>(this: &Object, _: Sel, _: id, file: id) -> BOOL {
+fn open_file_without_ui<T: AppDelegate>(this: &Object, _: Sel, _: id, file: id) -> BOOL {
     let filename = NSString::wrap(file);
 
     match app::<T>(this).open_file_without_ui(filename.to_str()) {
@@ -219,7 +220,7 @@ extern fn open_file_without_ui<T: AppDelegate>(this: &Object, _: Sel, _: id, fil
 }
 
 /// Fired when the application receives an `applicationShouldOpenUntitledFile:` message.
-extern fn should_open_untitled_file<T: AppDelegate>(this: &Object, _: Sel, _: id) -> BOOL {
+fn should_open_untitled_file<T: AppDelegate>(this: &Object, _: Sel, _: id) -> BOOL {
     match app::<T>(this).should_open_untitled_file() {
         true => YES,
         false => NO
@@ -227,7 +228,7 @@ extern fn should_open_untitled_file<T: AppDelegate>(this: &Object, _: Sel, _: id
 }
 
 /// Fired when the application receives an `applicationShouldTerminateAfterLastWindowClosed:` message.
-extern fn should_terminate_after_last_window_closed<T: AppDelegate>(this: &Object, _: Sel, _: id) -> BOOL {
+fn should_terminate_after_last_window_closed<T: AppDelegate>(this: &Object, _: Sel, _: id) -> BOOL {
     match app::<T>(this).should_terminate_after_last_window_closed() {
         true => YES,
         false => NO
@@ -235,7 +236,7 @@ extern fn should_terminate_after_last_window_closed<T: AppDelegate>(this: &Objec
 }
 
 /// Fired when the application receives an `applicationOpenUntitledFile:` message.
-extern fn open_untitled_file<T: AppDelegate>(this: &Object, _: Sel, _: id) -> BOOL {
+fn open_untitled_file<T: AppDelegate>(this: &Object, _: Sel, _: id) -> BOOL {
     match app::<T>(this).open_untitled_file() {
         true => YES,
         false => NO
@@ -243,7 +244,7 @@ extern fn open_untitled_file<T: AppDelegate>(this: &Object, _: Sel, _: id) -> BO
 }
 
 /// Fired when the application receives an `application:openTempFile:` message.
-extern fn open_temp_file<T: AppDelegate>(this: &Object, _: Sel, _: id, filename: id) -> BOOL {
+fn open_temp_file<T: AppDelegate>(this: &Object, _: Sel, _: id, filename: id) -> BOOL {
     let filename = NSString::wrap(filename);
 
     match app::<T>(this).open_temp_file(filename.to_str()) {
@@ -253,7 +254,7 @@ extern fn open_temp_file<T: AppDelegate>(this: &Object, _: Sel, _: id, filename:
 }
 
 /// Fired when the application receives an `application:printFile:` message.
-extern fn print_file<T: AppDelegate>(this: &Object, _: Sel, _: id, file: id) -> BOOL {
+fn print_file<T: AppDelegate>(this: &Object, _: Sel, _: id, file: id) -> BOOL {
     let filename = NSString::wrap(file);
 
     match app::<T>(this).print_file(filename.to_str()) {
@@ -264,7 +265,7 @@ extern fn print_file<T: AppDelegate>(this: &Object, _: Sel, _: id, file: id) ->
 
 /// Fired when the application receives an `application:printFiles:withSettings:showPrintPanels:`
 /// message.
-extern fn print_files<T: AppDelegate>(this: &Object, _: Sel, _: id, files: id, settings: id, show_print_panels: BOOL) -> NSUInteger {
+fn print_files<T: AppDelegate>(this: &Object, _: Sel, _: id, files: id, settings: id, show_print_panels: BOOL) -> NSUInteger {
     let files = NSArray::wrap(files).map(|file| {
         NSString::wrap(file).to_str().to_string()
     });
@@ -275,14 +276,14 @@ extern fn print_files<T: AppDelegate>(this: &Object, _: Sel, _: id, files: id, s
 }
 
 /// Called when the application's occlusion state has changed.
-extern fn did_change_occlusion_state<T: AppDelegate>(this: &Object, _: Sel, _: id) {
+fn did_change_occlusion_state<T: AppDelegate>(this: &Object, _: Sel, _: id) {
     app::<T>(this).occlusion_state_changed();
 }
 
 /// Called when the application receives an `application:delegateHandlesKey:` message.
 /// Note: this may not fire in sandboxed applications. Apple's documentation is unclear on the
 /// matter.
-extern fn delegate_handles_key<T: AppDelegate>(this: &Object, _: Sel, _: id, key: id) -> BOOL {
+fn delegate_handles_key<T: AppDelegate>(this: &Object, _: Sel, _: id, key: id) -> BOOL {
     let key = NSString::wrap(key);
 
     match app::<T>(this).delegate_handles_key(key.to_str()) {
@@ -304,72 +305,72 @@ pub(crate) fn register_app_delegate_class<T: AppDelegate + AppDelegate>() -> *co
         decl.add_ivar::<usize>(APP_PTR);
 
         // Launching Applications
-        decl.add_method(sel!(applicationWillFinishLaunching:), will_finish_launching::<T> as extern fn(&Object, _, _));
-        decl.add_method(sel!(applicationDidFinishLaunching:), did_finish_launching::<T> as extern fn(&Object, _, _));
+        decl.add_guarded_method(sel!(applicationWillFinishLaunching:), will_finish_launching::<T>);
+        decl.add_guarded_method(sel!(applicationDidFinishLaunching:), did_finish_launching::<T>);
         
         // Managing Active Status
-        decl.add_method(sel!(applicationWillBecomeActive:), will_become_active::<T> as extern fn(&Object, _, _));
-        decl.add_method(sel!(applicationDidBecomeActive:), did_become_active::<T> as extern fn(&Object, _, _));
-        decl.add_method(sel!(applicationWillResignActive:), will_resign_active::<T> as extern fn(&Object, _, _));
-        decl.add_method(sel!(applicationDidResignActive:), did_resign_active::<T> as extern fn(&Object, _, _));
+        decl.add_guarded_method(sel!(applicationWillBecomeActive:), will_become_active::<T>);
+        decl.add_guarded_method(sel!(applicationDidBecomeActive:), did_become_active::<T>);
+        decl.add_guarded_method(sel!(applicationWillResignActive:), will_resign_active::<T>);
+        decl.add_guarded_method(sel!(applicationDidResignActive:), did_resign_active::<T>);
 
         // Terminating Applications
-        decl.add_method(sel!(applicationShouldTerminate:), should_terminate::<T> as extern fn(&Object, _, _) -> NSUInteger);
-        decl.add_method(sel!(applicationWillTerminate:), will_terminate::<T> as extern fn(&Object, _, _));
-        decl.add_method(sel!(applicationShouldTerminateAfterLastWindowClosed:), should_terminate_after_last_window_closed::<T> as extern fn(&Object, _, _) -> BOOL);
+        decl.add_guarded_method(sel!(applicationShouldTerminate:), should_terminate::<T>);
+        decl.add_guarded_method(sel!(applicationWillTerminate:), will_terminate::<T>);
+        decl.add_guarded_method(sel!(applicationShouldTerminateAfterLastWindowClosed:), should_terminate_after_last_window_closed::<T>);
 
         // Hiding Applications
-        decl.add_method(sel!(applicationWillHide:), will_hide::<T> as extern fn(&Object, _, _));
-        decl.add_method(sel!(applicationDidHide:), did_hide::<T> as extern fn(&Object, _, _));
-        decl.add_method(sel!(applicationWillUnhide:), will_unhide::<T> as extern fn(&Object, _, _));
-        decl.add_method(sel!(applicationDidUnhide:), did_unhide::<T> as extern fn(&Object, _, _));
+        decl.add_guarded_method(sel!(applicationWillHide:), will_hide::<T>);
+        decl.add_guarded_method(sel!(applicationDidHide:), did_hide::<T>);
+        decl.add_guarded_method(sel!(applicationWillUnhide:), will_unhide::<T>);
+        decl.add_guarded_method(sel!(applicationDidUnhide:), did_unhide::<T>);
 
         // Managing Windows
-        decl.add_method(sel!(applicationWillUpdate:), will_update::<T> as extern fn(&Object, _, _));
-        decl.add_method(sel!(applicationDidUpdate:), did_update::<T> as extern fn(&Object, _, _));
-        decl.add_method(sel!(applicationShouldHandleReopen:hasVisibleWindows:), should_handle_reopen::<T> as extern fn(&Object, _, _, BOOL) -> BOOL);
+        decl.add_guarded_method(sel!(applicationWillUpdate:), will_update::<T>);
+        decl.add_guarded_method(sel!(applicationDidUpdate:), did_update::<T>);
+        decl.add_guarded_method(sel!(applicationShouldHandleReopen:hasVisibleWindows:), should_handle_reopen::<T>);
 
         // Dock Menu
-        decl.add_method(sel!(applicationDockMenu:), dock_menu::<T> as extern fn(&Object, _, _) -> id);
+        decl.add_guarded_method(sel!(applicationDockMenu:), dock_menu::<T>);
 
         // Displaying Errors
-        decl.add_method(sel!(application:willPresentError:), will_present_error::<T> as extern fn(&Object, _, _, id) -> id);
+        decl.add_guarded_method(sel!(application:willPresentError:), will_present_error::<T>);
 
         // Managing the Screen
-        decl.add_method(sel!(applicationDidChangeScreenParameters:), did_change_screen_parameters::<T> as extern fn(&Object, _, _));
-        decl.add_method(sel!(applicationDidChangeOcclusionState:), did_change_occlusion_state::<T> as extern fn(&Object, _, _));
+        decl.add_guarded_method(sel!(applicationDidChangeScreenParameters:), did_change_screen_parameters::<T>);
+        decl.add_guarded_method(sel!(applicationDidChangeOcclusionState:), did_change_occlusion_state::<T>);
 
         // User Activities
-        decl.add_method(sel!(application:willContinueUserActivityWithType:), will_continue_user_activity_with_type::<T> as extern fn(&Object, _, _, id) -> BOOL);
-        decl.add_method(sel!(application:continueUserActivity:restorationHandler:), continue_user_activity::<T> as extern fn(&Object, _, _, id, id) -> BOOL);
-        decl.add_method(sel!(application:didFailToContinueUserActivityWithType:error:), failed_to_continue_user_activity::<T> as extern fn(&Object, _, _, id, id));
-        decl.add_method(sel!(application:didUpdateUserActivity:), did_update_user_activity::<T> as extern fn(&Object, _, _, id));
+        decl.add_guarded_method(sel!(application:willContinueUserActivityWithType:), will_continue_user_activity_with_type::<T>);
+        decl.add_guarded_method(sel!(application:continueUserActivity:restorationHandler:), continue_user_activity::<T>);
+        decl.add_guarded_method(sel!(application:didFailToContinueUserActivityWithType:error:), failed_to_continue_user_activity::<T>);
+        decl.add_guarded_method(sel!(application:didUpdateUserActivity:), did_update_user_activity::<T>);
 
         // Handling push notifications
-        decl.add_method(sel!(application:didRegisterForRemoteNotificationsWithDeviceToken:), registered_for_remote_notifications::<T> as extern fn(&Object, _, _, id));
-        decl.add_method(sel!(application:didFailToRegisterForRemoteNotificationsWithError:), failed_to_register_for_remote_notifications::<T> as extern fn(&Object, _, _, id));
-        decl.add_method(sel!(application:didReceiveRemoteNotification:), did_receive_remote_notification::<T> as extern fn(&Object, _, _, id));
+        decl.add_guarded_method(sel!(application:didRegisterForRemoteNotificationsWithDeviceToken:), registered_for_remote_notifications::<T>);
+        decl.add_guarded_method(sel!(application:didFailToRegisterForRemoteNotificationsWithError:), failed_to_register_for_remote_notifications::<T>);
+        decl.add_guarded_method(sel!(application:didReceiveRemoteNotification:), did_receive_remote_notification::<T>);
 
         // CloudKit
         #[cfg(feature = "cloudkit")]
-        decl.add_method(sel!(application:userDidAcceptCloudKitShareWithMetadata:), accepted_cloudkit_share::<T> as extern fn(&Object, _, _, id));
+        decl.add_guarded_method(sel!(application:userDidAcceptCloudKitShareWithMetadata:), accepted_cloudkit_share::<T>);
 
         // Opening Files
-        decl.add_method(sel!(application:openURLs:), open_urls::<T> as extern fn(&Object, _, _, id));
-        decl.add_method(sel!(application:openFileWithoutUI:), open_file_without_ui::<T> as extern fn(&Object, _, _, id) -> BOOL);
-        decl.add_method(sel!(applicationShouldOpenUntitledFile:), should_open_untitled_file::<T> as extern fn(&Object, _, _) -> BOOL);
-        decl.add_method(sel!(applicationOpenUntitledFile:), open_untitled_file::<T> as extern fn(&Object, _, _) -> BOOL);
-        decl.add_method(sel!(application:openTempFile:), open_temp_file::<T> as extern fn(&Object, _, _, id) -> BOOL);
+        decl.add_guarded_method(sel!(application:openURLs:), open_urls::<T>);
+        decl.add_guarded_method(sel!(application:openFileWithoutUI:), open_file_without_ui::<T>);
+        decl.add_guarded_method(sel!(applicationShouldOpenUntitledFile:), should_open_untitled_file::<T>);
+        decl.add_guarded_method(sel!(applicationOpenUntitledFile:), open_untitled_file::<T>);
+        decl.add_guarded_method(sel!(application:openTempFile:), open_temp_file::<T>);
 
         // Printing
-        decl.add_method(sel!(application:printFile:), print_file::<T> as extern fn(&Object, _, _, id) -> BOOL);
-        decl.add_method(sel!(application:printFiles:withSettings:showPrintPanels:), print_files::<T> as extern fn(&Object, _, id, id, id, BOOL) -> NSUInteger);
+        decl.add_guarded_method(sel!(application:printFile:), print_file::<T>);
+        decl.add_guarded_method(sel!(application:printFiles:withSettings:showPrintPanels:), print_files::<T>);
 
         // @TODO: Restoring Application State
         // Depends on NSCoder support, which is... welp.
 
         // Scripting
-        decl.add_method(sel!(application:delegateHandlesKey:), delegate_handles_key::<T> as extern fn(&Object, _, _, id) -> BOOL);
+        decl.add_guarded_method(sel!(application:delegateHandlesKey:), delegate_handles_key::<T>);
 
         DELEGATE_CLASS = decl.register();
     });
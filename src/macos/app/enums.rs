@@ -1,6 +1,6 @@
 //! Various types used at the AppController level.
 
-use crate::foundation::NSUInteger;
+use crate::foundation::{NSInteger, NSUInteger};
 
 /// Used for determining how an application should handle quitting/terminating.
 /// You return this in your `AppController` `should_terminate` method.
@@ -30,6 +30,44 @@ impl From<TerminateResponse> for NSUInteger {
     }
 }
 
+/// Determines how an application is presented to the user - i.e, whether it shows up in the Dock,
+/// whether it can have a menu bar, and so on. You can switch between these at runtime via
+/// `App::set_activation_policy()`.
+#[derive(Copy, Clone, Debug, PartialEq)]
+pub enum ActivationPolicy {
+    /// An ordinary application that appears in the Dock, has a menu bar, and may have a user
+    /// interface. This is the default for most applications.
+    Regular,
+
+    /// The application doesn't appear in the Dock and doesn't have a menu bar, but it may be
+    /// activated programmatically or by clicking on one of its windows. Useful for menu bar
+    /// utilities and the like.
+    Accessory,
+
+    /// The application doesn't appear in the Dock and may not create windows or be activated.
+    Prohibited
+}
+
+impl From<ActivationPolicy> for NSInteger {
+    fn from(policy: ActivationPolicy) -> Self {
+        match policy {
+            ActivationPolicy::Regular => 0,
+            ActivationPolicy::Accessory => 1,
+            ActivationPolicy::Prohibited => 2
+        }
+    }
+}
+
+impl From<NSInteger> for ActivationPolicy {
+    fn from(policy: NSInteger) -> Self {
+        match policy {
+            1 => ActivationPolicy::Accessory,
+            2 => ActivationPolicy::Prohibited,
+            _ => ActivationPolicy::Regular
+        }
+    }
+}
+
 /// Used for responding to open/print/copy requests.
 /// You only really need this for calling `App::reply_to_open_or_print()`.
 /// The name is unfortunate, but it covers a variety of things, and by keeping it closer to the
@@ -34,6 +34,7 @@
 //! Certain lifecycle events are specific to certain platforms. Where this is the case, the
 //! documentation makes every effort to note.
 
+use std::future::Future;
 use std::sync::{Arc, Mutex};
 use lazy_static::lazy_static;
 
@@ -41,7 +42,8 @@ use objc_id::Id;
 use objc::runtime::Object;
 use objc::{class, msg_send, sel, sel_impl};
 
-use crate::foundation::{id, nil, YES, NO, NSUInteger, AutoReleasePool};
+use crate::executor;
+use crate::foundation::{id, nil, YES, NO, NSInteger, NSUInteger, NSString, AutoReleasePool};
 use crate::invoker::TargetActionHandler;
 use crate::macos::menu::Menu;
 use crate::notification_center::Dispatcher;
@@ -86,11 +88,26 @@ lazy_static! {
     static ref MENU_ITEMS_HANDLER_CACHE: Arc<Mutex<Vec<TargetActionHandler>>> = Arc::new(Mutex::new(Vec::new()));
 }
 
+/// A token representing an outstanding request for the user's attention, as returned from
+/// `App::request_user_attention()`. Call `cancel()` to stop the Dock icon from bouncing before
+/// the user activates the application.
+#[derive(Debug)]
+pub struct UserAttentionRequest(NSInteger);
+
+impl UserAttentionRequest {
+    /// Cancels this attention request.
+    pub fn cancel(self) {
+        shared_application(|app| unsafe {
+            let _: () = msg_send![app, cancelUserAttentionRequest:self.0];
+        });
+    }
+}
+
 /// A handler to make some boilerplate less annoying.
 #[inline]
-fn shared_application<F: Fn(id)>(handler: F) {
+fn shared_application<R, F: FnOnce(id) -> R>(handler: F) -> R {
     let app: id = unsafe { msg_send![register_app_class(), sharedApplication] };
-    handler(app);
+    handler(app)
 }
 
 /// A wrapper for `NSApplication` on macOS, and `UIApplication` on iOS.
@@ -166,8 +183,12 @@ impl<T> App<T> where T: AppDelegate + 'static {
 
 //  This is a very basic "dispatch" mechanism. In macOS, it's critical that UI work happen on the
 //  UI ("main") thread. We can hook into the standard mechanism for this by dispatching on
-//  queues; in our case, we'll just offer two points - one for a background queue, and one
-//  for the main queue. They automatically forward through to our registered `AppDelegate`.
+//  queues; in our case, we'll just offer two points - one for a background (global, concurrent)
+//  queue, and one for the main queue. They automatically forward through to our registered
+//  `AppDelegate`.
+//
+//  If you're working with futures, `App::spawn_local()` and `App::spawn_background()` are likely
+//  a better fit - they avoid the message enum boilerplate entirely.
 //
 //  One thing I don't like about GCD is that detecting incorrect thread usage has historically been
 //  a bit... annoying. Here, the `Dispatcher` trait explicitly requires implementing two methods - 
@@ -193,11 +214,17 @@ impl<T, M> App<T, M> where M: Send + Sync + 'static, T: AppDelegate + Dispatcher
             (&*delegate).on_ui_message(message);
         });
     }
+}
 
+impl<T, M> App<T, M> where M: Send + Sync + 'static, T: AppDelegate + Dispatcher<Message = M> + Sync {
     /// Dispatches a message by grabbing the `sharedApplication`, getting ahold of the delegate,
     /// and passing back through there.
+    ///
+    /// This runs on the global concurrent queue, _not_ the main thread - your
+    /// `on_background_message` implementation must not touch any UI. The delegate is shared with
+    /// the main thread while this runs, hence the `Sync` bound.
     pub fn dispatch_background(message: M) {
-        let queue = dispatch::Queue::main();
+        let queue = dispatch::Queue::global(dispatch::QueuePriority::Default);
         
         queue.exec_async(move || unsafe {
             let app: id = msg_send![register_app_class(), sharedApplication];
@@ -210,6 +237,22 @@ impl<T, M> App<T, M> where M: Send + Sync + 'static, T: AppDelegate + Dispatcher
 }
 
 impl App {
+    /// Spawns a future that's polled to completion on the main thread, as part of the main run
+    /// loop. The future doesn't need to be `Send`, so it's free to hold on to and update views -
+    /// e.g, awaiting some I/O and then setting the result on a `Label`.
+    ///
+    /// This must be called from the main thread, and will panic otherwise.
+    pub fn spawn_local<F: Future<Output = ()> + 'static>(future: F) {
+        executor::spawn_local(future);
+    }
+
+    /// Spawns a future that's polled to completion on the global concurrent dispatch queue. This
+    /// can be called from any thread. Don't touch UI from within this future; if you need to
+    /// report back, use `App::spawn_local()` or `App::dispatch_main()`.
+    pub fn spawn_background<F: Future<Output = ()> + Send + 'static>(future: F) {
+        executor::spawn_background(future);
+    }
+
     /// Registers for remote notifications from APNS.
     pub fn register_for_remote_notifications() {
         shared_application(|app| unsafe {
@@ -296,12 +339,91 @@ impl App {
     /// routines after the NSMenu has been set, otherwise it won't be interact-able without
     /// switching away from the app and then coming back.
     ///
-    /// @TODO: Accept an ActivationPolicy enum or something.
+    /// This sets the activation policy to `ActivationPolicy::Regular`; if you need something
+    /// different, call `App::set_activation_policy()` afterwards.
     pub fn activate() {
-        shared_application(|app| unsafe {
-            let _: () = msg_send![app, setActivationPolicy:0];
+        App::set_activation_policy(ActivationPolicy::Regular);
+
+        unsafe {
             let current_app: id = msg_send![class!(NSRunningApplication), currentApplication];
             let _: () = msg_send![current_app, activateWithOptions:1<<1];
+        }
+    }
+
+    /// Sets the activation policy for this application. This can be changed at runtime - e.g, a
+    /// menu bar utility might switch to `ActivationPolicy::Regular` while a settings window is
+    /// open, and back to `ActivationPolicy::Accessory` once it closes.
+    pub fn set_activation_policy(policy: ActivationPolicy) {
+        shared_application(|app| unsafe {
+            let policy: NSInteger = policy.into();
+            let _: () = msg_send![app, setActivationPolicy:policy];
+        });
+    }
+
+    /// Returns the current activation policy for this application.
+    pub fn activation_policy() -> ActivationPolicy {
+        shared_application(|app| unsafe {
+            let policy: NSInteger = msg_send![app, activationPolicy];
+            policy.into()
+        })
+    }
+
+    /// Sets the badge label on the Dock tile for this application. Pass `None` to remove an
+    /// existing badge.
+    pub fn set_badge_label(label: Option<&str>) {
+        shared_application(|app| unsafe {
+            let dock_tile: id = msg_send![app, dockTile];
+
+            match label {
+                Some(label) => {
+                    let label = NSString::new(label);
+                    let _: () = msg_send![dock_tile, setBadgeLabel:label.into_inner()];
+                },
+
+                None => {
+                    let _: () = msg_send![dock_tile, setBadgeLabel:nil];
+                }
+            }
+        });
+    }
+
+    /// Requests the user's attention by bouncing the Dock icon. If `critical` is `true`, the icon
+    /// will bounce until the application is activated or the request is cancelled; otherwise, it
+    /// bounces once.
+    ///
+    /// This does nothing if the application is already active. The returned
+    /// `UserAttentionRequest` can be used to cancel the request early.
+    pub fn request_user_attention(critical: bool) -> UserAttentionRequest {
+        let request_type: NSUInteger = match critical {
+            true => 0,
+            false => 10
+        };
+
+        UserAttentionRequest(shared_application(|app| unsafe {
+            msg_send![app, requestUserAttention:request_type]
+        }))
+    }
+
+    /// Hides all of this application's windows. The `AppDelegate` hide callbacks will fire
+    /// accordingly.
+    pub fn hide() {
+        shared_application(|app| unsafe {
+            let _: () = msg_send![app, hide:nil];
+        });
+    }
+
+    /// Restores this application's hidden windows. The `AppDelegate` unhide callbacks will fire
+    /// accordingly.
+    pub fn unhide() {
+        shared_application(|app| unsafe {
+            let _: () = msg_send![app, unhide:nil];
+        });
+    }
+
+    /// Hides all applications other than this one.
+    pub fn hide_other_applications() {
+        shared_application(|app| unsafe {
+            let _: () = msg_send![app, hideOtherApplications:nil];
         });
     }
 
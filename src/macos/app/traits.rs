@@ -96,6 +96,9 @@ pub trait AppDelegate {
     /// though, you can cancel the termination via `TerminateResponse::Cancel` to continue something essential. If
     /// you do this, you'll need to be sure to call `App::reply_to_termination_request()` to circle
     /// back.
+    ///
+    /// If this panics and the panic handler lets the application carry on, the termination is
+    /// cancelled (see the `panic` module).
     fn should_terminate(&self) -> TerminateResponse { TerminateResponse::Now }
 
     /// Called after closing the last open window. Return `true` here if you want
@@ -0,0 +1,218 @@
+//! A wrapper for `NSDockTile`, which controls how your application is displayed in the Dock.
+//!
+//! Beyond setting a badge (see `App::set_badge_label()`), you can supply your own content view to
+//! render in place of the application icon, show a progress bar overlaid on top of the icon, and
+//! swap out the application icon at runtime.
+//!
+//! ```rust,no_run
+//! use cacao::macos::DockTile;
+//!
+//! let mut dock_tile = DockTile::new();
+//! dock_tile.set_progress(Some(0.5));
+//!
+//! // ...and later, when the work is done:
+//! dock_tile.set_progress(None);
+//! ```
+//!
+//! Note that the Dock does not redraw a custom content view on its own; after updating anything
+//! inside your view, call `display()` to have the Dock pick up the change. The progress overlay
+//! handles this for you.
+
+use core_graphics::base::CGFloat;
+
+use objc_id::ShareId;
+use objc::runtime::Object;
+use objc::{class, msg_send, sel, sel_impl};
+
+use crate::foundation::{id, nil, NSRect};
+use crate::image::{Image, ImageView};
+use crate::layout::{Layout, LayoutConstraint};
+use crate::progress::{ProgressIndicator, ProgressIndicatorStyle};
+use crate::utils::CGSize;
+use crate::view::View;
+
+/// The pieces that make up the progress overlay. These are retained here, as the `NSDockTile`
+/// content view only lives as long as we hold onto it.
+#[derive(Debug)]
+struct ProgressOverlay {
+    content: View,
+    icon: ImageView,
+    indicator: ProgressIndicator
+}
+
+/// A handle to the Dock tile for this application. You'll want to hold on to this for as long as
+/// you have custom content or progress displayed, as dropping it will not reset the tile but will
+/// release any views that it created.
+#[derive(Debug)]
+pub struct DockTile {
+    /// A pointer to the underlying `NSDockTile`.
+    pub objc: ShareId<Object>,
+
+    progress: Option<ProgressOverlay>
+}
+
+impl Default for DockTile {
+    fn default() -> Self {
+        DockTile::new()
+    }
+}
+
+impl DockTile {
+    /// Returns a handle to the application's Dock tile.
+    pub fn new() -> Self {
+        DockTile {
+            objc: unsafe {
+                let app: id = msg_send![class!(NSApplication), sharedApplication];
+                ShareId::from_ptr(msg_send![app, dockTile])
+            },
+
+            progress: None
+        }
+    }
+
+    /// Returns the size of the tile, in points.
+    pub fn size(&self) -> (f64, f64) {
+        let size: CGSize = unsafe { msg_send![&*self.objc, size] };
+        (size.width as f64, size.height as f64)
+    }
+
+    /// Sets a custom view to be drawn in place of the application icon. The view is sized to fill
+    /// the tile. This replaces any active progress overlay.
+    ///
+    /// The Dock draws this view on demand only; call `display()` whenever you've changed
+    /// something in it.
+    pub fn set_content_view<V: Layout>(&mut self, view: &V) {
+        self.progress = None;
+        let backing_node = view.get_backing_node();
+
+        unsafe {
+            let (width, height) = self.size();
+            let frame = NSRect::new(0., 0., width as CGFloat, height as CGFloat);
+            let _: () = msg_send![&*backing_node, setFrame:frame];
+            let _: () = msg_send![&*self.objc, setContentView:&*backing_node];
+            let _: () = msg_send![&*self.objc, display];
+        }
+    }
+
+    /// Removes any custom content view (or progress overlay), restoring the standard application
+    /// icon.
+    pub fn clear_content_view(&mut self) {
+        self.progress = None;
+
+        unsafe {
+            let _: () = msg_send![&*self.objc, setContentView:nil];
+            let _: () = msg_send![&*self.objc, display];
+        }
+    }
+
+    /// Asks the Dock to redraw the tile. Call this after updating a custom content view.
+    pub fn display(&self) {
+        unsafe {
+            let _: () = msg_send![&*self.objc, display];
+        }
+    }
+
+    /// Shows a progress bar over the application icon, with `value` clamped to the range
+    /// `0.0...1.0`. Passing `None` removes the progress bar and restores the standard icon.
+    ///
+    /// The overlay view is created on first use and reused for subsequent updates, so this is
+    /// fine to call frequently.
+    pub fn set_progress(&mut self, value: Option<f64>) {
+        let value = match value {
+            Some(value) => value.max(0.).min(1.),
+            None => {
+                if self.progress.is_some() {
+                    self.clear_content_view();
+                }
+
+                return;
+            }
+        };
+
+        if self.progress.is_none() {
+            let overlay = self.create_progress_overlay();
+            self.set_content_view(&overlay.content);
+            self.progress = Some(overlay);
+        }
+
+        if let Some(overlay) = &self.progress {
+            overlay.indicator.set_value(value * 100.);
+        }
+
+        self.display();
+    }
+
+    /// Sets the application icon image. This is reflected in the Dock, as well as any alerts or
+    /// panels that display the application icon, until the application terminates.
+    pub fn set_application_icon(&self, image: &Image) {
+        unsafe {
+            let app: id = msg_send![class!(NSApplication), sharedApplication];
+            let _: () = msg_send![app, setApplicationIconImage:&*image.0];
+        }
+
+        if let Some(overlay) = &self.progress {
+            overlay.icon.set_image(image);
+        }
+
+        self.display();
+    }
+
+    /// Restores the application icon to the one specified in your bundle.
+    pub fn reset_application_icon(&self) {
+        unsafe {
+            let app: id = msg_send![class!(NSApplication), sharedApplication];
+            let _: () = msg_send![app, setApplicationIconImage:nil];
+        }
+
+        if let Some(overlay) = &self.progress {
+            overlay.icon.set_image(&application_icon());
+        }
+
+        self.display();
+    }
+
+    /// Builds the view heirarchy for the progress overlay: the current application icon, with a
+    /// bar-style `ProgressIndicator` pinned along the bottom.
+    fn create_progress_overlay(&self) -> ProgressOverlay {
+        let content = View::new();
+        content.set_translates_autoresizing_mask_into_constraints(true);
+
+        let icon = ImageView::new();
+        icon.set_image(&application_icon());
+
+        let indicator = ProgressIndicator::new();
+        indicator.set_style(ProgressIndicatorStyle::Bar);
+        indicator.set_indeterminate(false);
+        indicator.set_min_value(0.);
+        indicator.set_max_value(100.);
+
+        content.add_subview(&icon);
+        content.add_subview(&indicator);
+
+        LayoutConstraint::activate(&[
+            icon.top.constraint_equal_to(&content.top),
+            icon.leading.constraint_equal_to(&content.leading),
+            icon.trailing.constraint_equal_to(&content.trailing),
+            icon.bottom.constraint_equal_to(&content.bottom),
+
+            indicator.leading.constraint_equal_to(&content.leading).offset(8.),
+            indicator.trailing.constraint_equal_to(&content.trailing).offset(-8.),
+            indicator.bottom.constraint_equal_to(&content.bottom).offset(-8.),
+            indicator.height.constraint_equal_to_constant(12.)
+        ]);
+
+        ProgressOverlay {
+            content: content,
+            icon: icon,
+            indicator: indicator
+        }
+    }
+}
+
+/// Returns the current application icon image.
+fn application_icon() -> Image {
+    Image::with(unsafe {
+        let app: id = msg_send![class!(NSApplication), sharedApplication];
+        msg_send![app, applicationIconImage]
+    })
+}
@@ -6,6 +6,7 @@ use objc_id::Id;
 use objc::{class, msg_send, sel, sel_impl};
 
 use crate::foundation::{id, nil, NSString};
+use crate::panic::guard;
 
 /// An EventMask describes the type of event.
 #[derive(Debug)]
@@ -63,12 +64,14 @@ impl Event {
         F: Fn(Event) -> Option<Event> + Send + Sync + 'static
     {
         let block = ConcreteBlock::new(move |event: id| {
-            let evt = Event::new(event);
-
-            match handler(evt) {
-                Some(mut evt) => &mut *evt.0,
-                None => nil
-            }
+            guard(|| {
+                let evt = Event::new(event);
+
+                match handler(evt) {
+                    Some(mut evt) => &mut *evt.0,
+                    None => nil
+                }
+            })
         });
         let block = block.copy();
 
@@ -24,6 +24,9 @@ pub use app::*;
 mod cursor;
 pub use cursor::{Cursor, CursorType};
 
+mod dock_tile;
+pub use dock_tile::DockTile;
+
 mod enums;
 pub use enums::{FocusRingType};
 
@@ -9,9 +9,10 @@ use objc::{class, sel, sel_impl, msg_send};
 use crate::foundation::{load_or_register_class, id, NSArray, NSString};
 use crate::macos::toolbar::{TOOLBAR_PTR, ToolbarDelegate};
 use crate::utils::load;
+use crate::panic::AddGuardedMethod;
 
 /// Retrieves and passes the allowed item identifiers for this toolbar.
-extern fn allowed_item_identifiers<T: ToolbarDelegate>(this: &Object, _: Sel, _: id) -> id {
+fn allowed_item_identifiers<T: ToolbarDelegate>(this: &Object, _: Sel, _: id) -> id {
     let toolbar = load::<T>(this, TOOLBAR_PTR);
 
     let identifiers: NSArray = toolbar.allowed_item_identifiers().iter().map(|identifier| {
@@ -22,7 +23,7 @@ extern fn allowed_item_identifiers<T: ToolbarDelegate>(this: &Object, _: Sel, _:
 }
 
 /// Retrieves and passes the default item identifiers for this toolbar.
-extern fn default_item_identifiers<T: ToolbarDelegate>(this: &Object, _: Sel, _: id) -> id {
+fn default_item_identifiers<T: ToolbarDelegate>(this: &Object, _: Sel, _: id) -> id {
     let toolbar = load::<T>(this, TOOLBAR_PTR);
 
     let identifiers: NSArray = toolbar.default_item_identifiers().iter().map(|identifier| {
@@ -33,7 +34,7 @@ extern fn default_item_identifiers<T: ToolbarDelegate>(this: &Object, _: Sel, _:
 }
 
 /// Retrieves and passes the default item identifiers for this toolbar.
-extern fn selectable_item_identifiers<T: ToolbarDelegate>(this: &Object, _: Sel, _: id) -> id {
+fn selectable_item_identifiers<T: ToolbarDelegate>(this: &Object, _: Sel, _: id) -> id {
     let toolbar = load::<T>(this, TOOLBAR_PTR);
 
     let identifiers: NSArray = toolbar.selectable_item_identifiers().iter().map(|identifier| {
@@ -45,10 +46,10 @@ extern fn selectable_item_identifiers<T: ToolbarDelegate>(this: &Object, _: Sel,
 
 /// Loads the controller, grabs whatever item is for this identifier, and returns what the
 /// Objective-C runtime needs.
-extern fn item_for_identifier<T: ToolbarDelegate>(this: &Object, _: Sel, _: id, identifier: id, _: id) -> id {
+fn item_for_identifier<T: ToolbarDelegate>(this: &Object, _: Sel, _: id, identifier: id, _: id) -> id {
     let toolbar = load::<T>(this, TOOLBAR_PTR);
     let identifier = NSString::wrap(identifier);
-    
+
     let item = toolbar.item_for(identifier.to_str());
     unsafe {
         msg_send![&*item.objc, self]
@@ -64,9 +65,9 @@ pub(crate) fn register_toolbar_class<T: ToolbarDelegate>(instance: &T) -> *const
         decl.add_ivar::<usize>(TOOLBAR_PTR);
 
         // Add callback methods
-        decl.add_method(sel!(toolbarAllowedItemIdentifiers:), allowed_item_identifiers::<T> as extern fn(&Object, _, _) -> id);
-        decl.add_method(sel!(toolbarDefaultItemIdentifiers:), default_item_identifiers::<T> as extern fn(&Object, _, _) -> id);
-        decl.add_method(sel!(toolbarSelectableItemIdentifiers:), selectable_item_identifiers::<T> as extern fn(&Object, _, _) -> id);
-        decl.add_method(sel!(toolbar:itemForItemIdentifier:willBeInsertedIntoToolbar:), item_for_identifier::<T> as extern fn(&Object, _, _, _, _) -> id);
+        decl.add_guarded_method(sel!(toolbarAllowedItemIdentifiers:), allowed_item_identifiers::<T>);
+        decl.add_guarded_method(sel!(toolbarDefaultItemIdentifiers:), default_item_identifiers::<T>);
+        decl.add_guarded_method(sel!(toolbarSelectableItemIdentifiers:), selectable_item_identifiers::<T>);
+        decl.add_guarded_method(sel!(toolbar:itemForItemIdentifier:willBeInsertedIntoToolbar:), item_for_identifier::<T>);
     })
 }
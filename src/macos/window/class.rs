@@ -12,10 +12,11 @@ use objc::{class, sel, sel_impl};
 use crate::foundation::{load_or_register_class, id, BOOL, YES, NO, NSUInteger};
 use crate::utils::{load, CGSize};
 use crate::macos::window::{WindowDelegate, WINDOW_DELEGATE_PTR};
+use crate::panic::{guard_or, AddGuardedMethod};
 
 /// Called when an `NSWindowDelegate` receives a `windowWillClose:` event.
 /// Good place to clean up memory and what not.
-extern fn should_close<T: WindowDelegate>(this: &Object, _: Sel, _: id) -> BOOL {
+fn should_close<T: WindowDelegate>(this: &Object, _: Sel, _: id) -> BOOL {
     let window = load::<T>(this, WINDOW_DELEGATE_PTR);
 
     match window.should_close() {
@@ -26,96 +27,99 @@ extern fn should_close<T: WindowDelegate>(this: &Object, _: Sel, _: id) -> BOOL
 
 /// Called when an `NSWindowDelegate` receives a `windowWillClose:` event.
 /// Good place to clean up memory and what not.
-extern fn will_close<T: WindowDelegate>(this: &Object, _: Sel, _: id) {
+fn will_close<T: WindowDelegate>(this: &Object, _: Sel, _: id) {
     let window = load::<T>(this, WINDOW_DELEGATE_PTR);
     window.will_close();
 }
 
 /// Called when an `NSWindowDelegate` receives a `windowWillMove:` event.
-extern fn will_move<T: WindowDelegate>(this: &Object, _: Sel, _: id) {
+fn will_move<T: WindowDelegate>(this: &Object, _: Sel, _: id) {
     let window = load::<T>(this, WINDOW_DELEGATE_PTR);
     window.will_move();
 }
 
 /// Called when an `NSWindowDelegate` receives a `windowDidMove:` event.
-extern fn did_move<T: WindowDelegate>(this: &Object, _: Sel, _: id) {
+fn did_move<T: WindowDelegate>(this: &Object, _: Sel, _: id) {
     let window = load::<T>(this, WINDOW_DELEGATE_PTR);
     window.did_move();
 }
 
 /// Called when an `NSWindowDelegate` receives a `windowDidChangeScreen:` event.
-extern fn did_change_screen<T: WindowDelegate>(this: &Object, _: Sel, _: id) {
+fn did_change_screen<T: WindowDelegate>(this: &Object, _: Sel, _: id) {
     let window = load::<T>(this, WINDOW_DELEGATE_PTR);
     window.did_change_screen();
 }
 
 /// Called when an `NSWindowDelegate` receives a `windowDidChangeScreenProfile:` event.
-extern fn did_change_screen_profile<T: WindowDelegate>(this: &Object, _: Sel, _: id) {
+fn did_change_screen_profile<T: WindowDelegate>(this: &Object, _: Sel, _: id) {
     let window = load::<T>(this, WINDOW_DELEGATE_PTR);
     window.did_change_screen_profile();
 }
 
 /// Called when an `NSWindowDelegate` receives a `windowDidChangeScreen:` event.
-extern fn will_resize<T: WindowDelegate>(this: &Object, _: Sel, _: id, size: CGSize) -> CGSize {
-    let window = load::<T>(this, WINDOW_DELEGATE_PTR);
-    let s = window.will_resize(size.width as f64, size.height as f64);
-        
-    CGSize { 
-        width: s.0 as CGFloat,
-        height: s.1 as CGFloat
-    }
+fn will_resize<T: WindowDelegate>(this: &Object, _: Sel, _: id, size: CGSize) -> CGSize {
+    // A zero size is no use to anyone, so if the delegate panics, accept the proposed size.
+    guard_or(size, || {
+        let window = load::<T>(this, WINDOW_DELEGATE_PTR);
+        let s = window.will_resize(size.width as f64, size.height as f64);
+
+        CGSize { 
+            width: s.0 as CGFloat,
+            height: s.1 as CGFloat
+        }
+    })
 }
 
 /// Called when an `NSWindowDelegate` receives a `windowDidChangeScreen:` event.
-extern fn did_resize<T: WindowDelegate>(this: &Object, _: Sel, _: id) {
+fn did_resize<T: WindowDelegate>(this: &Object, _: Sel, _: id) {
     let window = load::<T>(this, WINDOW_DELEGATE_PTR);
     window.did_resize();
 }
 
 /// Called when an `NSWindowDelegate` receives a `windowDidChangeScreen:` event.
-extern fn will_start_live_resize<T: WindowDelegate>(this: &Object, _: Sel, _: id) {
+fn will_start_live_resize<T: WindowDelegate>(this: &Object, _: Sel, _: id) {
     let window = load::<T>(this, WINDOW_DELEGATE_PTR);
     window.will_start_live_resize();
 }
 
 /// Called when an `NSWindowDelegate` receives a `windowDidChangeScreen:` event.
-extern fn did_end_live_resize<T: WindowDelegate>(this: &Object, _: Sel, _: id) {
+fn did_end_live_resize<T: WindowDelegate>(this: &Object, _: Sel, _: id) {
     let window = load::<T>(this, WINDOW_DELEGATE_PTR);
     window.did_end_live_resize();
 }
 
 /// Called when an `NSWindowDelegate` receives a `windowDidChangeScreen:` event.
-extern fn will_miniaturize<T: WindowDelegate>(this: &Object, _: Sel, _: id) {
+fn will_miniaturize<T: WindowDelegate>(this: &Object, _: Sel, _: id) {
     let window = load::<T>(this, WINDOW_DELEGATE_PTR);
     window.will_miniaturize();
 }
 
 /// Called when an `NSWindowDelegate` receives a `windowDidChangeScreen:` event.
-extern fn did_miniaturize<T: WindowDelegate>(this: &Object, _: Sel, _: id) {
+fn did_miniaturize<T: WindowDelegate>(this: &Object, _: Sel, _: id) {
     let window = load::<T>(this, WINDOW_DELEGATE_PTR);
     window.did_miniaturize();
 }
 
 /// Called when an `NSWindowDelegate` receives a `windowDidChangeScreen:` event.
-extern fn did_deminiaturize<T: WindowDelegate>(this: &Object, _: Sel, _: id) {
+fn did_deminiaturize<T: WindowDelegate>(this: &Object, _: Sel, _: id) {
     let window = load::<T>(this, WINDOW_DELEGATE_PTR);
     window.did_deminiaturize();
 }
 
 /// Called when an `NSWindowDelegate` receives a `windowDidChangeScreenProfile:` event.
-extern fn will_enter_full_screen<T: WindowDelegate>(this: &Object, _: Sel, _: id) {
+fn will_enter_full_screen<T: WindowDelegate>(this: &Object, _: Sel, _: id) {
     let window = load::<T>(this, WINDOW_DELEGATE_PTR);
     window.will_enter_full_screen();
 }
 
 /// Called when an `NSWindowDelegate` receives a `windowDidChangeScreenProfile:` event.
-extern fn did_enter_full_screen<T: WindowDelegate>(this: &Object, _: Sel, _: id) {
+fn did_enter_full_screen<T: WindowDelegate>(this: &Object, _: Sel, _: id) {
     let window = load::<T>(this, WINDOW_DELEGATE_PTR);
     window.did_enter_full_screen();
 }
 
 /// Called when an `NSWindowDelegate` receives a `windowDidChangeScreenProfile:` event.
-extern fn content_size_for_full_screen<T: WindowDelegate>(this: &Object, _: Sel, _: id, size: CGSize) -> CGSize {
+fn content_size_for_full_screen<T: WindowDelegate>(this: &Object, _: Sel, _: id, size: CGSize) -> CGSize {
     let window = load::<T>(this, WINDOW_DELEGATE_PTR);
 
     let (width, height) = window.content_size_for_full_screen(
@@ -130,11 +134,11 @@ extern fn content_size_for_full_screen<T: WindowDelegate>(this: &Object, _: Sel,
 }
 
 /// Called when an `NSWindowDelegate` receives a `windowDidChangeScreenProfile:` event.
-extern fn options_for_full_screen<T: WindowDelegate>(this: &Object, _: Sel, _: id, options: NSUInteger) -> NSUInteger {
+fn options_for_full_screen<T: WindowDelegate>(this: &Object, _: Sel, _: id, options: NSUInteger) -> NSUInteger {
     let window = load::<T>(this, WINDOW_DELEGATE_PTR);
 
     let desired_opts = window.presentation_options_for_full_screen();
-        
+
     if desired_opts.is_none() { 
         options
     } else {
@@ -148,73 +152,73 @@ extern fn options_for_full_screen<T: WindowDelegate>(this: &Object, _: Sel, _: i
 }
 
 /// Called when an `NSWindowDelegate` receives a `windowDidChangeScreenProfile:` event.
-extern fn will_exit_full_screen<T: WindowDelegate>(this: &Object, _: Sel, _: id) {
+fn will_exit_full_screen<T: WindowDelegate>(this: &Object, _: Sel, _: id) {
     let window = load::<T>(this, WINDOW_DELEGATE_PTR);
     window.will_exit_full_screen();
 }
 
 /// Called when an `NSWindowDelegate` receives a `windowDidChangeScreenProfile:` event.
-extern fn did_exit_full_screen<T: WindowDelegate>(this: &Object, _: Sel, _: id) {
+fn did_exit_full_screen<T: WindowDelegate>(this: &Object, _: Sel, _: id) {
     let window = load::<T>(this, WINDOW_DELEGATE_PTR);
     window.did_exit_full_screen();
 }
 
 /// Called when an `NSWindowDelegate` receives a `windowDidChangeScreenProfile:` event.
-extern fn did_fail_to_enter_full_screen<T: WindowDelegate>(this: &Object, _: Sel, _: id) {
+fn did_fail_to_enter_full_screen<T: WindowDelegate>(this: &Object, _: Sel, _: id) {
     let window = load::<T>(this, WINDOW_DELEGATE_PTR);
     window.did_fail_to_enter_full_screen();
 }
 
 /// Called when an `NSWindowDelegate` receives a `windowDidChangeScreenProfile:` event.
-extern fn did_fail_to_exit_full_screen<T: WindowDelegate>(this: &Object, _: Sel, _: id) {
+fn did_fail_to_exit_full_screen<T: WindowDelegate>(this: &Object, _: Sel, _: id) {
     let window = load::<T>(this, WINDOW_DELEGATE_PTR);
     window.did_fail_to_exit_full_screen();
 }
 
 /// Called when an `NSWindowDelegate` receives a `windowDidChangeBackingProperties:` event.
-extern fn did_change_backing_properties<T: WindowDelegate>(this: &Object, _: Sel, _: id) {
+fn did_change_backing_properties<T: WindowDelegate>(this: &Object, _: Sel, _: id) {
     let window = load::<T>(this, WINDOW_DELEGATE_PTR);
     window.did_change_backing_properties();
 }
 
 /// Called when an `NSWindowDelegate` receives a `windowDidChangeBackingProperties:` event.
-extern fn did_change_occlusion_state<T: WindowDelegate>(this: &Object, _: Sel, _: id) {
+fn did_change_occlusion_state<T: WindowDelegate>(this: &Object, _: Sel, _: id) {
     let window = load::<T>(this, WINDOW_DELEGATE_PTR);
     window.did_change_occlusion_state();
 }
 
 /// Called when an `NSWindowDelegate` receives a `windowDidUpdate:` event.
-extern fn did_update<T: WindowDelegate>(this: &Object, _: Sel, _: id) {
+fn did_update<T: WindowDelegate>(this: &Object, _: Sel, _: id) {
     let window = load::<T>(this, WINDOW_DELEGATE_PTR);
     window.did_update();
 }
 
 /// Called when an `NSWindowDelegate` receives a `windowDidExpose:` event.
-extern fn did_become_main<T: WindowDelegate>(this: &Object, _: Sel, _: id) {
+fn did_become_main<T: WindowDelegate>(this: &Object, _: Sel, _: id) {
     let window = load::<T>(this, WINDOW_DELEGATE_PTR);
     window.did_become_main();
 }
 
 /// Called when an `NSWindowDelegate` receives a `windowDidExpose:` event.
-extern fn did_resign_main<T: WindowDelegate>(this: &Object, _: Sel, _: id) {
+fn did_resign_main<T: WindowDelegate>(this: &Object, _: Sel, _: id) {
     let window = load::<T>(this, WINDOW_DELEGATE_PTR);
     window.did_resign_main();
 }
 
 /// Called when an `NSWindowDelegate` receives a `windowDidExpose:` event.
-extern fn did_become_key<T: WindowDelegate>(this: &Object, _: Sel, _: id) {
+fn did_become_key<T: WindowDelegate>(this: &Object, _: Sel, _: id) {
     let window = load::<T>(this, WINDOW_DELEGATE_PTR);
     window.did_become_key();
 }
 
 /// Called when an `NSWindowDelegate` receives a `windowDidExpose:` event.
-extern fn did_resign_key<T: WindowDelegate>(this: &Object, _: Sel, _: id) {
+fn did_resign_key<T: WindowDelegate>(this: &Object, _: Sel, _: id) {
     let window = load::<T>(this, WINDOW_DELEGATE_PTR);
     window.did_resign_key();
 }
 
 /// Called when an `NSWindowDelegate` receives a `windowDidExpose:` event.
-extern fn did_expose<T: WindowDelegate>(this: &Object, _: Sel, _: id) {
+fn did_expose<T: WindowDelegate>(this: &Object, _: Sel, _: id) {
     let window = load::<T>(this, WINDOW_DELEGATE_PTR);
     window.did_expose();
 }
@@ -223,7 +227,7 @@ extern fn did_expose<T: WindowDelegate>(this: &Object, _: Sel, _: id) {
 /// delegate returns `true` in `should_cancel_on_esc`, then this will allow your
 /// window to close when the Esc key is hit. This is mostly useful for Sheet-presented
 /// windows, and so the default response from delegates is `false` and must be opted in to.
-extern fn cancel<T: WindowDelegate>(this: &Object, _: Sel, _: id) {
+fn cancel<T: WindowDelegate>(this: &Object, _: Sel, _: id) {
     let window = load::<T>(this, WINDOW_DELEGATE_PTR);
     window.cancel();
 }
@@ -235,49 +239,49 @@ pub(crate) fn register_window_class_with_delegate<T: WindowDelegate>(instance: &
         decl.add_ivar::<usize>(WINDOW_DELEGATE_PTR);
 
         // NSWindowDelegate methods
-        decl.add_method(sel!(windowShouldClose:), should_close::<T> as extern fn(&Object, _, _) -> BOOL);
-        decl.add_method(sel!(windowWillClose:), will_close::<T> as extern fn(&Object, _, _));
+        decl.add_guarded_method(sel!(windowShouldClose:), should_close::<T>);
+        decl.add_guarded_method(sel!(windowWillClose:), will_close::<T>);
 
         // Sizing
-        decl.add_method(sel!(windowWillResize:toSize:), will_resize::<T> as extern fn(&Object, _, _, CGSize) -> CGSize);
-        decl.add_method(sel!(windowDidResize:), did_resize::<T> as extern fn(&Object, _, _));
-        decl.add_method(sel!(windowWillStartLiveResize:), will_start_live_resize::<T> as extern fn(&Object, _, _));
-        decl.add_method(sel!(windowDidEndLiveResize:), did_end_live_resize::<T> as extern fn(&Object, _, _));
+        decl.add_guarded_method(sel!(windowWillResize:toSize:), will_resize::<T>);
+        decl.add_guarded_method(sel!(windowDidResize:), did_resize::<T>);
+        decl.add_guarded_method(sel!(windowWillStartLiveResize:), will_start_live_resize::<T>);
+        decl.add_guarded_method(sel!(windowDidEndLiveResize:), did_end_live_resize::<T>);
 
         // Minimizing
-        decl.add_method(sel!(windowWillMiniaturize:), will_miniaturize::<T> as extern fn(&Object, _, _));
-        decl.add_method(sel!(windowDidMiniaturize:), did_miniaturize::<T> as extern fn(&Object, _, _));
-        decl.add_method(sel!(windowDidDeminiaturize:), did_deminiaturize::<T> as extern fn(&Object, _, _));
+        decl.add_guarded_method(sel!(windowWillMiniaturize:), will_miniaturize::<T>);
+        decl.add_guarded_method(sel!(windowDidMiniaturize:), did_miniaturize::<T>);
+        decl.add_guarded_method(sel!(windowDidDeminiaturize:), did_deminiaturize::<T>);
 
         // Full Screen
-        decl.add_method(sel!(window:willUseFullScreenContentSize:), content_size_for_full_screen::<T> as extern fn(&Object, _, _, CGSize) -> CGSize);
-        decl.add_method(sel!(window:willUseFullScreenPresentationOptions:), options_for_full_screen::<T> as extern fn(&Object, _, _, NSUInteger) -> NSUInteger);
-        decl.add_method(sel!(windowWillEnterFullScreen:), will_enter_full_screen::<T> as extern fn(&Object, _, _));
-        decl.add_method(sel!(windowDidEnterFullScreen:), did_enter_full_screen::<T> as extern fn(&Object, _, _));
-        decl.add_method(sel!(windowWillExitFullScreen:), will_exit_full_screen::<T> as extern fn(&Object, _, _));
-        decl.add_method(sel!(windowDidExitFullScreen:), did_exit_full_screen::<T> as extern fn(&Object, _, _));
-        decl.add_method(sel!(windowDidFailToEnterFullScreen:), did_fail_to_enter_full_screen::<T> as extern fn(&Object, _, _));
-        decl.add_method(sel!(windowDidFailToExitFullScreen:), did_fail_to_exit_full_screen::<T> as extern fn(&Object, _, _));
+        decl.add_guarded_method(sel!(window:willUseFullScreenContentSize:), content_size_for_full_screen::<T>);
+        decl.add_guarded_method(sel!(window:willUseFullScreenPresentationOptions:), options_for_full_screen::<T>);
+        decl.add_guarded_method(sel!(windowWillEnterFullScreen:), will_enter_full_screen::<T>);
+        decl.add_guarded_method(sel!(windowDidEnterFullScreen:), did_enter_full_screen::<T>);
+        decl.add_guarded_method(sel!(windowWillExitFullScreen:), will_exit_full_screen::<T>);
+        decl.add_guarded_method(sel!(windowDidExitFullScreen:), did_exit_full_screen::<T>);
+        decl.add_guarded_method(sel!(windowDidFailToEnterFullScreen:), did_fail_to_enter_full_screen::<T>);
+        decl.add_guarded_method(sel!(windowDidFailToExitFullScreen:), did_fail_to_exit_full_screen::<T>);
 
         // Key status
-        decl.add_method(sel!(windowDidBecomeKey:), did_become_key::<T> as extern fn(&Object, _, _));
-        decl.add_method(sel!(windowDidResignKey:), did_resign_key::<T> as extern fn(&Object, _, _));
+        decl.add_guarded_method(sel!(windowDidBecomeKey:), did_become_key::<T>);
+        decl.add_guarded_method(sel!(windowDidResignKey:), did_resign_key::<T>);
 
         // Main status
-        decl.add_method(sel!(windowDidBecomeMain:), did_become_main::<T> as extern fn(&Object, _, _));
-        decl.add_method(sel!(windowDidResignMain:), did_resign_main::<T> as extern fn(&Object, _, _));
+        decl.add_guarded_method(sel!(windowDidBecomeMain:), did_become_main::<T>);
+        decl.add_guarded_method(sel!(windowDidResignMain:), did_resign_main::<T>);
 
         // Moving Windows
-        decl.add_method(sel!(windowWillMove:), will_move::<T> as extern fn(&Object, _, _));
-        decl.add_method(sel!(windowDidMove:), did_move::<T> as extern fn(&Object, _, _));
-        decl.add_method(sel!(windowDidChangeScreen:), did_change_screen::<T> as extern fn(&Object, _, _));
-        decl.add_method(sel!(windowDidChangeScreenProfile:), did_change_screen_profile::<T> as extern fn(&Object, _, _));
-        decl.add_method(sel!(windowDidChangeBackingProperties:), did_change_backing_properties::<T> as extern fn(&Object, _, _));
+        decl.add_guarded_method(sel!(windowWillMove:), will_move::<T>);
+        decl.add_guarded_method(sel!(windowDidMove:), did_move::<T>);
+        decl.add_guarded_method(sel!(windowDidChangeScreen:), did_change_screen::<T>);
+        decl.add_guarded_method(sel!(windowDidChangeScreenProfile:), did_change_screen_profile::<T>);
+        decl.add_guarded_method(sel!(windowDidChangeBackingProperties:), did_change_backing_properties::<T>);
 
         // Random
-        decl.add_method(sel!(windowDidChangeOcclusionState:), did_change_occlusion_state::<T> as extern fn(&Object, _, _));
-        decl.add_method(sel!(windowDidExpose:), did_expose::<T> as extern fn(&Object, _, _));
-        decl.add_method(sel!(windowDidUpdate:), did_update::<T> as extern fn(&Object, _, _));
-        decl.add_method(sel!(cancelOperation:), cancel::<T> as extern fn (&Object, _, _));
+        decl.add_guarded_method(sel!(windowDidChangeOcclusionState:), did_change_occlusion_state::<T>);
+        decl.add_guarded_method(sel!(windowDidExpose:), did_expose::<T>);
+        decl.add_guarded_method(sel!(windowDidUpdate:), did_update::<T>);
+        decl.add_guarded_method(sel!(cancelOperation:), cancel::<T>);
     })
 }
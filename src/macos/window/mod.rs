@@ -21,6 +21,7 @@ use crate::color::Color;
 use crate::foundation::{id, nil, to_bool, YES, NO, NSString, NSInteger, NSUInteger};
 use crate::layout::traits::Layout;
 use crate::macos::toolbar::{Toolbar, ToolbarDelegate};
+use crate::panic::guard;
 use crate::utils::{os, Controller};
 
 mod class;
@@ -454,7 +455,7 @@ impl<T> Window<T> {
         W: WindowDelegate + 'static
     {
         let block = ConcreteBlock::new(move |response: NSInteger| {
-            completion();
+            guard(|| completion());
         });
         let block = block.copy();
 
@@ -11,14 +11,43 @@
 //! integrating with certain aspects of the underlying Cocoa/Foundation/Kit frameworks.
 //!
 //! ## Example
+//! ```rust,no_run
+//! use std::collections::HashMap;
+//!
+//! use cacao::defaults::Value;
+//! use cacao::notification_center::{NotificationCenter, NotificationName};
+//!
+//! let center = NotificationCenter::default();
+//!
+//! // Hold on to this for as long as you want to be notified; dropping it removes the observer.
+//! let _token = center.observe(NotificationName::NSWindowDidResize, None, |notification| {
+//!     println!("Resized: {}", notification.name());
+//! });
+//!
+//! let sync_finished = NotificationName::Custom("MyAppSyncFinishedNotification");
+//! let _sync_token = center.observe(sync_finished, None, |notification| {
+//!     let info = notification.user_info();
+//!     println!("Synced {:?} items", info.get("count").and_then(|v| v.as_i64()));
+//! });
+//!
+//! center.post_with_user_info(sync_finished, None, {
+//!     let mut info = HashMap::new();
+//!     info.insert("count", Value::Integer(10));
+//!     info
+//! });
+//! ```
+
+use std::collections::HashMap;
 
-//use std::sync::Mutex;
-//use std::collections::HashMap;
+use block::ConcreteBlock;
 
-//use lazy_static::lazy_static;
-//use objc::{class, msg_send, sel, sel_impl};
-//use objc::runtime::Object;
-//use objc_id::ShareId;
+use objc::{class, msg_send, sel, sel_impl};
+use objc::runtime::Object;
+use objc_id::ShareId;
+
+use crate::defaults::Value;
+use crate::foundation::{id, nil, NSArray, NSDictionary, NSString};
+use crate::panic::guard;
 
 mod name;
 pub use name::NotificationName;
@@ -26,27 +55,22 @@ pub use name::NotificationName;
 mod traits;
 pub use traits::Dispatcher;
 
-/*lazy_static! {
-    pub static ref DefaultNotificationCenter: NotificationCenter = {
-        NotificationCenter {
-            objc: unsafe {
-                ShareId::from_ptr(msg_send![class!(NSNotificationCenter), defaultCenter])
-            },
+/// Returns either the passed object, or `nil`, for use as a notification sender/filter.
+fn object_or_nil(object: Option<&Object>) -> id {
+    match object {
+        Some(object) => object as *const Object as id,
+        None => nil
+    }
+}
 
-            subscribers: Mutex::new(HashMap::new())
-        }
-    };
-}*/
-
-// Wraps a reference to an `NSNotificationCenter` instance. Currently this only supports the
-// default center; in the future it should aim to support custom variants.
-//#[derive(Debug)]
-//pub struct NotificationCenter {
-//    pub objc: ShareId<Object>,
-    //pub subscribers: Mutex<HashMap<String, Vec<Dispatcher>>>
-//}
-
-/*impl Default for NotificationCenter {
+/// Wraps a reference to an `NSNotificationCenter` instance. Currently this only supports the
+/// default center; in the future it should aim to support custom variants.
+#[derive(Debug)]
+pub struct NotificationCenter {
+    pub objc: ShareId<Object>
+}
+
+impl Default for NotificationCenter {
     /// Returns a wrapper over `[NSNotificationCenter defaultCenter]`. From here you can handle
     /// observing, removing, and posting notifications.
     fn default() -> Self {
@@ -56,18 +80,156 @@ pub use traits::Dispatcher;
             }
         }
     }
-}*/
+}
+
+impl NotificationCenter {
+    /// Registers `handler` to be called whenever a notification with the given `name` is posted.
+    /// If `object` is provided, only notifications sent by that object are delivered; otherwise,
+    /// notifications from any sender are delivered.
+    ///
+    /// Handlers are always called on the main thread, regardless of which thread posted the
+    /// notification, so it's safe to update your UI from them.
+    ///
+    /// The returned `ObserverToken` keeps the observation alive; when it drops, the handler is
+    /// removed from the center.
+    pub fn observe<F>(&self, name: NotificationName, object: Option<&Object>, handler: F) -> ObserverToken
+    where
+        F: Fn(Notification) + 'static
+    {
+        let name: NSString = name.into();
+        let object = object_or_nil(object);
 
-/*impl NotificationCenter {
-    pub fn observe<T: Dispatcher>(&self, name: &str, handler: &T) {
+        let block = ConcreteBlock::new(move |notification: id| {
+            guard(|| handler(Notification::wrap(notification)));
+        });
+        let block = block.copy();
 
+        let observer = unsafe {
+            let queue: id = msg_send![class!(NSOperationQueue), mainQueue];
+            let observer: id = msg_send![&*self.objc, addObserverForName:name.into_inner()
+                object:object
+                queue:queue
+                usingBlock:&*block
+            ];
+
+            ShareId::from_ptr(observer)
+        };
+
+        ObserverToken {
+            center: self.objc.clone(),
+            observer: observer
+        }
     }
 
-    pub fn remove<T: Dispatcher>(&self, name: &str, handler: &T) {
+    /// Posts a notification with the given `name`, optionally specifying the sending object.
+    pub fn post(&self, name: NotificationName, object: Option<&Object>) {
+        let name: NSString = name.into();
+        let object = object_or_nil(object);
 
+        unsafe {
+            let _: () = msg_send![&*self.objc, postNotificationName:name.into_inner() object:object];
+        }
+    }
+
+    /// Posts a notification with the given `name`, carrying along a `userInfo` dictionary built
+    /// from `user_info`. Observers can retrieve these values via `Notification::user_info()`.
+    pub fn post_with_user_info<K: AsRef<str>>(
+        &self,
+        name: NotificationName,
+        object: Option<&Object>,
+        user_info: HashMap<K, Value>
+    ) {
+        let name: NSString = name.into();
+        let object = object_or_nil(object);
+        let user_info = NSDictionary::from(user_info);
+
+        unsafe {
+            let _: () = msg_send![&*self.objc, postNotificationName:name.into_inner()
+                object:object
+                userInfo:user_info.into_inner()
+            ];
+        }
     }
+}
 
-    pub fn post(&self, name: &str) {
+/// A handle to a registered observer. The observer is removed from the `NotificationCenter` when
+/// this is dropped, so store it somewhere that lives as long as you want to be notified.
+#[derive(Debug)]
+pub struct ObserverToken {
+    center: ShareId<Object>,
+    observer: ShareId<Object>
+}
+
+impl ObserverToken {
+    /// Removes the observer. This is equivalent to dropping the token, but can read a bit
+    /// clearer in some cases.
+    pub fn remove(self) {}
+}
+
+impl Drop for ObserverToken {
+    fn drop(&mut self) {
+        unsafe {
+            let _: () = msg_send![&*self.center, removeObserver:&*self.observer];
+        }
+    }
+}
+
+/// A wrapper for `NSNotification`, as delivered to an observer.
+#[derive(Debug)]
+pub struct Notification(pub ShareId<Object>);
+
+impl Notification {
+    /// Wraps and retains a system-vended `NSNotification`.
+    pub(crate) fn wrap(notification: id) -> Self {
+        Notification(unsafe {
+            ShareId::from_ptr(notification)
+        })
+    }
+
+    /// Returns the name of this notification - i.e, the underlying string constant.
+    pub fn name(&self) -> String {
+        NSString::wrap(unsafe {
+            msg_send![&*self.0, name]
+        }).to_string()
+    }
+
+    /// Returns a pointer to the object that posted this notification, if any. You can compare
+    /// this against the `objc` pointer on your own controls to check where it came from.
+    pub fn object(&self) -> Option<ShareId<Object>> {
+        unsafe {
+            let object: id = msg_send![&*self.0, object];
+
+            match object == nil {
+                true => None,
+                false => Some(ShareId::from_ptr(object))
+            }
+        }
+    }
+
+    /// Returns the `userInfo` dictionary for this notification, converted into `Value`s. Entries
+    /// with non-string keys, or values that `Value` can't represent, are skipped.
+    pub fn user_info(&self) -> HashMap<String, Value> {
+        let mut map = HashMap::new();
+
+        unsafe {
+            let user_info: id = msg_send![&*self.0, userInfo];
+            if user_info == nil {
+                return map;
+            }
+
+            let keys = NSArray::wrap(msg_send![user_info, allKeys]);
+            for key in keys.map(|key| key) {
+                if !NSString::is(key) {
+                    continue;
+                }
+
+                let value: id = msg_send![user_info, objectForKey:key];
+                if let Some(value) = Value::from_objc(value) {
+                    map.insert(NSString::wrap(key).to_string(), value);
+                }
+            }
+        }
 
+        map
     }
-}*/
+}
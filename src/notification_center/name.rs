@@ -6,7 +6,7 @@ use crate::foundation::NSString;
 ///
 /// Since this framework utilizes Objective-C, these are ultimately backed by `NSString`... but we
 /// want them to be a bit more type-friendly and autocomplete-able.
-#[derive(Copy, Clone, Debug)]
+#[derive(Copy, Clone, Debug, PartialEq)]
 pub enum NotificationName {
     /// Posted when the audio engine config changes.
     ///
@@ -1311,13 +1311,1331 @@ pub enum NotificationName {
     SKStorefrontCountryCodeDidChange,
 
     ///
-    WKAccessibilityReduceMotionStatusDidChange
+    WKAccessibilityReduceMotionStatusDidChange,
+
+    /// A notification name of your own choosing, for posting and observing notifications that
+    /// are specific to your application. Try to keep these unique - e.g, prefix them with
+    /// your application name.
+    Custom(&'static str)
+}
+
+impl NotificationName {
+    /// Returns the underlying string constant for this notification name - i.e, the value that
+    /// the corresponding `NSNotificationName` constant holds on the Objective-C side.
+    ///
+    /// For the majority of notifications this matches the symbol name (e.g, `NSWindowDidResize`
+    /// maps to `"NSWindowDidResizeNotification"`), but there are some notable exceptions
+    /// (accessibility notifications, Core Data, and so on).
+    pub fn as_str(&self) -> &'static str {
+        match self {
+            NotificationName::AudioEngineConfigurationChange => "AVAudioEngineConfigurationChangeNotification",
+            NotificationName::AudioSessionInterruption => "AVAudioSessionInterruptionNotification",
+            NotificationName::AudioSessionMediaServicesWereLost => "AVAudioSessionMediaServicesWereLostNotification",
+            NotificationName::AudioSessionMediaServicesWereReset => "AVAudioSessionMediaServicesWereResetNotification",
+            NotificationName::AudioSessionRouteChange => "AVAudioSessionRouteChangeNotification",
+            NotificationName::AudioSessionSilenceSecondaryAudioHint => "AVAudioSessionSilenceSecondaryAudioHintNotification",
+            NotificationName::AudioUnitComponentTagsDidChange => "AVAudioUnitComponentTagsDidChangeNotification",
+            NotificationName::CloudKitAccountChanged => "CKAccountChangedNotification",
+            NotificationName::CLKComplicationServerActiveComplicationsDidChange => "CLKComplicationServerActiveComplicationsDidChangeNotification",
+            NotificationName::CNContactStoreDidChange => "CNContactStoreDidChangeNotification",
+            NotificationName::EKEventStoreChanged => "EKEventStoreChangedNotification",
+            NotificationName::HKUserPreferencesDidChange => "HKUserPreferencesDidChangeNotification",
+            NotificationName::HMCharacteristicPropertySupportsEvent => "HMCharacteristicPropertySupportsEventNotification",
+            NotificationName::NSBundleResourceRequestLowDiskSpace => "NSBundleResourceRequestLowDiskSpaceNotification",
+            NotificationName::NSCalendarDayChanged => "NSCalendarDayChangedNotification",
+            NotificationName::NSExtensionHostDidBecomeActive => "NSExtensionHostDidBecomeActiveNotification",
+            NotificationName::NSExtensionHostDidEnterBackground => "NSExtensionHostDidEnterBackgroundNotification",
+            NotificationName::NSExtensionHostWillEnterForeground => "NSExtensionHostWillEnterForegroundNotification",
+            NotificationName::NSExtensionHostWillResignActive => "NSExtensionHostWillResignActiveNotification",
+            NotificationName::NSFileHandleConnectionAccepted => "NSFileHandleConnectionAcceptedNotification",
+            NotificationName::NSFileHandleDataAvailable => "NSFileHandleDataAvailableNotification",
+            NotificationName::NSFileHandleReadToEndOfFileCompletion => "NSFileHandleReadToEndOfFileCompletionNotification",
+            NotificationName::NSHTTPCookieManagerAcceptPolicyChanged => "NSHTTPCookieManagerAcceptPolicyChangedNotification",
+            NotificationName::NSHTTPCookieManagerCookiesChanged => "NSHTTPCookieManagerCookiesChangedNotification",
+            NotificationName::NSManagedObjectContextDidSave => "NSManagingContextDidSaveChangesNotification",
+            NotificationName::NSManagedObjectContextObjectsDidChange => "NSObjectsChangedInManagingContextNotification",
+            NotificationName::NSManagedObjectContextWillSave => "NSManagingContextWillSaveChangesNotification",
+            NotificationName::NSMetadataQueryDidFinishGathering => "NSMetadataQueryDidFinishGatheringNotification",
+            NotificationName::NSMetadataQueryDidStartGathering => "NSMetadataQueryDidStartGatheringNotification",
+            NotificationName::NSMetadataQueryDidUpdate => "NSMetadataQueryDidUpdateNotification",
+            NotificationName::NSMetadataQueryGatheringProgress => "NSMetadataQueryGatheringProgressNotification",
+            NotificationName::NSPersistentStoreCoordinatorStoresDidChange => "NSPersistentStoreCoordinatorStoresDidChangeNotification",
+            NotificationName::NSPersistentStoreCoordinatorStoresWillChange => "NSPersistentStoreCoordinatorStoresWillChangeNotification",
+            NotificationName::NSPersistentStoreCoordinatorWillRemoveStore => "NSPersistentStoreCoordinatorWillRemoveStoreNotification",
+            NotificationName::NSProcessInfoPowerStateDidChange => "NSProcessInfoPowerStateDidChangeNotification",
+            NotificationName::NSSystemClockDidChange => "NSSystemClockDidChangeNotification",
+            NotificationName::NSSystemTimeZoneDidChange => "NSSystemTimeZoneDidChangeNotification",
+            NotificationName::NSURLCredentialStorageChanged => "NSURLCredentialStorageChangedNotification",
+            NotificationName::NSUbiquityIdentityDidChange => "NSUbiquityIdentityDidChangeNotification",
+            NotificationName::NSUndoManagerCheckpoint => "NSUndoManagerCheckpointNotification",
+            NotificationName::NSUndoManagerDidCloseUndoGroup => "NSUndoManagerDidCloseUndoGroupNotification",
+            NotificationName::NSUndoManagerDidOpenUndoGroup => "NSUndoManagerDidOpenUndoGroupNotification",
+            NotificationName::NSUndoManagerDidRedoChange => "NSUndoManagerDidRedoChangeNotification",
+            NotificationName::NSUndoManagerDidUndoChange => "NSUndoManagerDidUndoChangeNotification",
+            NotificationName::NSUndoManagerWillCloseUndoGroup => "NSUndoManagerWillCloseUndoGroupNotification",
+            NotificationName::NSUndoManagerWillRedoChange => "NSUndoManagerWillRedoChangeNotification",
+            NotificationName::NSUndoManagerWillUndoChange => "NSUndoManagerWillUndoChangeNotification",
+            NotificationName::PKPassLibraryDidChange => "PKPassLibraryDidChangeNotification",
+            NotificationName::PKPassLibraryRemotePaymentPassesDidChange => "PKPassLibraryRemotePaymentPassesDidChangeNotification",
+            NotificationName::UIAccessibilityAnnouncementDidFinish => "UIAccessibilityAnnouncementDidFinishNotification",
+            NotificationName::UIAccessibilityElementFocused => "UIAccessibilityElementFocusedNotification",
+            NotificationName::WKAudioFilePlayerItemDidPlayToEndTime => "WKAudioFilePlayerItemDidPlayToEndTimeNotification",
+            NotificationName::WKAudioFilePlayerItemFailedToPlayToEndTime => "WKAudioFilePlayerItemFailedToPlayToEndTimeNotification",
+            NotificationName::WKAudioFilePlayerItemTimeJumped => "WKAudioFilePlayerItemTimeJumpedNotification",
+            NotificationName::ABPeoplePickerDisplayedPropertyDidChange => "ABPeoplePickerDisplayedPropertyDidChangeNotification",
+            NotificationName::ABPeoplePickerGroupSelectionDidChange => "ABPeoplePickerGroupSelectionDidChangeNotification",
+            NotificationName::ABPeoplePickerNameSelectionDidChange => "ABPeoplePickerNameSelectionDidChangeNotification",
+            NotificationName::ABPeoplePickerValueSelectionDidChange => "ABPeoplePickerValueSelectionDidChangeNotification",
+            NotificationName::ACAccountStoreDidChange => "ACAccountStoreDidChangeNotification",
+            NotificationName::AVAssetChapterMetadataGroupsDidChange => "AVAssetChapterMetadataGroupsDidChangeNotification",
+            NotificationName::AVAssetContainsFragmentsDidChange => "AVAssetContainsFragmentsDidChangeNotification",
+            NotificationName::AVAssetDurationDidChange => "AVAssetDurationDidChangeNotification",
+            NotificationName::AVAssetMediaSelectionGroupsDidChange => "AVAssetMediaSelectionGroupsDidChangeNotification",
+            NotificationName::AVAssetTrackSegmentsDidChange => "AVAssetTrackSegmentsDidChangeNotification",
+            NotificationName::AVAssetTrackTimeRangeDidChange => "AVAssetTrackTimeRangeDidChangeNotification",
+            NotificationName::AVAssetTrackTrackAssociationsDidChange => "AVAssetTrackTrackAssociationsDidChangeNotification",
+            NotificationName::AVAssetWasDefragmented => "AVAssetWasDefragmentedNotification",
+            NotificationName::AVCaptureDeviceWasConnected => "AVCaptureDeviceWasConnectedNotification",
+            NotificationName::AVCaptureDeviceWasDisconnected => "AVCaptureDeviceWasDisconnectedNotification",
+            NotificationName::AVCaptureInputPortFormatDescriptionDidChange => "AVCaptureInputPortFormatDescriptionDidChangeNotification",
+            NotificationName::AVCaptureSessionDidStartRunning => "AVCaptureSessionDidStartRunningNotification",
+            NotificationName::AVCaptureSessionDidStopRunning => "AVCaptureSessionDidStopRunningNotification",
+            NotificationName::AVCaptureSessionRuntimeError => "AVCaptureSessionRuntimeErrorNotification",
+            NotificationName::AVFragmentedMovieContainsMovieFragmentsDidChange => "AVFragmentedMovieContainsMovieFragmentsDidChangeNotification",
+            NotificationName::AVFragmentedMovieDurationDidChange => "AVFragmentedMovieDurationDidChangeNotification",
+            NotificationName::AVFragmentedMovieTrackSegmentsDidChange => "AVFragmentedMovieTrackSegmentsDidChangeNotification",
+            NotificationName::AVFragmentedMovieTrackTimeRangeDidChange => "AVFragmentedMovieTrackTimeRangeDidChangeNotification",
+            NotificationName::AVFragmentedMovieTrackTotalSampleDataLengthDidChange => "AVFragmentedMovieTrackTotalSampleDataLengthDidChangeNotification",
+            NotificationName::AVFragmentedMovieWasDefragmented => "AVFragmentedMovieWasDefragmentedNotification",
+            NotificationName::AVPlayerItemDidPlayToEndTime => "AVPlayerItemDidPlayToEndTimeNotification",
+            NotificationName::AVPlayerItemFailedToPlayToEndTime => "AVPlayerItemFailedToPlayToEndTimeNotification",
+            NotificationName::AVPlayerItemNewAccessLogEntry => "AVPlayerItemNewAccessLogEntryNotification",
+            NotificationName::AVPlayerItemNewErrorLogEntry => "AVPlayerItemNewErrorLogEntryNotification",
+            NotificationName::AVPlayerItemPlaybackStalled => "AVPlayerItemPlaybackStalledNotification",
+            NotificationName::AVPlayerItemTimeJumped => "AVPlayerItemTimeJumpedNotification",
+            NotificationName::AVSampleBufferDisplayLayerFailedToDecode => "AVSampleBufferDisplayLayerFailedToDecodeNotification",
+            NotificationName::CWBSSIDDidChange => "CWBSSIDDidChangeNotification",
+            NotificationName::CWCountryCodeDidChange => "CWCountryCodeDidChangeNotification",
+            NotificationName::CWLinkDidChange => "CWLinkDidChangeNotification",
+            NotificationName::CWLinkQualityDidChange => "CWLinkQualityDidChangeNotification",
+            NotificationName::CWModeDidChange => "CWModeDidChangeNotification",
+            NotificationName::CWPowerDidChange => "CWPowerDidChangeNotification",
+            NotificationName::CWSSIDDidChange => "CWSSIDDidChangeNotification",
+            NotificationName::CWScanCacheDidUpdate => "CWScanCacheDidUpdateNotification",
+            NotificationName::GCControllerDidConnect => "GCControllerDidConnectNotification",
+            NotificationName::GCControllerDidDisconnect => "GCControllerDidDisconnectNotification",
+            NotificationName::IKFilterBrowserFilterDoubleClick => "IKFilterBrowserFilterDoubleClickNotification",
+            NotificationName::IKFilterBrowserFilterSelected => "IKFilterBrowserFilterSelectedNotification",
+            NotificationName::IKFilterBrowserWillPreviewFilter => "IKFilterBrowserWillPreviewFilterNotification",
+            NotificationName::IOBluetoothHostControllerPoweredOff => "IOBluetoothHostControllerPoweredOffNotification",
+            NotificationName::IOBluetoothHostControllerPoweredOn => "IOBluetoothHostControllerPoweredOnNotification",
+            NotificationName::IOBluetoothL2CAPChannelPublished => "IOBluetoothL2CAPChannelPublishedNotification",
+            NotificationName::IOBluetoothL2CAPChannelTerminated => "IOBluetoothL2CAPChannelTerminatedNotification",
+            NotificationName::MKAnnotationCalloutInfoDidChange => "MKAnnotationCalloutInfoDidChangeNotification",
+            NotificationName::NEFilterConfigurationDidChange => "NEFilterConfigurationDidChangeNotification",
+            NotificationName::NEVPNConfigurationChange => "NEVPNConfigurationChangeNotification",
+            NotificationName::NEVPNStatusDidChange => "NEVPNStatusDidChangeNotification",
+            NotificationName::NSAccessibilityAnnouncementRequested => "AXAnnouncementRequested",
+            NotificationName::NSAccessibilityAnnouncementKey => "AXAnnouncementKey",
+            NotificationName::NSAccessibilityPriorityKey => "AXPriorityKey",
+            NotificationName::NSAccessibilityApplicationActivated => "AXApplicationActivated",
+            NotificationName::NSAccessibilityApplicationDeactivated => "AXApplicationDeactivated",
+            NotificationName::NSAccessibilityApplicationHidden => "AXApplicationHidden",
+            NotificationName::NSAccessibilityApplicationShown => "AXApplicationShown",
+            NotificationName::NSAccessibilityCreated => "AXCreated",
+            NotificationName::NSAccessibilityDrawerCreated => "AXDrawerCreated",
+            NotificationName::NSAccessibilityFocusedUIElementChanged => "AXFocusedUIElementChanged",
+            NotificationName::NSAccessibilityFocusedWindowChanged => "AXFocusedWindowChanged",
+            NotificationName::NSAccessibilityHelpTagCreated => "AXHelpTagCreated",
+            NotificationName::NSAccessibilityLayoutChanged => "AXLayoutChanged",
+            NotificationName::NSAccessibilityUIElementsKey => "AXUIElementsKey",
+            NotificationName::NSAccessibilityMainWindowChanged => "AXMainWindowChanged",
+            NotificationName::NSAccessibilityMoved => "AXMoved",
+            NotificationName::NSAccessibilityResized => "AXResized",
+            NotificationName::NSAccessibilityRowCollapsed => "AXRowCollapsed",
+            NotificationName::NSAccessibilityRowCountChanged => "AXRowCountChanged",
+            NotificationName::NSAccessibilityRowExpanded => "AXRowExpanded",
+            NotificationName::NSAccessibilitySelectedCellsChanged => "AXSelectedCellsChanged",
+            NotificationName::NSAccessibilitySelectedChildrenChanged => "AXSelectedChildrenChanged",
+            NotificationName::NSAccessibilitySelectedChildrenMoved => "AXSelectedChildrenMoved",
+            NotificationName::NSAccessibilitySelectedColumnsChanged => "AXSelectedColumnsChanged",
+            NotificationName::NSAccessibilitySelectedRowsChanged => "AXSelectedRowsChanged",
+            NotificationName::NSAccessibilitySelectedTextChanged => "AXSelectedTextChanged",
+            NotificationName::NSAccessibilitySheetCreated => "AXSheetCreated",
+            NotificationName::NSAccessibilityTitleChanged => "AXTitleChanged",
+            NotificationName::NSAccessibilityUIElementDestroyed => "AXUIElementDestroyed",
+            NotificationName::NSAccessibilityUnitsChanged => "AXUnitsChanged",
+            NotificationName::NSAccessibilityValueChanged => "AXValueChanged",
+            NotificationName::NSAccessibilityWindowCreated => "AXWindowCreated",
+            NotificationName::NSAccessibilityWindowDeminiaturized => "AXWindowDeminiaturized",
+            NotificationName::NSAccessibilityWindowMiniaturized => "AXWindowMiniaturized",
+            NotificationName::NSAccessibilityWindowMoved => "AXWindowMoved",
+            NotificationName::NSAccessibilityWindowResized => "AXWindowResized",
+            NotificationName::NSAnimationProgressMark => "NSAnimationProgressMarkNotification",
+            NotificationName::NSAntialiasThresholdChanged => "NSAntialiasThresholdChangedNotification",
+            NotificationName::NSAppleEventManagerWillProcessFirstEvent => "NSAppleEventManagerWillProcessFirstEventNotification",
+            NotificationName::NSApplicationDidBecomeActive => "NSApplicationDidBecomeActiveNotification",
+            NotificationName::NSApplicationDidChangeOcclusionState => "NSApplicationDidChangeOcclusionStateNotification",
+            NotificationName::NSApplicationDidChangeScreenParameters => "NSApplicationDidChangeScreenParametersNotification",
+            NotificationName::NSApplicationDidFinishLaunching => "NSApplicationDidFinishLaunchingNotification",
+            NotificationName::NSApplicationDidFinishRestoringWindows => "NSApplicationDidFinishRestoringWindowsNotification",
+            NotificationName::NSApplicationDidHide => "NSApplicationDidHideNotification",
+            NotificationName::NSApplicationDidResignActive => "NSApplicationDidResignActiveNotification",
+            NotificationName::NSApplicationDidUnhide => "NSApplicationDidUnhideNotification",
+            NotificationName::NSApplicationDidUpdate => "NSApplicationDidUpdateNotification",
+            NotificationName::NSApplicationWillBecomeActive => "NSApplicationWillBecomeActiveNotification",
+            NotificationName::NSApplicationWillFinishLaunching => "NSApplicationWillFinishLaunchingNotification",
+            NotificationName::NSApplicationWillHide => "NSApplicationWillHideNotification",
+            NotificationName::NSApplicationWillResignActive => "NSApplicationWillResignActiveNotification",
+            NotificationName::NSApplicationWillTerminate => "NSApplicationWillTerminateNotification",
+            NotificationName::NSApplicationWillUnhide => "NSApplicationWillUnhideNotification",
+            NotificationName::NSApplicationWillUpdate => "NSApplicationWillUpdateNotification",
+            NotificationName::NSBrowserColumnConfigurationDidChange => "NSBrowserColumnConfigurationDidChangeNotification",
+            NotificationName::NSClassDescriptionNeededForClass => "NSClassDescriptionNeededForClassNotification",
+            NotificationName::NSColorListDidChange => "NSColorListDidChangeNotification",
+            NotificationName::NSColorPanelColorDidChange => "NSColorPanelColorDidChangeNotification",
+            NotificationName::NSColorPanel => "NSColorPanelNotification",
+            NotificationName::NSComboBoxSelectionDidChange => "NSComboBoxSelectionDidChangeNotification",
+            NotificationName::NSComboBoxSelectionIsChanging => "NSComboBoxSelectionIsChangingNotification",
+            NotificationName::NSComboBoxWillDismiss => "NSComboBoxWillDismissNotification",
+            NotificationName::NSComboBoxWillPopUp => "NSComboBoxWillPopUpNotification",
+            NotificationName::NSContextHelpModeDidActivate => "NSContextHelpModeDidActivateNotification",
+            NotificationName::NSContextHelpModeDidDeactivate => "NSContextHelpModeDidDeactivateNotification",
+            NotificationName::NSControlTextDidBeginEditing => "NSControlTextDidBeginEditingNotification",
+            NotificationName::NSControlTextDidChange => "NSControlTextDidChangeNotification",
+            NotificationName::NSControlTextDidEndEditing => "NSControlTextDidEndEditingNotification",
+            NotificationName::NSControlTintDidChange => "NSControlTintDidChangeNotification",
+            NotificationName::NSDrawerDidClose => "NSDrawerDidCloseNotification",
+            NotificationName::NSDrawerDidOpen => "NSDrawerDidOpenNotification",
+            NotificationName::NSDrawerWillClose => "NSDrawerWillCloseNotification",
+            NotificationName::NSDrawerWillOpen => "NSDrawerWillOpenNotification",
+            NotificationName::NSFontCollectionDidChange => "NSFontCollectionDidChangeNotification",
+            NotificationName::NSFontSetChanged => "NSFontSetChangedNotification",
+            NotificationName::NSImageRepRegistryDidChange => "NSImageRepRegistryDidChangeNotification",
+            NotificationName::NSMenuDidAddItem => "NSMenuDidAddItemNotification",
+            NotificationName::NSMenuDidBeginTracking => "NSMenuDidBeginTrackingNotification",
+            NotificationName::NSMenuDidChangeItem => "NSMenuDidChangeItemNotification",
+            NotificationName::NSMenuDidEndTracking => "NSMenuDidEndTrackingNotification",
+            NotificationName::NSMenuDidRemoveItem => "NSMenuDidRemoveItemNotification",
+            NotificationName::NSMenuDidSendAction => "NSMenuDidSendActionNotification",
+            NotificationName::NSMenuWillSendAction => "NSMenuWillSendActionNotification",
+            NotificationName::NSOutlineViewColumnDidMove => "NSOutlineViewColumnDidMoveNotification",
+            NotificationName::NSOutlineViewColumnDidResize => "NSOutlineViewColumnDidResizeNotification",
+            NotificationName::NSOutlineViewItemDidCollapse => "NSOutlineViewItemDidCollapseNotification",
+            NotificationName::NSOutlineViewItemDidExpand => "NSOutlineViewItemDidExpandNotification",
+            NotificationName::NSOutlineViewItemWillCollapse => "NSOutlineViewItemWillCollapseNotification",
+            NotificationName::NSOutlineViewItemWillExpand => "NSOutlineViewItemWillExpandNotification",
+            NotificationName::NSOutlineViewSelectionDidChange => "NSOutlineViewSelectionDidChangeNotification",
+            NotificationName::NSOutlineViewSelectionIsChanging => "NSOutlineViewSelectionIsChangingNotification",
+            NotificationName::NSPersistentStoreDidImportUbiquitousContentChanges => "NSPersistentStoreDidImportUbiquitousContentChangesNotification",
+            NotificationName::NSPopUpButtonCellWillPopUp => "NSPopUpButtonCellWillPopUpNotification",
+            NotificationName::NSPopUpButtonWillPopUp => "NSPopUpButtonWillPopUpNotification",
+            NotificationName::NSPopoverDidClose => "NSPopoverDidCloseNotification",
+            NotificationName::NSPopoverDidShow => "NSPopoverDidShowNotification",
+            NotificationName::NSPopoverWillClose => "NSPopoverWillCloseNotification",
+            NotificationName::NSPopoverWillShow => "NSPopoverWillShowNotification",
+            NotificationName::NSPreferencePaneCancelUnselect => "NSPreferencePaneCancelUnselectNotification",
+            NotificationName::NSPreferencePaneDoUnselect => "NSPreferencePaneDoUnselectNotification",
+            NotificationName::NSPreferencePaneSwitchToPane => "NSPreferencePaneSwitchToPaneNotification",
+            NotificationName::NSPreferencePaneUpdateHelpMenu => "NSPreferencePaneUpdateHelpMenuNotification",
+            NotificationName::NSPreferencePrefPaneIsAvailable => "NSPreferencePrefPaneIsAvailableNotification",
+            NotificationName::NSPreferredScrollerStyleDidChange => "NSPreferredScrollerStyleDidChangeNotification",
+            NotificationName::NSRuleEditorRowsDidChange => "NSRuleEditorRowsDidChangeNotification",
+            NotificationName::NSScreenColorSpaceDidChange => "NSScreenColorSpaceDidChangeNotification",
+            NotificationName::NSScrollViewDidEndLiveMagnify => "NSScrollViewDidEndLiveMagnifyNotification",
+            NotificationName::NSScrollViewDidEndLiveScroll => "NSScrollViewDidEndLiveScrollNotification",
+            NotificationName::NSScrollViewDidLiveScroll => "NSScrollViewDidLiveScrollNotification",
+            NotificationName::NSScrollViewWillStartLiveMagnify => "NSScrollViewWillStartLiveMagnifyNotification",
+            NotificationName::NSScrollViewWillStartLiveScroll => "NSScrollViewWillStartLiveScrollNotification",
+            NotificationName::NSSpellCheckerDidChangeAutomaticCapitalization => "NSSpellCheckerDidChangeAutomaticCapitalizationNotification",
+            NotificationName::NSSpellCheckerDidChangeAutomaticDashSubstitution => "NSSpellCheckerDidChangeAutomaticDashSubstitutionNotification",
+            NotificationName::NSSpellCheckerDidChangeAutomaticPeriodSubstitution => "NSSpellCheckerDidChangeAutomaticPeriodSubstitutionNotification",
+            NotificationName::NSSpellCheckerDidChangeAutomaticQuoteSubstitution => "NSSpellCheckerDidChangeAutomaticQuoteSubstitutionNotification",
+            NotificationName::NSSpellCheckerDidChangeAutomaticSpellingCorrection => "NSSpellCheckerDidChangeAutomaticSpellingCorrectionNotification",
+            NotificationName::NSSpellCheckerDidChangeAutomaticTextReplacement => "NSSpellCheckerDidChangeAutomaticTextReplacementNotification",
+            NotificationName::NSSplitViewDidResizeSubviews => "NSSplitViewDidResizeSubviewsNotification",
+            NotificationName::NSSplitViewWillResizeSubviews => "NSSplitViewWillResizeSubviewsNotification",
+            NotificationName::NSSystemColorsDidChange => "NSSystemColorsDidChangeNotification",
+            NotificationName::NSTableViewColumnDidMove => "NSTableViewColumnDidMoveNotification",
+            NotificationName::NSTableViewColumnDidResize => "NSTableViewColumnDidResizeNotification",
+            NotificationName::NSTableViewSelectionDidChange => "NSTableViewSelectionDidChangeNotification",
+            NotificationName::NSTableViewSelectionIsChanging => "NSTableViewSelectionIsChangingNotification",
+            NotificationName::NSTextAlternativesSelectedAlternativeString => "NSTextAlternativesSelectedAlternativeStringNotification",
+            NotificationName::NSTextDidBeginEditing => "NSTextDidBeginEditingNotification",
+            NotificationName::NSTextDidChange => "NSTextDidChangeNotification",
+            NotificationName::NSTextDidEndEditing => "NSTextDidEndEditingNotification",
+            NotificationName::NSTextInputContextKeyboardSelectionDidChange => "NSTextInputContextKeyboardSelectionDidChangeNotification",
+            NotificationName::NSTextStorageDidProcessEditing => "NSTextStorageDidProcessEditingNotification",
+            NotificationName::NSTextStorageWillProcessEditing => "NSTextStorageWillProcessEditingNotification",
+            NotificationName::NSTextViewDidChangeSelection => "NSTextViewDidChangeSelectionNotification",
+            NotificationName::NSTextViewDidChangeTypingAttributes => "NSTextViewDidChangeTypingAttributesNotification",
+            NotificationName::NSTextViewWillChangeNotifyingTextView => "NSTextViewWillChangeNotifyingTextViewNotification",
+            NotificationName::NSToolbarDidRemoveItem => "NSToolbarDidRemoveItemNotification",
+            NotificationName::NSToolbarWillAddItem => "NSToolbarWillAddItemNotification",
+            NotificationName::NSViewBoundsDidChange => "NSViewBoundsDidChangeNotification",
+            NotificationName::NSViewDidUpdateTrackingAreas => "NSViewDidUpdateTrackingAreasNotification",
+            NotificationName::NSViewFocusDidChange => "NSViewFocusDidChangeNotification",
+            NotificationName::NSViewFrameDidChange => "NSViewFrameDidChangeNotification",
+            NotificationName::NSViewGlobalFrameDidChange => "NSViewGlobalFrameDidChangeNotification",
+            NotificationName::NSWindowDidBecomeKey => "NSWindowDidBecomeKeyNotification",
+            NotificationName::NSWindowDidBecomeMain => "NSWindowDidBecomeMainNotification",
+            NotificationName::NSWindowDidChangeBackingProperties => "NSWindowDidChangeBackingPropertiesNotification",
+            NotificationName::NSWindowDidChangeOcclusionState => "NSWindowDidChangeOcclusionStateNotification",
+            NotificationName::NSWindowDidChangeScreen => "NSWindowDidChangeScreenNotification",
+            NotificationName::NSWindowDidChangeScreenProfile => "NSWindowDidChangeScreenProfileNotification",
+            NotificationName::NSWindowDidDeminiaturize => "NSWindowDidDeminiaturizeNotification",
+            NotificationName::NSWindowDidEndLiveResize => "NSWindowDidEndLiveResizeNotification",
+            NotificationName::NSWindowDidEndSheet => "NSWindowDidEndSheetNotification",
+            NotificationName::NSWindowDidEnterFullScreen => "NSWindowDidEnterFullScreenNotification",
+            NotificationName::NSWindowDidEnterVersionBrowser => "NSWindowDidEnterVersionBrowserNotification",
+            NotificationName::NSWindowDidExitFullScreen => "NSWindowDidExitFullScreenNotification",
+            NotificationName::NSWindowDidExitVersionBrowser => "NSWindowDidExitVersionBrowserNotification",
+            NotificationName::NSWindowDidExpose => "NSWindowDidExposeNotification",
+            NotificationName::NSWindowDidMiniaturize => "NSWindowDidMiniaturizeNotification",
+            NotificationName::NSWindowDidMove => "NSWindowDidMoveNotification",
+            NotificationName::NSWindowDidResignKey => "NSWindowDidResignKeyNotification",
+            NotificationName::NSWindowDidResignMain => "NSWindowDidResignMainNotification",
+            NotificationName::NSWindowDidResize => "NSWindowDidResizeNotification",
+            NotificationName::NSWindowDidUpdate => "NSWindowDidUpdateNotification",
+            NotificationName::NSWindowWillBeginSheet => "NSWindowWillBeginSheetNotification",
+            NotificationName::NSWindowWillClose => "NSWindowWillCloseNotification",
+            NotificationName::NSWindowWillEnterFullScreen => "NSWindowWillEnterFullScreenNotification",
+            NotificationName::NSWindowWillEnterVersionBrowser => "NSWindowWillEnterVersionBrowserNotification",
+            NotificationName::NSWindowWillExitFullScreen => "NSWindowWillExitFullScreenNotification",
+            NotificationName::NSWindowWillExitVersionBrowser => "NSWindowWillExitVersionBrowserNotification",
+            NotificationName::NSWindowWillMiniaturize => "NSWindowWillMiniaturizeNotification",
+            NotificationName::NSWindowWillMove => "NSWindowWillMoveNotification",
+            NotificationName::NSWindowWillStartLiveResize => "NSWindowWillStartLiveResizeNotification",
+            NotificationName::NSWorkspaceAccessibilityDisplayOptionsDidChange => "NSWorkspaceAccessibilityDisplayOptionsDidChangeNotification",
+            NotificationName::NSWorkspaceActiveSpaceDidChange => "NSWorkspaceActiveSpaceDidChangeNotification",
+            NotificationName::NSWorkspaceDidActivateApplication => "NSWorkspaceDidActivateApplicationNotification",
+            NotificationName::NSWorkspaceDidChangeFileLabels => "NSWorkspaceDidChangeFileLabelsNotification",
+            NotificationName::NSWorkspaceDidDeactivateApplication => "NSWorkspaceDidDeactivateApplicationNotification",
+            NotificationName::NSWorkspaceDidHideApplication => "NSWorkspaceDidHideApplicationNotification",
+            NotificationName::NSWorkspaceDidLaunchApplication => "NSWorkspaceDidLaunchApplicationNotification",
+            NotificationName::NSWorkspaceDidMount => "NSWorkspaceDidMountNotification",
+            NotificationName::NSWorkspaceDidPerformFileOperation => "NSWorkspaceDidPerformFileOperationNotification",
+            NotificationName::NSWorkspaceDidRenameVolume => "NSWorkspaceDidRenameVolumeNotification",
+            NotificationName::NSWorkspaceDidTerminateApplication => "NSWorkspaceDidTerminateApplicationNotification",
+            NotificationName::NSWorkspaceDidUnhideApplication => "NSWorkspaceDidUnhideApplicationNotification",
+            NotificationName::NSWorkspaceDidUnmount => "NSWorkspaceDidUnmountNotification",
+            NotificationName::NSWorkspaceDidWake => "NSWorkspaceDidWakeNotification",
+            NotificationName::NSWorkspaceScreensDidSleep => "NSWorkspaceScreensDidSleepNotification",
+            NotificationName::NSWorkspaceScreensDidWake => "NSWorkspaceScreensDidWakeNotification",
+            NotificationName::NSWorkspaceSessionDidBecomeActive => "NSWorkspaceSessionDidBecomeActiveNotification",
+            NotificationName::NSWorkspaceSessionDidResignActive => "NSWorkspaceSessionDidResignActiveNotification",
+            NotificationName::NSWorkspaceWillLaunchApplication => "NSWorkspaceWillLaunchApplicationNotification",
+            NotificationName::NSWorkspaceWillPowerOff => "NSWorkspaceWillPowerOffNotification",
+            NotificationName::NSWorkspaceWillSleep => "NSWorkspaceWillSleepNotification",
+            NotificationName::NSWorkspaceWillUnmount => "NSWorkspaceWillUnmountNotification",
+            NotificationName::PDFDocumentDidBeginFind => "PDFDocumentDidBeginFindNotification",
+            NotificationName::PDFDocumentDidBeginPageFind => "PDFDocumentDidBeginPageFindNotification",
+            NotificationName::PDFDocumentDidBeginPageWrite => "PDFDocumentDidBeginPageWriteNotification",
+            NotificationName::PDFDocumentDidBeginWrite => "PDFDocumentDidBeginWriteNotification",
+            NotificationName::PDFDocumentDidEndFind => "PDFDocumentDidEndFindNotification",
+            NotificationName::PDFDocumentDidEndPageFind => "PDFDocumentDidEndPageFindNotification",
+            NotificationName::PDFDocumentDidEndPageWrite => "PDFDocumentDidEndPageWriteNotification",
+            NotificationName::PDFDocumentDidEndWrite => "PDFDocumentDidEndWriteNotification",
+            NotificationName::PDFDocumentDidFindMatch => "PDFDocumentDidFindMatchNotification",
+            NotificationName::PDFDocumentDidUnlock => "PDFDocumentDidUnlockNotification",
+            NotificationName::PDFThumbnailViewDocumentEdited => "PDFThumbnailViewDocumentEditedNotification",
+            NotificationName::PDFViewAnnotationHit => "PDFViewAnnotationHitNotification",
+            NotificationName::PDFViewAnnotationWillHit => "PDFViewAnnotationWillHitNotification",
+            NotificationName::PDFViewChangedHistory => "PDFViewChangedHistoryNotification",
+            NotificationName::PDFViewCopyPermission => "PDFViewCopyPermissionNotification",
+            NotificationName::PDFViewDisplayBoxChanged => "PDFViewDisplayBoxChangedNotification",
+            NotificationName::PDFViewDisplayModeChanged => "PDFViewDisplayModeChangedNotification",
+            NotificationName::PDFViewDocumentChanged => "PDFViewDocumentChangedNotification",
+            NotificationName::PDFViewPageChanged => "PDFViewPageChangedNotification",
+            NotificationName::PDFViewPrintPermission => "PDFViewPrintPermissionNotification",
+            NotificationName::PDFViewScaleChanged => "PDFViewScaleChangedNotification",
+            NotificationName::PDFViewSelectionChanged => "PDFViewSelectionChangedNotification",
+            NotificationName::PDFViewVisiblePagesChanged => "PDFViewVisiblePagesChangedNotification",
+            NotificationName::KABDatabaseChanged => "ABDatabaseChangedNotification",
+            NotificationName::KABDatabaseChangedExternally => "ABDatabaseChangedExternallyNotification",
+            NotificationName::KQuartzFilterManagerDidAddFilter => "kQuartzFilterManagerDidAddFilterNotification",
+            NotificationName::KQuartzFilterManagerDidModifyFilter => "kQuartzFilterManagerDidModifyFilterNotification",
+            NotificationName::KQuartzFilterManagerDidRemoveFilter => "kQuartzFilterManagerDidRemoveFilterNotification",
+            NotificationName::KQuartzFilterManagerDidSelectFilter => "kQuartzFilterManagerDidSelectFilterNotification",
+            NotificationName::EAAccessoryDidConnect => "EAAccessoryDidConnectNotification",
+            NotificationName::EAAccessoryDidDisconnect => "EAAccessoryDidDisconnectNotification",
+            NotificationName::SKCloudServiceCapabilitiesDidChange => "SKCloudServiceCapabilitiesDidChangeNotification",
+            NotificationName::SKStorefrontIdentifierDidChange => "SKStorefrontIdentifierDidChangeNotification",
+            NotificationName::UIAccessibilityAssistiveTouchStatusDidChange => "UIAccessibilityAssistiveTouchStatusDidChangeNotification",
+            NotificationName::UIAccessibilityBoldTextStatusDidChange => "UIAccessibilityBoldTextStatusDidChangeNotification",
+            NotificationName::UIAccessibilityClosedCaptioningStatusDidChange => "UIAccessibilityClosedCaptioningStatusDidChangeNotification",
+            NotificationName::UIAccessibilityDarkerSystemColorsStatusDidChange => "UIAccessibilityDarkerSystemColorsStatusDidChangeNotification",
+            NotificationName::UIAccessibilityGrayscaleStatusDidChange => "UIAccessibilityGrayscaleStatusDidChangeNotification",
+            NotificationName::UIAccessibilityGuidedAccessStatusDidChange => "UIAccessibilityGuidedAccessStatusDidChangeNotification",
+            NotificationName::UIAccessibilityHearingDevicePairedEarDidChange => "UIAccessibilityHearingDevicePairedEarDidChangeNotification",
+            NotificationName::UIAccessibilityInvertColorsStatusDidChange => "UIAccessibilityInvertColorsStatusDidChangeNotification",
+            NotificationName::UIAccessibilityMonoAudioStatusDidChange => "UIAccessibilityMonoAudioStatusDidChangeNotification",
+            NotificationName::UIAccessibilityReduceMotionStatusDidChange => "UIAccessibilityReduceMotionStatusDidChangeNotification",
+            NotificationName::UIAccessibilityReduceTransparencyStatusDidChange => "UIAccessibilityReduceTransparencyStatusDidChangeNotification",
+            NotificationName::UIAccessibilityShakeToUndoDidChange => "UIAccessibilityShakeToUndoDidChangeNotification",
+            NotificationName::UIAccessibilitySpeakScreenStatusDidChange => "UIAccessibilitySpeakScreenStatusDidChangeNotification",
+            NotificationName::UIAccessibilitySpeakSelectionStatusDidChange => "UIAccessibilitySpeakSelectionStatusDidChangeNotification",
+            NotificationName::UIAccessibilitySwitchControlStatusDidChange => "UIAccessibilitySwitchControlStatusDidChangeNotification",
+            NotificationName::UIApplicationDidBecomeActive => "UIApplicationDidBecomeActiveNotification",
+            NotificationName::UIApplicationDidEnterBackground => "UIApplicationDidEnterBackgroundNotification",
+            NotificationName::UIApplicationDidFinishLaunching => "UIApplicationDidFinishLaunchingNotification",
+            NotificationName::UIApplicationDidReceiveMemoryWarning => "UIApplicationDidReceiveMemoryWarningNotification",
+            NotificationName::UIApplicationSignificantTimeChange => "UIApplicationSignificantTimeChangeNotification",
+            NotificationName::UIApplicationUserDidTakeScreenshot => "UIApplicationUserDidTakeScreenshotNotification",
+            NotificationName::UIApplicationWillEnterForeground => "UIApplicationWillEnterForegroundNotification",
+            NotificationName::UIApplicationWillResignActive => "UIApplicationWillResignActiveNotification",
+            NotificationName::UIApplicationWillTerminate => "UIApplicationWillTerminateNotification",
+            NotificationName::UIContentSizeCategoryDidChange => "UIContentSizeCategoryDidChangeNotification",
+            NotificationName::UIDeviceProximityStateDidChange => "UIDeviceProximityStateDidChangeNotification",
+            NotificationName::UIScreenBrightnessDidChange => "UIScreenBrightnessDidChangeNotification",
+            NotificationName::UIScreenDidConnect => "UIScreenDidConnectNotification",
+            NotificationName::UIScreenDidDisconnect => "UIScreenDidDisconnectNotification",
+            NotificationName::UIScreenModeDidChange => "UIScreenModeDidChangeNotification",
+            NotificationName::UITableViewSelectionDidChange => "UITableViewSelectionDidChangeNotification",
+            NotificationName::UITextFieldTextDidBeginEditing => "UITextFieldTextDidBeginEditingNotification",
+            NotificationName::UITextFieldTextDidChange => "UITextFieldTextDidChangeNotification",
+            NotificationName::UITextFieldTextDidEndEditing => "UITextFieldTextDidEndEditingNotification",
+            NotificationName::UITextInputCurrentInputModeDidChange => "UITextInputCurrentInputModeDidChangeNotification",
+            NotificationName::UITextViewTextDidBeginEditing => "UITextViewTextDidBeginEditingNotification",
+            NotificationName::UITextViewTextDidChange => "UITextViewTextDidChangeNotification",
+            NotificationName::UITextViewTextDidEndEditing => "UITextViewTextDidEndEditingNotification",
+            NotificationName::UIViewControllerShowDetailTargetDidChange => "UIViewControllerShowDetailTargetDidChangeNotification",
+            NotificationName::UIWindowDidBecomeHidden => "UIWindowDidBecomeHiddenNotification",
+            NotificationName::UIWindowDidBecomeKey => "UIWindowDidBecomeKeyNotification",
+            NotificationName::UIWindowDidBecomeVisible => "UIWindowDidBecomeVisibleNotification",
+            NotificationName::UIWindowDidResignKey => "UIWindowDidResignKeyNotification",
+            NotificationName::AVCaptureDeviceSubjectAreaDidChange => "AVCaptureDeviceSubjectAreaDidChangeNotification",
+            NotificationName::AVCaptureSessionInterruptionEnded => "AVCaptureSessionInterruptionEndedNotification",
+            NotificationName::AVCaptureSessionWasInterrupted => "AVCaptureSessionWasInterruptedNotification",
+            NotificationName::MFMessageComposeViewControllerTextMessageAvailabilityDidChange => "MFMessageComposeViewControllerTextMessageAvailabilityDidChangeNotification",
+            NotificationName::MPMediaLibraryDidChange => "MPMediaLibraryDidChangeNotification",
+            NotificationName::MPMusicPlayerControllerNowPlayingItemDidChange => "MPMusicPlayerControllerNowPlayingItemDidChangeNotification",
+            NotificationName::MPMusicPlayerControllerPlaybackStateDidChange => "MPMusicPlayerControllerPlaybackStateDidChangeNotification",
+            NotificationName::MPMusicPlayerControllerVolumeDidChange => "MPMusicPlayerControllerVolumeDidChangeNotification",
+            NotificationName::UIApplicationBackgroundRefreshStatusDidChange => "UIApplicationBackgroundRefreshStatusDidChangeNotification",
+            NotificationName::UIDeviceBatteryLevelDidChange => "UIDeviceBatteryLevelDidChangeNotification",
+            NotificationName::UIDeviceBatteryStateDidChange => "UIDeviceBatteryStateDidChangeNotification",
+            NotificationName::UIDeviceOrientationDidChange => "UIDeviceOrientationDidChangeNotification",
+            NotificationName::UIDocumentStateChanged => "UIDocumentStateChangedNotification",
+            NotificationName::UIKeyboardDidChangeFrame => "UIKeyboardDidChangeFrameNotification",
+            NotificationName::UIKeyboardDidHide => "UIKeyboardDidHideNotification",
+            NotificationName::UIKeyboardDidShow => "UIKeyboardDidShowNotification",
+            NotificationName::UIKeyboardWillChangeFrame => "UIKeyboardWillChangeFrameNotification",
+            NotificationName::UIKeyboardWillHide => "UIKeyboardWillHideNotification",
+            NotificationName::UIKeyboardWillShow => "UIKeyboardWillShowNotification",
+            NotificationName::UIMenuControllerDidHideMenu => "UIMenuControllerDidHideMenuNotification",
+            NotificationName::UIMenuControllerDidShowMenu => "UIMenuControllerDidShowMenuNotification",
+            NotificationName::UIMenuControllerMenuFrameDidChange => "UIMenuControllerMenuFrameDidChangeNotification",
+            NotificationName::UIMenuControllerWillHideMenu => "UIMenuControllerWillHideMenuNotification",
+            NotificationName::UIMenuControllerWillShowMenu => "UIMenuControllerWillShowMenuNotification",
+            NotificationName::UIPasteboardChanged => "UIPasteboardChangedNotification",
+            NotificationName::UIPasteboardRemoved => "UIPasteboardRemovedNotification",
+            NotificationName::UIApplicationProtectedDataDidBecomeAvailable => "UIApplicationProtectedDataDidBecomeAvailableNotification",
+            NotificationName::UIApplicationProtectedDataWillBecomeUnavailable => "UIApplicationProtectedDataWillBecomeUnavailableNotification",
+            NotificationName::NSSpellCheckerDidChangeAutomaticTextCompletion => "NSSpellCheckerDidChangeAutomaticTextCompletionNotification",
+            NotificationName::MPMusicPlayerControllerQueueDidChange => "MPMusicPlayerControllerQueueDidChangeNotification",
+            NotificationName::AVDisplayManagerModeSwitchEnd => "AVDisplayManagerModeSwitchEndNotification",
+            NotificationName::AVDisplayManagerModeSwitchSettingsChanged => "AVDisplayManagerModeSwitchSettingsChangedNotification",
+            NotificationName::AVDisplayManagerModeSwitchStart => "AVDisplayManagerModeSwitchStartNotification",
+            NotificationName::AVPlayerAvailableHDRModesDidChange => "AVPlayerAvailableHDRModesDidChangeNotification",
+            NotificationName::AVRouteDetectorMultipleRoutesDetectedDidChange => "AVRouteDetectorMultipleRoutesDetectedDidChangeNotification",
+            NotificationName::AVSampleBufferAudioRendererWasFlushedAutomatically => "AVSampleBufferAudioRendererWasFlushedAutomaticallyNotification",
+            NotificationName::CTServiceRadioAccessTechnologyDidChange => "CTServiceRadioAccessTechnologyDidChangeNotification",
+            NotificationName::GKPlayerAuthenticationDidChangeNotificationName => "GKPlayerAuthenticationDidChangeNotificationName",
+            NotificationName::GKPlayerDidChangeNotificationName => "GKPlayerDidChangeNotificationName",
+            NotificationName::NEDNSProxyConfigurationDidChange => "NEDNSProxyConfigurationDidChangeNotification",
+            NotificationName::NSPersistentStoreRemoteChange => "NSPersistentStoreRemoteChangeNotification",
+            NotificationName::SKStorefrontCountryCodeDidChange => "SKStorefrontCountryCodeDidChangeNotification",
+            NotificationName::WKAccessibilityReduceMotionStatusDidChange => "WKAccessibilityReduceMotionStatusDidChangeNotification",
+            NotificationName::Custom(name) => *name
+        }
+    }
+}
+
+impl From<&'static str> for NotificationName {
+    /// Maps a notification string constant back to the corresponding `NotificationName`. If the
+    /// name isn't one that we know about, you'll get a `NotificationName::Custom` back.
+    fn from(name: &'static str) -> Self {
+        match name {
+            "AVAudioEngineConfigurationChangeNotification" => NotificationName::AudioEngineConfigurationChange,
+            "AVAudioSessionInterruptionNotification" => NotificationName::AudioSessionInterruption,
+            "AVAudioSessionMediaServicesWereLostNotification" => NotificationName::AudioSessionMediaServicesWereLost,
+            "AVAudioSessionMediaServicesWereResetNotification" => NotificationName::AudioSessionMediaServicesWereReset,
+            "AVAudioSessionRouteChangeNotification" => NotificationName::AudioSessionRouteChange,
+            "AVAudioSessionSilenceSecondaryAudioHintNotification" => NotificationName::AudioSessionSilenceSecondaryAudioHint,
+            "AVAudioUnitComponentTagsDidChangeNotification" => NotificationName::AudioUnitComponentTagsDidChange,
+            "CKAccountChangedNotification" => NotificationName::CloudKitAccountChanged,
+            "CLKComplicationServerActiveComplicationsDidChangeNotification" => NotificationName::CLKComplicationServerActiveComplicationsDidChange,
+            "CNContactStoreDidChangeNotification" => NotificationName::CNContactStoreDidChange,
+            "EKEventStoreChangedNotification" => NotificationName::EKEventStoreChanged,
+            "HKUserPreferencesDidChangeNotification" => NotificationName::HKUserPreferencesDidChange,
+            "HMCharacteristicPropertySupportsEventNotification" => NotificationName::HMCharacteristicPropertySupportsEvent,
+            "NSBundleResourceRequestLowDiskSpaceNotification" => NotificationName::NSBundleResourceRequestLowDiskSpace,
+            "NSCalendarDayChangedNotification" => NotificationName::NSCalendarDayChanged,
+            "NSExtensionHostDidBecomeActiveNotification" => NotificationName::NSExtensionHostDidBecomeActive,
+            "NSExtensionHostDidEnterBackgroundNotification" => NotificationName::NSExtensionHostDidEnterBackground,
+            "NSExtensionHostWillEnterForegroundNotification" => NotificationName::NSExtensionHostWillEnterForeground,
+            "NSExtensionHostWillResignActiveNotification" => NotificationName::NSExtensionHostWillResignActive,
+            "NSFileHandleConnectionAcceptedNotification" => NotificationName::NSFileHandleConnectionAccepted,
+            "NSFileHandleDataAvailableNotification" => NotificationName::NSFileHandleDataAvailable,
+            "NSFileHandleReadToEndOfFileCompletionNotification" => NotificationName::NSFileHandleReadToEndOfFileCompletion,
+            "NSHTTPCookieManagerAcceptPolicyChangedNotification" => NotificationName::NSHTTPCookieManagerAcceptPolicyChanged,
+            "NSHTTPCookieManagerCookiesChangedNotification" => NotificationName::NSHTTPCookieManagerCookiesChanged,
+            "NSManagingContextDidSaveChangesNotification" => NotificationName::NSManagedObjectContextDidSave,
+            "NSObjectsChangedInManagingContextNotification" => NotificationName::NSManagedObjectContextObjectsDidChange,
+            "NSManagingContextWillSaveChangesNotification" => NotificationName::NSManagedObjectContextWillSave,
+            "NSMetadataQueryDidFinishGatheringNotification" => NotificationName::NSMetadataQueryDidFinishGathering,
+            "NSMetadataQueryDidStartGatheringNotification" => NotificationName::NSMetadataQueryDidStartGathering,
+            "NSMetadataQueryDidUpdateNotification" => NotificationName::NSMetadataQueryDidUpdate,
+            "NSMetadataQueryGatheringProgressNotification" => NotificationName::NSMetadataQueryGatheringProgress,
+            "NSPersistentStoreCoordinatorStoresDidChangeNotification" => NotificationName::NSPersistentStoreCoordinatorStoresDidChange,
+            "NSPersistentStoreCoordinatorStoresWillChangeNotification" => NotificationName::NSPersistentStoreCoordinatorStoresWillChange,
+            "NSPersistentStoreCoordinatorWillRemoveStoreNotification" => NotificationName::NSPersistentStoreCoordinatorWillRemoveStore,
+            "NSProcessInfoPowerStateDidChangeNotification" => NotificationName::NSProcessInfoPowerStateDidChange,
+            "NSSystemClockDidChangeNotification" => NotificationName::NSSystemClockDidChange,
+            "NSSystemTimeZoneDidChangeNotification" => NotificationName::NSSystemTimeZoneDidChange,
+            "NSURLCredentialStorageChangedNotification" => NotificationName::NSURLCredentialStorageChanged,
+            "NSUbiquityIdentityDidChangeNotification" => NotificationName::NSUbiquityIdentityDidChange,
+            "NSUndoManagerCheckpointNotification" => NotificationName::NSUndoManagerCheckpoint,
+            "NSUndoManagerDidCloseUndoGroupNotification" => NotificationName::NSUndoManagerDidCloseUndoGroup,
+            "NSUndoManagerDidOpenUndoGroupNotification" => NotificationName::NSUndoManagerDidOpenUndoGroup,
+            "NSUndoManagerDidRedoChangeNotification" => NotificationName::NSUndoManagerDidRedoChange,
+            "NSUndoManagerDidUndoChangeNotification" => NotificationName::NSUndoManagerDidUndoChange,
+            "NSUndoManagerWillCloseUndoGroupNotification" => NotificationName::NSUndoManagerWillCloseUndoGroup,
+            "NSUndoManagerWillRedoChangeNotification" => NotificationName::NSUndoManagerWillRedoChange,
+            "NSUndoManagerWillUndoChangeNotification" => NotificationName::NSUndoManagerWillUndoChange,
+            "PKPassLibraryDidChangeNotification" => NotificationName::PKPassLibraryDidChange,
+            "PKPassLibraryRemotePaymentPassesDidChangeNotification" => NotificationName::PKPassLibraryRemotePaymentPassesDidChange,
+            "UIAccessibilityAnnouncementDidFinishNotification" => NotificationName::UIAccessibilityAnnouncementDidFinish,
+            "UIAccessibilityElementFocusedNotification" => NotificationName::UIAccessibilityElementFocused,
+            "WKAudioFilePlayerItemDidPlayToEndTimeNotification" => NotificationName::WKAudioFilePlayerItemDidPlayToEndTime,
+            "WKAudioFilePlayerItemFailedToPlayToEndTimeNotification" => NotificationName::WKAudioFilePlayerItemFailedToPlayToEndTime,
+            "WKAudioFilePlayerItemTimeJumpedNotification" => NotificationName::WKAudioFilePlayerItemTimeJumped,
+            "ABPeoplePickerDisplayedPropertyDidChangeNotification" => NotificationName::ABPeoplePickerDisplayedPropertyDidChange,
+            "ABPeoplePickerGroupSelectionDidChangeNotification" => NotificationName::ABPeoplePickerGroupSelectionDidChange,
+            "ABPeoplePickerNameSelectionDidChangeNotification" => NotificationName::ABPeoplePickerNameSelectionDidChange,
+            "ABPeoplePickerValueSelectionDidChangeNotification" => NotificationName::ABPeoplePickerValueSelectionDidChange,
+            "ACAccountStoreDidChangeNotification" => NotificationName::ACAccountStoreDidChange,
+            "AVAssetChapterMetadataGroupsDidChangeNotification" => NotificationName::AVAssetChapterMetadataGroupsDidChange,
+            "AVAssetContainsFragmentsDidChangeNotification" => NotificationName::AVAssetContainsFragmentsDidChange,
+            "AVAssetDurationDidChangeNotification" => NotificationName::AVAssetDurationDidChange,
+            "AVAssetMediaSelectionGroupsDidChangeNotification" => NotificationName::AVAssetMediaSelectionGroupsDidChange,
+            "AVAssetTrackSegmentsDidChangeNotification" => NotificationName::AVAssetTrackSegmentsDidChange,
+            "AVAssetTrackTimeRangeDidChangeNotification" => NotificationName::AVAssetTrackTimeRangeDidChange,
+            "AVAssetTrackTrackAssociationsDidChangeNotification" => NotificationName::AVAssetTrackTrackAssociationsDidChange,
+            "AVAssetWasDefragmentedNotification" => NotificationName::AVAssetWasDefragmented,
+            "AVCaptureDeviceWasConnectedNotification" => NotificationName::AVCaptureDeviceWasConnected,
+            "AVCaptureDeviceWasDisconnectedNotification" => NotificationName::AVCaptureDeviceWasDisconnected,
+            "AVCaptureInputPortFormatDescriptionDidChangeNotification" => NotificationName::AVCaptureInputPortFormatDescriptionDidChange,
+            "AVCaptureSessionDidStartRunningNotification" => NotificationName::AVCaptureSessionDidStartRunning,
+            "AVCaptureSessionDidStopRunningNotification" => NotificationName::AVCaptureSessionDidStopRunning,
+            "AVCaptureSessionRuntimeErrorNotification" => NotificationName::AVCaptureSessionRuntimeError,
+            "AVFragmentedMovieContainsMovieFragmentsDidChangeNotification" => NotificationName::AVFragmentedMovieContainsMovieFragmentsDidChange,
+            "AVFragmentedMovieDurationDidChangeNotification" => NotificationName::AVFragmentedMovieDurationDidChange,
+            "AVFragmentedMovieTrackSegmentsDidChangeNotification" => NotificationName::AVFragmentedMovieTrackSegmentsDidChange,
+            "AVFragmentedMovieTrackTimeRangeDidChangeNotification" => NotificationName::AVFragmentedMovieTrackTimeRangeDidChange,
+            "AVFragmentedMovieTrackTotalSampleDataLengthDidChangeNotification" => NotificationName::AVFragmentedMovieTrackTotalSampleDataLengthDidChange,
+            "AVFragmentedMovieWasDefragmentedNotification" => NotificationName::AVFragmentedMovieWasDefragmented,
+            "AVPlayerItemDidPlayToEndTimeNotification" => NotificationName::AVPlayerItemDidPlayToEndTime,
+            "AVPlayerItemFailedToPlayToEndTimeNotification" => NotificationName::AVPlayerItemFailedToPlayToEndTime,
+            "AVPlayerItemNewAccessLogEntryNotification" => NotificationName::AVPlayerItemNewAccessLogEntry,
+            "AVPlayerItemNewErrorLogEntryNotification" => NotificationName::AVPlayerItemNewErrorLogEntry,
+            "AVPlayerItemPlaybackStalledNotification" => NotificationName::AVPlayerItemPlaybackStalled,
+            "AVPlayerItemTimeJumpedNotification" => NotificationName::AVPlayerItemTimeJumped,
+            "AVSampleBufferDisplayLayerFailedToDecodeNotification" => NotificationName::AVSampleBufferDisplayLayerFailedToDecode,
+            "CWBSSIDDidChangeNotification" => NotificationName::CWBSSIDDidChange,
+            "CWCountryCodeDidChangeNotification" => NotificationName::CWCountryCodeDidChange,
+            "CWLinkDidChangeNotification" => NotificationName::CWLinkDidChange,
+            "CWLinkQualityDidChangeNotification" => NotificationName::CWLinkQualityDidChange,
+            "CWModeDidChangeNotification" => NotificationName::CWModeDidChange,
+            "CWPowerDidChangeNotification" => NotificationName::CWPowerDidChange,
+            "CWSSIDDidChangeNotification" => NotificationName::CWSSIDDidChange,
+            "CWScanCacheDidUpdateNotification" => NotificationName::CWScanCacheDidUpdate,
+            "GCControllerDidConnectNotification" => NotificationName::GCControllerDidConnect,
+            "GCControllerDidDisconnectNotification" => NotificationName::GCControllerDidDisconnect,
+            "IKFilterBrowserFilterDoubleClickNotification" => NotificationName::IKFilterBrowserFilterDoubleClick,
+            "IKFilterBrowserFilterSelectedNotification" => NotificationName::IKFilterBrowserFilterSelected,
+            "IKFilterBrowserWillPreviewFilterNotification" => NotificationName::IKFilterBrowserWillPreviewFilter,
+            "IOBluetoothHostControllerPoweredOffNotification" => NotificationName::IOBluetoothHostControllerPoweredOff,
+            "IOBluetoothHostControllerPoweredOnNotification" => NotificationName::IOBluetoothHostControllerPoweredOn,
+            "IOBluetoothL2CAPChannelPublishedNotification" => NotificationName::IOBluetoothL2CAPChannelPublished,
+            "IOBluetoothL2CAPChannelTerminatedNotification" => NotificationName::IOBluetoothL2CAPChannelTerminated,
+            "MKAnnotationCalloutInfoDidChangeNotification" => NotificationName::MKAnnotationCalloutInfoDidChange,
+            "NEFilterConfigurationDidChangeNotification" => NotificationName::NEFilterConfigurationDidChange,
+            "NEVPNConfigurationChangeNotification" => NotificationName::NEVPNConfigurationChange,
+            "NEVPNStatusDidChangeNotification" => NotificationName::NEVPNStatusDidChange,
+            "AXAnnouncementRequested" => NotificationName::NSAccessibilityAnnouncementRequested,
+            "AXAnnouncementKey" => NotificationName::NSAccessibilityAnnouncementKey,
+            "AXPriorityKey" => NotificationName::NSAccessibilityPriorityKey,
+            "AXApplicationActivated" => NotificationName::NSAccessibilityApplicationActivated,
+            "AXApplicationDeactivated" => NotificationName::NSAccessibilityApplicationDeactivated,
+            "AXApplicationHidden" => NotificationName::NSAccessibilityApplicationHidden,
+            "AXApplicationShown" => NotificationName::NSAccessibilityApplicationShown,
+            "AXCreated" => NotificationName::NSAccessibilityCreated,
+            "AXDrawerCreated" => NotificationName::NSAccessibilityDrawerCreated,
+            "AXFocusedUIElementChanged" => NotificationName::NSAccessibilityFocusedUIElementChanged,
+            "AXFocusedWindowChanged" => NotificationName::NSAccessibilityFocusedWindowChanged,
+            "AXHelpTagCreated" => NotificationName::NSAccessibilityHelpTagCreated,
+            "AXLayoutChanged" => NotificationName::NSAccessibilityLayoutChanged,
+            "AXUIElementsKey" => NotificationName::NSAccessibilityUIElementsKey,
+            "AXMainWindowChanged" => NotificationName::NSAccessibilityMainWindowChanged,
+            "AXMoved" => NotificationName::NSAccessibilityMoved,
+            "AXResized" => NotificationName::NSAccessibilityResized,
+            "AXRowCollapsed" => NotificationName::NSAccessibilityRowCollapsed,
+            "AXRowCountChanged" => NotificationName::NSAccessibilityRowCountChanged,
+            "AXRowExpanded" => NotificationName::NSAccessibilityRowExpanded,
+            "AXSelectedCellsChanged" => NotificationName::NSAccessibilitySelectedCellsChanged,
+            "AXSelectedChildrenChanged" => NotificationName::NSAccessibilitySelectedChildrenChanged,
+            "AXSelectedChildrenMoved" => NotificationName::NSAccessibilitySelectedChildrenMoved,
+            "AXSelectedColumnsChanged" => NotificationName::NSAccessibilitySelectedColumnsChanged,
+            "AXSelectedRowsChanged" => NotificationName::NSAccessibilitySelectedRowsChanged,
+            "AXSelectedTextChanged" => NotificationName::NSAccessibilitySelectedTextChanged,
+            "AXSheetCreated" => NotificationName::NSAccessibilitySheetCreated,
+            "AXTitleChanged" => NotificationName::NSAccessibilityTitleChanged,
+            "AXUIElementDestroyed" => NotificationName::NSAccessibilityUIElementDestroyed,
+            "AXUnitsChanged" => NotificationName::NSAccessibilityUnitsChanged,
+            "AXValueChanged" => NotificationName::NSAccessibilityValueChanged,
+            "AXWindowCreated" => NotificationName::NSAccessibilityWindowCreated,
+            "AXWindowDeminiaturized" => NotificationName::NSAccessibilityWindowDeminiaturized,
+            "AXWindowMiniaturized" => NotificationName::NSAccessibilityWindowMiniaturized,
+            "AXWindowMoved" => NotificationName::NSAccessibilityWindowMoved,
+            "AXWindowResized" => NotificationName::NSAccessibilityWindowResized,
+            "NSAnimationProgressMarkNotification" => NotificationName::NSAnimationProgressMark,
+            "NSAntialiasThresholdChangedNotification" => NotificationName::NSAntialiasThresholdChanged,
+            "NSAppleEventManagerWillProcessFirstEventNotification" => NotificationName::NSAppleEventManagerWillProcessFirstEvent,
+            "NSApplicationDidBecomeActiveNotification" => NotificationName::NSApplicationDidBecomeActive,
+            "NSApplicationDidChangeOcclusionStateNotification" => NotificationName::NSApplicationDidChangeOcclusionState,
+            "NSApplicationDidChangeScreenParametersNotification" => NotificationName::NSApplicationDidChangeScreenParameters,
+            "NSApplicationDidFinishLaunchingNotification" => NotificationName::NSApplicationDidFinishLaunching,
+            "NSApplicationDidFinishRestoringWindowsNotification" => NotificationName::NSApplicationDidFinishRestoringWindows,
+            "NSApplicationDidHideNotification" => NotificationName::NSApplicationDidHide,
+            "NSApplicationDidResignActiveNotification" => NotificationName::NSApplicationDidResignActive,
+            "NSApplicationDidUnhideNotification" => NotificationName::NSApplicationDidUnhide,
+            "NSApplicationDidUpdateNotification" => NotificationName::NSApplicationDidUpdate,
+            "NSApplicationWillBecomeActiveNotification" => NotificationName::NSApplicationWillBecomeActive,
+            "NSApplicationWillFinishLaunchingNotification" => NotificationName::NSApplicationWillFinishLaunching,
+            "NSApplicationWillHideNotification" => NotificationName::NSApplicationWillHide,
+            "NSApplicationWillResignActiveNotification" => NotificationName::NSApplicationWillResignActive,
+            "NSApplicationWillTerminateNotification" => NotificationName::NSApplicationWillTerminate,
+            "NSApplicationWillUnhideNotification" => NotificationName::NSApplicationWillUnhide,
+            "NSApplicationWillUpdateNotification" => NotificationName::NSApplicationWillUpdate,
+            "NSBrowserColumnConfigurationDidChangeNotification" => NotificationName::NSBrowserColumnConfigurationDidChange,
+            "NSClassDescriptionNeededForClassNotification" => NotificationName::NSClassDescriptionNeededForClass,
+            "NSColorListDidChangeNotification" => NotificationName::NSColorListDidChange,
+            "NSColorPanelColorDidChangeNotification" => NotificationName::NSColorPanelColorDidChange,
+            "NSColorPanelNotification" => NotificationName::NSColorPanel,
+            "NSComboBoxSelectionDidChangeNotification" => NotificationName::NSComboBoxSelectionDidChange,
+            "NSComboBoxSelectionIsChangingNotification" => NotificationName::NSComboBoxSelectionIsChanging,
+            "NSComboBoxWillDismissNotification" => NotificationName::NSComboBoxWillDismiss,
+            "NSComboBoxWillPopUpNotification" => NotificationName::NSComboBoxWillPopUp,
+            "NSContextHelpModeDidActivateNotification" => NotificationName::NSContextHelpModeDidActivate,
+            "NSContextHelpModeDidDeactivateNotification" => NotificationName::NSContextHelpModeDidDeactivate,
+            "NSControlTextDidBeginEditingNotification" => NotificationName::NSControlTextDidBeginEditing,
+            "NSControlTextDidChangeNotification" => NotificationName::NSControlTextDidChange,
+            "NSControlTextDidEndEditingNotification" => NotificationName::NSControlTextDidEndEditing,
+            "NSControlTintDidChangeNotification" => NotificationName::NSControlTintDidChange,
+            "NSDrawerDidCloseNotification" => NotificationName::NSDrawerDidClose,
+            "NSDrawerDidOpenNotification" => NotificationName::NSDrawerDidOpen,
+            "NSDrawerWillCloseNotification" => NotificationName::NSDrawerWillClose,
+            "NSDrawerWillOpenNotification" => NotificationName::NSDrawerWillOpen,
+            "NSFontCollectionDidChangeNotification" => NotificationName::NSFontCollectionDidChange,
+            "NSFontSetChangedNotification" => NotificationName::NSFontSetChanged,
+            "NSImageRepRegistryDidChangeNotification" => NotificationName::NSImageRepRegistryDidChange,
+            "NSMenuDidAddItemNotification" => NotificationName::NSMenuDidAddItem,
+            "NSMenuDidBeginTrackingNotification" => NotificationName::NSMenuDidBeginTracking,
+            "NSMenuDidChangeItemNotification" => NotificationName::NSMenuDidChangeItem,
+            "NSMenuDidEndTrackingNotification" => NotificationName::NSMenuDidEndTracking,
+            "NSMenuDidRemoveItemNotification" => NotificationName::NSMenuDidRemoveItem,
+            "NSMenuDidSendActionNotification" => NotificationName::NSMenuDidSendAction,
+            "NSMenuWillSendActionNotification" => NotificationName::NSMenuWillSendAction,
+            "NSOutlineViewColumnDidMoveNotification" => NotificationName::NSOutlineViewColumnDidMove,
+            "NSOutlineViewColumnDidResizeNotification" => NotificationName::NSOutlineViewColumnDidResize,
+            "NSOutlineViewItemDidCollapseNotification" => NotificationName::NSOutlineViewItemDidCollapse,
+            "NSOutlineViewItemDidExpandNotification" => NotificationName::NSOutlineViewItemDidExpand,
+            "NSOutlineViewItemWillCollapseNotification" => NotificationName::NSOutlineViewItemWillCollapse,
+            "NSOutlineViewItemWillExpandNotification" => NotificationName::NSOutlineViewItemWillExpand,
+            "NSOutlineViewSelectionDidChangeNotification" => NotificationName::NSOutlineViewSelectionDidChange,
+            "NSOutlineViewSelectionIsChangingNotification" => NotificationName::NSOutlineViewSelectionIsChanging,
+            "NSPersistentStoreDidImportUbiquitousContentChangesNotification" => NotificationName::NSPersistentStoreDidImportUbiquitousContentChanges,
+            "NSPopUpButtonCellWillPopUpNotification" => NotificationName::NSPopUpButtonCellWillPopUp,
+            "NSPopUpButtonWillPopUpNotification" => NotificationName::NSPopUpButtonWillPopUp,
+            "NSPopoverDidCloseNotification" => NotificationName::NSPopoverDidClose,
+            "NSPopoverDidShowNotification" => NotificationName::NSPopoverDidShow,
+            "NSPopoverWillCloseNotification" => NotificationName::NSPopoverWillClose,
+            "NSPopoverWillShowNotification" => NotificationName::NSPopoverWillShow,
+            "NSPreferencePaneCancelUnselectNotification" => NotificationName::NSPreferencePaneCancelUnselect,
+            "NSPreferencePaneDoUnselectNotification" => NotificationName::NSPreferencePaneDoUnselect,
+            "NSPreferencePaneSwitchToPaneNotification" => NotificationName::NSPreferencePaneSwitchToPane,
+            "NSPreferencePaneUpdateHelpMenuNotification" => NotificationName::NSPreferencePaneUpdateHelpMenu,
+            "NSPreferencePrefPaneIsAvailableNotification" => NotificationName::NSPreferencePrefPaneIsAvailable,
+            "NSPreferredScrollerStyleDidChangeNotification" => NotificationName::NSPreferredScrollerStyleDidChange,
+            "NSRuleEditorRowsDidChangeNotification" => NotificationName::NSRuleEditorRowsDidChange,
+            "NSScreenColorSpaceDidChangeNotification" => NotificationName::NSScreenColorSpaceDidChange,
+            "NSScrollViewDidEndLiveMagnifyNotification" => NotificationName::NSScrollViewDidEndLiveMagnify,
+            "NSScrollViewDidEndLiveScrollNotification" => NotificationName::NSScrollViewDidEndLiveScroll,
+            "NSScrollViewDidLiveScrollNotification" => NotificationName::NSScrollViewDidLiveScroll,
+            "NSScrollViewWillStartLiveMagnifyNotification" => NotificationName::NSScrollViewWillStartLiveMagnify,
+            "NSScrollViewWillStartLiveScrollNotification" => NotificationName::NSScrollViewWillStartLiveScroll,
+            "NSSpellCheckerDidChangeAutomaticCapitalizationNotification" => NotificationName::NSSpellCheckerDidChangeAutomaticCapitalization,
+            "NSSpellCheckerDidChangeAutomaticDashSubstitutionNotification" => NotificationName::NSSpellCheckerDidChangeAutomaticDashSubstitution,
+            "NSSpellCheckerDidChangeAutomaticPeriodSubstitutionNotification" => NotificationName::NSSpellCheckerDidChangeAutomaticPeriodSubstitution,
+            "NSSpellCheckerDidChangeAutomaticQuoteSubstitutionNotification" => NotificationName::NSSpellCheckerDidChangeAutomaticQuoteSubstitution,
+            "NSSpellCheckerDidChangeAutomaticSpellingCorrectionNotification" => NotificationName::NSSpellCheckerDidChangeAutomaticSpellingCorrection,
+            "NSSpellCheckerDidChangeAutomaticTextReplacementNotification" => NotificationName::NSSpellCheckerDidChangeAutomaticTextReplacement,
+            "NSSplitViewDidResizeSubviewsNotification" => NotificationName::NSSplitViewDidResizeSubviews,
+            "NSSplitViewWillResizeSubviewsNotification" => NotificationName::NSSplitViewWillResizeSubviews,
+            "NSSystemColorsDidChangeNotification" => NotificationName::NSSystemColorsDidChange,
+            "NSTableViewColumnDidMoveNotification" => NotificationName::NSTableViewColumnDidMove,
+            "NSTableViewColumnDidResizeNotification" => NotificationName::NSTableViewColumnDidResize,
+            "NSTableViewSelectionDidChangeNotification" => NotificationName::NSTableViewSelectionDidChange,
+            "NSTableViewSelectionIsChangingNotification" => NotificationName::NSTableViewSelectionIsChanging,
+            "NSTextAlternativesSelectedAlternativeStringNotification" => NotificationName::NSTextAlternativesSelectedAlternativeString,
+            "NSTextDidBeginEditingNotification" => NotificationName::NSTextDidBeginEditing,
+            "NSTextDidChangeNotification" => NotificationName::NSTextDidChange,
+            "NSTextDidEndEditingNotification" => NotificationName::NSTextDidEndEditing,
+            "NSTextInputContextKeyboardSelectionDidChangeNotification" => NotificationName::NSTextInputContextKeyboardSelectionDidChange,
+            "NSTextStorageDidProcessEditingNotification" => NotificationName::NSTextStorageDidProcessEditing,
+            "NSTextStorageWillProcessEditingNotification" => NotificationName::NSTextStorageWillProcessEditing,
+            "NSTextViewDidChangeSelectionNotification" => NotificationName::NSTextViewDidChangeSelection,
+            "NSTextViewDidChangeTypingAttributesNotification" => NotificationName::NSTextViewDidChangeTypingAttributes,
+            "NSTextViewWillChangeNotifyingTextViewNotification" => NotificationName::NSTextViewWillChangeNotifyingTextView,
+            "NSToolbarDidRemoveItemNotification" => NotificationName::NSToolbarDidRemoveItem,
+            "NSToolbarWillAddItemNotification" => NotificationName::NSToolbarWillAddItem,
+            "NSViewBoundsDidChangeNotification" => NotificationName::NSViewBoundsDidChange,
+            "NSViewDidUpdateTrackingAreasNotification" => NotificationName::NSViewDidUpdateTrackingAreas,
+            "NSViewFocusDidChangeNotification" => NotificationName::NSViewFocusDidChange,
+            "NSViewFrameDidChangeNotification" => NotificationName::NSViewFrameDidChange,
+            "NSViewGlobalFrameDidChangeNotification" => NotificationName::NSViewGlobalFrameDidChange,
+            "NSWindowDidBecomeKeyNotification" => NotificationName::NSWindowDidBecomeKey,
+            "NSWindowDidBecomeMainNotification" => NotificationName::NSWindowDidBecomeMain,
+            "NSWindowDidChangeBackingPropertiesNotification" => NotificationName::NSWindowDidChangeBackingProperties,
+            "NSWindowDidChangeOcclusionStateNotification" => NotificationName::NSWindowDidChangeOcclusionState,
+            "NSWindowDidChangeScreenNotification" => NotificationName::NSWindowDidChangeScreen,
+            "NSWindowDidChangeScreenProfileNotification" => NotificationName::NSWindowDidChangeScreenProfile,
+            "NSWindowDidDeminiaturizeNotification" => NotificationName::NSWindowDidDeminiaturize,
+            "NSWindowDidEndLiveResizeNotification" => NotificationName::NSWindowDidEndLiveResize,
+            "NSWindowDidEndSheetNotification" => NotificationName::NSWindowDidEndSheet,
+            "NSWindowDidEnterFullScreenNotification" => NotificationName::NSWindowDidEnterFullScreen,
+            "NSWindowDidEnterVersionBrowserNotification" => NotificationName::NSWindowDidEnterVersionBrowser,
+            "NSWindowDidExitFullScreenNotification" => NotificationName::NSWindowDidExitFullScreen,
+            "NSWindowDidExitVersionBrowserNotification" => NotificationName::NSWindowDidExitVersionBrowser,
+            "NSWindowDidExposeNotification" => NotificationName::NSWindowDidExpose,
+            "NSWindowDidMiniaturizeNotification" => NotificationName::NSWindowDidMiniaturize,
+            "NSWindowDidMoveNotification" => NotificationName::NSWindowDidMove,
+            "NSWindowDidResignKeyNotification" => NotificationName::NSWindowDidResignKey,
+            "NSWindowDidResignMainNotification" => NotificationName::NSWindowDidResignMain,
+            "NSWindowDidResizeNotification" => NotificationName::NSWindowDidResize,
+            "NSWindowDidUpdateNotification" => NotificationName::NSWindowDidUpdate,
+            "NSWindowWillBeginSheetNotification" => NotificationName::NSWindowWillBeginSheet,
+            "NSWindowWillCloseNotification" => NotificationName::NSWindowWillClose,
+            "NSWindowWillEnterFullScreenNotification" => NotificationName::NSWindowWillEnterFullScreen,
+            "NSWindowWillEnterVersionBrowserNotification" => NotificationName::NSWindowWillEnterVersionBrowser,
+            "NSWindowWillExitFullScreenNotification" => NotificationName::NSWindowWillExitFullScreen,
+            "NSWindowWillExitVersionBrowserNotification" => NotificationName::NSWindowWillExitVersionBrowser,
+            "NSWindowWillMiniaturizeNotification" => NotificationName::NSWindowWillMiniaturize,
+            "NSWindowWillMoveNotification" => NotificationName::NSWindowWillMove,
+            "NSWindowWillStartLiveResizeNotification" => NotificationName::NSWindowWillStartLiveResize,
+            "NSWorkspaceAccessibilityDisplayOptionsDidChangeNotification" => NotificationName::NSWorkspaceAccessibilityDisplayOptionsDidChange,
+            "NSWorkspaceActiveSpaceDidChangeNotification" => NotificationName::NSWorkspaceActiveSpaceDidChange,
+            "NSWorkspaceDidActivateApplicationNotification" => NotificationName::NSWorkspaceDidActivateApplication,
+            "NSWorkspaceDidChangeFileLabelsNotification" => NotificationName::NSWorkspaceDidChangeFileLabels,
+            "NSWorkspaceDidDeactivateApplicationNotification" => NotificationName::NSWorkspaceDidDeactivateApplication,
+            "NSWorkspaceDidHideApplicationNotification" => NotificationName::NSWorkspaceDidHideApplication,
+            "NSWorkspaceDidLaunchApplicationNotification" => NotificationName::NSWorkspaceDidLaunchApplication,
+            "NSWorkspaceDidMountNotification" => NotificationName::NSWorkspaceDidMount,
+            "NSWorkspaceDidPerformFileOperationNotification" => NotificationName::NSWorkspaceDidPerformFileOperation,
+            "NSWorkspaceDidRenameVolumeNotification" => NotificationName::NSWorkspaceDidRenameVolume,
+            "NSWorkspaceDidTerminateApplicationNotification" => NotificationName::NSWorkspaceDidTerminateApplication,
+            "NSWorkspaceDidUnhideApplicationNotification" => NotificationName::NSWorkspaceDidUnhideApplication,
+            "NSWorkspaceDidUnmountNotification" => NotificationName::NSWorkspaceDidUnmount,
+            "NSWorkspaceDidWakeNotification" => NotificationName::NSWorkspaceDidWake,
+            "NSWorkspaceScreensDidSleepNotification" => NotificationName::NSWorkspaceScreensDidSleep,
+            "NSWorkspaceScreensDidWakeNotification" => NotificationName::NSWorkspaceScreensDidWake,
+            "NSWorkspaceSessionDidBecomeActiveNotification" => NotificationName::NSWorkspaceSessionDidBecomeActive,
+            "NSWorkspaceSessionDidResignActiveNotification" => NotificationName::NSWorkspaceSessionDidResignActive,
+            "NSWorkspaceWillLaunchApplicationNotification" => NotificationName::NSWorkspaceWillLaunchApplication,
+            "NSWorkspaceWillPowerOffNotification" => NotificationName::NSWorkspaceWillPowerOff,
+            "NSWorkspaceWillSleepNotification" => NotificationName::NSWorkspaceWillSleep,
+            "NSWorkspaceWillUnmountNotification" => NotificationName::NSWorkspaceWillUnmount,
+            "PDFDocumentDidBeginFindNotification" => NotificationName::PDFDocumentDidBeginFind,
+            "PDFDocumentDidBeginPageFindNotification" => NotificationName::PDFDocumentDidBeginPageFind,
+            "PDFDocumentDidBeginPageWriteNotification" => NotificationName::PDFDocumentDidBeginPageWrite,
+            "PDFDocumentDidBeginWriteNotification" => NotificationName::PDFDocumentDidBeginWrite,
+            "PDFDocumentDidEndFindNotification" => NotificationName::PDFDocumentDidEndFind,
+            "PDFDocumentDidEndPageFindNotification" => NotificationName::PDFDocumentDidEndPageFind,
+            "PDFDocumentDidEndPageWriteNotification" => NotificationName::PDFDocumentDidEndPageWrite,
+            "PDFDocumentDidEndWriteNotification" => NotificationName::PDFDocumentDidEndWrite,
+            "PDFDocumentDidFindMatchNotification" => NotificationName::PDFDocumentDidFindMatch,
+            "PDFDocumentDidUnlockNotification" => NotificationName::PDFDocumentDidUnlock,
+            "PDFThumbnailViewDocumentEditedNotification" => NotificationName::PDFThumbnailViewDocumentEdited,
+            "PDFViewAnnotationHitNotification" => NotificationName::PDFViewAnnotationHit,
+            "PDFViewAnnotationWillHitNotification" => NotificationName::PDFViewAnnotationWillHit,
+            "PDFViewChangedHistoryNotification" => NotificationName::PDFViewChangedHistory,
+            "PDFViewCopyPermissionNotification" => NotificationName::PDFViewCopyPermission,
+            "PDFViewDisplayBoxChangedNotification" => NotificationName::PDFViewDisplayBoxChanged,
+            "PDFViewDisplayModeChangedNotification" => NotificationName::PDFViewDisplayModeChanged,
+            "PDFViewDocumentChangedNotification" => NotificationName::PDFViewDocumentChanged,
+            "PDFViewPageChangedNotification" => NotificationName::PDFViewPageChanged,
+            "PDFViewPrintPermissionNotification" => NotificationName::PDFViewPrintPermission,
+            "PDFViewScaleChangedNotification" => NotificationName::PDFViewScaleChanged,
+            "PDFViewSelectionChangedNotification" => NotificationName::PDFViewSelectionChanged,
+            "PDFViewVisiblePagesChangedNotification" => NotificationName::PDFViewVisiblePagesChanged,
+            "ABDatabaseChangedNotification" => NotificationName::KABDatabaseChanged,
+            "ABDatabaseChangedExternallyNotification" => NotificationName::KABDatabaseChangedExternally,
+            "kQuartzFilterManagerDidAddFilterNotification" => NotificationName::KQuartzFilterManagerDidAddFilter,
+            "kQuartzFilterManagerDidModifyFilterNotification" => NotificationName::KQuartzFilterManagerDidModifyFilter,
+            "kQuartzFilterManagerDidRemoveFilterNotification" => NotificationName::KQuartzFilterManagerDidRemoveFilter,
+            "kQuartzFilterManagerDidSelectFilterNotification" => NotificationName::KQuartzFilterManagerDidSelectFilter,
+            "EAAccessoryDidConnectNotification" => NotificationName::EAAccessoryDidConnect,
+            "EAAccessoryDidDisconnectNotification" => NotificationName::EAAccessoryDidDisconnect,
+            "SKCloudServiceCapabilitiesDidChangeNotification" => NotificationName::SKCloudServiceCapabilitiesDidChange,
+            "SKStorefrontIdentifierDidChangeNotification" => NotificationName::SKStorefrontIdentifierDidChange,
+            "UIAccessibilityAssistiveTouchStatusDidChangeNotification" => NotificationName::UIAccessibilityAssistiveTouchStatusDidChange,
+            "UIAccessibilityBoldTextStatusDidChangeNotification" => NotificationName::UIAccessibilityBoldTextStatusDidChange,
+            "UIAccessibilityClosedCaptioningStatusDidChangeNotification" => NotificationName::UIAccessibilityClosedCaptioningStatusDidChange,
+            "UIAccessibilityDarkerSystemColorsStatusDidChangeNotification" => NotificationName::UIAccessibilityDarkerSystemColorsStatusDidChange,
+            "UIAccessibilityGrayscaleStatusDidChangeNotification" => NotificationName::UIAccessibilityGrayscaleStatusDidChange,
+            "UIAccessibilityGuidedAccessStatusDidChangeNotification" => NotificationName::UIAccessibilityGuidedAccessStatusDidChange,
+            "UIAccessibilityHearingDevicePairedEarDidChangeNotification" => NotificationName::UIAccessibilityHearingDevicePairedEarDidChange,
+            "UIAccessibilityInvertColorsStatusDidChangeNotification" => NotificationName::UIAccessibilityInvertColorsStatusDidChange,
+            "UIAccessibilityMonoAudioStatusDidChangeNotification" => NotificationName::UIAccessibilityMonoAudioStatusDidChange,
+            "UIAccessibilityReduceMotionStatusDidChangeNotification" => NotificationName::UIAccessibilityReduceMotionStatusDidChange,
+            "UIAccessibilityReduceTransparencyStatusDidChangeNotification" => NotificationName::UIAccessibilityReduceTransparencyStatusDidChange,
+            "UIAccessibilityShakeToUndoDidChangeNotification" => NotificationName::UIAccessibilityShakeToUndoDidChange,
+            "UIAccessibilitySpeakScreenStatusDidChangeNotification" => NotificationName::UIAccessibilitySpeakScreenStatusDidChange,
+            "UIAccessibilitySpeakSelectionStatusDidChangeNotification" => NotificationName::UIAccessibilitySpeakSelectionStatusDidChange,
+            "UIAccessibilitySwitchControlStatusDidChangeNotification" => NotificationName::UIAccessibilitySwitchControlStatusDidChange,
+            "UIApplicationDidBecomeActiveNotification" => NotificationName::UIApplicationDidBecomeActive,
+            "UIApplicationDidEnterBackgroundNotification" => NotificationName::UIApplicationDidEnterBackground,
+            "UIApplicationDidFinishLaunchingNotification" => NotificationName::UIApplicationDidFinishLaunching,
+            "UIApplicationDidReceiveMemoryWarningNotification" => NotificationName::UIApplicationDidReceiveMemoryWarning,
+            "UIApplicationSignificantTimeChangeNotification" => NotificationName::UIApplicationSignificantTimeChange,
+            "UIApplicationUserDidTakeScreenshotNotification" => NotificationName::UIApplicationUserDidTakeScreenshot,
+            "UIApplicationWillEnterForegroundNotification" => NotificationName::UIApplicationWillEnterForeground,
+            "UIApplicationWillResignActiveNotification" => NotificationName::UIApplicationWillResignActive,
+            "UIApplicationWillTerminateNotification" => NotificationName::UIApplicationWillTerminate,
+            "UIContentSizeCategoryDidChangeNotification" => NotificationName::UIContentSizeCategoryDidChange,
+            "UIDeviceProximityStateDidChangeNotification" => NotificationName::UIDeviceProximityStateDidChange,
+            "UIScreenBrightnessDidChangeNotification" => NotificationName::UIScreenBrightnessDidChange,
+            "UIScreenDidConnectNotification" => NotificationName::UIScreenDidConnect,
+            "UIScreenDidDisconnectNotification" => NotificationName::UIScreenDidDisconnect,
+            "UIScreenModeDidChangeNotification" => NotificationName::UIScreenModeDidChange,
+            "UITableViewSelectionDidChangeNotification" => NotificationName::UITableViewSelectionDidChange,
+            "UITextFieldTextDidBeginEditingNotification" => NotificationName::UITextFieldTextDidBeginEditing,
+            "UITextFieldTextDidChangeNotification" => NotificationName::UITextFieldTextDidChange,
+            "UITextFieldTextDidEndEditingNotification" => NotificationName::UITextFieldTextDidEndEditing,
+            "UITextInputCurrentInputModeDidChangeNotification" => NotificationName::UITextInputCurrentInputModeDidChange,
+            "UITextViewTextDidBeginEditingNotification" => NotificationName::UITextViewTextDidBeginEditing,
+            "UITextViewTextDidChangeNotification" => NotificationName::UITextViewTextDidChange,
+            "UITextViewTextDidEndEditingNotification" => NotificationName::UITextViewTextDidEndEditing,
+            "UIViewControllerShowDetailTargetDidChangeNotification" => NotificationName::UIViewControllerShowDetailTargetDidChange,
+            "UIWindowDidBecomeHiddenNotification" => NotificationName::UIWindowDidBecomeHidden,
+            "UIWindowDidBecomeKeyNotification" => NotificationName::UIWindowDidBecomeKey,
+            "UIWindowDidBecomeVisibleNotification" => NotificationName::UIWindowDidBecomeVisible,
+            "UIWindowDidResignKeyNotification" => NotificationName::UIWindowDidResignKey,
+            "AVCaptureDeviceSubjectAreaDidChangeNotification" => NotificationName::AVCaptureDeviceSubjectAreaDidChange,
+            "AVCaptureSessionInterruptionEndedNotification" => NotificationName::AVCaptureSessionInterruptionEnded,
+            "AVCaptureSessionWasInterruptedNotification" => NotificationName::AVCaptureSessionWasInterrupted,
+            "MFMessageComposeViewControllerTextMessageAvailabilityDidChangeNotification" => NotificationName::MFMessageComposeViewControllerTextMessageAvailabilityDidChange,
+            "MPMediaLibraryDidChangeNotification" => NotificationName::MPMediaLibraryDidChange,
+            "MPMusicPlayerControllerNowPlayingItemDidChangeNotification" => NotificationName::MPMusicPlayerControllerNowPlayingItemDidChange,
+            "MPMusicPlayerControllerPlaybackStateDidChangeNotification" => NotificationName::MPMusicPlayerControllerPlaybackStateDidChange,
+            "MPMusicPlayerControllerVolumeDidChangeNotification" => NotificationName::MPMusicPlayerControllerVolumeDidChange,
+            "UIApplicationBackgroundRefreshStatusDidChangeNotification" => NotificationName::UIApplicationBackgroundRefreshStatusDidChange,
+            "UIDeviceBatteryLevelDidChangeNotification" => NotificationName::UIDeviceBatteryLevelDidChange,
+            "UIDeviceBatteryStateDidChangeNotification" => NotificationName::UIDeviceBatteryStateDidChange,
+            "UIDeviceOrientationDidChangeNotification" => NotificationName::UIDeviceOrientationDidChange,
+            "UIDocumentStateChangedNotification" => NotificationName::UIDocumentStateChanged,
+            "UIKeyboardDidChangeFrameNotification" => NotificationName::UIKeyboardDidChangeFrame,
+            "UIKeyboardDidHideNotification" => NotificationName::UIKeyboardDidHide,
+            "UIKeyboardDidShowNotification" => NotificationName::UIKeyboardDidShow,
+            "UIKeyboardWillChangeFrameNotification" => NotificationName::UIKeyboardWillChangeFrame,
+            "UIKeyboardWillHideNotification" => NotificationName::UIKeyboardWillHide,
+            "UIKeyboardWillShowNotification" => NotificationName::UIKeyboardWillShow,
+            "UIMenuControllerDidHideMenuNotification" => NotificationName::UIMenuControllerDidHideMenu,
+            "UIMenuControllerDidShowMenuNotification" => NotificationName::UIMenuControllerDidShowMenu,
+            "UIMenuControllerMenuFrameDidChangeNotification" => NotificationName::UIMenuControllerMenuFrameDidChange,
+            "UIMenuControllerWillHideMenuNotification" => NotificationName::UIMenuControllerWillHideMenu,
+            "UIMenuControllerWillShowMenuNotification" => NotificationName::UIMenuControllerWillShowMenu,
+            "UIPasteboardChangedNotification" => NotificationName::UIPasteboardChanged,
+            "UIPasteboardRemovedNotification" => NotificationName::UIPasteboardRemoved,
+            "UIApplicationProtectedDataDidBecomeAvailableNotification" => NotificationName::UIApplicationProtectedDataDidBecomeAvailable,
+            "UIApplicationProtectedDataWillBecomeUnavailableNotification" => NotificationName::UIApplicationProtectedDataWillBecomeUnavailable,
+            "NSSpellCheckerDidChangeAutomaticTextCompletionNotification" => NotificationName::NSSpellCheckerDidChangeAutomaticTextCompletion,
+            "MPMusicPlayerControllerQueueDidChangeNotification" => NotificationName::MPMusicPlayerControllerQueueDidChange,
+            "AVDisplayManagerModeSwitchEndNotification" => NotificationName::AVDisplayManagerModeSwitchEnd,
+            "AVDisplayManagerModeSwitchSettingsChangedNotification" => NotificationName::AVDisplayManagerModeSwitchSettingsChanged,
+            "AVDisplayManagerModeSwitchStartNotification" => NotificationName::AVDisplayManagerModeSwitchStart,
+            "AVPlayerAvailableHDRModesDidChangeNotification" => NotificationName::AVPlayerAvailableHDRModesDidChange,
+            "AVRouteDetectorMultipleRoutesDetectedDidChangeNotification" => NotificationName::AVRouteDetectorMultipleRoutesDetectedDidChange,
+            "AVSampleBufferAudioRendererWasFlushedAutomaticallyNotification" => NotificationName::AVSampleBufferAudioRendererWasFlushedAutomatically,
+            "CTServiceRadioAccessTechnologyDidChangeNotification" => NotificationName::CTServiceRadioAccessTechnologyDidChange,
+            "GKPlayerAuthenticationDidChangeNotificationName" => NotificationName::GKPlayerAuthenticationDidChangeNotificationName,
+            "GKPlayerDidChangeNotificationName" => NotificationName::GKPlayerDidChangeNotificationName,
+            "NEDNSProxyConfigurationDidChangeNotification" => NotificationName::NEDNSProxyConfigurationDidChange,
+            "NSPersistentStoreRemoteChangeNotification" => NotificationName::NSPersistentStoreRemoteChange,
+            "SKStorefrontCountryCodeDidChangeNotification" => NotificationName::SKStorefrontCountryCodeDidChange,
+            "WKAccessibilityReduceMotionStatusDidChangeNotification" => NotificationName::WKAccessibilityReduceMotionStatusDidChange,
+            name => NotificationName::Custom(name)
+        }
+    }
 }
 
 impl From<NotificationName> for NSString {
     fn from(name: NotificationName) -> Self {
-        match name {
-            _ => NSString::new("")
+        NSString::new(name.as_str())
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use std::collections::HashSet;
+
+    use super::NotificationName;
+
+    /// Every named (non-`Custom`) notification.
+    const ALL: &[NotificationName] = &[
+        NotificationName::AudioEngineConfigurationChange,
+        NotificationName::AudioSessionInterruption,
+        NotificationName::AudioSessionMediaServicesWereLost,
+        NotificationName::AudioSessionMediaServicesWereReset,
+        NotificationName::AudioSessionRouteChange,
+        NotificationName::AudioSessionSilenceSecondaryAudioHint,
+        NotificationName::AudioUnitComponentTagsDidChange,
+        NotificationName::CloudKitAccountChanged,
+        NotificationName::CLKComplicationServerActiveComplicationsDidChange,
+        NotificationName::CNContactStoreDidChange,
+        NotificationName::EKEventStoreChanged,
+        NotificationName::HKUserPreferencesDidChange,
+        NotificationName::HMCharacteristicPropertySupportsEvent,
+        NotificationName::NSBundleResourceRequestLowDiskSpace,
+        NotificationName::NSCalendarDayChanged,
+        NotificationName::NSExtensionHostDidBecomeActive,
+        NotificationName::NSExtensionHostDidEnterBackground,
+        NotificationName::NSExtensionHostWillEnterForeground,
+        NotificationName::NSExtensionHostWillResignActive,
+        NotificationName::NSFileHandleConnectionAccepted,
+        NotificationName::NSFileHandleDataAvailable,
+        NotificationName::NSFileHandleReadToEndOfFileCompletion,
+        NotificationName::NSHTTPCookieManagerAcceptPolicyChanged,
+        NotificationName::NSHTTPCookieManagerCookiesChanged,
+        NotificationName::NSManagedObjectContextDidSave,
+        NotificationName::NSManagedObjectContextObjectsDidChange,
+        NotificationName::NSManagedObjectContextWillSave,
+        NotificationName::NSMetadataQueryDidFinishGathering,
+        NotificationName::NSMetadataQueryDidStartGathering,
+        NotificationName::NSMetadataQueryDidUpdate,
+        NotificationName::NSMetadataQueryGatheringProgress,
+        NotificationName::NSPersistentStoreCoordinatorStoresDidChange,
+        NotificationName::NSPersistentStoreCoordinatorStoresWillChange,
+        NotificationName::NSPersistentStoreCoordinatorWillRemoveStore,
+        NotificationName::NSProcessInfoPowerStateDidChange,
+        NotificationName::NSSystemClockDidChange,
+        NotificationName::NSSystemTimeZoneDidChange,
+        NotificationName::NSURLCredentialStorageChanged,
+        NotificationName::NSUbiquityIdentityDidChange,
+        NotificationName::NSUndoManagerCheckpoint,
+        NotificationName::NSUndoManagerDidCloseUndoGroup,
+        NotificationName::NSUndoManagerDidOpenUndoGroup,
+        NotificationName::NSUndoManagerDidRedoChange,
+        NotificationName::NSUndoManagerDidUndoChange,
+        NotificationName::NSUndoManagerWillCloseUndoGroup,
+        NotificationName::NSUndoManagerWillRedoChange,
+        NotificationName::NSUndoManagerWillUndoChange,
+        NotificationName::PKPassLibraryDidChange,
+        NotificationName::PKPassLibraryRemotePaymentPassesDidChange,
+        NotificationName::UIAccessibilityAnnouncementDidFinish,
+        NotificationName::UIAccessibilityElementFocused,
+        NotificationName::WKAudioFilePlayerItemDidPlayToEndTime,
+        NotificationName::WKAudioFilePlayerItemFailedToPlayToEndTime,
+        NotificationName::WKAudioFilePlayerItemTimeJumped,
+        NotificationName::ABPeoplePickerDisplayedPropertyDidChange,
+        NotificationName::ABPeoplePickerGroupSelectionDidChange,
+        NotificationName::ABPeoplePickerNameSelectionDidChange,
+        NotificationName::ABPeoplePickerValueSelectionDidChange,
+        NotificationName::ACAccountStoreDidChange,
+        NotificationName::AVAssetChapterMetadataGroupsDidChange,
+        NotificationName::AVAssetContainsFragmentsDidChange,
+        NotificationName::AVAssetDurationDidChange,
+        NotificationName::AVAssetMediaSelectionGroupsDidChange,
+        NotificationName::AVAssetTrackSegmentsDidChange,
+        NotificationName::AVAssetTrackTimeRangeDidChange,
+        NotificationName::AVAssetTrackTrackAssociationsDidChange,
+        NotificationName::AVAssetWasDefragmented,
+        NotificationName::AVCaptureDeviceWasConnected,
+        NotificationName::AVCaptureDeviceWasDisconnected,
+        NotificationName::AVCaptureInputPortFormatDescriptionDidChange,
+        NotificationName::AVCaptureSessionDidStartRunning,
+        NotificationName::AVCaptureSessionDidStopRunning,
+        NotificationName::AVCaptureSessionRuntimeError,
+        NotificationName::AVFragmentedMovieContainsMovieFragmentsDidChange,
+        NotificationName::AVFragmentedMovieDurationDidChange,
+        NotificationName::AVFragmentedMovieTrackSegmentsDidChange,
+        NotificationName::AVFragmentedMovieTrackTimeRangeDidChange,
+        NotificationName::AVFragmentedMovieTrackTotalSampleDataLengthDidChange,
+        NotificationName::AVFragmentedMovieWasDefragmented,
+        NotificationName::AVPlayerItemDidPlayToEndTime,
+        NotificationName::AVPlayerItemFailedToPlayToEndTime,
+        NotificationName::AVPlayerItemNewAccessLogEntry,
+        NotificationName::AVPlayerItemNewErrorLogEntry,
+        NotificationName::AVPlayerItemPlaybackStalled,
+        NotificationName::AVPlayerItemTimeJumped,
+        NotificationName::AVSampleBufferDisplayLayerFailedToDecode,
+        NotificationName::CWBSSIDDidChange,
+        NotificationName::CWCountryCodeDidChange,
+        NotificationName::CWLinkDidChange,
+        NotificationName::CWLinkQualityDidChange,
+        NotificationName::CWModeDidChange,
+        NotificationName::CWPowerDidChange,
+        NotificationName::CWSSIDDidChange,
+        NotificationName::CWScanCacheDidUpdate,
+        NotificationName::GCControllerDidConnect,
+        NotificationName::GCControllerDidDisconnect,
+        NotificationName::IKFilterBrowserFilterDoubleClick,
+        NotificationName::IKFilterBrowserFilterSelected,
+        NotificationName::IKFilterBrowserWillPreviewFilter,
+        NotificationName::IOBluetoothHostControllerPoweredOff,
+        NotificationName::IOBluetoothHostControllerPoweredOn,
+        NotificationName::IOBluetoothL2CAPChannelPublished,
+        NotificationName::IOBluetoothL2CAPChannelTerminated,
+        NotificationName::MKAnnotationCalloutInfoDidChange,
+        NotificationName::NEFilterConfigurationDidChange,
+        NotificationName::NEVPNConfigurationChange,
+        NotificationName::NEVPNStatusDidChange,
+        NotificationName::NSAccessibilityAnnouncementRequested,
+        NotificationName::NSAccessibilityAnnouncementKey,
+        NotificationName::NSAccessibilityPriorityKey,
+        NotificationName::NSAccessibilityApplicationActivated,
+        NotificationName::NSAccessibilityApplicationDeactivated,
+        NotificationName::NSAccessibilityApplicationHidden,
+        NotificationName::NSAccessibilityApplicationShown,
+        NotificationName::NSAccessibilityCreated,
+        NotificationName::NSAccessibilityDrawerCreated,
+        NotificationName::NSAccessibilityFocusedUIElementChanged,
+        NotificationName::NSAccessibilityFocusedWindowChanged,
+        NotificationName::NSAccessibilityHelpTagCreated,
+        NotificationName::NSAccessibilityLayoutChanged,
+        NotificationName::NSAccessibilityUIElementsKey,
+        NotificationName::NSAccessibilityMainWindowChanged,
+        NotificationName::NSAccessibilityMoved,
+        NotificationName::NSAccessibilityResized,
+        NotificationName::NSAccessibilityRowCollapsed,
+        NotificationName::NSAccessibilityRowCountChanged,
+        NotificationName::NSAccessibilityRowExpanded,
+        NotificationName::NSAccessibilitySelectedCellsChanged,
+        NotificationName::NSAccessibilitySelectedChildrenChanged,
+        NotificationName::NSAccessibilitySelectedChildrenMoved,
+        NotificationName::NSAccessibilitySelectedColumnsChanged,
+        NotificationName::NSAccessibilitySelectedRowsChanged,
+        NotificationName::NSAccessibilitySelectedTextChanged,
+        NotificationName::NSAccessibilitySheetCreated,
+        NotificationName::NSAccessibilityTitleChanged,
+        NotificationName::NSAccessibilityUIElementDestroyed,
+        NotificationName::NSAccessibilityUnitsChanged,
+        NotificationName::NSAccessibilityValueChanged,
+        NotificationName::NSAccessibilityWindowCreated,
+        NotificationName::NSAccessibilityWindowDeminiaturized,
+        NotificationName::NSAccessibilityWindowMiniaturized,
+        NotificationName::NSAccessibilityWindowMoved,
+        NotificationName::NSAccessibilityWindowResized,
+        NotificationName::NSAnimationProgressMark,
+        NotificationName::NSAntialiasThresholdChanged,
+        NotificationName::NSAppleEventManagerWillProcessFirstEvent,
+        NotificationName::NSApplicationDidBecomeActive,
+        NotificationName::NSApplicationDidChangeOcclusionState,
+        NotificationName::NSApplicationDidChangeScreenParameters,
+        NotificationName::NSApplicationDidFinishLaunching,
+        NotificationName::NSApplicationDidFinishRestoringWindows,
+        NotificationName::NSApplicationDidHide,
+        NotificationName::NSApplicationDidResignActive,
+        NotificationName::NSApplicationDidUnhide,
+        NotificationName::NSApplicationDidUpdate,
+        NotificationName::NSApplicationWillBecomeActive,
+        NotificationName::NSApplicationWillFinishLaunching,
+        NotificationName::NSApplicationWillHide,
+        NotificationName::NSApplicationWillResignActive,
+        NotificationName::NSApplicationWillTerminate,
+        NotificationName::NSApplicationWillUnhide,
+        NotificationName::NSApplicationWillUpdate,
+        NotificationName::NSBrowserColumnConfigurationDidChange,
+        NotificationName::NSClassDescriptionNeededForClass,
+        NotificationName::NSColorListDidChange,
+        NotificationName::NSColorPanelColorDidChange,
+        NotificationName::NSColorPanel,
+        NotificationName::NSComboBoxSelectionDidChange,
+        NotificationName::NSComboBoxSelectionIsChanging,
+        NotificationName::NSComboBoxWillDismiss,
+        NotificationName::NSComboBoxWillPopUp,
+        NotificationName::NSContextHelpModeDidActivate,
+        NotificationName::NSContextHelpModeDidDeactivate,
+        NotificationName::NSControlTextDidBeginEditing,
+        NotificationName::NSControlTextDidChange,
+        NotificationName::NSControlTextDidEndEditing,
+        NotificationName::NSControlTintDidChange,
+        NotificationName::NSDrawerDidClose,
+        NotificationName::NSDrawerDidOpen,
+        NotificationName::NSDrawerWillClose,
+        NotificationName::NSDrawerWillOpen,
+        NotificationName::NSFontCollectionDidChange,
+        NotificationName::NSFontSetChanged,
+        NotificationName::NSImageRepRegistryDidChange,
+        NotificationName::NSMenuDidAddItem,
+        NotificationName::NSMenuDidBeginTracking,
+        NotificationName::NSMenuDidChangeItem,
+        NotificationName::NSMenuDidEndTracking,
+        NotificationName::NSMenuDidRemoveItem,
+        NotificationName::NSMenuDidSendAction,
+        NotificationName::NSMenuWillSendAction,
+        NotificationName::NSOutlineViewColumnDidMove,
+        NotificationName::NSOutlineViewColumnDidResize,
+        NotificationName::NSOutlineViewItemDidCollapse,
+        NotificationName::NSOutlineViewItemDidExpand,
+        NotificationName::NSOutlineViewItemWillCollapse,
+        NotificationName::NSOutlineViewItemWillExpand,
+        NotificationName::NSOutlineViewSelectionDidChange,
+        NotificationName::NSOutlineViewSelectionIsChanging,
+        NotificationName::NSPersistentStoreDidImportUbiquitousContentChanges,
+        NotificationName::NSPopUpButtonCellWillPopUp,
+        NotificationName::NSPopUpButtonWillPopUp,
+        NotificationName::NSPopoverDidClose,
+        NotificationName::NSPopoverDidShow,
+        NotificationName::NSPopoverWillClose,
+        NotificationName::NSPopoverWillShow,
+        NotificationName::NSPreferencePaneCancelUnselect,
+        NotificationName::NSPreferencePaneDoUnselect,
+        NotificationName::NSPreferencePaneSwitchToPane,
+        NotificationName::NSPreferencePaneUpdateHelpMenu,
+        NotificationName::NSPreferencePrefPaneIsAvailable,
+        NotificationName::NSPreferredScrollerStyleDidChange,
+        NotificationName::NSRuleEditorRowsDidChange,
+        NotificationName::NSScreenColorSpaceDidChange,
+        NotificationName::NSScrollViewDidEndLiveMagnify,
+        NotificationName::NSScrollViewDidEndLiveScroll,
+        NotificationName::NSScrollViewDidLiveScroll,
+        NotificationName::NSScrollViewWillStartLiveMagnify,
+        NotificationName::NSScrollViewWillStartLiveScroll,
+        NotificationName::NSSpellCheckerDidChangeAutomaticCapitalization,
+        NotificationName::NSSpellCheckerDidChangeAutomaticDashSubstitution,
+        NotificationName::NSSpellCheckerDidChangeAutomaticPeriodSubstitution,
+        NotificationName::NSSpellCheckerDidChangeAutomaticQuoteSubstitution,
+        NotificationName::NSSpellCheckerDidChangeAutomaticSpellingCorrection,
+        NotificationName::NSSpellCheckerDidChangeAutomaticTextReplacement,
+        NotificationName::NSSplitViewDidResizeSubviews,
+        NotificationName::NSSplitViewWillResizeSubviews,
+        NotificationName::NSSystemColorsDidChange,
+        NotificationName::NSTableViewColumnDidMove,
+        NotificationName::NSTableViewColumnDidResize,
+        NotificationName::NSTableViewSelectionDidChange,
+        NotificationName::NSTableViewSelectionIsChanging,
+        NotificationName::NSTextAlternativesSelectedAlternativeString,
+        NotificationName::NSTextDidBeginEditing,
+        NotificationName::NSTextDidChange,
+        NotificationName::NSTextDidEndEditing,
+        NotificationName::NSTextInputContextKeyboardSelectionDidChange,
+        NotificationName::NSTextStorageDidProcessEditing,
+        NotificationName::NSTextStorageWillProcessEditing,
+        NotificationName::NSTextViewDidChangeSelection,
+        NotificationName::NSTextViewDidChangeTypingAttributes,
+        NotificationName::NSTextViewWillChangeNotifyingTextView,
+        NotificationName::NSToolbarDidRemoveItem,
+        NotificationName::NSToolbarWillAddItem,
+        NotificationName::NSViewBoundsDidChange,
+        NotificationName::NSViewDidUpdateTrackingAreas,
+        NotificationName::NSViewFocusDidChange,
+        NotificationName::NSViewFrameDidChange,
+        NotificationName::NSViewGlobalFrameDidChange,
+        NotificationName::NSWindowDidBecomeKey,
+        NotificationName::NSWindowDidBecomeMain,
+        NotificationName::NSWindowDidChangeBackingProperties,
+        NotificationName::NSWindowDidChangeOcclusionState,
+        NotificationName::NSWindowDidChangeScreen,
+        NotificationName::NSWindowDidChangeScreenProfile,
+        NotificationName::NSWindowDidDeminiaturize,
+        NotificationName::NSWindowDidEndLiveResize,
+        NotificationName::NSWindowDidEndSheet,
+        NotificationName::NSWindowDidEnterFullScreen,
+        NotificationName::NSWindowDidEnterVersionBrowser,
+        NotificationName::NSWindowDidExitFullScreen,
+        NotificationName::NSWindowDidExitVersionBrowser,
+        NotificationName::NSWindowDidExpose,
+        NotificationName::NSWindowDidMiniaturize,
+        NotificationName::NSWindowDidMove,
+        NotificationName::NSWindowDidResignKey,
+        NotificationName::NSWindowDidResignMain,
+        NotificationName::NSWindowDidResize,
+        NotificationName::NSWindowDidUpdate,
+        NotificationName::NSWindowWillBeginSheet,
+        NotificationName::NSWindowWillClose,
+        NotificationName::NSWindowWillEnterFullScreen,
+        NotificationName::NSWindowWillEnterVersionBrowser,
+        NotificationName::NSWindowWillExitFullScreen,
+        NotificationName::NSWindowWillExitVersionBrowser,
+        NotificationName::NSWindowWillMiniaturize,
+        NotificationName::NSWindowWillMove,
+        NotificationName::NSWindowWillStartLiveResize,
+        NotificationName::NSWorkspaceAccessibilityDisplayOptionsDidChange,
+        NotificationName::NSWorkspaceActiveSpaceDidChange,
+        NotificationName::NSWorkspaceDidActivateApplication,
+        NotificationName::NSWorkspaceDidChangeFileLabels,
+        NotificationName::NSWorkspaceDidDeactivateApplication,
+        NotificationName::NSWorkspaceDidHideApplication,
+        NotificationName::NSWorkspaceDidLaunchApplication,
+        NotificationName::NSWorkspaceDidMount,
+        NotificationName::NSWorkspaceDidPerformFileOperation,
+        NotificationName::NSWorkspaceDidRenameVolume,
+        NotificationName::NSWorkspaceDidTerminateApplication,
+        NotificationName::NSWorkspaceDidUnhideApplication,
+        NotificationName::NSWorkspaceDidUnmount,
+        NotificationName::NSWorkspaceDidWake,
+        NotificationName::NSWorkspaceScreensDidSleep,
+        NotificationName::NSWorkspaceScreensDidWake,
+        NotificationName::NSWorkspaceSessionDidBecomeActive,
+        NotificationName::NSWorkspaceSessionDidResignActive,
+        NotificationName::NSWorkspaceWillLaunchApplication,
+        NotificationName::NSWorkspaceWillPowerOff,
+        NotificationName::NSWorkspaceWillSleep,
+        NotificationName::NSWorkspaceWillUnmount,
+        NotificationName::PDFDocumentDidBeginFind,
+        NotificationName::PDFDocumentDidBeginPageFind,
+        NotificationName::PDFDocumentDidBeginPageWrite,
+        NotificationName::PDFDocumentDidBeginWrite,
+        NotificationName::PDFDocumentDidEndFind,
+        NotificationName::PDFDocumentDidEndPageFind,
+        NotificationName::PDFDocumentDidEndPageWrite,
+        NotificationName::PDFDocumentDidEndWrite,
+        NotificationName::PDFDocumentDidFindMatch,
+        NotificationName::PDFDocumentDidUnlock,
+        NotificationName::PDFThumbnailViewDocumentEdited,
+        NotificationName::PDFViewAnnotationHit,
+        NotificationName::PDFViewAnnotationWillHit,
+        NotificationName::PDFViewChangedHistory,
+        NotificationName::PDFViewCopyPermission,
+        NotificationName::PDFViewDisplayBoxChanged,
+        NotificationName::PDFViewDisplayModeChanged,
+        NotificationName::PDFViewDocumentChanged,
+        NotificationName::PDFViewPageChanged,
+        NotificationName::PDFViewPrintPermission,
+        NotificationName::PDFViewScaleChanged,
+        NotificationName::PDFViewSelectionChanged,
+        NotificationName::PDFViewVisiblePagesChanged,
+        NotificationName::KABDatabaseChanged,
+        NotificationName::KABDatabaseChangedExternally,
+        NotificationName::KQuartzFilterManagerDidAddFilter,
+        NotificationName::KQuartzFilterManagerDidModifyFilter,
+        NotificationName::KQuartzFilterManagerDidRemoveFilter,
+        NotificationName::KQuartzFilterManagerDidSelectFilter,
+        NotificationName::EAAccessoryDidConnect,
+        NotificationName::EAAccessoryDidDisconnect,
+        NotificationName::SKCloudServiceCapabilitiesDidChange,
+        NotificationName::SKStorefrontIdentifierDidChange,
+        NotificationName::UIAccessibilityAssistiveTouchStatusDidChange,
+        NotificationName::UIAccessibilityBoldTextStatusDidChange,
+        NotificationName::UIAccessibilityClosedCaptioningStatusDidChange,
+        NotificationName::UIAccessibilityDarkerSystemColorsStatusDidChange,
+        NotificationName::UIAccessibilityGrayscaleStatusDidChange,
+        NotificationName::UIAccessibilityGuidedAccessStatusDidChange,
+        NotificationName::UIAccessibilityHearingDevicePairedEarDidChange,
+        NotificationName::UIAccessibilityInvertColorsStatusDidChange,
+        NotificationName::UIAccessibilityMonoAudioStatusDidChange,
+        NotificationName::UIAccessibilityReduceMotionStatusDidChange,
+        NotificationName::UIAccessibilityReduceTransparencyStatusDidChange,
+        NotificationName::UIAccessibilityShakeToUndoDidChange,
+        NotificationName::UIAccessibilitySpeakScreenStatusDidChange,
+        NotificationName::UIAccessibilitySpeakSelectionStatusDidChange,
+        NotificationName::UIAccessibilitySwitchControlStatusDidChange,
+        NotificationName::UIApplicationDidBecomeActive,
+        NotificationName::UIApplicationDidEnterBackground,
+        NotificationName::UIApplicationDidFinishLaunching,
+        NotificationName::UIApplicationDidReceiveMemoryWarning,
+        NotificationName::UIApplicationSignificantTimeChange,
+        NotificationName::UIApplicationUserDidTakeScreenshot,
+        NotificationName::UIApplicationWillEnterForeground,
+        NotificationName::UIApplicationWillResignActive,
+        NotificationName::UIApplicationWillTerminate,
+        NotificationName::UIContentSizeCategoryDidChange,
+        NotificationName::UIDeviceProximityStateDidChange,
+        NotificationName::UIScreenBrightnessDidChange,
+        NotificationName::UIScreenDidConnect,
+        NotificationName::UIScreenDidDisconnect,
+        NotificationName::UIScreenModeDidChange,
+        NotificationName::UITableViewSelectionDidChange,
+        NotificationName::UITextFieldTextDidBeginEditing,
+        NotificationName::UITextFieldTextDidChange,
+        NotificationName::UITextFieldTextDidEndEditing,
+        NotificationName::UITextInputCurrentInputModeDidChange,
+        NotificationName::UITextViewTextDidBeginEditing,
+        NotificationName::UITextViewTextDidChange,
+        NotificationName::UITextViewTextDidEndEditing,
+        NotificationName::UIViewControllerShowDetailTargetDidChange,
+        NotificationName::UIWindowDidBecomeHidden,
+        NotificationName::UIWindowDidBecomeKey,
+        NotificationName::UIWindowDidBecomeVisible,
+        NotificationName::UIWindowDidResignKey,
+        NotificationName::AVCaptureDeviceSubjectAreaDidChange,
+        NotificationName::AVCaptureSessionInterruptionEnded,
+        NotificationName::AVCaptureSessionWasInterrupted,
+        NotificationName::MFMessageComposeViewControllerTextMessageAvailabilityDidChange,
+        NotificationName::MPMediaLibraryDidChange,
+        NotificationName::MPMusicPlayerControllerNowPlayingItemDidChange,
+        NotificationName::MPMusicPlayerControllerPlaybackStateDidChange,
+        NotificationName::MPMusicPlayerControllerVolumeDidChange,
+        NotificationName::UIApplicationBackgroundRefreshStatusDidChange,
+        NotificationName::UIDeviceBatteryLevelDidChange,
+        NotificationName::UIDeviceBatteryStateDidChange,
+        NotificationName::UIDeviceOrientationDidChange,
+        NotificationName::UIDocumentStateChanged,
+        NotificationName::UIKeyboardDidChangeFrame,
+        NotificationName::UIKeyboardDidHide,
+        NotificationName::UIKeyboardDidShow,
+        NotificationName::UIKeyboardWillChangeFrame,
+        NotificationName::UIKeyboardWillHide,
+        NotificationName::UIKeyboardWillShow,
+        NotificationName::UIMenuControllerDidHideMenu,
+        NotificationName::UIMenuControllerDidShowMenu,
+        NotificationName::UIMenuControllerMenuFrameDidChange,
+        NotificationName::UIMenuControllerWillHideMenu,
+        NotificationName::UIMenuControllerWillShowMenu,
+        NotificationName::UIPasteboardChanged,
+        NotificationName::UIPasteboardRemoved,
+        NotificationName::UIApplicationProtectedDataDidBecomeAvailable,
+        NotificationName::UIApplicationProtectedDataWillBecomeUnavailable,
+        NotificationName::NSSpellCheckerDidChangeAutomaticTextCompletion,
+        NotificationName::MPMusicPlayerControllerQueueDidChange,
+        NotificationName::AVDisplayManagerModeSwitchEnd,
+        NotificationName::AVDisplayManagerModeSwitchSettingsChanged,
+        NotificationName::AVDisplayManagerModeSwitchStart,
+        NotificationName::AVPlayerAvailableHDRModesDidChange,
+        NotificationName::AVRouteDetectorMultipleRoutesDetectedDidChange,
+        NotificationName::AVSampleBufferAudioRendererWasFlushedAutomatically,
+        NotificationName::CTServiceRadioAccessTechnologyDidChange,
+        NotificationName::GKPlayerAuthenticationDidChangeNotificationName,
+        NotificationName::GKPlayerDidChangeNotificationName,
+        NotificationName::NEDNSProxyConfigurationDidChange,
+        NotificationName::NSPersistentStoreRemoteChange,
+        NotificationName::SKStorefrontCountryCodeDidChange,
+        NotificationName::WKAccessibilityReduceMotionStatusDidChange,
+    ];
+
+    #[test]
+    fn every_name_round_trips() {
+        for name in ALL {
+            assert_eq!(NotificationName::from(name.as_str()), *name, "{} did not round-trip", name.as_str());
+        }
+    }
+
+    #[test]
+    fn every_name_has_a_distinct_string() {
+        let mut seen = HashSet::new();
+
+        for name in ALL {
+            assert!(seen.insert(name.as_str()), "{} is used by more than one name", name.as_str());
         }
     }
+
+    #[test]
+    fn unknown_strings_are_custom() {
+        let name = NotificationName::from("MyAppDidRefreshNotification");
+        assert_eq!(name, NotificationName::Custom("MyAppDidRefreshNotification"));
+        assert_eq!(name.as_str(), "MyAppDidRefreshNotification");
+    }
 }
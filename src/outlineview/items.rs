@@ -0,0 +1,88 @@
+//! `NSOutlineView` identifies items by object pointer, and expects the same object back for the
+//! same item across calls. This module hands out a stable Objective-C object per Rust item, and
+//! caches each parent's children between reloads.
+
+use std::collections::HashMap;
+use std::hash::Hash;
+
+use objc_id::ShareId;
+use objc::runtime::Object;
+use objc::{class, msg_send, sel, sel_impl};
+
+use crate::foundation::{id, nil};
+
+/// Maps Rust items to the Objective-C objects that stand in for them, and back.
+#[derive(Debug)]
+pub(crate) struct ItemRegistry<I> {
+    objects: HashMap<I, ShareId<Object>>,
+    items: HashMap<usize, I>,
+    children: HashMap<Option<I>, Vec<I>>
+}
+
+impl<I> ItemRegistry<I>
+where
+    I: Clone + Eq + Hash
+{
+    /// Returns a new, empty registry.
+    pub fn new() -> Self {
+        ItemRegistry {
+            objects: HashMap::new(),
+            items: HashMap::new(),
+            children: HashMap::new()
+        }
+    }
+
+    /// Returns the object standing in for `item`, creating one if need be.
+    pub fn object_for(&mut self, item: &I) -> id {
+        if let Some(object) = self.objects.get(item) {
+            return &**object as *const Object as id;
+        }
+
+        let object: ShareId<Object> = unsafe {
+            ShareId::from_retained_ptr(msg_send![class!(NSObject), new])
+        };
+
+        let ptr = &*object as *const Object as id;
+        self.items.insert(ptr as usize, item.clone());
+        self.objects.insert(item.clone(), object);
+        ptr
+    }
+
+    /// Returns the object standing in for `item`, if the outline view has ever been handed one.
+    pub fn existing_object_for(&self, item: &I) -> Option<id> {
+        self.objects.get(item).map(|object| &**object as *const Object as id)
+    }
+
+    /// Returns the item that `object` stands in for. `nil` is the (hidden) root, and maps to
+    /// `None`, as do objects this registry didn't vend.
+    pub fn item_for(&self, object: id) -> Option<I> {
+        match object == nil {
+            true => None,
+            false => self.items.get(&(object as usize)).cloned()
+        }
+    }
+
+    /// Returns the cached children of `parent`, if there are any.
+    pub fn cached_children(&self, parent: Option<&I>) -> Option<&Vec<I>> {
+        self.children.get(&parent.cloned())
+    }
+
+    /// Caches the children of `parent`, registering an object for each.
+    pub fn cache_children(&mut self, parent: Option<&I>, children: Vec<I>) {
+        for child in &children {
+            self.object_for(child);
+        }
+
+        self.children.insert(parent.cloned(), children);
+    }
+
+    /// Drops the cached children of `parent`, so they're asked for again.
+    pub fn invalidate_children(&mut self, parent: Option<&I>) {
+        self.children.remove(&parent.cloned());
+    }
+
+    /// Drops every cached child list.
+    pub fn invalidate_all_children(&mut self) {
+        self.children.clear();
+    }
+}
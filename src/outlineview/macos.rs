@@ -0,0 +1,327 @@
+//! Registers the `NSOutlineView` subclass that backs `OutlineView`, and forwards data source and
+//! delegate calls on to the Rust side.
+
+use std::cell::RefCell;
+
+use objc::runtime::{Class, Object, Sel, BOOL};
+use objc::{class, sel, sel_impl, msg_send};
+
+use crate::foundation::{load_or_register_class, id, nil, YES, NO, NSArray, NSInteger, NSString, NSUInteger};
+use crate::listview::index_set_to_vec;
+use crate::outlineview::{OUTLINEVIEW_DELEGATE_PTR, OUTLINEVIEW_ITEMS_PTR, OUTLINEVIEW_DRAG_TYPE, OutlineViewDelegate};
+use crate::outlineview::items::ItemRegistry;
+use crate::utils::load;
+use crate::panic::AddGuardedMethod;
+
+/// Returns the children of `parent`, asking the delegate if they're not cached.
+fn children_of<T: OutlineViewDelegate>(this: &Object, parent: id) -> Vec<T::Item> {
+    let items = load::<RefCell<ItemRegistry<T::Item>>>(this, OUTLINEVIEW_ITEMS_PTR);
+    let parent = items.borrow().item_for(parent);
+
+    if let Some(children) = items.borrow().cached_children(parent.as_ref()) {
+        return children.clone();
+    }
+
+    // Ask outside of the borrow, as the delegate may well call back into the `OutlineView`.
+    let view = load::<T>(this, OUTLINEVIEW_DELEGATE_PTR);
+    let children = view.children_of(parent.as_ref());
+    items.borrow_mut().cache_children(parent.as_ref(), children.clone());
+    children
+}
+
+/// Returns the item that `object` stands in for.
+fn item_for<T: OutlineViewDelegate>(this: &Object, object: id) -> Option<T::Item> {
+    let items = load::<RefCell<ItemRegistry<T::Item>>>(this, OUTLINEVIEW_ITEMS_PTR);
+    let item = items.borrow().item_for(object);
+    item
+}
+
+/// Returns the items being dragged within this outline, if `info` is such a drag.
+fn dragged_items<T: OutlineViewDelegate>(this: &Object, info: id) -> Vec<T::Item> {
+    let source: id = unsafe { msg_send![info, draggingSource] };
+    if source != this as *const Object as id {
+        return vec![];
+    }
+
+    let pasteboard: id = unsafe { msg_send![info, draggingPasteboard] };
+    let pasteboard_items = NSArray::wrap(unsafe { msg_send![pasteboard, pasteboardItems] });
+    let items = load::<RefCell<ItemRegistry<T::Item>>>(this, OUTLINEVIEW_ITEMS_PTR);
+    let items = items.borrow();
+
+    pasteboard_items.map(|pasteboard_item| {
+        let value: id = unsafe {
+            msg_send![pasteboard_item, stringForType:NSString::new(OUTLINEVIEW_DRAG_TYPE).into_inner()]
+        };
+
+        match value == nil {
+            true => None,
+            false => NSString::wrap(value).to_str().parse::<usize>().ok()
+        }
+    }).into_iter().filter_map(|ptr| ptr.and_then(|ptr| items.item_for(ptr as id))).collect()
+}
+
+/// Converts a proposed child index into an `Option`, where `-1` (i.e, a drop onto the item
+/// itself) is `None`.
+fn drop_index(index: NSInteger) -> Option<usize> {
+    match index < 0 {
+        true => None,
+        false => Some(index as usize)
+    }
+}
+
+/// Returns the number of children for a given item.
+fn number_of_children<T: OutlineViewDelegate>(this: &Object, _: Sel, _outline_view: id, item: id) -> NSInteger {
+    children_of::<T>(this, item).len() as NSInteger
+}
+
+/// Returns the object for a given child of an item.
+fn child_of_item<T: OutlineViewDelegate>(this: &Object, _: Sel, _outline_view: id, index: NSInteger, item: id) -> id {
+    let children = children_of::<T>(this, item);
+
+    match children.get(index as usize) {
+        Some(child) => {
+            let items = load::<RefCell<ItemRegistry<T::Item>>>(this, OUTLINEVIEW_ITEMS_PTR);
+            let object = items.borrow_mut().object_for(child);
+            object
+        },
+
+        None => nil
+    }
+}
+
+/// Returns whether an item can be expanded.
+fn is_item_expandable<T: OutlineViewDelegate>(this: &Object, _: Sel, _outline_view: id, item: id) -> BOOL {
+    match item_for::<T>(this, item) {
+        Some(item) => {
+            let view = load::<T>(this, OUTLINEVIEW_DELEGATE_PTR);
+
+            match view.is_expandable(&item) {
+                true => YES,
+                false => NO
+            }
+        },
+
+        None => NO
+    }
+}
+
+/// Vends the view for a given item.
+fn view_for_item<T: OutlineViewDelegate>(this: &Object, _: Sel, _outline_view: id, _column: id, item: id) -> id {
+    match item_for::<T>(this, item) {
+        Some(item) => {
+            let view = load::<T>(this, OUTLINEVIEW_DELEGATE_PTR);
+            let row = view.view_for(&item);
+            let objc = row.objc.borrow();
+
+            // See the matching note in `ListView` - the outline view retains the row.
+            unsafe {
+                msg_send![&**objc, self]
+            }
+        },
+
+        None => nil
+    }
+}
+
+/// Returns whether an item should expand.
+fn should_expand_item<T: OutlineViewDelegate>(this: &Object, _: Sel, _outline_view: id, item: id) -> BOOL {
+    match item_for::<T>(this, item) {
+        Some(item) => {
+            let view = load::<T>(this, OUTLINEVIEW_DELEGATE_PTR);
+
+            match view.should_expand(&item) {
+                true => YES,
+                false => NO
+            }
+        },
+
+        None => YES
+    }
+}
+
+/// Returns whether an item should collapse.
+fn should_collapse_item<T: OutlineViewDelegate>(this: &Object, _: Sel, _outline_view: id, item: id) -> BOOL {
+    match item_for::<T>(this, item) {
+        Some(item) => {
+            let view = load::<T>(this, OUTLINEVIEW_DELEGATE_PTR);
+
+            match view.should_collapse(&item) {
+                true => YES,
+                false => NO
+            }
+        },
+
+        None => YES
+    }
+}
+
+/// Returns the item from an expand/collapse notification.
+fn notification_item<T: OutlineViewDelegate>(this: &Object, notification: id) -> Option<T::Item> {
+    let object: id = unsafe {
+        let user_info: id = msg_send![notification, userInfo];
+        msg_send![user_info, objectForKey:NSString::new("NSObject").into_inner()]
+    };
+
+    item_for::<T>(this, object)
+}
+
+/// Called after an item has expanded.
+fn item_did_expand<T: OutlineViewDelegate>(this: &Object, _: Sel, notification: id) {
+    if let Some(item) = notification_item::<T>(this, notification) {
+        let view = load::<T>(this, OUTLINEVIEW_DELEGATE_PTR);
+        view.item_did_expand(&item);
+    }
+}
+
+/// Called after an item has collapsed.
+fn item_did_collapse<T: OutlineViewDelegate>(this: &Object, _: Sel, notification: id) {
+    if let Some(item) = notification_item::<T>(this, notification) {
+        let view = load::<T>(this, OUTLINEVIEW_DELEGATE_PTR);
+        view.item_did_collapse(&item);
+    }
+}
+
+/// Called when the selection has changed.
+fn selection_did_change<T: OutlineViewDelegate>(this: &Object, _: Sel, _: id) {
+    let rows = unsafe {
+        let index_set: id = msg_send![this, selectedRowIndexes];
+        index_set_to_vec(index_set)
+    };
+
+    let selected = rows.into_iter().filter_map(|row| {
+        let object: id = unsafe { msg_send![this, itemAtRow:row as NSInteger] };
+        item_for::<T>(this, object)
+    }).collect();
+
+    let view = load::<T>(this, OUTLINEVIEW_DELEGATE_PTR);
+    view.selection_did_change(selected);
+}
+
+/// Returns the object that an item's expansion state is autosaved under.
+fn persistent_object_for_item<T: OutlineViewDelegate>(this: &Object, _: Sel, _outline_view: id, item: id) -> id {
+    let persistent_id = item_for::<T>(this, item).and_then(|item| {
+        let view = load::<T>(this, OUTLINEVIEW_DELEGATE_PTR);
+        view.persistent_id_for(&item)
+    });
+
+    match persistent_id {
+        Some(persistent_id) => NSString::new(&persistent_id).into_inner(),
+        None => nil
+    }
+}
+
+/// Returns the item for an autosaved object.
+fn item_for_persistent_object<T: OutlineViewDelegate>(this: &Object, _: Sel, _outline_view: id, object: id) -> id {
+    if object == nil || !NSString::is(object) {
+        return nil;
+    }
+
+    let view = load::<T>(this, OUTLINEVIEW_DELEGATE_PTR);
+    let item = view.item_for_persistent_id(NSString::wrap(object).to_str());
+
+    match item {
+        Some(item) => {
+            let items = load::<RefCell<ItemRegistry<T::Item>>>(this, OUTLINEVIEW_ITEMS_PTR);
+            let object = items.borrow_mut().object_for(&item);
+            object
+        },
+
+        None => nil
+    }
+}
+
+/// Vends a pasteboard writer for a dragged item. The pasteboard carries the address of the
+/// object standing in for the item, which only means anything to this outline view.
+fn pasteboard_writer_for_item<T: OutlineViewDelegate>(_this: &Object, _: Sel, _outline_view: id, item: id) -> id {
+    unsafe {
+        let pasteboard_item: id = msg_send![class!(NSPasteboardItem), new];
+        let value = NSString::new(&(item as usize).to_string());
+        let _: () = msg_send![pasteboard_item, setString:value.into_inner() forType:NSString::new(OUTLINEVIEW_DRAG_TYPE).into_inner()];
+        msg_send![pasteboard_item, autorelease]
+    }
+}
+
+/// Validates a proposed drop, returning the drag operation to perform.
+fn validate_drop<T: OutlineViewDelegate>(
+    this: &Object,
+    _: Sel,
+    _outline_view: id,
+    info: id,
+    item: id,
+    index: NSInteger
+) -> NSUInteger {
+    let dragged = dragged_items::<T>(this, info);
+    if dragged.is_empty() {
+        return 0;
+    }
+
+    let parent = item_for::<T>(this, item);
+    let view = load::<T>(this, OUTLINEVIEW_DELEGATE_PTR);
+
+    // NSDragOperationMove, or NSDragOperationNone.
+    match view.validate_drop(&dragged, parent.as_ref(), drop_index(index)) {
+        true => 16,
+        false => 0
+    }
+}
+
+/// Accepts a drop.
+fn accept_drop<T: OutlineViewDelegate>(
+    this: &Object,
+    _: Sel,
+    _outline_view: id,
+    info: id,
+    item: id,
+    index: NSInteger
+) -> BOOL {
+    let dragged = dragged_items::<T>(this, info);
+    if dragged.is_empty() {
+        return NO;
+    }
+
+    let parent = item_for::<T>(this, item);
+    let view = load::<T>(this, OUTLINEVIEW_DELEGATE_PTR);
+
+    match view.accept_drop(dragged, parent, drop_index(index)) {
+        true => YES,
+        false => NO
+    }
+}
+
+/// Enforces normalcy, or: a needlessly cruel method in terms of the name. You get the idea though.
+fn enforce_normalcy(_: &Object, _: Sel) -> BOOL {
+    return YES;
+}
+
+/// Injects an `NSOutlineView` subclass, with some callback and pointer ivars for what we
+/// need to do.
+pub(crate) fn register_outlineview_class_with_delegate<T: OutlineViewDelegate>(instance: &T) -> *const Class {
+    load_or_register_class("NSOutlineView", instance.subclass_name(), |decl| unsafe {
+        decl.add_ivar::<usize>(OUTLINEVIEW_DELEGATE_PTR);
+        decl.add_ivar::<usize>(OUTLINEVIEW_ITEMS_PTR);
+
+        decl.add_guarded_method(sel!(isFlipped), enforce_normalcy);
+
+        // Data source
+        decl.add_guarded_method(sel!(outlineView:numberOfChildrenOfItem:), number_of_children::<T>);
+        decl.add_guarded_method(sel!(outlineView:child:ofItem:), child_of_item::<T>);
+        decl.add_guarded_method(sel!(outlineView:isItemExpandable:), is_item_expandable::<T>);
+        decl.add_guarded_method(sel!(outlineView:viewForTableColumn:item:), view_for_item::<T>);
+        decl.add_guarded_method(sel!(outlineView:persistentObjectForItem:), persistent_object_for_item::<T>);
+        decl.add_guarded_method(sel!(outlineView:itemForPersistentObject:), item_for_persistent_object::<T>);
+
+        // Expanding and collapsing
+        decl.add_guarded_method(sel!(outlineView:shouldExpandItem:), should_expand_item::<T>);
+        decl.add_guarded_method(sel!(outlineView:shouldCollapseItem:), should_collapse_item::<T>);
+        decl.add_guarded_method(sel!(outlineViewItemDidExpand:), item_did_expand::<T>);
+        decl.add_guarded_method(sel!(outlineViewItemDidCollapse:), item_did_collapse::<T>);
+
+        // Selection
+        decl.add_guarded_method(sel!(outlineViewSelectionDidChange:), selection_did_change::<T>);
+
+        // Drag and drop (reordering)
+        decl.add_guarded_method(sel!(outlineView:pasteboardWriterForItem:), pasteboard_writer_for_item::<T>);
+        decl.add_guarded_method(sel!(outlineView:validateDrop:proposedItem:proposedChildIndex:), validate_drop::<T>);
+        decl.add_guarded_method(sel!(outlineView:acceptDrop:item:childIndex:), accept_drop::<T>);
+    })
+}
@@ -0,0 +1,527 @@
+//! Wraps `NSOutlineView`, for displaying hierarchical data - file trees, project sidebars, and so
+//! on.
+//!
+//! Your delegate picks an `Item` type to identify rows by, and supplies the children of each item
+//! on demand; `OutlineView` takes care of mapping those to the objects `NSOutlineView` wants.
+//!
+//! ```rust,no_run
+//! use std::path::PathBuf;
+//!
+//! use cacao::listview::ListViewRow;
+//! use cacao::outlineview::{OutlineView, OutlineViewDelegate};
+//!
+//! #[derive(Default)]
+//! struct FileTree {
+//!     view: Option<OutlineView<FileTree>>
+//! }
+//!
+//! impl OutlineViewDelegate for FileTree {
+//!     const NAME: &'static str = "FileTree";
+//!     type Item = PathBuf;
+//!
+//!     fn did_load(&mut self, view: OutlineView<FileTree>) {
+//!         view.set_source_list_style(true);
+//!         view.set_autosave_name("FileTree");
+//!         self.view = Some(view);
+//!     }
+//!
+//!     fn children_of(&self, parent: Option<&PathBuf>) -> Vec<PathBuf> {
+//!         let parent = parent.cloned().unwrap_or_else(|| PathBuf::from("/"));
+//!
+//!         std::fs::read_dir(parent).map(|entries| {
+//!             entries.filter_map(|entry| entry.ok().map(|entry| entry.path())).collect()
+//!         }).unwrap_or_default()
+//!     }
+//!
+//!     fn is_expandable(&self, item: &PathBuf) -> bool {
+//!         item.is_dir()
+//!     }
+//!
+//!     fn view_for(&self, item: &PathBuf) -> ListViewRow {
+//!         ListViewRow::new()
+//!     }
+//!
+//!     fn persistent_id_for(&self, item: &PathBuf) -> Option<String> {
+//!         item.to_str().map(|path| path.to_string())
+//!     }
+//!
+//!     fn item_for_persistent_id(&self, id: &str) -> Option<PathBuf> {
+//!         Some(PathBuf::from(id))
+//!     }
+//! }
+//! ```
+
+use std::cell::RefCell;
+use std::rc::Rc;
+
+use core_graphics::base::CGFloat;
+use objc_id::ShareId;
+use objc::runtime::{Class, Object};
+use objc::{class, msg_send, sel, sel_impl};
+
+use crate::foundation::{id, nil, to_bool, BOOL, YES, NO, NSArray, NSString, NSInteger, NSUInteger};
+use crate::layout::{Layout, LayoutAnchorX, LayoutAnchorY, LayoutAnchorDimension};
+use crate::listview::{index_set_to_vec, CellFactory, ListViewRow, RowAnimation};
+use crate::scrollview::ScrollView;
+use crate::view::ViewDelegate;
+
+mod items;
+use items::ItemRegistry;
+
+mod macos;
+use macos::register_outlineview_class_with_delegate;
+
+mod traits;
+pub use traits::OutlineViewDelegate;
+
+pub(crate) static OUTLINEVIEW_DELEGATE_PTR: &str = "rstOutlineViewDelegatePtr";
+pub(crate) static OUTLINEVIEW_ITEMS_PTR: &str = "rstOutlineViewItemsPtr";
+
+/// The pasteboard type used when dragging items around within an outline.
+pub(crate) static OUTLINEVIEW_DRAG_TYPE: &str = "com.cacao.outlineview.item";
+
+/// A helper method for instantiating view classes and applying default settings to them.
+fn common_init(class: *const Class) -> id {
+    unsafe {
+        let outline_view: id = msg_send![class, new];
+        let _: () = msg_send![outline_view, setTranslatesAutoresizingMaskIntoConstraints:NO];
+        let _: () = msg_send![outline_view, setWantsLayer:YES];
+        let _: () = msg_send![outline_view, setUsesAutomaticRowHeights:YES];
+        let _: () = msg_send![outline_view, setColumnAutoresizingStyle:1];
+        let _: () = msg_send![outline_view, setHeaderView:nil];
+
+        // Like `ListView`, this is a single column - which is also the column that draws the
+        // disclosure triangles and indentation.
+        let identifier = NSString::new("CacaoOutlineViewColumn");
+        let column: id = msg_send![class!(NSTableColumn), alloc];
+        let column: id = msg_send![column, initWithIdentifier:identifier.into_inner()];
+        let _: () = msg_send![column, setResizingMask:(1<<0)];
+        let _: () = msg_send![outline_view, addTableColumn:column];
+        let _: () = msg_send![outline_view, setOutlineTableColumn:column];
+
+        outline_view
+    }
+}
+
+/// An outline (i.e, a tree-structured list), backed by `NSOutlineView` inside an `NSScrollView`.
+pub struct OutlineView<T: OutlineViewDelegate> {
+    /// Internal map of cell identifers/vendors, for row reuse.
+    cell_factory: CellFactory,
+
+    /// The objects standing in for items on the Objective-C side, shared with any handles.
+    items: Rc<RefCell<ItemRegistry<T::Item>>>,
+
+    /// A pointer to the Objective-C runtime view controller.
+    pub objc: ShareId<Object>,
+
+    /// The `NSScrollView` that hosts the outline. Layout anchors refer to this.
+    pub scrollview: ScrollView,
+
+    /// A pointer to the delegate for this view. This is `None` for the handle passed to
+    /// `OutlineViewDelegate::did_load()`.
+    pub delegate: Option<Box<T>>,
+
+    /// A pointer to the Objective-C runtime top layout constraint.
+    pub top: LayoutAnchorY,
+
+    /// A pointer to the Objective-C runtime leading layout constraint.
+    pub leading: LayoutAnchorX,
+
+    /// A pointer to the Objective-C runtime trailing layout constraint.
+    pub trailing: LayoutAnchorX,
+
+    /// A pointer to the Objective-C runtime bottom layout constraint.
+    pub bottom: LayoutAnchorY,
+
+    /// A pointer to the Objective-C runtime width layout constraint.
+    pub width: LayoutAnchorDimension,
+
+    /// A pointer to the Objective-C runtime height layout constraint.
+    pub height: LayoutAnchorDimension,
+
+    /// A pointer to the Objective-C runtime center X layout constraint.
+    pub center_x: LayoutAnchorX,
+
+    /// A pointer to the Objective-C runtime center Y layout constraint.
+    pub center_y: LayoutAnchorY
+}
+
+impl<T: OutlineViewDelegate> std::fmt::Debug for OutlineView<T> {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        f.debug_struct("OutlineView")
+            .field("objc", &self.objc)
+            .field("scrollview", &self.scrollview)
+            .finish()
+    }
+}
+
+impl<T> OutlineView<T> where T: OutlineViewDelegate + 'static {
+    /// Initializes a new OutlineView with a given `OutlineViewDelegate`, which supplies the
+    /// items to display.
+    pub fn with(delegate: T) -> OutlineView<T> {
+        let class = register_outlineview_class_with_delegate::<T>(&delegate);
+        let view = common_init(class);
+        let mut delegate = Box::new(delegate);
+        let items = Rc::new(RefCell::new(ItemRegistry::new()));
+
+        unsafe {
+            let delegate_ptr: *const T = &*delegate;
+            let items_ptr: *const RefCell<ItemRegistry<T::Item>> = &*items;
+            (&mut *view).set_ivar(OUTLINEVIEW_DELEGATE_PTR, delegate_ptr as usize);
+            (&mut *view).set_ivar(OUTLINEVIEW_ITEMS_PTR, items_ptr as usize);
+            let _: () = msg_send![view, setDelegate:view];
+            let _: () = msg_send![view, setDataSource:view];
+        }
+
+        let scrollview = ScrollView::new();
+
+        unsafe {
+            let _: () = msg_send![&*scrollview.objc, setDocumentView:view];
+        }
+
+        let anchor_view = &*scrollview.objc;
+
+        let mut view = OutlineView {
+            cell_factory: CellFactory::new(),
+            items: items,
+            delegate: None,
+            top: LayoutAnchorY::new(unsafe { msg_send![anchor_view, topAnchor] }),
+            leading: LayoutAnchorX::new(unsafe { msg_send![anchor_view, leadingAnchor] }),
+            trailing: LayoutAnchorX::new(unsafe { msg_send![anchor_view, trailingAnchor] }),
+            bottom: LayoutAnchorY::new(unsafe { msg_send![anchor_view, bottomAnchor] }),
+            width: LayoutAnchorDimension::new(unsafe { msg_send![anchor_view, widthAnchor] }),
+            height: LayoutAnchorDimension::new(unsafe { msg_send![anchor_view, heightAnchor] }),
+            center_x: LayoutAnchorX::new(unsafe { msg_send![anchor_view, centerXAnchor] }),
+            center_y: LayoutAnchorY::new(unsafe { msg_send![anchor_view, centerYAnchor] }),
+            objc: unsafe { ShareId::from_ptr(view) },
+            scrollview: scrollview
+        };
+
+        (&mut delegate).did_load(view.clone_as_handle());
+        view.delegate = Some(delegate);
+        view
+    }
+}
+
+impl<T> OutlineView<T> where T: OutlineViewDelegate {
+    /// An internal method that returns a clone of this object, sans references to the delegate.
+    /// We use this in calling `did_load()` - implementing delegates get a way to reference,
+    /// customize and use the view but without the trickery of holding pieces of the delegate -
+    /// the `OutlineView` is the only true holder of those.
+    pub(crate) fn clone_as_handle(&self) -> OutlineView<T> {
+        OutlineView {
+            cell_factory: self.cell_factory.clone(),
+            items: Rc::clone(&self.items),
+            delegate: None,
+            top: self.top.clone(),
+            leading: self.leading.clone(),
+            trailing: self.trailing.clone(),
+            bottom: self.bottom.clone(),
+            width: self.width.clone(),
+            height: self.height.clone(),
+            center_x: self.center_x.clone(),
+            center_y: self.center_y.clone(),
+            objc: self.objc.clone(),
+            scrollview: self.scrollview.clone_as_handle()
+        }
+    }
+
+    /// Returns the object standing in for `item`, or `nil` (the root) for `None`.
+    fn object_for(&self, item: Option<&T::Item>) -> id {
+        match item {
+            Some(item) => self.items.borrow_mut().object_for(item),
+            None => nil
+        }
+    }
+
+    /// Register a row vendor function with an identifier. This is stored internally and used
+    /// for row-reuse.
+    pub fn register<F, R>(&self, identifier: &'static str, vendor: F)
+    where
+        F: Fn() -> R + 'static,
+        R: ViewDelegate + 'static
+    {
+        self.cell_factory.insert(identifier, vendor);
+    }
+
+    /// Dequeue a reusable row. If one is not in the queue, will create and cache one for reuse.
+    pub fn dequeue<R: ViewDelegate + 'static>(&self, identifier: &'static str) -> ListViewRow<R> {
+        unsafe {
+            let key = NSString::new(identifier).into_inner();
+            let cell: id = msg_send![&*self.objc, makeViewWithIdentifier:key owner:nil];
+
+            if cell != nil {
+                ListViewRow::from_cached(cell)
+            } else {
+                let delegate: Box<R> = self.cell_factory.get(identifier);
+                let view = ListViewRow::with_boxed(delegate);
+                view.set_identifier(identifier);
+                view
+            }
+        }
+    }
+
+    /// Reloads the entire outline, asking the delegate for everything again. Expanded items stay
+    /// expanded, so long as they're still there.
+    pub fn reload(&self) {
+        self.items.borrow_mut().invalidate_all_children();
+
+        unsafe {
+            let _: () = msg_send![&*self.objc, reloadData];
+        }
+    }
+
+    /// Reloads `item` (or the root, for `None`), and - if `reload_children` is `true` - its
+    /// children, asking the delegate for them again.
+    pub fn reload_item(&self, item: Option<&T::Item>, reload_children: bool) {
+        if reload_children {
+            self.items.borrow_mut().invalidate_all_children();
+        }
+
+        let object = self.object_for(item);
+
+        unsafe {
+            let _: () = msg_send![&*self.objc, reloadItem:object reloadChildren:match reload_children {
+                true => YES,
+                false => NO
+            }];
+        }
+    }
+
+    /// Inserts children of `parent` at the given indexes, which refer to positions once the
+    /// insert is done. Your delegate should already reflect the new children when this is called.
+    pub fn insert_items(&self, parent: Option<&T::Item>, indexes: &[usize], animation: RowAnimation) {
+        self.items.borrow_mut().invalidate_children(parent);
+        let parent = self.object_for(parent);
+
+        unsafe {
+            let index_set: id = msg_send![class!(NSMutableIndexSet), new];
+
+            for index in indexes {
+                let x: NSUInteger = *index as NSUInteger;
+                let _: () = msg_send![index_set, addIndex:x];
+            }
+
+            let animation_options: NSUInteger = animation.into();
+            let x = ShareId::from_retained_ptr(index_set);
+            let _: () = msg_send![&*self.objc, insertItemsAtIndexes:&*x inParent:parent withAnimation:animation_options];
+        }
+    }
+
+    /// Removes children of `parent` at the given indexes, which refer to positions before the
+    /// removal.
+    pub fn remove_items(&self, parent: Option<&T::Item>, indexes: &[usize], animation: RowAnimation) {
+        self.items.borrow_mut().invalidate_children(parent);
+        let parent = self.object_for(parent);
+
+        unsafe {
+            let index_set: id = msg_send![class!(NSMutableIndexSet), new];
+
+            for index in indexes {
+                let x: NSUInteger = *index as NSUInteger;
+                let _: () = msg_send![index_set, addIndex:x];
+            }
+
+            let animation_options: NSUInteger = animation.into();
+            let x = ShareId::from_retained_ptr(index_set);
+            let _: () = msg_send![&*self.objc, removeItemsAtIndexes:&*x inParent:parent withAnimation:animation_options];
+        }
+    }
+
+    /// Moves the child at `from_index` of `from_parent` so that it ends up at `to_index` of
+    /// `to_parent`. This is handy for applying a drop in `OutlineViewDelegate::accept_drop()`.
+    pub fn move_item(&self, from_parent: Option<&T::Item>, from_index: usize, to_parent: Option<&T::Item>, to_index: usize) {
+        {
+            let mut items = self.items.borrow_mut();
+            items.invalidate_children(from_parent);
+            items.invalidate_children(to_parent);
+        }
+
+        let from_parent = self.object_for(from_parent);
+        let to_parent = self.object_for(to_parent);
+
+        unsafe {
+            let from_index = from_index as NSInteger;
+            let to_index = to_index as NSInteger;
+            let _: () = msg_send![&*self.objc, moveItemAtIndex:from_index inParent:from_parent toIndex:to_index inParent:to_parent];
+        }
+    }
+
+    /// Expands `item`. If `recursive` is `true`, its descendants are expanded too.
+    pub fn expand(&self, item: &T::Item, animated: bool, recursive: bool) {
+        self.expand_object(self.object_for(Some(item)), animated, recursive);
+    }
+
+    /// Collapses `item`. If `recursive` is `true`, its descendants are collapsed too.
+    pub fn collapse(&self, item: &T::Item, animated: bool, recursive: bool) {
+        self.collapse_object(self.object_for(Some(item)), animated, recursive);
+    }
+
+    /// Expands every item in the outline.
+    pub fn expand_all(&self, animated: bool) {
+        self.expand_object(nil, animated, true);
+    }
+
+    /// Collapses every item in the outline.
+    pub fn collapse_all(&self, animated: bool) {
+        self.collapse_object(nil, animated, true);
+    }
+
+    /// Returns the view (or its animator proxy) to send expand/collapse calls to.
+    fn target(&self, animated: bool) -> id {
+        let objc = &*self.objc as *const Object as id;
+
+        match animated {
+            true => unsafe { msg_send![objc, animator] },
+            false => objc
+        }
+    }
+
+    fn expand_object(&self, object: id, animated: bool, recursive: bool) {
+        unsafe {
+            let _: () = msg_send![self.target(animated), expandItem:object expandChildren:match recursive {
+                true => YES,
+                false => NO
+            }];
+        }
+    }
+
+    fn collapse_object(&self, object: id, animated: bool, recursive: bool) {
+        unsafe {
+            let _: () = msg_send![self.target(animated), collapseItem:object collapseChildren:match recursive {
+                true => YES,
+                false => NO
+            }];
+        }
+    }
+
+    /// Returns whether `item` is currently expanded.
+    pub fn is_expanded(&self, item: &T::Item) -> bool {
+        match self.items.borrow().existing_object_for(item) {
+            Some(object) => unsafe {
+                let expanded: BOOL = msg_send![&*self.objc, isItemExpanded:object];
+                to_bool(expanded)
+            },
+
+            None => false
+        }
+    }
+
+    /// Sets the name that expanded items are saved under (in the user's defaults), and restores
+    /// the saved state. Only items with a `OutlineViewDelegate::persistent_id_for()` are saved.
+    pub fn set_autosave_name(&self, name: &str) {
+        unsafe {
+            let _: () = msg_send![&*self.objc, setAutosaveName:NSString::new(name).into_inner()];
+            let _: () = msg_send![&*self.objc, setAutosaveExpandedItems:YES];
+        }
+    }
+
+    /// Styles the outline as a source list - i.e, the translucent sidebar look you'd see in
+    /// Finder or Mail.
+    pub fn set_source_list_style(&self, source_list: bool) {
+        unsafe {
+            // NSTableViewSelectionHighlightStyleSourceList, or NSTableViewSelectionHighlightStyleRegular
+            let style: NSInteger = match source_list {
+                true => 1,
+                false => 0
+            };
+
+            let _: () = msg_send![&*self.objc, setSelectionHighlightStyle:style];
+        }
+    }
+
+    /// Sets the per-level indentation for child items.
+    pub fn set_indentation(&self, indentation: CGFloat) {
+        unsafe {
+            let _: () = msg_send![&*self.objc, setIndentationPerLevel:indentation];
+        }
+    }
+
+    /// Sets whether items can be dragged around within the outline. Drops are validated and
+    /// applied by `OutlineViewDelegate::validate_drop()` and `accept_drop()`.
+    pub fn set_allows_reordering(&self, allows: bool) {
+        unsafe {
+            match allows {
+                true => {
+                    let types = NSArray::new(&[NSString::new(OUTLINEVIEW_DRAG_TYPE).into_inner()]);
+                    let _: () = msg_send![&*self.objc, registerForDraggedTypes:types.into_inner()];
+
+                    // NSDragOperationMove, within this application only.
+                    let mask: NSUInteger = 16;
+                    let _: () = msg_send![&*self.objc, setDraggingSourceOperationMask:mask forLocal:YES];
+                },
+
+                false => {
+                    let _: () = msg_send![&*self.objc, unregisterDraggedTypes];
+                }
+            }
+        }
+    }
+
+    /// Returns the selected items, in row order.
+    pub fn selected_items(&self) -> Vec<T::Item> {
+        let rows = unsafe {
+            let index_set: id = msg_send![&*self.objc, selectedRowIndexes];
+            index_set_to_vec(index_set)
+        };
+
+        let items = self.items.borrow();
+
+        rows.into_iter().filter_map(|row| {
+            let object: id = unsafe { msg_send![&*self.objc, itemAtRow:row as NSInteger] };
+            items.item_for(object)
+        }).collect()
+    }
+
+    /// Selects `item`, if it's visible (i.e, all of its ancestors are expanded). If `extend` is
+    /// `true`, it's added to the current selection.
+    pub fn select_item(&self, item: &T::Item, extend: bool) {
+        let object = match self.items.borrow().existing_object_for(item) {
+            Some(object) => object,
+            None => { return; }
+        };
+
+        unsafe {
+            let row: NSInteger = msg_send![&*self.objc, rowForItem:object];
+            if row < 0 {
+                return;
+            }
+
+            let index_set: id = msg_send![class!(NSIndexSet), indexSetWithIndex:row as NSUInteger];
+            let _: () = msg_send![&*self.objc, selectRowIndexes:index_set byExtendingSelection:match extend {
+                true => YES,
+                false => NO
+            }];
+        }
+    }
+}
+
+impl<T: OutlineViewDelegate> Layout for OutlineView<T> {
+    /// This returns the NSScrollView, not the NSOutlineView.
+    fn get_backing_node(&self) -> ShareId<Object> {
+        self.scrollview.objc.clone()
+    }
+
+    fn add_subview<V: Layout>(&self, view: &V) {
+        let backing_node = view.get_backing_node();
+
+        unsafe {
+            let _: () = msg_send![&*self.scrollview.objc, addSubview:backing_node];
+        }
+    }
+}
+
+impl<T: OutlineViewDelegate> Drop for OutlineView<T> {
+    /// If the originating `OutlineView` is being dropped, remove it from the view heirarchy, to
+    /// match the semantics of how Rust handles things.
+    fn drop(&mut self) {
+        if self.delegate.is_some() {
+            unsafe {
+                let superview: id = msg_send![&*self.scrollview.objc, superview];
+                if superview != nil {
+                    let _: () = msg_send![&*self.scrollview.objc, removeFromSuperview];
+                }
+            }
+        }
+    }
+}
@@ -0,0 +1,75 @@
+//! Various traits used for OutlineViews.
+
+use std::hash::Hash;
+
+use crate::listview::ListViewRow;
+use crate::outlineview::OutlineView;
+
+#[allow(unused_variables)]
+pub trait OutlineViewDelegate {
+    /// Used to cache subclass creations on the Objective-C side.
+    /// You can just set this to be the name of your view type. This
+    /// value *must* be unique per-type.
+    const NAME: &'static str;
+
+    /// Identifies an item in the outline - e.g, a path for a file tree, or a database ID. Items
+    /// that compare equal are treated as the same row, so this should be unique across the
+    /// whole outline (not just among siblings), and cheap to clone.
+    type Item: Clone + Eq + Hash + 'static;
+
+    /// You should rarely (read: probably never) need to implement this yourself.
+    /// It simply acts as a getter for the associated `NAME` const on this trait.
+    fn subclass_name(&self) -> &'static str {
+        Self::NAME
+    }
+
+    /// Called when the OutlineView is ready to work with. You're passed an `OutlineView` - this
+    /// is safe to store and use repeatedly, but it's not thread safe - any UI calls must be made
+    /// from the main thread!
+    fn did_load(&mut self, view: OutlineView<Self>) where Self: Sized;
+
+    /// Returns the children of `parent`, in order; `None` is the root of the outline. This is
+    /// cached until the outline (or `parent`) is reloaded.
+    fn children_of(&self, parent: Option<&Self::Item>) -> Vec<Self::Item>;
+
+    /// Returns whether `item` can be expanded, i.e, whether it shows a disclosure triangle. This
+    /// is asked before `children_of()`, so it should be cheap.
+    fn is_expandable(&self, item: &Self::Item) -> bool;
+
+    /// Returns the view for `item`. Use `OutlineView::dequeue()` to reuse views as the outline
+    /// scrolls.
+    fn view_for(&self, item: &Self::Item) -> ListViewRow;
+
+    /// Return `false` to prevent `item` from being expanded.
+    fn should_expand(&self, item: &Self::Item) -> bool { true }
+
+    /// Return `false` to prevent `item` from being collapsed.
+    fn should_collapse(&self, item: &Self::Item) -> bool { true }
+
+    /// Called after `item` has been expanded.
+    fn item_did_expand(&self, item: &Self::Item) {}
+
+    /// Called after `item` has been collapsed.
+    fn item_did_collapse(&self, item: &Self::Item) {}
+
+    /// Called whenever the selection changes, with the now-selected items in row order.
+    fn selection_did_change(&self, selected: Vec<Self::Item>) {}
+
+    /// Returns a string that identifies `item` across launches. Expansion state is only saved
+    /// (see `OutlineView::set_autosave_name()`) for items that return one.
+    fn persistent_id_for(&self, item: &Self::Item) -> Option<String> { None }
+
+    /// The inverse of `persistent_id_for()`: returns the item for a saved identifier, if it
+    /// still exists.
+    fn item_for_persistent_id(&self, id: &str) -> Option<Self::Item> { None }
+
+    /// Called while items are being dragged within the outline (see
+    /// `OutlineView::set_allows_reordering()`), to ask whether they can be dropped into
+    /// `parent` (`None` being the root) at `index` - or onto `parent` itself, if `index` is
+    /// `None`. Return `false` to refuse the drop.
+    fn validate_drop(&self, items: &[Self::Item], parent: Option<&Self::Item>, index: Option<usize>) -> bool { true }
+
+    /// Called when items are dropped. Update your model, reload the affected parents (or
+    /// use `OutlineView::move_item()`), and return whether the drop was accepted.
+    fn accept_drop(&self, items: Vec<Self::Item>, parent: Option<Self::Item>, index: Option<usize>) -> bool { false }
+}
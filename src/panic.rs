@@ -0,0 +1,410 @@
+//! Panic safety for callbacks from Objective-C.
+//!
+//! Nearly every delegate method in this crate is a trampoline that Objective-C calls into, which
+//! then calls your Rust code. A panic unwinding out of one of those functions and into
+//! Objective-C frames is undefined behavior - so every trampoline catches panics at the boundary,
+//! and hands them off to a handler instead. (Internally, methods are registered with
+//! `add_guarded_method()`, which does this for them - there's no way to register one without it.)
+//!
+//! The default handler logs the panic, shows an `Alert` (on macOS) with the message, and then
+//! terminates the application. You can swap it out with `set_handler()`:
+//!
+//! ```rust,no_run
+//! cacao::panic::set_handler(|panic| {
+//!     eprintln!("Callback panicked: {}", panic.message);
+//!     std::process::exit(1);
+//! });
+//! ```
+//!
+//! If your handler returns, the callback that panicked returns a neutral value to Objective-C, and
+//! the application carries on - which may or may not be what you want, depending on what state
+//! the panic left things in. The value depends only on the return type:
+//!
+//! - `BOOL` methods return `NO`, so e.g a panicking `should_select()` refuses the selection.
+//! - Object-returning methods return `nil`, so e.g a panicking `view_for()` gets an empty row.
+//! - Numeric methods return `0`. Where that's an enum, it's whichever case has the value `0` -
+//!   notably, `AppDelegate::should_terminate()` falls back to `NSTerminateCancel`, so a panicking
+//!   delegate cancels the quit, and `dragging_entered()` falls back to `NSDragOperationNone`.
+//! - Sizes are zero, except `WindowDelegate::will_resize()`, which keeps the proposed size.
+
+use std::any::Any;
+use std::mem;
+use std::panic::{catch_unwind, AssertUnwindSafe};
+use std::sync::{Arc, RwLock};
+use std::sync::atomic::{AtomicBool, Ordering};
+
+use lazy_static::lazy_static;
+use objc::declare::{ClassDecl, MethodImplementation};
+use objc::runtime::{Object, Sel};
+use objc::Encode;
+
+use crate::foundation::{id, nil};
+use crate::utils::CGSize;
+
+/// Details about a panic that was caught at a callback boundary.
+#[derive(Clone, Debug)]
+pub struct CallbackPanic {
+    /// The panic message, if it was a string; otherwise, a placeholder.
+    pub message: String
+}
+
+impl CallbackPanic {
+    /// Builds a `CallbackPanic` from the payload handed back by `catch_unwind`.
+    fn from_payload(payload: Box<dyn Any + Send>) -> Self {
+        let message = match payload.downcast::<String>() {
+            Ok(message) => *message,
+            Err(payload) => match payload.downcast::<&'static str>() {
+                Ok(message) => message.to_string(),
+                Err(_) => "Unknown panic payload".to_string()
+            }
+        };
+
+        CallbackPanic { message }
+    }
+}
+
+type Handler = Arc<dyn Fn(&CallbackPanic) + Send + Sync + 'static>;
+
+lazy_static! {
+    static ref HANDLER: RwLock<Option<Handler>> = RwLock::new(None);
+}
+
+/// Set while a panic is being handled, so that a panic from within the handler (or from a
+/// callback it triggers, e.g during termination) doesn't loop.
+static HANDLING_PANIC: AtomicBool = AtomicBool::new(false);
+
+/// Sets the handler that's called whenever a panic is caught at a callback boundary. This replaces
+/// the default handler (and any handler previously set).
+pub fn set_handler<F>(handler: F)
+where
+    F: Fn(&CallbackPanic) + Send + Sync + 'static
+{
+    let mut slot = HANDLER.write().unwrap_or_else(|e| e.into_inner());
+    *slot = Some(Arc::new(handler));
+}
+
+/// Restores the default handler.
+pub fn reset_handler() {
+    let mut slot = HANDLER.write().unwrap_or_else(|e| e.into_inner());
+    *slot = None;
+}
+
+/// The default panic handler: logs the panic, shows an alert with the message (on macOS), and
+/// terminates the application. This is public so that custom handlers can defer to it after doing
+/// their own work (e.g, reporting).
+pub fn default_handler(panic: &CallbackPanic) {
+    eprintln!("cacao: a callback panicked, terminating: {}", panic.message);
+
+    #[cfg(feature = "macos")]
+    {
+        use crate::macos::{Alert, App};
+
+        Alert::new("An unexpected error occurred", &panic.message).show();
+        App::terminate();
+    }
+
+    // If termination was cancelled (or there's no application to terminate), don't carry on in
+    // an unknown state.
+    std::process::exit(101);
+}
+
+/// Calls the installed handler (or the default) for a caught panic.
+fn handle(panic: CallbackPanic) {
+    if HANDLING_PANIC.swap(true, Ordering::SeqCst) {
+        eprintln!("cacao: panicked while handling a panic, aborting: {}", panic.message);
+        std::process::abort();
+    }
+
+    // Clone the handler out rather than holding the lock, in case it calls `set_handler()`.
+    let handler = HANDLER.read().unwrap_or_else(|e| e.into_inner()).clone();
+
+    match handler {
+        Some(handler) => handler(&panic),
+        None => default_handler(&panic)
+    }
+
+    HANDLING_PANIC.store(false, Ordering::SeqCst);
+}
+
+/// A value to hand back to Objective-C when a callback panics and the handler lets the
+/// application continue.
+pub(crate) trait Fallback {
+    fn fallback() -> Self;
+}
+
+impl Fallback for () {
+    fn fallback() -> Self {}
+}
+
+impl Fallback for bool {
+    fn fallback() -> Self { false }
+}
+
+impl Fallback for id {
+    fn fallback() -> Self { nil }
+}
+
+impl Fallback for CGSize {
+    fn fallback() -> Self { CGSize::zero() }
+}
+
+macro_rules! numeric_fallback {
+    ($($ty:ty),*) => {
+        $(impl Fallback for $ty {
+            fn fallback() -> Self { 0 as $ty }
+        })*
+    }
+}
+
+// `BOOL`, `NSInteger`, `NSUInteger` and `CGFloat` are all aliases for one of these, depending on
+// the platform.
+numeric_fallback!(i8, i32, i64, u32, u64, isize, usize, f32, f64);
+
+/// Runs `f`, catching any panic and passing it to the panic handler. If the handler returns,
+/// `fallback` is returned in place of whatever `f` would have produced.
+///
+/// Methods registered with `add_guarded_method()` get this for free; blocks (and anything else
+/// Objective-C calls into) should wrap their body in this, or `guard()`. It's also how a method
+/// picks a better fallback than `Fallback::fallback()`.
+pub(crate) fn guard_or<R, F>(fallback: R, f: F) -> R
+where
+    F: FnOnce() -> R
+{
+    match catch_unwind(AssertUnwindSafe(f)) {
+        Ok(value) => value,
+        Err(payload) => {
+            handle(CallbackPanic::from_payload(payload));
+            fallback
+        }
+    }
+}
+
+/// Runs `f`, catching any panic and passing it to the panic handler. If the handler returns, a
+/// neutral value for the return type is handed back instead.
+pub(crate) fn guard<R, F>(f: F) -> R
+where
+    R: Fallback,
+    F: FnOnce() -> R
+{
+    guard_or(R::fallback(), f)
+}
+
+/// A plain Rust `fn` that can be registered as an Objective-C method with
+/// `ClassDecl::add_guarded_method()`. `M` is the shape of its signature; it's always inferred,
+/// and only exists to keep the implementations for each arity apart.
+pub(crate) trait GuardedMethod<M>: Copy + 'static {
+    /// The `extern fn` that Objective-C actually calls into.
+    type Implementation: MethodImplementation<Callee = Object>;
+
+    /// Returns an `extern fn` that calls this one under `guard()`.
+    fn implementation(self) -> Self::Implementation;
+}
+
+macro_rules! guarded_method {
+    ($trampoline:ident, $trampoline_mut:ident; $($arg:ident: $ty:ident),*) => {
+        extern "C" fn $trampoline<F, R, $($ty),*>(this: &Object, sel: Sel, $($arg: $ty),*) -> R
+        where
+            F: Fn(&Object, Sel, $($ty),*) -> R,
+            R: Fallback
+        {
+            let f: F = unsafe { summon() };
+            guard(|| f(this, sel, $($arg),*))
+        }
+
+        extern "C" fn $trampoline_mut<F, R, $($ty),*>(this: &mut Object, sel: Sel, $($arg: $ty),*) -> R
+        where
+            F: Fn(&mut Object, Sel, $($ty),*) -> R,
+            R: Fallback
+        {
+            let f: F = unsafe { summon() };
+            guard(|| f(this, sel, $($arg),*))
+        }
+
+        impl<F, R, $($ty),*> GuardedMethod<fn(&Object, Sel, $($ty),*) -> R> for F
+        where
+            F: Fn(&Object, Sel, $($ty),*) -> R + Copy + 'static,
+            R: Fallback + Encode + 'static,
+            $($ty: Encode + 'static),*
+        {
+            type Implementation = extern "C" fn(&Object, Sel, $($ty),*) -> R;
+
+            fn implementation(self) -> Self::Implementation {
+                assert_stateless::<F>();
+                $trampoline::<F, R, $($ty),*>
+            }
+        }
+
+        impl<F, R, $($ty),*> GuardedMethod<fn(&mut Object, Sel, $($ty),*) -> R> for F
+        where
+            F: Fn(&mut Object, Sel, $($ty),*) -> R + Copy + 'static,
+            R: Fallback + Encode + 'static,
+            $($ty: Encode + 'static),*
+        {
+            type Implementation = extern "C" fn(&mut Object, Sel, $($ty),*) -> R;
+
+            fn implementation(self) -> Self::Implementation {
+                assert_stateless::<F>();
+                $trampoline_mut::<F, R, $($ty),*>
+            }
+        }
+    }
+}
+
+guarded_method!(trampoline_0, trampoline_mut_0;);
+guarded_method!(trampoline_1, trampoline_mut_1; a: A);
+guarded_method!(trampoline_2, trampoline_mut_2; a: A, b: B);
+guarded_method!(trampoline_3, trampoline_mut_3; a: A, b: B, c: C);
+guarded_method!(trampoline_4, trampoline_mut_4; a: A, b: B, c: C, d: D);
+guarded_method!(trampoline_5, trampoline_mut_5; a: A, b: B, c: C, d: D, e: E);
+guarded_method!(trampoline_6, trampoline_mut_6; a: A, b: B, c: C, d: D, e: E, g: G);
+
+/// Trampolines only get a type to work with, not a value - so the methods they call can't carry
+/// any state. `fn` items (and closures that capture nothing) are zero-sized, which means there's
+/// exactly one value of the type, and the trampoline can summon it.
+fn assert_stateless<F>() {
+    assert!(
+        mem::size_of::<F>() == 0,
+        "add_guarded_method() takes a `fn` item (or a closure that captures nothing), not a pointer or a closure with state"
+    );
+}
+
+/// Produces the one value of a zero-sized type.
+///
+/// # Safety
+///
+/// `F` must be zero-sized, as checked by `assert_stateless()` at registration. A zero-sized type
+/// has no bytes, so there's nothing for `zeroed()` to get wrong.
+unsafe fn summon<F>() -> F {
+    mem::zeroed()
+}
+
+/// Registers methods on a class being declared so that they're always run under `guard()`.
+///
+/// Every delegate class in this crate registers its methods this way, rather than with
+/// `add_method()`: the implementations are plain Rust `fn`s (which `add_method()` won't take),
+/// so there's no way to register one without the guard.
+pub(crate) trait AddGuardedMethod {
+    /// Adds a method that calls `f`, catching any panic at the boundary. If the panic handler
+    /// returns, Objective-C gets `Fallback::fallback()` for the return type.
+    ///
+    /// # Panics
+    ///
+    /// Panics if `f` isn't zero-sized - i.e, it's a function pointer or a closure with state,
+    /// rather than a `fn` item.
+    unsafe fn add_guarded_method<F, M>(&mut self, sel: Sel, f: F)
+    where
+        F: GuardedMethod<M>;
+}
+
+impl AddGuardedMethod for ClassDecl {
+    unsafe fn add_guarded_method<F, M>(&mut self, sel: Sel, f: F)
+    where
+        F: GuardedMethod<M>
+    {
+        self.add_method(sel, f.implementation());
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use std::sync::Mutex;
+
+    use objc::runtime::Class;
+    use objc::{msg_send, sel, sel_impl};
+
+    use crate::foundation::load_or_register_class;
+    use super::*;
+
+    lazy_static! {
+        static ref CAUGHT: Mutex<Vec<String>> = Mutex::new(Vec::new());
+    }
+
+    fn add(_: &Object, _: Sel, a: i64, b: i64) -> i64 {
+        a + b
+    }
+
+    fn explode(_: &Object, _: Sel) -> i64 {
+        panic!("exploded")
+    }
+
+    fn explode_object(_: &Object, _: Sel) -> id {
+        panic!("exploded with an object")
+    }
+
+    fn identity(this: &mut Object, _: Sel) -> id {
+        this
+    }
+
+    /// A class with a few guarded methods, and a handler that records panics rather than
+    /// terminating the test run.
+    fn test_class() -> &'static Class {
+        set_handler(|panic| {
+            CAUGHT.lock().unwrap().push(panic.message.clone());
+        });
+
+        let class = load_or_register_class("NSObject", "RSTGuardedMethodTest", |decl| unsafe {
+            decl.add_guarded_method(sel!(add:to:), add);
+            decl.add_guarded_method(sel!(explode), explode);
+            decl.add_guarded_method(sel!(explodeObject), explode_object);
+            decl.add_guarded_method(sel!(identity), identity);
+        });
+
+        unsafe { &*class }
+    }
+
+    #[test]
+    fn arguments_and_results_pass_through() {
+        let class = test_class();
+
+        unsafe {
+            let object: id = msg_send![class, new];
+            let sum: i64 = msg_send![object, add:2i64 to:3i64];
+            assert_eq!(sum, 5);
+
+            let same: id = msg_send![object, identity];
+            assert_eq!(same, object);
+
+            let _: () = msg_send![object, release];
+        }
+    }
+
+    #[test]
+    fn panics_are_caught_and_return_the_fallback() {
+        let class = test_class();
+
+        unsafe {
+            let object: id = msg_send![class, new];
+
+            let value: i64 = msg_send![object, explode];
+            assert_eq!(value, 0);
+
+            let value: id = msg_send![object, explodeObject];
+            assert_eq!(value, nil);
+
+            let _: () = msg_send![object, release];
+        }
+
+        let caught = CAUGHT.lock().unwrap();
+        assert!(caught.iter().any(|message| message == "exploded"));
+        assert!(caught.iter().any(|message| message == "exploded with an object"));
+    }
+
+    #[test]
+    fn closures_without_state_can_be_registered() {
+        let implementation = (|_: &Object, _: Sel| -> i64 { 7 }).implementation();
+        assert_eq!(mem::size_of_val(&implementation), mem::size_of::<usize>());
+    }
+
+    #[test]
+    #[should_panic(expected = "`fn` item")]
+    fn function_pointers_are_refused() {
+        let _ = (add as fn(&Object, Sel, i64, i64) -> i64).implementation();
+    }
+
+    #[test]
+    #[should_panic(expected = "`fn` item")]
+    fn closures_with_state_are_refused() {
+        let offset = 1;
+        let _ = (move |_: &Object, _: Sel, a: i64| -> i64 { a + offset }).implementation();
+    }
+}
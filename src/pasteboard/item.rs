@@ -0,0 +1,64 @@
+//! A wrapper for `NSPasteboardItem`, which represents a single item being written to (or read
+//! from) a pasteboard - e.g, a row being dragged out of a `ListView`.
+
+use objc::runtime::Object;
+use objc::{class, msg_send, sel, sel_impl};
+use objc_id::ShareId;
+
+use crate::foundation::{id, nil, NSData, NSString};
+use crate::pasteboard::PasteboardType;
+
+/// A single pasteboard item, which can hold the same content in several representations (types).
+#[derive(Debug)]
+pub struct PasteboardItem(pub ShareId<Object>);
+
+impl Default for PasteboardItem {
+    fn default() -> Self {
+        PasteboardItem::new()
+    }
+}
+
+impl PasteboardItem {
+    /// Creates a new, empty pasteboard item.
+    pub fn new() -> Self {
+        PasteboardItem(unsafe {
+            ShareId::from_retained_ptr(msg_send![class!(NSPasteboardItem), new])
+        })
+    }
+
+    /// Sets a string representation of this item, for the given type.
+    pub fn set_string(&self, value: &str, pboard_type: PasteboardType) {
+        self.set_string_for(value, pboard_type.into());
+    }
+
+    /// Sets a string representation for an arbitrary type identifier. This is how crate
+    /// internals write private types.
+    pub(crate) fn set_string_for(&self, value: &str, pboard_type: NSString) {
+        unsafe {
+            let value = NSString::new(value);
+            let _: () = msg_send![&*self.0, setString:value.into_inner() forType:pboard_type.into_inner()];
+        }
+    }
+
+    /// Sets a data representation of this item, for the given type.
+    pub fn set_data(&self, data: Vec<u8>, pboard_type: PasteboardType) {
+        unsafe {
+            let data = NSData::new(data);
+            let pboard_type: NSString = pboard_type.into();
+            let _: () = msg_send![&*self.0, setData:data.into_inner() forType:pboard_type.into_inner()];
+        }
+    }
+
+    /// Returns the string representation of this item for the given type identifier, if there
+    /// is one.
+    pub(crate) fn string_for(item: id, pboard_type: &str) -> Option<String> {
+        let value: id = unsafe {
+            msg_send![item, stringForType:NSString::new(pboard_type).into_inner()]
+        };
+
+        match value == nil {
+            true => None,
+            false => Some(NSString::wrap(value).to_string())
+        }
+    }
+}
@@ -15,6 +15,9 @@ use crate::error::Error;
 mod types;
 pub use types::{PasteboardName, PasteboardType};
 
+mod item;
+pub use item::PasteboardItem;
+
 /// Represents an `NSPasteboard`, enabling you to handle copy/paste/drag and drop.
 pub struct Pasteboard(pub ShareId<Object>);
 
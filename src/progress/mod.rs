@@ -115,6 +115,28 @@ impl ProgressIndicator {
         }
     }
 
+    /// Sets the current value for a determinate indicator. This should fall between the minimum
+    /// and maximum values (by default, `0.0` and `100.0`).
+    pub fn set_value(&self, value: f64) {
+        unsafe {
+            let _: () = msg_send![&*self.objc, setDoubleValue:value];
+        }
+    }
+
+    /// Sets the minimum value for a determinate indicator. Defaults to `0.0`.
+    pub fn set_min_value(&self, value: f64) {
+        unsafe {
+            let _: () = msg_send![&*self.objc, setMinValue:value];
+        }
+    }
+
+    /// Sets the maximum value for a determinate indicator. Defaults to `100.0`.
+    pub fn set_max_value(&self, value: f64) {
+        unsafe {
+            let _: () = msg_send![&*self.objc, setMaxValue:value];
+        }
+    }
+
     pub fn set_style(&self, style: ProgressIndicatorStyle) {
         unsafe {
             let style = style as NSUInteger;
@@ -9,6 +9,7 @@ use block::ConcreteBlock;
 use crate::error::Error;
 use crate::foundation::{id, nil, NSUInteger};
 use crate::image::Image;
+use crate::panic::guard;
 
 mod config;
 pub use config::{ThumbnailConfig, ThumbnailQuality};
@@ -28,16 +29,18 @@ impl ThumbnailGenerator {
         F: Fn(Result<(Image, ThumbnailQuality), Error>) + Send + Sync + 'static
     {
         let block = ConcreteBlock::new(move |thumbnail: id, thumbnail_type: NSUInteger, error: id| {
-            if error == nil {
-                unsafe {
-                    let image = Image::with(msg_send![thumbnail, NSImage]);
-                    let quality = ThumbnailQuality::from(thumbnail_type);
-                    callback(Ok((image, quality)));
+            guard(|| {
+                if error == nil {
+                    unsafe {
+                        let image = Image::with(msg_send![thumbnail, NSImage]);
+                        let quality = ThumbnailQuality::from(thumbnail_type);
+                        callback(Ok((image, quality)));
+                    }
+                } else {
+                    let error = Error::new(error);
+                    callback(Err(error));
                 }
-            } else {
-                let error = Error::new(error);
-                callback(Err(error));
-            }
+            });
         });
 
         let block = block.copy();
@@ -0,0 +1,329 @@
+//! A small router for dispatching URLs to handlers, for custom URL schemes (`myapp://...`) and
+//! Universal Links.
+//!
+//! Register patterns with `{placeholders}`, then hand incoming URLs to the router from your
+//! `AppDelegate`:
+//!
+//! ```rust,no_run
+//! use cacao::macos::app::AppDelegate;
+//! use cacao::routing::Router;
+//! use cacao::url::Url;
+//! use cacao::user_activity::UserActivity;
+//!
+//! struct TodosApp {
+//!     router: Router
+//! }
+//!
+//! impl Default for TodosApp {
+//!     fn default() -> Self {
+//!         let mut router = Router::new();
+//!
+//!         router.route("myapp://project/{id}/task/{task_id}?tab={tab}", |_url, params| {
+//!             let project_id: Option<u64> = params.get("id");
+//!             let task_id: Option<u64> = params.get("task_id");
+//!             let tab = params.get_str("tab").unwrap_or("details");
+//!             println!("Open task {:?} in project {:?} ({})", task_id, project_id, tab);
+//!         });
+//!
+//!         router.fallback(|url| println!("No route for {}", url));
+//!
+//!         TodosApp { router }
+//!     }
+//! }
+//!
+//! impl AppDelegate for TodosApp {
+//!     fn open_urls(&self, urls: Vec<Url>) {
+//!         self.router.handle_urls(urls);
+//!     }
+//!
+//!     fn continue_user_activity<F: Fn()>(&self, activity: UserActivity, _handler: F) -> bool {
+//!         self.router.handle_user_activity(&activity)
+//!     }
+//! }
+//! ```
+//!
+//! For a custom scheme to be opened by your app at all, it has to be declared in your
+//! `Info.plist`; `Router::info_plist_url_types()` will generate the entry for you.
+
+use url::Url;
+
+use crate::user_activity::UserActivity;
+
+mod params;
+pub use params::Params;
+
+mod pattern;
+pub use pattern::{PatternError, RoutePattern};
+
+/// A registered pattern and the handler to call when it matches.
+struct Route {
+    pattern: RoutePattern,
+    handler: Box<dyn Fn(&Url, &Params) + 'static>
+}
+
+/// Matches URLs against a list of patterns, in the order they were registered, and calls the
+/// handler for the first one that matches.
+#[derive(Default)]
+pub struct Router {
+    routes: Vec<Route>,
+    fallback: Option<Box<dyn Fn(&Url) + 'static>>
+}
+
+impl std::fmt::Debug for Router {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        f.debug_struct("Router")
+            .field("routes", &self.routes.iter().map(|route| route.pattern.as_str()).collect::<Vec<&str>>())
+            .field("fallback", &self.fallback.is_some())
+            .finish()
+    }
+}
+
+impl Router {
+    /// Returns a new, empty `Router`.
+    pub fn new() -> Self {
+        Router::default()
+    }
+
+    /// Registers `handler` to be called for URLs matching `pattern`. See `RoutePattern` for the
+    /// pattern syntax.
+    ///
+    /// # Panics
+    /// This panics if the pattern is malformed, as that's a programmer error rather than
+    /// something that should be handled at runtime. Use `try_route()` if your patterns are built
+    /// dynamically.
+    pub fn route<F>(&mut self, pattern: &str, handler: F) -> &mut Self
+    where
+        F: Fn(&Url, &Params) + 'static
+    {
+        if let Err(e) = self.try_route(pattern, handler) {
+            panic!("{}", e);
+        }
+
+        self
+    }
+
+    /// Registers `handler` to be called for URLs matching `pattern`, returning an error if the
+    /// pattern can't be parsed.
+    pub fn try_route<F>(&mut self, pattern: &str, handler: F) -> Result<&mut Self, PatternError>
+    where
+        F: Fn(&Url, &Params) + 'static
+    {
+        self.routes.push(Route {
+            pattern: RoutePattern::parse(pattern)?,
+            handler: Box::new(handler)
+        });
+
+        Ok(self)
+    }
+
+    /// Sets a handler to be called for any URL that doesn't match a registered route.
+    pub fn fallback<F>(&mut self, handler: F) -> &mut Self
+    where
+        F: Fn(&Url) + 'static
+    {
+        self.fallback = Some(Box::new(handler));
+        self
+    }
+
+    /// Returns the index of the first route matching `url`, along with the captured parameters.
+    fn find(&self, url: &Url) -> Option<(usize, Params)> {
+        self.routes.iter().enumerate().find_map(|(index, route)| {
+            route.pattern.matches(url).map(|params| (index, params))
+        })
+    }
+
+    /// Returns the pattern that `url` would be routed to, along with the parameters it captures,
+    /// without calling any handler.
+    pub fn recognize(&self, url: &Url) -> Option<(&RoutePattern, Params)> {
+        self.find(url).map(|(index, params)| (&self.routes[index].pattern, params))
+    }
+
+    /// Routes `url` to the first matching handler, or the fallback if nothing matches. Returns
+    /// whether a handler (including the fallback) was called.
+    pub fn handle(&self, url: &Url) -> bool {
+        if let Some((index, params)) = self.find(url) {
+            (self.routes[index].handler)(url, &params);
+            return true;
+        }
+
+        if let Some(fallback) = &self.fallback {
+            fallback(url);
+            return true;
+        }
+
+        false
+    }
+
+    /// Routes each URL in turn. This is shaped to be called directly from
+    /// `AppDelegate::open_urls()`.
+    pub fn handle_urls(&self, urls: Vec<Url>) {
+        for url in &urls {
+            self.handle(url);
+        }
+    }
+
+    /// Routes the webpage URL of a user activity (e.g, a Universal Link), returning whether it was
+    /// handled. This is shaped to be called from `AppDelegate::continue_user_activity()`.
+    pub fn handle_user_activity(&self, activity: &UserActivity) -> bool {
+        match activity.webpage_url() {
+            Some(url) => self.handle(&url),
+            None => false
+        }
+    }
+
+    /// Returns the distinct custom URL schemes used by registered routes, sorted. `http` and
+    /// `https` are excluded, as those are handled via Associated Domains rather than URL types.
+    pub fn schemes(&self) -> Vec<String> {
+        let mut schemes: Vec<String> = self.routes.iter()
+            .map(|route| route.pattern.scheme().to_string())
+            .filter(|scheme| scheme != "http" && scheme != "https")
+            .collect();
+
+        schemes.sort();
+        schemes.dedup();
+        schemes
+    }
+
+    /// Generates the `CFBundleURLTypes` entry to paste into your `Info.plist`, declaring every
+    /// custom scheme this router handles. `identifier` is used as the `CFBundleURLName`, and is
+    /// typically your bundle identifier.
+    pub fn info_plist_url_types(&self, identifier: &str) -> String {
+        let schemes = self.schemes().iter()
+            .map(|scheme| format!("\t\t\t\t<string>{}</string>\n", escape_xml(scheme)))
+            .collect::<String>();
+
+        format!(concat!(
+            "<key>CFBundleURLTypes</key>\n",
+            "<array>\n",
+            "\t<dict>\n",
+            "\t\t<key>CFBundleURLName</key>\n",
+            "\t\t<string>{}</string>\n",
+            "\t\t<key>CFBundleURLSchemes</key>\n",
+            "\t\t<array>\n",
+            "{}",
+            "\t\t</array>\n",
+            "\t</dict>\n",
+            "</array>\n"
+        ), escape_xml(identifier), schemes)
+    }
+}
+
+/// Escapes the handful of characters that aren't allowed as-is in plist string values.
+fn escape_xml(value: &str) -> String {
+    value.replace('&', "&amp;").replace('<', "&lt;").replace('>', "&gt;")
+}
+
+#[cfg(test)]
+mod tests {
+    use std::cell::RefCell;
+    use std::rc::Rc;
+
+    use url::Url;
+
+    use super::Router;
+
+    fn url(url: &str) -> Url {
+        Url::parse(url).unwrap()
+    }
+
+    /// A log of which handlers ran, and with what.
+    type Log = Rc<RefCell<Vec<String>>>;
+
+    fn router(log: &Log) -> Router {
+        let mut router = Router::new();
+
+        let project_log = log.clone();
+        router.route("myapp://project/{id}", move |_, params| {
+            project_log.borrow_mut().push(format!("project {}", params.get_str("id").unwrap()));
+        });
+
+        let settings_log = log.clone();
+        router.route("myapp://project/settings", move |_, _| {
+            settings_log.borrow_mut().push("settings".to_string());
+        });
+
+        let link_log = log.clone();
+        router.route("https://example.com/p/{id}", move |_, params| {
+            link_log.borrow_mut().push(format!("link {}", params.get::<u32>("id").unwrap()));
+        });
+
+        router
+    }
+
+    #[test]
+    fn dispatches_to_the_first_matching_route() {
+        let log = Log::default();
+        let router = router(&log);
+
+        assert!(router.handle(&url("myapp://project/7")));
+        assert!(router.handle(&url("myapp://project/settings")));
+        assert!(router.handle(&url("https://example.com/p/9")));
+
+        // `project/settings` is shadowed by the earlier `project/{id}` route.
+        assert_eq!(*log.borrow(), vec!["project 7", "project settings", "link 9"]);
+    }
+
+    #[test]
+    fn unmatched_urls_go_to_the_fallback() {
+        let log = Log::default();
+        let mut router = router(&log);
+
+        assert!(!router.handle(&url("myapp://unknown")));
+
+        let fallback_log = log.clone();
+        router.fallback(move |url| fallback_log.borrow_mut().push(format!("fallback {}", url)));
+
+        assert!(router.handle(&url("myapp://unknown")));
+        assert_eq!(*log.borrow(), vec!["fallback myapp://unknown"]);
+    }
+
+    #[test]
+    fn handle_urls_routes_each_in_order() {
+        let log = Log::default();
+        let router = router(&log);
+
+        router.handle_urls(vec![url("myapp://project/1"), url("myapp://project/2")]);
+        assert_eq!(*log.borrow(), vec!["project 1", "project 2"]);
+    }
+
+    #[test]
+    fn recognize_does_not_call_handlers() {
+        let log = Log::default();
+        let router = router(&log);
+
+        let (pattern, params) = router.recognize(&url("myapp://project/3")).unwrap();
+        assert_eq!(pattern.as_str(), "myapp://project/{id}");
+        assert_eq!(params.get::<u32>("id"), Some(3));
+
+        assert!(router.recognize(&url("myapp://nothing")).is_none());
+        assert!(log.borrow().is_empty());
+    }
+
+    #[test]
+    fn try_route_reports_bad_patterns() {
+        let mut router = Router::new();
+        assert!(router.try_route("no-scheme/{id}", |_, _| {}).is_err());
+        assert!(router.recognize(&url("no-scheme://x")).is_none());
+    }
+
+    #[test]
+    #[should_panic(expected = "Invalid route pattern")]
+    fn route_panics_on_bad_patterns() {
+        Router::new().route("myapp://a/b{c}", |_, _| {});
+    }
+
+    #[test]
+    fn lists_custom_schemes_for_info_plist() {
+        let log = Log::default();
+        let mut router = router(&log);
+        router.route("OtherApp://x", |_, _| {});
+
+        assert_eq!(router.schemes(), vec!["myapp", "otherapp"]);
+
+        let plist = router.info_plist_url_types("com.example.app&co");
+        assert!(plist.contains("<string>com.example.app&amp;co</string>"));
+        assert!(plist.contains("<string>myapp</string>"));
+        assert!(plist.contains("<string>otherapp</string>"));
+        assert!(!plist.contains("https"));
+    }
+}
@@ -0,0 +1,100 @@
+//! Parameters captured from a URL by a route pattern.
+
+use std::collections::HashMap;
+use std::str::FromStr;
+
+/// The values captured by a `{placeholder}` in a route pattern, keyed by placeholder name.
+/// Values are percent-decoded.
+#[derive(Clone, Debug, Default, PartialEq)]
+pub struct Params {
+    values: HashMap<String, String>
+}
+
+impl Params {
+    /// Stores a captured value.
+    pub(crate) fn insert(&mut self, name: String, value: String) {
+        self.values.insert(name, value);
+    }
+
+    /// Returns the raw captured value for `name`, if there is one.
+    pub fn get_str(&self, name: &str) -> Option<&str> {
+        self.values.get(name).map(|value| value.as_str())
+    }
+
+    /// Returns the captured value for `name`, parsed into `T`. This returns `None` if the value is
+    /// missing or can't be parsed; use `get_str()` if you need to tell the two apart.
+    ///
+    /// ```rust,no_run
+    /// # fn check(params: &cacao::routing::Params) {
+    /// let project_id: Option<u64> = params.get("id");
+    /// # }
+    /// ```
+    pub fn get<T: FromStr>(&self, name: &str) -> Option<T> {
+        self.get_str(name).and_then(|value| value.parse().ok())
+    }
+
+    /// Returns whether a value was captured for `name`.
+    pub fn contains(&self, name: &str) -> bool {
+        self.values.contains_key(name)
+    }
+
+    /// Returns the number of captured values.
+    pub fn len(&self) -> usize {
+        self.values.len()
+    }
+
+    /// Returns whether nothing was captured.
+    pub fn is_empty(&self) -> bool {
+        self.values.is_empty()
+    }
+
+    /// Iterates over all captured `(name, value)` pairs, in no particular order.
+    pub fn iter(&self) -> impl Iterator<Item = (&str, &str)> {
+        self.values.iter().map(|(name, value)| (name.as_str(), value.as_str()))
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::Params;
+
+    fn params() -> Params {
+        let mut params = Params::default();
+        params.insert("id".to_string(), "42".to_string());
+        params.insert("name".to_string(), "ferris".to_string());
+        params
+    }
+
+    #[test]
+    fn get_parses_into_the_requested_type() {
+        let params = params();
+
+        assert_eq!(params.get::<u64>("id"), Some(42));
+        assert_eq!(params.get::<i8>("id"), Some(42));
+        assert_eq!(params.get::<String>("name"), Some("ferris".to_string()));
+    }
+
+    #[test]
+    fn get_returns_none_for_missing_or_unparseable_values() {
+        let params = params();
+
+        assert_eq!(params.get::<u64>("missing"), None);
+        assert_eq!(params.get::<u64>("name"), None);
+        assert_eq!(params.get_str("name"), Some("ferris"));
+    }
+
+    #[test]
+    fn reports_what_was_captured() {
+        let params = params();
+
+        assert!(params.contains("id"));
+        assert!(!params.contains("missing"));
+        assert_eq!(params.len(), 2);
+        assert!(!params.is_empty());
+        assert!(Params::default().is_empty());
+
+        let mut pairs: Vec<(&str, &str)> = params.iter().collect();
+        pairs.sort();
+        assert_eq!(pairs, vec![("id", "42"), ("name", "ferris")]);
+    }
+}
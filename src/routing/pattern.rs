@@ -0,0 +1,326 @@
+//! Parsing and matching of route patterns, e.g `myapp://project/{id}/task/{task_id}?tab={tab}`.
+
+use std::error::Error;
+use std::fmt;
+
+use url::Url;
+
+use crate::routing::Params;
+
+/// An error raised when a route pattern can't be parsed.
+#[derive(Clone, Debug, PartialEq)]
+pub struct PatternError {
+    /// The pattern that failed to parse.
+    pub pattern: String,
+
+    /// A description of what's wrong with it.
+    pub reason: &'static str
+}
+
+impl fmt::Display for PatternError {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(f, "Invalid route pattern \"{}\": {}", self.pattern, self.reason)
+    }
+}
+
+impl Error for PatternError {}
+
+/// A single piece of a pattern: either literal text that must match exactly, or a named
+/// placeholder that captures whatever is there.
+#[derive(Clone, Debug, PartialEq)]
+enum Segment {
+    Literal(String),
+    Capture(String)
+}
+
+impl Segment {
+    fn parse(pattern: &str, segment: &str) -> Result<Self, PatternError> {
+        if segment.starts_with('{') && segment.ends_with('}') && segment.len() > 2 {
+            let name = &segment[1..segment.len() - 1];
+
+            if name.contains(|c| c == '{' || c == '}') {
+                return Err(PatternError {
+                    pattern: pattern.to_string(),
+                    reason: "placeholders must be of the form {name}"
+                });
+            }
+
+            return Ok(Segment::Capture(name.to_string()));
+        }
+
+        if segment.contains(|c| c == '{' || c == '}') {
+            return Err(PatternError {
+                pattern: pattern.to_string(),
+                reason: "placeholders must make up an entire segment"
+            });
+        }
+
+        Ok(Segment::Literal(segment.to_string()))
+    }
+}
+
+/// A parsed route pattern.
+///
+/// Patterns are made up of a scheme, followed by `/`-separated segments (the first of which is
+/// the URL host), and optionally a query. Any segment or query value can be a `{placeholder}`:
+///
+/// - Placeholders in the host or path must be present for the pattern to match.
+/// - Placeholders in the query are optional, as query parameters generally are; if the URL
+///   doesn't have them, they're simply absent from the `Params`.
+///
+/// Literal segments and query values must match exactly, with the exception of the scheme and
+/// host, which are compared case-insensitively.
+#[derive(Clone, Debug, PartialEq)]
+pub struct RoutePattern {
+    pattern: String,
+    scheme: String,
+    segments: Vec<Segment>,
+    query: Vec<(String, Segment)>
+}
+
+impl RoutePattern {
+    /// Parses a pattern string.
+    pub fn parse(pattern: &str) -> Result<Self, PatternError> {
+        let error = |reason| PatternError {
+            pattern: pattern.to_string(),
+            reason: reason
+        };
+
+        let (scheme, rest) = match pattern.find("://") {
+            Some(index) => (&pattern[..index], &pattern[index + 3..]),
+            None => { return Err(error("patterns must start with a scheme, e.g myapp://")); }
+        };
+
+        if scheme.is_empty() {
+            return Err(error("the scheme cannot be empty"));
+        }
+
+        let (path, query) = match rest.find('?') {
+            Some(index) => (&rest[..index], Some(&rest[index + 1..])),
+            None => (rest, None)
+        };
+
+        let segments = path.split('/')
+            .filter(|segment| !segment.is_empty())
+            .map(|segment| Segment::parse(pattern, segment))
+            .collect::<Result<Vec<Segment>, PatternError>>()?;
+
+        let mut query_segments = vec![];
+        for pair in query.unwrap_or("").split('&').filter(|pair| !pair.is_empty()) {
+            let (key, value) = match pair.find('=') {
+                Some(index) => (&pair[..index], &pair[index + 1..]),
+                None => { return Err(error("query items must be of the form key=value")); }
+            };
+
+            if key.is_empty() || key.contains(|c| c == '{' || c == '}') {
+                return Err(error("query keys must be literal text"));
+            }
+
+            query_segments.push((key.to_string(), Segment::parse(pattern, value)?));
+        }
+
+        Ok(RoutePattern {
+            pattern: pattern.to_string(),
+            scheme: scheme.to_lowercase(),
+            segments: segments,
+            query: query_segments
+        })
+    }
+
+    /// Returns the pattern as it was originally written.
+    pub fn as_str(&self) -> &str {
+        &self.pattern
+    }
+
+    /// Returns the (lowercased) scheme this pattern matches.
+    pub fn scheme(&self) -> &str {
+        &self.scheme
+    }
+
+    /// Attempts to match `url` against this pattern, returning the captured parameters on success.
+    pub fn matches(&self, url: &Url) -> Option<Params> {
+        if !url.scheme().eq_ignore_ascii_case(&self.scheme) {
+            return None;
+        }
+
+        // Treat the host as the first segment, so that `myapp://project/{id}` reads naturally. The
+        // host is kept as written, so that captures see the original text; literals are compared
+        // case-insensitively below.
+        let mut components: Vec<String> = vec![];
+        if let Some(host) = url.host_str() {
+            if !host.is_empty() {
+                components.push(host.to_string());
+            }
+        }
+
+        if let Some(segments) = url.path_segments() {
+            components.extend(segments.filter(|segment| !segment.is_empty()).map(percent_decode));
+        }
+
+        if components.len() != self.segments.len() {
+            return None;
+        }
+
+        let mut params = Params::default();
+
+        for (index, (segment, component)) in self.segments.iter().zip(components.into_iter()).enumerate() {
+            match segment {
+                Segment::Literal(literal) => {
+                    let is_match = match index == 0 && url.host_str().is_some() {
+                        true => literal.eq_ignore_ascii_case(&component),
+                        false => *literal == component
+                    };
+
+                    if !is_match {
+                        return None;
+                    }
+                },
+
+                Segment::Capture(name) => {
+                    params.insert(name.clone(), component);
+                }
+            }
+        }
+
+        for (key, segment) in &self.query {
+            let value = url.query_pairs().find(|(k, _)| k == key).map(|(_, v)| v.into_owned());
+
+            match (segment, value) {
+                (Segment::Literal(literal), Some(value)) if *literal == value => {},
+                (Segment::Literal(_), _) => { return None; },
+                (Segment::Capture(name), Some(value)) => { params.insert(name.clone(), value); },
+                (Segment::Capture(_), None) => {}
+            }
+        }
+
+        Some(params)
+    }
+}
+
+/// Decodes `%XX` escapes in a path segment. Invalid escapes are left as-is, and invalid UTF-8 is
+/// replaced rather than rejected.
+fn percent_decode(segment: &str) -> String {
+    let bytes = segment.as_bytes();
+    let mut decoded = Vec::with_capacity(bytes.len());
+    let mut index = 0;
+
+    while index < bytes.len() {
+        if bytes[index] == b'%' && index + 2 < bytes.len() {
+            let hex = std::str::from_utf8(&bytes[index + 1..index + 3]).ok()
+                .and_then(|hex| u8::from_str_radix(hex, 16).ok());
+
+            if let Some(byte) = hex {
+                decoded.push(byte);
+                index += 3;
+                continue;
+            }
+        }
+
+        decoded.push(bytes[index]);
+        index += 1;
+    }
+
+    String::from_utf8_lossy(&decoded).into_owned()
+}
+
+#[cfg(test)]
+mod tests {
+    use url::Url;
+
+    use super::RoutePattern;
+
+    fn url(url: &str) -> Url {
+        Url::parse(url).unwrap()
+    }
+
+    fn pattern(pattern: &str) -> RoutePattern {
+        RoutePattern::parse(pattern).unwrap()
+    }
+
+    #[test]
+    fn rejects_malformed_patterns() {
+        assert!(RoutePattern::parse("project/{id}").is_err());
+        assert!(RoutePattern::parse("://project").is_err());
+        assert!(RoutePattern::parse("myapp://project/id-{id}").is_err());
+        assert!(RoutePattern::parse("myapp://project/{{id}}").is_err());
+        assert!(RoutePattern::parse("myapp://search?query").is_err());
+        assert!(RoutePattern::parse("myapp://search?{key}=value").is_err());
+    }
+
+    #[test]
+    fn matches_literal_and_captured_segments() {
+        let pattern = pattern("myapp://project/{id}/task/{task_id}");
+        let params = pattern.matches(&url("myapp://project/12/task/34")).unwrap();
+
+        assert_eq!(params.get_str("id"), Some("12"));
+        assert_eq!(params.get_str("task_id"), Some("34"));
+        assert_eq!(params.len(), 2);
+    }
+
+    #[test]
+    fn requires_every_path_segment() {
+        let pattern = pattern("myapp://project/{id}/task/{task_id}");
+
+        assert!(pattern.matches(&url("myapp://project/12/task")).is_none());
+        assert!(pattern.matches(&url("myapp://project/12/task/34/extra")).is_none());
+        assert!(pattern.matches(&url("myapp://project/12/tasks/34")).is_none());
+        assert!(pattern.matches(&url("otherapp://project/12/task/34")).is_none());
+    }
+
+    #[test]
+    fn scheme_and_host_literals_ignore_case() {
+        let pattern = pattern("MyApp://Settings/{pane}");
+
+        assert_eq!(pattern.scheme(), "myapp");
+        assert!(pattern.matches(&url("myapp://settings/General")).is_some());
+        assert!(pattern.matches(&url("MYAPP://SETTINGS/General")).is_some());
+    }
+
+    #[test]
+    fn path_literals_are_case_sensitive() {
+        let pattern = pattern("myapp://project/{id}/task");
+        assert!(pattern.matches(&url("myapp://project/12/Task")).is_none());
+    }
+
+    #[test]
+    fn captures_keep_their_original_case() {
+        let params = pattern("myapp://{section}/{item}").matches(&url("myapp://Settings/General")).unwrap();
+
+        assert_eq!(params.get_str("section"), Some("Settings"));
+        assert_eq!(params.get_str("item"), Some("General"));
+    }
+
+    #[test]
+    fn captures_are_percent_decoded() {
+        let params = pattern("myapp://tag/{name}").matches(&url("myapp://tag/caf%C3%A9%20au%20lait")).unwrap();
+        assert_eq!(params.get_str("name"), Some("caf\u{e9} au lait"));
+    }
+
+    #[test]
+    fn query_captures_are_optional() {
+        let pattern = pattern("myapp://search?q={query}&page={page}");
+
+        let params = pattern.matches(&url("myapp://search?page=2&q=hello+world")).unwrap();
+        assert_eq!(params.get_str("query"), Some("hello world"));
+        assert_eq!(params.get::<u32>("page"), Some(2));
+
+        let params = pattern.matches(&url("myapp://search")).unwrap();
+        assert!(params.is_empty());
+    }
+
+    #[test]
+    fn query_literals_are_required() {
+        let pattern = pattern("myapp://search?mode=advanced");
+
+        assert!(pattern.matches(&url("myapp://search?mode=advanced&q=x")).is_some());
+        assert!(pattern.matches(&url("myapp://search?mode=basic")).is_none());
+        assert!(pattern.matches(&url("myapp://search")).is_none());
+    }
+
+    #[test]
+    fn https_patterns_match_on_path() {
+        let pattern = pattern("https://example.com/u/{user}");
+        let params = pattern.matches(&url("https://EXAMPLE.com/u/Ferris")).unwrap();
+        assert_eq!(params.get_str("user"), Some("Ferris"));
+    }
+}
@@ -18,14 +18,15 @@ use crate::foundation::{id, YES, NO, NSUInteger};
 use crate::dragdrop::DragInfo;
 use crate::scrollview::{SCROLLVIEW_DELEGATE_PTR, ScrollViewDelegate};
 use crate::utils::load;
+use crate::panic::AddGuardedMethod;
 
 /// Enforces normalcy, or: a needlessly cruel method in terms of the name. You get the idea though.
-extern fn enforce_normalcy(_: &Object, _: Sel) -> BOOL {
+fn enforce_normalcy(_: &Object, _: Sel) -> BOOL {
     return YES;
 }
 
 /// Called when a drag/drop operation has entered this view.
-extern fn dragging_entered<T: ScrollViewDelegate>(this: &mut Object, _: Sel, info: id) -> NSUInteger {
+fn dragging_entered<T: ScrollViewDelegate>(this: &mut Object, _: Sel, info: id) -> NSUInteger {
     let view = load::<T>(this, SCROLLVIEW_DELEGATE_PTR);
     view.dragging_entered(DragInfo {
         info: unsafe { Id::from_ptr(info) }
@@ -33,9 +34,9 @@ extern fn dragging_entered<T: ScrollViewDelegate>(this: &mut Object, _: Sel, inf
 }
 
 /// Called when a drag/drop operation has entered this view.
-extern fn prepare_for_drag_operation<T: ScrollViewDelegate>(this: &mut Object, _: Sel, info: id) -> BOOL {
+fn prepare_for_drag_operation<T: ScrollViewDelegate>(this: &mut Object, _: Sel, info: id) -> BOOL {
     let view = load::<T>(this, SCROLLVIEW_DELEGATE_PTR);
-    
+
     match view.prepare_for_drag_operation(DragInfo {
         info: unsafe { Id::from_ptr(info) }
     }) {
@@ -45,9 +46,9 @@ extern fn prepare_for_drag_operation<T: ScrollViewDelegate>(this: &mut Object, _
 }
 
 /// Called when a drag/drop operation has entered this view.
-extern fn perform_drag_operation<T: ScrollViewDelegate>(this: &mut Object, _: Sel, info: id) -> BOOL {
+fn perform_drag_operation<T: ScrollViewDelegate>(this: &mut Object, _: Sel, info: id) -> BOOL {
     let view = load::<T>(this, SCROLLVIEW_DELEGATE_PTR);
-        
+
     match view.perform_drag_operation(DragInfo {
         info: unsafe { Id::from_ptr(info) }
     }) {
@@ -57,18 +58,18 @@ extern fn perform_drag_operation<T: ScrollViewDelegate>(this: &mut Object, _: Se
 }
 
 /// Called when a drag/drop operation has entered this view.
-extern fn conclude_drag_operation<T: ScrollViewDelegate>(this: &mut Object, _: Sel, info: id) {
+fn conclude_drag_operation<T: ScrollViewDelegate>(this: &mut Object, _: Sel, info: id) {
     let view = load::<T>(this, SCROLLVIEW_DELEGATE_PTR);
-    
+
     view.conclude_drag_operation(DragInfo {
         info: unsafe { Id::from_ptr(info) }
     });           
 }
 
 /// Called when a drag/drop operation has entered this view.
-extern fn dragging_exited<T: ScrollViewDelegate>(this: &mut Object, _: Sel, info: id) {
+fn dragging_exited<T: ScrollViewDelegate>(this: &mut Object, _: Sel, info: id) {
     let view = load::<T>(this, SCROLLVIEW_DELEGATE_PTR);
-        
+
     view.dragging_exited(DragInfo {
         info: unsafe { Id::from_ptr(info) }
     });
@@ -102,14 +103,14 @@ pub(crate) fn register_scrollview_class_with_delegate<T: ScrollViewDelegate>() -
         // move.
         decl.add_ivar::<usize>(SCROLLVIEW_DELEGATE_PTR);
         
-        decl.add_method(sel!(isFlipped), enforce_normalcy as extern fn(&Object, _) -> BOOL);
+        decl.add_guarded_method(sel!(isFlipped), enforce_normalcy);
 
         // Drag and drop operations (e.g, accepting files)
-        decl.add_method(sel!(draggingEntered:), dragging_entered::<T> as extern fn (&mut Object, _, _) -> NSUInteger);
-        decl.add_method(sel!(prepareForDragOperation:), prepare_for_drag_operation::<T> as extern fn (&mut Object, _, _) -> BOOL);
-        decl.add_method(sel!(performDragOperation:), perform_drag_operation::<T> as extern fn (&mut Object, _, _) -> BOOL);
-        decl.add_method(sel!(concludeDragOperation:), conclude_drag_operation::<T> as extern fn (&mut Object, _, _));
-        decl.add_method(sel!(draggingExited:), dragging_exited::<T> as extern fn (&mut Object, _, _));
+        decl.add_guarded_method(sel!(draggingEntered:), dragging_entered::<T>);
+        decl.add_guarded_method(sel!(prepareForDragOperation:), prepare_for_drag_operation::<T>);
+        decl.add_guarded_method(sel!(performDragOperation:), perform_drag_operation::<T>);
+        decl.add_guarded_method(sel!(concludeDragOperation:), conclude_drag_operation::<T>);
+        decl.add_guarded_method(sel!(draggingExited:), dragging_exited::<T>);
         
         VIEW_CLASS = decl.register();
     });
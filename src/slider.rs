@@ -0,0 +1,263 @@
+//! A wrapper for `NSSlider`, for picking a value from a continuous range - horizontally,
+//! vertically, or around a dial.
+//!
+//! ```rust,no_run
+//! use cacao::slider::{Slider, SliderKind, TickMarkPosition};
+//!
+//! let mut volume = Slider::new(SliderKind::Horizontal);
+//! volume.set_min_value(0.);
+//! volume.set_max_value(11.);
+//! volume.set_tick_marks(12, TickMarkPosition::Below);
+//! volume.set_snaps_to_ticks(true);
+//! volume.set_value(7.);
+//!
+//! volume.set_action_with_value(|value| {
+//!     println!("Volume is now {}", value);
+//! });
+//! ```
+
+use std::sync::Once;
+
+use objc_id::ShareId;
+use objc::declare::ClassDecl;
+use objc::runtime::{Class, Object};
+use objc::{class, msg_send, sel, sel_impl};
+
+use crate::foundation::{id, nil, YES, NO, NSInteger, NSUInteger};
+use crate::invoker::TargetActionHandler;
+use crate::layout::{Layout, LayoutAnchorX, LayoutAnchorY, LayoutAnchorDimension};
+
+/// The shape of a slider.
+#[derive(Copy, Clone, Debug, PartialEq)]
+pub enum SliderKind {
+    /// A bar, with the minimum at the left.
+    Horizontal,
+
+    /// A bar, with the minimum at the bottom.
+    Vertical,
+
+    /// A round dial, with the minimum (and maximum) at the top.
+    Circular
+}
+
+/// Where tick marks are drawn, relative to the slider's bar. `Above` and `Below` apply to
+/// horizontal sliders, while `Leading` and `Trailing` apply to vertical ones.
+#[derive(Copy, Clone, Debug, PartialEq)]
+pub enum TickMarkPosition {
+    Below,
+    Above,
+    Leading,
+    Trailing
+}
+
+impl From<TickMarkPosition> for NSUInteger {
+    fn from(position: TickMarkPosition) -> Self {
+        match position {
+            TickMarkPosition::Below => 0,
+            TickMarkPosition::Above => 1,
+            TickMarkPosition::Leading => 1,
+            TickMarkPosition::Trailing => 0
+        }
+    }
+}
+
+/// A wrapper for `NSSlider`. Holds (retains) pointers for the Objective-C runtime
+/// where our `NSSlider` lives.
+#[derive(Debug)]
+pub struct Slider {
+    pub objc: ShareId<Object>,
+    handler: Option<TargetActionHandler>,
+
+    /// A pointer to the Objective-C runtime top layout constraint.
+    pub top: LayoutAnchorY,
+
+    /// A pointer to the Objective-C runtime leading layout constraint.
+    pub leading: LayoutAnchorX,
+
+    /// A pointer to the Objective-C runtime trailing layout constraint.
+    pub trailing: LayoutAnchorX,
+
+    /// A pointer to the Objective-C runtime bottom layout constraint.
+    pub bottom: LayoutAnchorY,
+
+    /// A pointer to the Objective-C runtime width layout constraint.
+    pub width: LayoutAnchorDimension,
+
+    /// A pointer to the Objective-C runtime height layout constraint.
+    pub height: LayoutAnchorDimension,
+
+    /// A pointer to the Objective-C runtime center X layout constraint.
+    pub center_x: LayoutAnchorX,
+
+    /// A pointer to the Objective-C runtime center Y layout constraint.
+    pub center_y: LayoutAnchorY
+}
+
+impl Default for Slider {
+    fn default() -> Self {
+        Slider::new(SliderKind::Horizontal)
+    }
+}
+
+impl Slider {
+    /// Creates a new `NSSlider` instance of the given kind, ranging from 0 to 1, and retains the
+    /// necessary Objective-C runtime pointer.
+    pub fn new(kind: SliderKind) -> Self {
+        let view: id = unsafe {
+            let slider: id = msg_send![register_class(), new];
+            let _: () = msg_send![slider, setTranslatesAutoresizingMaskIntoConstraints:NO];
+
+            match kind {
+                SliderKind::Horizontal => {},
+                SliderKind::Vertical => {
+                    let _: () = msg_send![slider, setVertical:YES];
+                },
+
+                // NSSliderTypeCircular
+                SliderKind::Circular => {
+                    let _: () = msg_send![slider, setSliderType:1 as NSUInteger];
+                }
+            }
+
+            slider
+        };
+
+        Slider {
+            handler: None,
+            top: LayoutAnchorY::new(unsafe { msg_send![view, topAnchor] }),
+            leading: LayoutAnchorX::new(unsafe { msg_send![view, leadingAnchor] }),
+            trailing: LayoutAnchorX::new(unsafe { msg_send![view, trailingAnchor] }),
+            bottom: LayoutAnchorY::new(unsafe { msg_send![view, bottomAnchor] }),
+            width: LayoutAnchorDimension::new(unsafe { msg_send![view, widthAnchor] }),
+            height: LayoutAnchorDimension::new(unsafe { msg_send![view, heightAnchor] }),
+            center_x: LayoutAnchorX::new(unsafe { msg_send![view, centerXAnchor] }),
+            center_y: LayoutAnchorY::new(unsafe { msg_send![view, centerYAnchor] }),
+            objc: unsafe { ShareId::from_retained_ptr(view) },
+        }
+    }
+
+    /// Returns the current value.
+    pub fn value(&self) -> f64 {
+        unsafe { msg_send![&*self.objc, doubleValue] }
+    }
+
+    /// Sets the current value. This is clamped to the slider's range, and doesn't fire the action.
+    pub fn set_value(&self, value: f64) {
+        unsafe {
+            let _: () = msg_send![&*self.objc, setDoubleValue:value];
+        }
+    }
+
+    /// Sets the value at the start of the slider.
+    pub fn set_min_value(&self, value: f64) {
+        unsafe {
+            let _: () = msg_send![&*self.objc, setMinValue:value];
+        }
+    }
+
+    /// Sets the value at the end of the slider.
+    pub fn set_max_value(&self, value: f64) {
+        unsafe {
+            let _: () = msg_send![&*self.objc, setMaxValue:value];
+        }
+    }
+
+    /// Draws `count` evenly spaced tick marks, at the given position. Pass `0` to remove them.
+    pub fn set_tick_marks(&self, count: usize, position: TickMarkPosition) {
+        let count = count as NSInteger;
+        let position: NSUInteger = position.into();
+
+        unsafe {
+            let _: () = msg_send![&*self.objc, setNumberOfTickMarks:count];
+            let _: () = msg_send![&*self.objc, setTickMarkPosition:position];
+        }
+    }
+
+    /// Sets whether the slider only stops on tick mark values. This requires tick marks.
+    pub fn set_snaps_to_ticks(&self, snaps: bool) {
+        unsafe {
+            let _: () = msg_send![&*self.objc, setAllowsTickMarkValuesOnly:match snaps {
+                true => YES,
+                false => NO
+            }];
+        }
+    }
+
+    /// Sets whether the action fires continuously while the slider is dragged (the default), or
+    /// only once it's let go.
+    pub fn set_continuous(&self, continuous: bool) {
+        unsafe {
+            let _: () = msg_send![&*self.objc, setContinuous:match continuous {
+                true => YES,
+                false => NO
+            }];
+        }
+    }
+
+    /// Sets whether the user can interact with the slider.
+    pub fn set_enabled(&self, enabled: bool) {
+        unsafe {
+            let _: () = msg_send![&*self.objc, setEnabled:match enabled {
+                true => YES,
+                false => NO
+            }];
+        }
+    }
+
+    /// Attaches a callback for when the value changes. Don't get too creative now...
+    /// best just to message pass or something.
+    pub fn set_action<F: Fn() + Send + Sync + 'static>(&mut self, action: F) {
+        let handler = TargetActionHandler::new(&*self.objc, action);
+        self.handler = Some(handler);
+    }
+
+    /// Attaches a callback for when the value changes, which is passed the new value.
+    pub fn set_action_with_value<F: Fn(f64) + Send + Sync + 'static>(&mut self, action: F) {
+        // The handler is dropped (and the target cleared) before the slider goes away, so the
+        // pointer is valid whenever the action fires.
+        let slider = &*self.objc as *const Object as usize;
+
+        self.set_action(move || {
+            let value: f64 = unsafe { msg_send![slider as id, doubleValue] };
+            action(value);
+        });
+    }
+}
+
+impl Layout for Slider {
+    fn get_backing_node(&self) -> ShareId<Object> {
+        self.objc.clone()
+    }
+
+    fn add_subview<V: Layout>(&self, _view: &V) {
+        panic!(r#"
+            Tried to add a subview to a Slider. This is not allowed in Cacao. If you think this should be supported,
+            open a discussion on the GitHub repo.
+        "#);
+    }
+}
+
+impl Drop for Slider {
+    // Just to be sure, let's... nil these out. They should be weak references,
+    // but I'd rather be paranoid and remove them later.
+    fn drop(&mut self) {
+        unsafe {
+            let _: () = msg_send![&*self.objc, setTarget:nil];
+            let _: () = msg_send![&*self.objc, setAction:nil];
+        }
+    }
+}
+
+/// Registers an `NSSlider` subclass.
+fn register_class() -> *const Class {
+    static mut VIEW_CLASS: *const Class = 0 as *const Class;
+    static INIT: Once = Once::new();
+
+    INIT.call_once(|| unsafe {
+        let superclass = class!(NSSlider);
+        let decl = ClassDecl::new("RSTSlider", superclass).unwrap();
+        VIEW_CLASS = decl.register();
+    });
+
+    unsafe { VIEW_CLASS }
+}
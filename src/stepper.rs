@@ -0,0 +1,212 @@
+//! A wrapper for `NSStepper`, the pair of up/down arrows for nudging a value by a fixed
+//! increment. These are usually placed next to a text field or label showing the value.
+//!
+//! ```rust,no_run
+//! use cacao::stepper::Stepper;
+//!
+//! let mut quantity = Stepper::new();
+//! quantity.set_min_value(1.);
+//! quantity.set_max_value(99.);
+//! quantity.set_increment(1.);
+//! quantity.set_value(1.);
+//!
+//! quantity.set_action_with_value(|value| {
+//!     println!("Quantity is now {}", value as usize);
+//! });
+//! ```
+
+use std::sync::Once;
+
+use objc_id::ShareId;
+use objc::declare::ClassDecl;
+use objc::runtime::{Class, Object};
+use objc::{class, msg_send, sel, sel_impl};
+
+use crate::foundation::{id, nil, YES, NO};
+use crate::invoker::TargetActionHandler;
+use crate::layout::{Layout, LayoutAnchorX, LayoutAnchorY, LayoutAnchorDimension};
+
+/// A wrapper for `NSStepper`. Holds (retains) pointers for the Objective-C runtime
+/// where our `NSStepper` lives.
+#[derive(Debug)]
+pub struct Stepper {
+    pub objc: ShareId<Object>,
+    handler: Option<TargetActionHandler>,
+
+    /// A pointer to the Objective-C runtime top layout constraint.
+    pub top: LayoutAnchorY,
+
+    /// A pointer to the Objective-C runtime leading layout constraint.
+    pub leading: LayoutAnchorX,
+
+    /// A pointer to the Objective-C runtime trailing layout constraint.
+    pub trailing: LayoutAnchorX,
+
+    /// A pointer to the Objective-C runtime bottom layout constraint.
+    pub bottom: LayoutAnchorY,
+
+    /// A pointer to the Objective-C runtime width layout constraint.
+    pub width: LayoutAnchorDimension,
+
+    /// A pointer to the Objective-C runtime height layout constraint.
+    pub height: LayoutAnchorDimension,
+
+    /// A pointer to the Objective-C runtime center X layout constraint.
+    pub center_x: LayoutAnchorX,
+
+    /// A pointer to the Objective-C runtime center Y layout constraint.
+    pub center_y: LayoutAnchorY
+}
+
+impl Default for Stepper {
+    fn default() -> Self {
+        Stepper::new()
+    }
+}
+
+impl Stepper {
+    /// Creates a new `NSStepper` instance, ranging from 0 to 59 in steps of 1, and retains the
+    /// necessary Objective-C runtime pointer.
+    pub fn new() -> Self {
+        let view: id = unsafe {
+            let stepper: id = msg_send![register_class(), new];
+            let _: () = msg_send![stepper, setTranslatesAutoresizingMaskIntoConstraints:NO];
+            stepper
+        };
+
+        Stepper {
+            handler: None,
+            top: LayoutAnchorY::new(unsafe { msg_send![view, topAnchor] }),
+            leading: LayoutAnchorX::new(unsafe { msg_send![view, leadingAnchor] }),
+            trailing: LayoutAnchorX::new(unsafe { msg_send![view, trailingAnchor] }),
+            bottom: LayoutAnchorY::new(unsafe { msg_send![view, bottomAnchor] }),
+            width: LayoutAnchorDimension::new(unsafe { msg_send![view, widthAnchor] }),
+            height: LayoutAnchorDimension::new(unsafe { msg_send![view, heightAnchor] }),
+            center_x: LayoutAnchorX::new(unsafe { msg_send![view, centerXAnchor] }),
+            center_y: LayoutAnchorY::new(unsafe { msg_send![view, centerYAnchor] }),
+            objc: unsafe { ShareId::from_retained_ptr(view) },
+        }
+    }
+
+    /// Returns the current value.
+    pub fn value(&self) -> f64 {
+        unsafe { msg_send![&*self.objc, doubleValue] }
+    }
+
+    /// Sets the current value. This is clamped to the stepper's range, and doesn't fire the action.
+    pub fn set_value(&self, value: f64) {
+        unsafe {
+            let _: () = msg_send![&*self.objc, setDoubleValue:value];
+        }
+    }
+
+    /// Sets the lowest value the stepper can reach.
+    pub fn set_min_value(&self, value: f64) {
+        unsafe {
+            let _: () = msg_send![&*self.objc, setMinValue:value];
+        }
+    }
+
+    /// Sets the highest value the stepper can reach.
+    pub fn set_max_value(&self, value: f64) {
+        unsafe {
+            let _: () = msg_send![&*self.objc, setMaxValue:value];
+        }
+    }
+
+    /// Sets how much each click changes the value by.
+    pub fn set_increment(&self, increment: f64) {
+        unsafe {
+            let _: () = msg_send![&*self.objc, setIncrement:increment];
+        }
+    }
+
+    /// Sets whether holding down an arrow keeps changing the value (the default), or only
+    /// changes it once per click.
+    pub fn set_autorepeat(&self, autorepeat: bool) {
+        unsafe {
+            let _: () = msg_send![&*self.objc, setAutorepeat:match autorepeat {
+                true => YES,
+                false => NO
+            }];
+        }
+    }
+
+    /// Sets whether stepping past the maximum wraps around to the minimum (and vice versa),
+    /// rather than stopping.
+    pub fn set_wraps(&self, wraps: bool) {
+        unsafe {
+            let _: () = msg_send![&*self.objc, setValueWraps:match wraps {
+                true => YES,
+                false => NO
+            }];
+        }
+    }
+
+    /// Sets whether the user can interact with the stepper.
+    pub fn set_enabled(&self, enabled: bool) {
+        unsafe {
+            let _: () = msg_send![&*self.objc, setEnabled:match enabled {
+                true => YES,
+                false => NO
+            }];
+        }
+    }
+
+    /// Attaches a callback for when the value changes. Don't get too creative now...
+    /// best just to message pass or something.
+    pub fn set_action<F: Fn() + Send + Sync + 'static>(&mut self, action: F) {
+        let handler = TargetActionHandler::new(&*self.objc, action);
+        self.handler = Some(handler);
+    }
+
+    /// Attaches a callback for when the value changes, which is passed the new value.
+    pub fn set_action_with_value<F: Fn(f64) + Send + Sync + 'static>(&mut self, action: F) {
+        // The handler is dropped (and the target cleared) before the stepper goes away, so the
+        // pointer is valid whenever the action fires.
+        let stepper = &*self.objc as *const Object as usize;
+
+        self.set_action(move || {
+            let value: f64 = unsafe { msg_send![stepper as id, doubleValue] };
+            action(value);
+        });
+    }
+}
+
+impl Layout for Stepper {
+    fn get_backing_node(&self) -> ShareId<Object> {
+        self.objc.clone()
+    }
+
+    fn add_subview<V: Layout>(&self, _view: &V) {
+        panic!(r#"
+            Tried to add a subview to a Stepper. This is not allowed in Cacao. If you think this should be supported,
+            open a discussion on the GitHub repo.
+        "#);
+    }
+}
+
+impl Drop for Stepper {
+    // Just to be sure, let's... nil these out. They should be weak references,
+    // but I'd rather be paranoid and remove them later.
+    fn drop(&mut self) {
+        unsafe {
+            let _: () = msg_send![&*self.objc, setTarget:nil];
+            let _: () = msg_send![&*self.objc, setAction:nil];
+        }
+    }
+}
+
+/// Registers an `NSStepper` subclass.
+fn register_class() -> *const Class {
+    static mut VIEW_CLASS: *const Class = 0 as *const Class;
+    static INIT: Once = Once::new();
+
+    INIT.call_once(|| unsafe {
+        let superclass = class!(NSStepper);
+        let decl = ClassDecl::new("RSTStepper", superclass).unwrap();
+        VIEW_CLASS = decl.register();
+    });
+
+    unsafe { VIEW_CLASS }
+}
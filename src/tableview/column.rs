@@ -0,0 +1,134 @@
+//! Wraps `NSTableColumn`, which describes a single column in a `TableView`.
+
+use core_graphics::base::CGFloat;
+use objc_id::ShareId;
+use objc::runtime::Object;
+use objc::{class, msg_send, sel, sel_impl};
+
+use crate::foundation::{id, nil, to_bool, BOOL, YES, NO, NSString, NSUInteger};
+
+/// Describes a column in a `TableView`: its identifier, header title, width constraints, and
+/// whether it can be resized, hidden or sorted on.
+///
+/// Configure a column, then hand it to `TableView::add_column()`. The identifier is what you'll be
+/// passed in `TableViewDelegate::cell_for()`, and what the column is saved under when using
+/// `TableView::set_autosave_name()` - so it should be unique within a table, and stable across
+/// releases.
+#[derive(Debug)]
+pub struct TableColumn {
+    /// The identifier for this column.
+    pub identifier: &'static str,
+
+    /// A pointer to the Objective-C runtime `NSTableColumn`.
+    pub objc: ShareId<Object>
+}
+
+impl TableColumn {
+    /// Creates a new column with the given identifier and header title. Columns are resizable
+    /// by default.
+    pub fn new(identifier: &'static str, title: &str) -> Self {
+        let objc = unsafe {
+            let column: id = msg_send![class!(NSTableColumn), alloc];
+            let column: id = msg_send![column, initWithIdentifier:NSString::new(identifier).into_inner()];
+            let _: () = msg_send![column, setTitle:NSString::new(title).into_inner()];
+            ShareId::from_retained_ptr(column)
+        };
+
+        let column = TableColumn {
+            identifier: identifier,
+            objc: objc
+        };
+
+        column.set_resizable(true);
+        column
+    }
+
+    /// Sets the header title for this column.
+    pub fn set_title(&self, title: &str) {
+        unsafe {
+            let _: () = msg_send![&*self.objc, setTitle:NSString::new(title).into_inner()];
+        }
+    }
+
+    /// Sets the current width of this column. If the column has been autosaved, the saved width
+    /// will take precedence once the table restores it.
+    pub fn set_width(&self, width: CGFloat) {
+        unsafe {
+            let _: () = msg_send![&*self.objc, setWidth:width];
+        }
+    }
+
+    /// Returns the current width of this column.
+    pub fn width(&self) -> CGFloat {
+        unsafe {
+            msg_send![&*self.objc, width]
+        }
+    }
+
+    /// Sets the minimum width this column can be resized to.
+    pub fn set_min_width(&self, width: CGFloat) {
+        unsafe {
+            let _: () = msg_send![&*self.objc, setMinWidth:width];
+        }
+    }
+
+    /// Sets the maximum width this column can be resized to.
+    pub fn set_max_width(&self, width: CGFloat) {
+        unsafe {
+            let _: () = msg_send![&*self.objc, setMaxWidth:width];
+        }
+    }
+
+    /// Sets whether the user can resize this column. Resizable columns also take part in
+    /// autoresizing when the table itself is resized.
+    pub fn set_resizable(&self, resizable: bool) {
+        // NSTableColumnAutoresizingMask | NSTableColumnUserResizingMask
+        let mask: NSUInteger = match resizable {
+            true => (1 << 0) | (1 << 1),
+            false => 0
+        };
+
+        unsafe {
+            let _: () = msg_send![&*self.objc, setResizingMask:mask];
+        }
+    }
+
+    /// Sets whether this column is hidden.
+    pub fn set_hidden(&self, hidden: bool) {
+        unsafe {
+            let _: () = msg_send![&*self.objc, setHidden:match hidden {
+                true => YES,
+                false => NO
+            }];
+        }
+    }
+
+    /// Returns whether this column is hidden.
+    pub fn is_hidden(&self) -> bool {
+        unsafe {
+            let hidden: BOOL = msg_send![&*self.objc, isHidden];
+            to_bool(hidden)
+        }
+    }
+
+    /// Makes this column sortable: clicking its header will sort by `key`, ascending on the
+    /// first click and toggling from there. The resulting sort order is delivered to
+    /// `TableViewDelegate::sort_descriptors_did_change()`. Pass `None` to make it unsortable.
+    pub fn set_sort_key(&self, key: Option<&str>) {
+        unsafe {
+            let prototype: id = match key {
+                Some(key) => msg_send![class!(NSSortDescriptor), sortDescriptorWithKey:NSString::new(key).into_inner() ascending:YES],
+                None => nil
+            };
+
+            let _: () = msg_send![&*self.objc, setSortDescriptorPrototype:prototype];
+        }
+    }
+
+    /// Sets the tooltip shown when hovering over this column's header.
+    pub fn set_header_tooltip(&self, tooltip: &str) {
+        unsafe {
+            let _: () = msg_send![&*self.objc, setHeaderToolTip:NSString::new(tooltip).into_inner()];
+        }
+    }
+}
@@ -0,0 +1,187 @@
+//! Registers the `NSTableView` subclasses that back `TableView`, and forwards data source and
+//! delegate calls on to the Rust side.
+
+use std::sync::Once;
+
+use objc::declare::ClassDecl;
+use objc::runtime::{Class, Object, Sel, BOOL};
+use objc::{class, sel, sel_impl, msg_send};
+
+use crate::foundation::{load_or_register_class, id, nil, YES, NO, NSInteger, NSString, NSUInteger};
+use crate::listview::index_set_to_vec;
+use crate::tableview::{TABLEVIEW_DELEGATE_PTR, TABLEVIEW_CELL_VENDOR_PTR, TableViewDelegate};
+use crate::tableview::sort::sort_descriptors_from;
+use crate::utils::load;
+use crate::panic::AddGuardedMethod;
+
+/// Returns the identifier of an `NSTableColumn`, if there is one.
+fn column_identifier(column: id) -> Option<String> {
+    if column == nil {
+        return None;
+    }
+
+    let identifier: id = unsafe { msg_send![column, identifier] };
+
+    match identifier == nil {
+        true => None,
+        false => Some(NSString::wrap(identifier).to_string())
+    }
+}
+
+/// Reads an integer out of a notification's `userInfo`.
+fn user_info_integer(notification: id, key: &str) -> NSInteger {
+    unsafe {
+        let user_info: id = msg_send![notification, userInfo];
+        let value: id = msg_send![user_info, objectForKey:NSString::new(key).into_inner()];
+        msg_send![value, integerValue]
+    }
+}
+
+/// Determines the number of rows by way of the backing data source (the Rust struct).
+fn number_of_rows<T: TableViewDelegate>(this: &Object, _: Sel, _: id) -> NSInteger {
+    let view = load::<T>(this, TABLEVIEW_DELEGATE_PTR);
+    view.number_of_rows() as NSInteger
+}
+
+/// Vends the view for a given cell.
+fn view_for_column<T: TableViewDelegate>(
+    this: &Object,
+    _: Sel,
+    _table_view: id,
+    column: id,
+    row: NSInteger
+) -> id {
+    // Group rows are passed a nil column; we don't have any of those.
+    let column = match column_identifier(column) {
+        Some(column) => column,
+        None => { return nil; }
+    };
+
+    let view = load::<T>(this, TABLEVIEW_DELEGATE_PTR);
+    let cell = view.cell_for(row as usize, &column);
+    let objc = cell.objc.borrow();
+
+    // See the matching note in `ListView` - the table retains the cell.
+    unsafe {
+        msg_send![&**objc, self]
+    }
+}
+
+/// Called when the sort descriptors have changed, typically as a result of a header click.
+fn sort_descriptors_did_change<T: TableViewDelegate>(this: &Object, _: Sel, _table_view: id, _old: id) {
+    let descriptors = sort_descriptors_from(unsafe { msg_send![this, sortDescriptors] });
+    let view = load::<T>(this, TABLEVIEW_DELEGATE_PTR);
+    view.sort_descriptors_did_change(descriptors);
+}
+
+/// Determines whether a row can be selected.
+fn should_select_row<T: TableViewDelegate>(this: &Object, _: Sel, _table_view: id, row: NSInteger) -> BOOL {
+    let view = load::<T>(this, TABLEVIEW_DELEGATE_PTR);
+
+    match view.should_select(row as usize) {
+        true => YES,
+        false => NO
+    }
+}
+
+/// Called when the selection has changed.
+fn selection_did_change<T: TableViewDelegate>(this: &Object, _: Sel, _: id) {
+    let selected = unsafe {
+        let index_set: id = msg_send![this, selectedRowIndexes];
+        index_set_to_vec(index_set)
+    };
+
+    let view = load::<T>(this, TABLEVIEW_DELEGATE_PTR);
+    view.selection_did_change(selected);
+}
+
+/// Called when a row is double clicked; this is the table's `doubleAction`.
+fn row_double_clicked<T: TableViewDelegate>(this: &Object, _: Sel, _: id) {
+    let row: NSInteger = unsafe { msg_send![this, clickedRow] };
+    if row < 0 {
+        return;
+    }
+
+    let column: NSInteger = unsafe { msg_send![this, clickedColumn] };
+    let column = match column < 0 {
+        true => None,
+        false => {
+            let columns: id = unsafe { msg_send![this, tableColumns] };
+            column_identifier(unsafe { msg_send![columns, objectAtIndex:column as NSUInteger] })
+        }
+    };
+
+    let view = load::<T>(this, TABLEVIEW_DELEGATE_PTR);
+    view.row_double_clicked(row as usize, column);
+}
+
+/// Called after a column has been dragged to a new position.
+fn column_did_move<T: TableViewDelegate>(this: &Object, _: Sel, notification: id) {
+    let from = user_info_integer(notification, "NSOldColumn");
+    let to = user_info_integer(notification, "NSNewColumn");
+
+    let view = load::<T>(this, TABLEVIEW_DELEGATE_PTR);
+    view.column_did_move(from as usize, to as usize);
+}
+
+/// Called after a column has been resized.
+fn column_did_resize<T: TableViewDelegate>(this: &Object, _: Sel, notification: id) {
+    let column = unsafe {
+        let user_info: id = msg_send![notification, userInfo];
+        let column: id = msg_send![user_info, objectForKey:NSString::new("NSTableColumn").into_inner()];
+        column_identifier(column)
+    };
+
+    if let Some(column) = column {
+        let view = load::<T>(this, TABLEVIEW_DELEGATE_PTR);
+        view.column_did_resize(&column);
+    }
+}
+
+/// Enforces normalcy, or: a needlessly cruel method in terms of the name. You get the idea though.
+fn enforce_normalcy(_: &Object, _: Sel) -> BOOL {
+    return YES;
+}
+
+/// Injects an `NSTableView` subclass. This is used for the default views that don't use
+/// delegates - we have separate classes here since we don't want to waste cycles on methods that
+/// will never be used if there's no delegates.
+pub(crate) fn register_tableview_class() -> *const Class {
+    static mut VIEW_CLASS: *const Class = 0 as *const Class;
+    static INIT: Once = Once::new();
+
+    INIT.call_once(|| unsafe {
+        let superclass = class!(NSTableView);
+        let decl = ClassDecl::new("RSTTableView", superclass).unwrap();
+        VIEW_CLASS = decl.register();
+    });
+
+    unsafe {
+        VIEW_CLASS
+    }
+}
+
+/// Injects an `NSTableView` subclass, with some callback and pointer ivars for what we
+/// need to do.
+pub(crate) fn register_tableview_class_with_delegate<T: TableViewDelegate>(instance: &T) -> *const Class {
+    load_or_register_class("NSTableView", instance.subclass_name(), |decl| unsafe {
+        decl.add_ivar::<usize>(TABLEVIEW_DELEGATE_PTR);
+        decl.add_ivar::<usize>(TABLEVIEW_CELL_VENDOR_PTR);
+
+        decl.add_guarded_method(sel!(isFlipped), enforce_normalcy);
+
+        // Tableview-specific
+        decl.add_guarded_method(sel!(numberOfRowsInTableView:), number_of_rows::<T>);
+        decl.add_guarded_method(sel!(tableView:viewForTableColumn:row:), view_for_column::<T>);
+        decl.add_guarded_method(sel!(tableView:sortDescriptorsDidChange:), sort_descriptors_did_change::<T>);
+
+        // Selection
+        decl.add_guarded_method(sel!(tableView:shouldSelectRow:), should_select_row::<T>);
+        decl.add_guarded_method(sel!(tableViewSelectionDidChange:), selection_did_change::<T>);
+        decl.add_guarded_method(sel!(rstRowDoubleClicked:), row_double_clicked::<T>);
+
+        // Columns
+        decl.add_guarded_method(sel!(tableViewColumnDidMove:), column_did_move::<T>);
+        decl.add_guarded_method(sel!(tableViewColumnDidResize:), column_did_resize::<T>);
+    })
+}
@@ -0,0 +1,457 @@
+//! Wraps `NSTableView` as a multi-column table, with sortable headers.
+//!
+//! Where `ListView` treats `NSTableView` as a single-column, `UITableView`-ish list, `TableView`
+//! keeps the columns: you declare them up front, vend a view per cell, and get told when the user
+//! clicks a header to sort. Column widths, order and visibility can be persisted across launches
+//! by setting an autosave name.
+//!
+//! ```rust,no_run
+//! use cacao::listview::ListViewRow;
+//! use cacao::tableview::{SortDescriptor, TableColumn, TableView, TableViewDelegate};
+//!
+//! #[derive(Default)]
+//! struct Inspector {
+//!     table: Option<TableView>
+//! }
+//!
+//! impl TableViewDelegate for Inspector {
+//!     const NAME: &'static str = "InspectorTable";
+//!
+//!     fn did_load(&mut self, table: TableView) {
+//!         let name = TableColumn::new("name", "Name");
+//!         name.set_min_width(120.);
+//!         name.set_sort_key(Some("name"));
+//!         table.add_column(&name);
+//!
+//!         let size = TableColumn::new("size", "Size");
+//!         size.set_width(80.);
+//!         size.set_sort_key(Some("size"));
+//!         table.add_column(&size);
+//!
+//!         table.set_autosave_name("InspectorTable");
+//!         self.table = Some(table);
+//!     }
+//!
+//!     fn number_of_rows(&self) -> usize { 0 }
+//!
+//!     fn cell_for(&self, row: usize, column: &str) -> ListViewRow {
+//!         ListViewRow::new()
+//!     }
+//!
+//!     fn sort_descriptors_did_change(&self, descriptors: Vec<SortDescriptor>) {
+//!         // Sort your data by `descriptors`, then reload.
+//!     }
+//! }
+//! ```
+
+use core_graphics::base::CGFloat;
+use objc_id::ShareId;
+use objc::runtime::{Class, Object};
+use objc::{class, msg_send, sel, sel_impl};
+
+use crate::foundation::{id, nil, YES, NO, NSArray, NSString, NSInteger, NSUInteger};
+use crate::color::Color;
+use crate::layout::{Layout, LayoutAnchorX, LayoutAnchorY, LayoutAnchorDimension};
+use crate::listview::{index_set_to_vec, CellFactory, CellFactoryMap, ListViewRow};
+use crate::scrollview::ScrollView;
+use crate::view::ViewDelegate;
+
+mod macos;
+use macos::{register_tableview_class, register_tableview_class_with_delegate};
+
+mod column;
+pub use column::TableColumn;
+
+mod sort;
+pub use sort::SortDescriptor;
+
+mod traits;
+pub use traits::TableViewDelegate;
+
+pub(crate) static TABLEVIEW_DELEGATE_PTR: &str = "rstTableViewDelegatePtr";
+pub(crate) static TABLEVIEW_CELL_VENDOR_PTR: &str = "rstTableViewCellVendorPtr";
+
+use std::cell::RefCell;
+
+/// A helper method for instantiating view classes and applying default settings to them.
+fn common_init(class: *const Class) -> id {
+    unsafe {
+        let tableview: id = msg_send![class, new];
+        let _: () = msg_send![tableview, setTranslatesAutoresizingMaskIntoConstraints:NO];
+        let _: () = msg_send![tableview, setWantsLayer:YES];
+        let _: () = msg_send![tableview, setUsesAutomaticRowHeights:YES];
+        let _: () = msg_send![tableview, setAllowsColumnReordering:YES];
+        let _: () = msg_send![tableview, setAllowsColumnResizing:YES];
+        let _: () = msg_send![tableview, setAllowsEmptySelection:YES];
+        let _: () = msg_send![tableview, setAllowsMultipleSelection:NO];
+
+        // NSTableColumnUniformColumnAutoresizingStyle
+        let _: () = msg_send![tableview, setColumnAutoresizingStyle:1];
+
+        // Ensure there are headers to click on (and drag around).
+        let header: id = msg_send![class!(NSTableHeaderView), new];
+        let _: () = msg_send![tableview, setHeaderView:header];
+        let _: () = msg_send![header, release];
+
+        tableview
+    }
+}
+
+/// A multi-column table, backed by `NSTableView` (inside an `NSScrollView`).
+#[derive(Debug)]
+pub struct TableView<T = ()> {
+    /// Internal map of cell identifers/vendors. These are used for handling dynamic cell
+    /// allocation and reuse.
+    cell_factory: CellFactory,
+
+    /// A pointer to the Objective-C runtime view controller.
+    pub objc: ShareId<Object>,
+
+    /// The `NSScrollView` that hosts the table. Layout anchors refer to this.
+    pub scrollview: ScrollView,
+
+    /// A pointer to the delegate for this view.
+    pub delegate: Option<Box<T>>,
+
+    /// A pointer to the Objective-C runtime top layout constraint.
+    pub top: LayoutAnchorY,
+
+    /// A pointer to the Objective-C runtime leading layout constraint.
+    pub leading: LayoutAnchorX,
+
+    /// A pointer to the Objective-C runtime trailing layout constraint.
+    pub trailing: LayoutAnchorX,
+
+    /// A pointer to the Objective-C runtime bottom layout constraint.
+    pub bottom: LayoutAnchorY,
+
+    /// A pointer to the Objective-C runtime width layout constraint.
+    pub width: LayoutAnchorDimension,
+
+    /// A pointer to the Objective-C runtime height layout constraint.
+    pub height: LayoutAnchorDimension,
+
+    /// A pointer to the Objective-C runtime center X layout constraint.
+    pub center_x: LayoutAnchorX,
+
+    /// A pointer to the Objective-C runtime center Y layout constraint.
+    pub center_y: LayoutAnchorY
+}
+
+impl Default for TableView {
+    fn default() -> Self {
+        TableView::new()
+    }
+}
+
+impl TableView {
+    /// Returns a default `TableView`, with no columns and no delegate.
+    pub fn new() -> Self {
+        let view = common_init(register_tableview_class());
+        let scrollview = ScrollView::new();
+
+        unsafe {
+            let _: () = msg_send![&*scrollview.objc, setDocumentView:view];
+        }
+
+        TableView::init(view, CellFactory::new(), scrollview)
+    }
+}
+
+impl<T> TableView<T> where T: TableViewDelegate + 'static {
+    /// Initializes a new TableView with a given `TableViewDelegate`. This enables you to respond
+    /// to events and vend cells.
+    pub fn with(delegate: T) -> TableView<T> {
+        let class = register_tableview_class_with_delegate::<T>(&delegate);
+        let view = common_init(class);
+        let mut delegate = Box::new(delegate);
+        let cell = CellFactory::new();
+
+        unsafe {
+            let delegate_ptr: *const T = &*delegate;
+            let cell_vendor_ptr: *const RefCell<CellFactoryMap> = &*cell.0;
+            (&mut *view).set_ivar(TABLEVIEW_DELEGATE_PTR, delegate_ptr as usize);
+            (&mut *view).set_ivar(TABLEVIEW_CELL_VENDOR_PTR, cell_vendor_ptr as usize);
+            let _: () = msg_send![view, setDelegate:view];
+            let _: () = msg_send![view, setDataSource:view];
+            let _: () = msg_send![view, setTarget:view];
+            let _: () = msg_send![view, setDoubleAction:sel!(rstRowDoubleClicked:)];
+        }
+
+        let scrollview = ScrollView::new();
+
+        unsafe {
+            let _: () = msg_send![&*scrollview.objc, setDocumentView:view];
+        }
+
+        let mut view = TableView::init(view, cell, scrollview);
+        (&mut delegate).did_load(view.clone_as_handle());
+        view.delegate = Some(delegate);
+        view
+    }
+}
+
+impl<T> TableView<T> {
+    /// Wires up the layout anchors (which, as with `ListView`, come from the `NSScrollView`).
+    fn init(view: id, cell_factory: CellFactory, scrollview: ScrollView) -> Self {
+        let anchor_view = &*scrollview.objc;
+
+        TableView {
+            cell_factory: cell_factory,
+            delegate: None,
+            top: LayoutAnchorY::new(unsafe { msg_send![anchor_view, topAnchor] }),
+            leading: LayoutAnchorX::new(unsafe { msg_send![anchor_view, leadingAnchor] }),
+            trailing: LayoutAnchorX::new(unsafe { msg_send![anchor_view, trailingAnchor] }),
+            bottom: LayoutAnchorY::new(unsafe { msg_send![anchor_view, bottomAnchor] }),
+            width: LayoutAnchorDimension::new(unsafe { msg_send![anchor_view, widthAnchor] }),
+            height: LayoutAnchorDimension::new(unsafe { msg_send![anchor_view, heightAnchor] }),
+            center_x: LayoutAnchorX::new(unsafe { msg_send![anchor_view, centerXAnchor] }),
+            center_y: LayoutAnchorY::new(unsafe { msg_send![anchor_view, centerYAnchor] }),
+            objc: unsafe { ShareId::from_ptr(view) },
+            scrollview: scrollview
+        }
+    }
+
+    /// An internal method that returns a clone of this object, sans references to the delegate or
+    /// callback pointer. We use this in calling `did_load()` - implementing delegates get a way to
+    /// reference, customize and use the view but without the trickery of holding pieces of the
+    /// delegate - the `TableView` is the only true holder of those.
+    pub(crate) fn clone_as_handle(&self) -> TableView {
+        TableView {
+            cell_factory: self.cell_factory.clone(),
+            delegate: None,
+            top: self.top.clone(),
+            leading: self.leading.clone(),
+            trailing: self.trailing.clone(),
+            bottom: self.bottom.clone(),
+            width: self.width.clone(),
+            height: self.height.clone(),
+            center_x: self.center_x.clone(),
+            center_y: self.center_y.clone(),
+            objc: self.objc.clone(),
+            scrollview: self.scrollview.clone_as_handle()
+        }
+    }
+
+    /// Adds a column to the end of the table.
+    pub fn add_column(&self, column: &TableColumn) {
+        unsafe {
+            let _: () = msg_send![&*self.objc, addTableColumn:&*column.objc];
+        }
+    }
+
+    /// Returns the `NSTableColumn` with the given identifier, or `nil`.
+    fn column_with_identifier(&self, identifier: &str) -> id {
+        unsafe {
+            msg_send![&*self.objc, tableColumnWithIdentifier:NSString::new(identifier).into_inner()]
+        }
+    }
+
+    /// Removes the column with the given identifier, if there is one.
+    pub fn remove_column(&self, identifier: &str) {
+        let column = self.column_with_identifier(identifier);
+
+        if column != nil {
+            unsafe {
+                let _: () = msg_send![&*self.objc, removeTableColumn:column];
+            }
+        }
+    }
+
+    /// Shows or hides the column with the given identifier. Visibility is persisted if an
+    /// autosave name is set.
+    pub fn set_column_hidden(&self, identifier: &str, hidden: bool) {
+        let column = self.column_with_identifier(identifier);
+
+        if column != nil {
+            unsafe {
+                let _: () = msg_send![column, setHidden:match hidden {
+                    true => YES,
+                    false => NO
+                }];
+            }
+        }
+    }
+
+    /// Returns the identifiers of the table's columns, in their current (possibly user-arranged)
+    /// order. Hidden columns are included.
+    pub fn column_identifiers(&self) -> Vec<String> {
+        let columns = NSArray::wrap(unsafe { msg_send![&*self.objc, tableColumns] });
+
+        columns.map(|column| {
+            NSString::wrap(unsafe { msg_send![column, identifier] }).to_string()
+        })
+    }
+
+    /// Sets whether the user can drag columns around to reorder them. Defaults to `true`.
+    pub fn set_allows_column_reordering(&self, allows: bool) {
+        unsafe {
+            let _: () = msg_send![&*self.objc, setAllowsColumnReordering:match allows {
+                true => YES,
+                false => NO
+            }];
+        }
+    }
+
+    /// Sets the name that column widths, order and visibility are saved under (in the user's
+    /// defaults), and restores them if they've been saved before. Call this after adding your
+    /// columns, so that there's something to restore.
+    pub fn set_autosave_name(&self, name: &str) {
+        unsafe {
+            let _: () = msg_send![&*self.objc, setAutosaveName:NSString::new(name).into_inner()];
+            let _: () = msg_send![&*self.objc, setAutosaveTableColumns:YES];
+        }
+    }
+
+    /// Returns the current sort order, most significant first.
+    pub fn sort_descriptors(&self) -> Vec<SortDescriptor> {
+        sort::sort_descriptors_from(unsafe { msg_send![&*self.objc, sortDescriptors] })
+    }
+
+    /// Sets the current sort order, which updates the sort indicators in the column headers.
+    /// This calls through to `TableViewDelegate::sort_descriptors_did_change()`.
+    pub fn set_sort_descriptors(&self, descriptors: &[SortDescriptor]) {
+        let descriptors: NSArray = descriptors.iter().map(|descriptor| {
+            descriptor.to_objc()
+        }).collect::<Vec<id>>().into();
+
+        unsafe {
+            let _: () = msg_send![&*self.objc, setSortDescriptors:descriptors.into_inner()];
+        }
+    }
+
+    /// Register a cell vendor function with an identifier. This is stored internally and used
+    /// for cell-reuse.
+    pub fn register<F, R>(&self, identifier: &'static str, vendor: F)
+    where
+        F: Fn() -> R + 'static,
+        R: ViewDelegate + 'static
+    {
+        self.cell_factory.insert(identifier, vendor);
+    }
+
+    /// Dequeue a reusable cell. If one is not in the queue, will create and cache one for reuse.
+    pub fn dequeue<R: ViewDelegate + 'static>(&self, identifier: &'static str) -> ListViewRow<R> {
+        unsafe {
+            let key = NSString::new(identifier).into_inner();
+            let cell: id = msg_send![&*self.objc, makeViewWithIdentifier:key owner:nil];
+
+            if cell != nil {
+                ListViewRow::from_cached(cell)
+            } else {
+                let delegate: Box<R> = self.cell_factory.get(identifier);
+                let view = ListViewRow::with_boxed(delegate);
+                view.set_identifier(identifier);
+                view
+            }
+        }
+    }
+
+    /// Reloads every row.
+    pub fn reload(&self) {
+        unsafe {
+            let _: () = msg_send![&*self.objc, reloadData];
+        }
+    }
+
+    /// Reloads the given rows, across every column.
+    pub fn reload_rows(&self, rows: &[usize]) {
+        unsafe {
+            let row_set: id = msg_send![class!(NSMutableIndexSet), new];
+
+            for row in rows {
+                let x: NSUInteger = *row as NSUInteger;
+                let _: () = msg_send![row_set, addIndex:x];
+            }
+
+            let count: NSInteger = msg_send![&*self.objc, numberOfColumns];
+            let column_set: id = msg_send![class!(NSMutableIndexSet), new];
+
+            for column in 0..count {
+                let x: NSUInteger = column as NSUInteger;
+                let _: () = msg_send![column_set, addIndex:x];
+            }
+
+            let x = ShareId::from_retained_ptr(row_set);
+            let y = ShareId::from_retained_ptr(column_set);
+            let _: () = msg_send![&*self.objc, reloadDataForRowIndexes:&*x columnIndexes:&*y];
+        }
+    }
+
+    /// Sets whether more than one row can be selected at a time. Defaults to `false`.
+    pub fn set_allows_multiple_selection(&self, allows: bool) {
+        unsafe {
+            let _: () = msg_send![&*self.objc, setAllowsMultipleSelection:match allows {
+                true => YES,
+                false => NO
+            }];
+        }
+    }
+
+    /// Returns the selected rows, in ascending order.
+    pub fn selected_rows(&self) -> Vec<usize> {
+        unsafe {
+            let index_set: id = msg_send![&*self.objc, selectedRowIndexes];
+            index_set_to_vec(index_set)
+        }
+    }
+
+    /// Call this to set the background color for the backing layer.
+    pub fn set_background_color(&self, color: Color) {
+        let bg = color.into_platform_specific_color();
+
+        unsafe {
+            let cg: id = msg_send![bg, CGColor];
+            let layer: id = msg_send![&*self.objc, layer];
+            let _: () = msg_send![layer, setBackgroundColor:cg];
+        }
+    }
+
+    /// Sets an enforced row-height, and turns off automatic row heights.
+    pub fn set_row_height(&self, height: CGFloat) {
+        unsafe {
+            let _: () = msg_send![&*self.objc, setUsesAutomaticRowHeights:NO];
+            let _: () = msg_send![&*self.objc, setRowHeight:height];
+        }
+    }
+
+    /// Instructs the underlying NSTableView to alternate background colors automatically.
+    pub fn set_uses_alternating_backgrounds(&self, uses: bool) {
+        unsafe {
+            let _: () = msg_send![&*self.objc, setUsesAlternatingRowBackgroundColors:match uses {
+                true => YES,
+                false => NO
+            }];
+        }
+    }
+}
+
+impl<T> Layout for TableView<T> {
+    /// This returns the NSScrollView, not the NSTableView.
+    fn get_backing_node(&self) -> ShareId<Object> {
+        self.scrollview.objc.clone()
+    }
+
+    fn add_subview<V: Layout>(&self, view: &V) {
+        let backing_node = view.get_backing_node();
+
+        unsafe {
+            let _: () = msg_send![&*self.scrollview.objc, addSubview:backing_node];
+        }
+    }
+}
+
+impl<T> Drop for TableView<T> {
+    /// If the originating `TableView` is being dropped, remove it from the view heirarchy, to
+    /// match the semantics of how Rust handles things.
+    fn drop(&mut self) {
+        if self.delegate.is_some() {
+            unsafe {
+                let superview: id = msg_send![&*self.scrollview.objc, superview];
+                if superview != nil {
+                    let _: () = msg_send![&*self.scrollview.objc, removeFromSuperview];
+                }
+            }
+        }
+    }
+}
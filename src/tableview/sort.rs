@@ -0,0 +1,54 @@
+//! Sort descriptors, as produced by clicking on sortable column headers.
+
+use objc::{class, msg_send, sel, sel_impl};
+
+use crate::foundation::{id, to_bool, BOOL, YES, NO, NSArray, NSString};
+
+/// Describes one level of a sort order: the key to sort on, and in which direction.
+///
+/// A table's sort order is a list of these, most significant first. The key is the one set via
+/// `TableColumn::set_sort_key()`; it's up to you what it means for your data.
+#[derive(Clone, Debug, PartialEq)]
+pub struct SortDescriptor {
+    /// The key to sort on.
+    pub key: String,
+
+    /// Whether to sort in ascending order.
+    pub ascending: bool
+}
+
+impl SortDescriptor {
+    /// Returns a new `SortDescriptor`.
+    pub fn new<S: Into<String>>(key: S, ascending: bool) -> Self {
+        SortDescriptor {
+            key: key.into(),
+            ascending: ascending
+        }
+    }
+
+    /// Reads a `SortDescriptor` out of an `NSSortDescriptor`.
+    pub(crate) fn from_objc(descriptor: id) -> Self {
+        let key = NSString::wrap(unsafe { msg_send![descriptor, key] });
+        let ascending: BOOL = unsafe { msg_send![descriptor, ascending] };
+
+        SortDescriptor {
+            key: key.to_string(),
+            ascending: to_bool(ascending)
+        }
+    }
+
+    /// Returns an autoreleased `NSSortDescriptor` for this descriptor.
+    pub(crate) fn to_objc(&self) -> id {
+        unsafe {
+            msg_send![class!(NSSortDescriptor), sortDescriptorWithKey:NSString::new(&self.key).into_inner() ascending:match self.ascending {
+                true => YES,
+                false => NO
+            }]
+        }
+    }
+}
+
+/// Reads an `NSArray` of `NSSortDescriptor`s.
+pub(crate) fn sort_descriptors_from(array: id) -> Vec<SortDescriptor> {
+    NSArray::wrap(array).map(SortDescriptor::from_objc)
+}
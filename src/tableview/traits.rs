@@ -0,0 +1,51 @@
+//! Various traits used for TableViews.
+
+use crate::listview::ListViewRow;
+use crate::tableview::{SortDescriptor, TableView};
+
+#[allow(unused_variables)]
+pub trait TableViewDelegate {
+    /// Used to cache subclass creations on the Objective-C side.
+    /// You can just set this to be the name of your view type. This
+    /// value *must* be unique per-type.
+    const NAME: &'static str;
+
+    /// You should rarely (read: probably never) need to implement this yourself.
+    /// It simply acts as a getter for the associated `NAME` const on this trait.
+    fn subclass_name(&self) -> &'static str {
+        Self::NAME
+    }
+
+    /// Called when the TableView is ready to work with. This is where you'll want to add your
+    /// columns (and then set an autosave name, if you want one). You're passed a `TableView` -
+    /// this is safe to store and use repeatedly, but it's not thread safe - any UI calls must be
+    /// made from the main thread!
+    fn did_load(&mut self, view: TableView);
+
+    /// Returns the number of rows in the table.
+    fn number_of_rows(&self) -> usize;
+
+    /// Returns the view for the cell at `row` in the column with the given identifier. Use
+    /// `TableView::dequeue()` to reuse cells as the table scrolls.
+    fn cell_for(&self, row: usize, column: &str) -> ListViewRow;
+
+    /// Called when the user clicks a sortable column header, with the table's new sort order
+    /// (most significant first). You should sort your data accordingly and reload the table.
+    fn sort_descriptors_did_change(&self, descriptors: Vec<SortDescriptor>) {}
+
+    /// Called when the user is about to select a row; return `false` to prevent it.
+    fn should_select(&self, row: usize) -> bool { true }
+
+    /// Called whenever the selection changes, with the now-selected rows in ascending order.
+    fn selection_did_change(&self, selected: Vec<usize>) {}
+
+    /// Called when a row is double clicked, along with the identifier of the column that was
+    /// clicked in.
+    fn row_double_clicked(&self, row: usize, column: Option<String>) {}
+
+    /// Called after the user drags a column to a new position. Positions include hidden columns.
+    fn column_did_move(&self, from: usize, to: usize) {}
+
+    /// Called after a column has been resized, with the identifier of that column.
+    fn column_did_resize(&self, column: &str) {}
+}
@@ -0,0 +1,355 @@
+//! Bridges `AttributedString` to and from `NSAttributedString`.
+
+use core_graphics::base::CGFloat;
+use objc::runtime::BOOL;
+use objc::{class, msg_send, sel, sel_impl};
+use objc_id::ShareId;
+
+use crate::color::Color;
+use crate::foundation::{id, nil, to_bool, NSInteger, NSRange, NSString, NSUInteger};
+use crate::text::{Font, FontTrait, FontWeight, LineBreakMode, TextAlign};
+use super::{AttributedString, Attribute, ParagraphStyle, UnderlineStyle};
+
+extern "C" {
+    static NSFontAttributeName: id;
+    static NSForegroundColorAttributeName: id;
+    static NSBackgroundColorAttributeName: id;
+    static NSUnderlineStyleAttributeName: id;
+    static NSStrikethroughStyleAttributeName: id;
+    static NSLinkAttributeName: id;
+    static NSParagraphStyleAttributeName: id;
+}
+
+impl From<UnderlineStyle> for NSInteger {
+    fn from(style: UnderlineStyle) -> Self {
+        match style {
+            UnderlineStyle::Single => 0x01,
+            UnderlineStyle::Thick => 0x02,
+            UnderlineStyle::Double => 0x09
+        }
+    }
+}
+
+impl UnderlineStyle {
+    /// Maps an `NSUnderlineStyle` back, ignoring pattern flags. `None` means no line.
+    fn from_objc(style: NSInteger) -> Option<Self> {
+        match style & 0xff {
+            0 => None,
+            0x02 => Some(UnderlineStyle::Thick),
+            0x09 => Some(UnderlineStyle::Double),
+            _ => Some(UnderlineStyle::Single)
+        }
+    }
+}
+
+/// Works out the font for a run, combining an explicit `Font` (or the system font) with any
+/// weight, italic and monospace attributes. Returns `None` if nothing font-related applies.
+unsafe fn resolve_font(attributes: &[&Attribute]) -> Option<Font> {
+    let mut font = None;
+    let mut weight = None;
+    let mut italic = false;
+    let mut monospace = false;
+
+    for attribute in attributes {
+        match attribute {
+            Attribute::Font(f) => { font = Some(f); },
+            Attribute::Weight(w) => { weight = Some(*w); },
+            Attribute::Italic => { italic = true; },
+            Attribute::Monospace => { monospace = true; },
+            _ => {}
+        }
+    }
+
+    if font.is_none() && weight.is_none() && !italic && !monospace {
+        return None;
+    }
+
+    let mut traits = Vec::new();
+
+    if italic {
+        traits.push(FontTrait::Italic);
+    }
+
+    let font = match font {
+        // An arbitrary font can't take an arbitrary weight, so we ask for the closest trait.
+        Some(font) => {
+            if weight.map_or(false, |w| w.is_bold()) {
+                traits.push(FontTrait::Bold);
+            }
+
+            if monospace {
+                traits.push(FontTrait::Monospace);
+            }
+
+            font.clone()
+        },
+
+        None => {
+            let size: CGFloat = msg_send![class!(NSFont), systemFontSize];
+            let weight = weight.unwrap_or(FontWeight::Regular);
+
+            match monospace {
+                true => Font::monospaced_system(size, weight),
+                false => Font::system_with_weight(size, weight)
+            }
+        }
+    };
+
+    // If the font has no such variant, this hands back what we have.
+    Some(match traits.is_empty() {
+        true => font,
+        false => font.with_traits(&traits)
+    })
+}
+
+/// Builds an `NSParagraphStyle` for the given style. The returned object is autoreleased.
+unsafe fn paragraph_style(style: &ParagraphStyle) -> id {
+    let paragraph: id = msg_send![class!(NSMutableParagraphStyle), new];
+
+    if let Some(alignment) = style.alignment {
+        let alignment: NSInteger = alignment.into();
+        let _: () = msg_send![paragraph, setAlignment:alignment];
+    }
+
+    if let Some(mode) = style.line_break_mode {
+        let mode: NSUInteger = mode.into();
+        let _: () = msg_send![paragraph, setLineBreakMode:mode];
+    }
+
+    if let Some(spacing) = style.line_spacing {
+        let _: () = msg_send![paragraph, setLineSpacing:spacing];
+    }
+
+    if let Some(spacing) = style.paragraph_spacing {
+        let _: () = msg_send![paragraph, setParagraphSpacing:spacing];
+    }
+
+    if let Some(indent) = style.first_line_head_indent {
+        let _: () = msg_send![paragraph, setFirstLineHeadIndent:indent];
+    }
+
+    if let Some(indent) = style.head_indent {
+        let _: () = msg_send![paragraph, setHeadIndent:indent];
+
+        // A left-aligned (NSTextAlignmentLeft) tab stop at the indent, for list markers.
+        let alloc: id = msg_send![class!(NSTextTab), alloc];
+        let tab: id = msg_send![alloc, initWithTextAlignment:0 as NSInteger location:indent options:nil];
+        let tabs: id = msg_send![class!(NSArray), arrayWithObject:tab];
+        let _: () = msg_send![tab, release];
+        let _: () = msg_send![paragraph, setTabStops:tabs];
+    }
+
+    msg_send![paragraph, autorelease]
+}
+
+/// Builds the attributes dictionary for a run. The returned object is autoreleased.
+unsafe fn attributes_dictionary(attributes: &[&Attribute]) -> id {
+    let dictionary: id = msg_send![class!(NSMutableDictionary), dictionary];
+
+    if let Some(font) = resolve_font(attributes) {
+        let _: () = msg_send![dictionary, setObject:&*font.objc forKey:NSFontAttributeName];
+    }
+
+    // Later attributes win, so we just write them in order.
+    for attribute in attributes {
+        match attribute {
+            Attribute::Color(color) => {
+                let _: () = msg_send![dictionary, setObject:color.to_objc() forKey:NSForegroundColorAttributeName];
+            },
+
+            Attribute::BackgroundColor(color) => {
+                let _: () = msg_send![dictionary, setObject:color.to_objc() forKey:NSBackgroundColorAttributeName];
+            },
+
+            Attribute::Underline(style) => {
+                let style: NSInteger = (*style).into();
+                let number: id = msg_send![class!(NSNumber), numberWithInteger:style];
+                let _: () = msg_send![dictionary, setObject:number forKey:NSUnderlineStyleAttributeName];
+            },
+
+            Attribute::Strikethrough(style) => {
+                let style: NSInteger = (*style).into();
+                let number: id = msg_send![class!(NSNumber), numberWithInteger:style];
+                let _: () = msg_send![dictionary, setObject:number forKey:NSStrikethroughStyleAttributeName];
+            },
+
+            Attribute::Link(url) => {
+                let string = NSString::new(url).into_inner();
+                let link: id = msg_send![class!(NSURL), URLWithString:string];
+
+                // Not everything that's linkable parses as a URL; AppKit also takes a string.
+                let link = match link == nil {
+                    true => string,
+                    false => link
+                };
+
+                let _: () = msg_send![dictionary, setObject:link forKey:NSLinkAttributeName];
+            },
+
+            Attribute::Paragraph(style) => {
+                let _: () = msg_send![dictionary, setObject:paragraph_style(style) forKey:NSParagraphStyleAttributeName];
+            },
+
+            _ => {}
+        }
+    }
+
+    dictionary
+}
+
+impl AttributedString {
+    /// Converts this to an `NSMutableAttributedString`. The returned object is autoreleased.
+    ///
+    /// This method is primarily for internal use, but is kept public for those who might need to
+    /// hand attributed text to a control this framework doesn't wrap.
+    pub fn to_objc(&self) -> id {
+        // Split the text at every attribute boundary, so that each run has a fixed set of
+        // attributes to resolve.
+        let mut boundaries: Vec<usize> = self.attributes.iter()
+            .flat_map(|(range, _)| vec![range.start, range.end])
+            .chain(vec![0, self.text.len()])
+            .collect();
+
+        boundaries.sort();
+        boundaries.dedup();
+
+        unsafe {
+            let result: id = msg_send![class!(NSMutableAttributedString), new];
+
+            for run in boundaries.windows(2) {
+                let (start, end) = (run[0], run[1]);
+
+                let attributes: Vec<&Attribute> = self.attributes.iter()
+                    .filter(|(range, _)| range.start <= start && range.end >= end)
+                    .map(|(_, attribute)| attribute)
+                    .collect();
+
+                let string = NSString::new(&self.text[start..end]);
+                let alloc: id = msg_send![class!(NSAttributedString), alloc];
+                let piece: id = msg_send![alloc, initWithString:string.into_inner() attributes:attributes_dictionary(&attributes)];
+                let _: () = msg_send![result, appendAttributedString:piece];
+                let _: () = msg_send![piece, release];
+            }
+
+            msg_send![result, autorelease]
+        }
+    }
+
+    /// Reads an `NSAttributedString` back into the Rust-side model. Fonts and colors are kept as
+    /// the objects AppKit vended, rather than being broken back down into weights and traits.
+    pub(crate) fn from_objc(object: id) -> Self {
+        let text = NSString::wrap(unsafe { msg_send![object, string] }).to_string();
+
+        // Maps UTF-16 offsets (AppKit) to byte offsets (Rust).
+        let mut offsets: Vec<usize> = Vec::with_capacity(text.len() + 1);
+        for (index, c) in text.char_indices() {
+            for _ in 0..c.len_utf16() {
+                offsets.push(index);
+            }
+        }
+        offsets.push(text.len());
+
+        let mut string = AttributedString::from(text);
+        let length = offsets.len() - 1;
+        let mut location = 0;
+
+        while location < length {
+            let mut range = NSRange::default();
+
+            unsafe {
+                let attributes: id = msg_send![object, attributesAtIndex:location as NSUInteger effectiveRange:&mut range];
+                let start = offsets[range.location as usize];
+                let end = offsets[(range.location + range.length) as usize];
+
+                let font: id = msg_send![attributes, objectForKey:NSFontAttributeName];
+                if font != nil {
+                    string.add_attribute(start..end, Attribute::Font(Font {
+                        objc: ShareId::from_ptr(font)
+                    }));
+                }
+
+                let color: id = msg_send![attributes, objectForKey:NSForegroundColorAttributeName];
+                if color != nil {
+                    string.add_attribute(start..end, Attribute::Color(Color::Object(ShareId::from_ptr(color))));
+                }
+
+                let color: id = msg_send![attributes, objectForKey:NSBackgroundColorAttributeName];
+                if color != nil {
+                    string.add_attribute(start..end, Attribute::BackgroundColor(Color::Object(ShareId::from_ptr(color))));
+                }
+
+                let underline: id = msg_send![attributes, objectForKey:NSUnderlineStyleAttributeName];
+                if underline != nil {
+                    let style: NSInteger = msg_send![underline, integerValue];
+
+                    if let Some(style) = UnderlineStyle::from_objc(style) {
+                        string.add_attribute(start..end, Attribute::Underline(style));
+                    }
+                }
+
+                let strikethrough: id = msg_send![attributes, objectForKey:NSStrikethroughStyleAttributeName];
+                if strikethrough != nil {
+                    let style: NSInteger = msg_send![strikethrough, integerValue];
+
+                    if let Some(style) = UnderlineStyle::from_objc(style) {
+                        string.add_attribute(start..end, Attribute::Strikethrough(style));
+                    }
+                }
+
+                let link: id = msg_send![attributes, objectForKey:NSLinkAttributeName];
+                if link != nil {
+                    let is_url: BOOL = msg_send![link, isKindOfClass:class!(NSURL)];
+                    let link: id = match to_bool(is_url) {
+                        true => msg_send![link, absoluteString],
+                        false => link
+                    };
+
+                    string.add_attribute(start..end, Attribute::Link(NSString::wrap(link).to_string()));
+                }
+
+                let paragraph: id = msg_send![attributes, objectForKey:NSParagraphStyleAttributeName];
+                if paragraph != nil {
+                    string.add_attribute(start..end, Attribute::Paragraph(read_paragraph_style(paragraph)));
+                }
+            }
+
+            location = std::cmp::max(location + 1, (range.location + range.length) as usize);
+        }
+
+        string
+    }
+}
+
+/// Reads the parts of an `NSParagraphStyle` that `ParagraphStyle` models.
+unsafe fn read_paragraph_style(paragraph: id) -> ParagraphStyle {
+    let alignment: NSInteger = msg_send![paragraph, alignment];
+    let mode: NSUInteger = msg_send![paragraph, lineBreakMode];
+    let line_spacing: CGFloat = msg_send![paragraph, lineSpacing];
+    let paragraph_spacing: CGFloat = msg_send![paragraph, paragraphSpacing];
+    let first_line_head_indent: CGFloat = msg_send![paragraph, firstLineHeadIndent];
+    let head_indent: CGFloat = msg_send![paragraph, headIndent];
+
+    ParagraphStyle {
+        alignment: Some(match alignment {
+            0 => TextAlign::Left,
+            1 => TextAlign::Center,
+            2 => TextAlign::Right,
+            3 => TextAlign::Justified,
+            _ => TextAlign::Natural
+        }),
+
+        line_break_mode: Some(match mode {
+            1 => LineBreakMode::WrapChars,
+            2 => LineBreakMode::Clip,
+            3 => LineBreakMode::TruncateHead,
+            4 => LineBreakMode::TruncateTail,
+            5 => LineBreakMode::TruncateMiddle,
+            _ => LineBreakMode::WrapWords
+        }),
+
+        line_spacing: Some(line_spacing),
+        paragraph_spacing: Some(paragraph_spacing),
+        first_line_head_indent: Some(first_line_head_indent),
+        head_indent: Some(head_indent)
+    }
+}
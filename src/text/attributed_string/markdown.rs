@@ -0,0 +1,628 @@
+//! A small CommonMark-subset parser that produces an `AttributedString`.
+//!
+//! Supported are paragraphs (separated by blank lines), `**bold**`/`__bold__`,
+//! `*italic*`/`_italic_`, `` `code` `` spans, fenced code blocks, `[links](url)`, bulleted (`-`,
+//! `*`, `+`) and numbered (`1.`) lists nested by indentation, and backslash escapes. Anything
+//! else comes through as plain text. None of this touches the Objective-C runtime.
+
+use core_graphics::base::CGFloat;
+
+use crate::text::FontWeight;
+use super::{AttributedString, Attribute, ParagraphStyle};
+
+/// How far each level of list nesting is indented, in points.
+const LIST_INDENT: CGFloat = 18.;
+
+/// A block-level element.
+enum Block {
+    Paragraph(String),
+    Code(String),
+    Item {
+        level: usize,
+        marker: String,
+        text: String
+    }
+}
+
+/// Parses `source` into an `AttributedString`.
+pub(crate) fn parse(source: &str) -> AttributedString {
+    let mut output = AttributedString::default();
+    let mut previous_was_item = false;
+
+    for (index, block) in blocks(source).into_iter().enumerate() {
+        let is_item = match block {
+            Block::Item { .. } => true,
+            _ => false
+        };
+
+        // List items sit on consecutive lines; everything else is separated by a blank line.
+        if index > 0 {
+            output.push_str(match previous_was_item && is_item {
+                true => "\n",
+                false => "\n\n"
+            });
+        }
+
+        let start = output.len();
+
+        match block {
+            Block::Paragraph(text) => inline(&text, &mut output),
+            Block::Code(code) => output.push_styled(&code, &[Attribute::Monospace]),
+
+            Block::Item { level, marker, text } => {
+                output.push_str(&marker);
+                output.push_str("\t");
+                inline(&text, &mut output);
+
+                let indent = LIST_INDENT * (level + 1) as CGFloat;
+                let end = output.len();
+                output.add_attribute(start..end, Attribute::Paragraph(ParagraphStyle {
+                    first_line_head_indent: Some(indent - LIST_INDENT),
+                    head_indent: Some(indent),
+                    ..ParagraphStyle::default()
+                }));
+            }
+        }
+
+        previous_was_item = is_item;
+    }
+
+    output
+}
+
+/// Returns the width of a line's leading whitespace, counting tabs as four spaces.
+fn indent_width(line: &str) -> usize {
+    line.chars()
+        .take_while(|c| c.is_whitespace())
+        .map(|c| match c {
+            '\t' => 4,
+            _ => 1
+        })
+        .sum()
+}
+
+/// Returns whether a (left-trimmed) line opens or closes a fenced code block.
+fn is_fence(trimmed: &str) -> bool {
+    trimmed.starts_with("```") || trimmed.starts_with("~~~")
+}
+
+/// If a (left-trimmed) line starts a list item, returns the marker to display and the rest of
+/// the line.
+fn list_item(trimmed: &str) -> Option<(String, &str)> {
+    for bullet in &["- ", "* ", "+ "] {
+        if trimmed.starts_with(bullet) {
+            return Some(("\u{2022}".to_string(), &trimmed[2..]));
+        }
+    }
+
+    let digits = trimmed.chars().take_while(|c| c.is_ascii_digit()).count();
+    if digits > 0 && digits < 10 {
+        let rest = &trimmed[digits..];
+
+        if rest.starts_with(". ") || rest.starts_with(") ") {
+            return Some((format!("{}.", &trimmed[..digits]), &rest[2..]));
+        }
+    }
+
+    None
+}
+
+/// Splits the source into blocks. Lines within a paragraph (or list item) are joined with a
+/// space, as CommonMark treats single newlines as soft breaks.
+fn blocks(source: &str) -> Vec<Block> {
+    let mut blocks = Vec::new();
+    let mut lines = source.lines().peekable();
+
+    while let Some(line) = lines.next() {
+        let trimmed = line.trim_start();
+
+        if trimmed.is_empty() {
+            continue;
+        }
+
+        if is_fence(trimmed) {
+            let fence = &trimmed[..3];
+            let mut code = Vec::new();
+
+            while let Some(line) = lines.next() {
+                if line.trim_start().starts_with(fence) {
+                    break;
+                }
+
+                code.push(line);
+            }
+
+            blocks.push(Block::Code(code.join("\n")));
+            continue;
+        }
+
+        if let Some((marker, text)) = list_item(trimmed) {
+            let mut text = text.trim().to_string();
+
+            // Indented lines that don't start a new item continue this one.
+            while let Some(next) = lines.peek() {
+                let next_trimmed = next.trim_start();
+
+                if next_trimmed.is_empty() || indent_width(next) == 0 || list_item(next_trimmed).is_some() {
+                    break;
+                }
+
+                text.push(' ');
+                text.push_str(next_trimmed.trim_end());
+                lines.next();
+            }
+
+            blocks.push(Block::Item {
+                level: indent_width(line) / 2,
+                marker: marker,
+                text: text
+            });
+
+            continue;
+        }
+
+        let mut text = trimmed.trim_end().to_string();
+
+        while let Some(next) = lines.peek() {
+            let next_trimmed = next.trim_start();
+
+            if next_trimmed.is_empty() || is_fence(next_trimmed) || list_item(next_trimmed).is_some() {
+                break;
+            }
+
+            text.push(' ');
+            text.push_str(next_trimmed.trim_end());
+            lines.next();
+        }
+
+        blocks.push(Block::Paragraph(text));
+    }
+
+    blocks
+}
+
+/// Counts how many times `c` repeats at the start of `source`.
+fn run_of(source: &str, c: char) -> usize {
+    source.chars().take_while(|&next| next == c).count()
+}
+
+/// Parses inline formatting in `source`, appending the result to `output`.
+fn inline(source: &str, output: &mut AttributedString) {
+    let mut rest = source;
+
+    while let Some(c) = rest.chars().next() {
+        match c {
+            '\\' => {
+                if let Some(escaped) = rest[1..].chars().next() {
+                    if escaped.is_ascii_punctuation() {
+                        output.push_str(&rest[1..2]);
+                        rest = &rest[2..];
+                        continue;
+                    }
+                }
+            },
+
+            '`' => {
+                let consumed = match code_span(rest) {
+                    Some((code, consumed)) => {
+                        output.push_styled(code, &[Attribute::Monospace]);
+                        consumed
+                    },
+
+                    // An unmatched run of backticks is literal, all of it.
+                    None => {
+                        let run = run_of(rest, '`');
+                        output.push_str(&rest[..run]);
+                        run
+                    }
+                };
+
+                rest = &rest[consumed..];
+                continue;
+            },
+
+            '[' => {
+                if let Some((text, url, consumed)) = link(rest) {
+                    let start = output.len();
+                    inline(text, output);
+
+                    let end = output.len();
+                    output.add_attribute(start..end, Attribute::Link(url.to_string()));
+                    rest = &rest[consumed..];
+                    continue;
+                }
+            },
+
+            '*' | '_' => {
+                let previous = output.as_str().chars().last();
+
+                if let Some((text, consumed, strong)) = emphasis(rest, previous) {
+                    let start = output.len();
+                    inline(text, output);
+
+                    let end = output.len();
+                    output.add_attribute(start..end, match strong {
+                        true => Attribute::Weight(FontWeight::Bold),
+                        false => Attribute::Italic
+                    });
+
+                    rest = &rest[consumed..];
+                    continue;
+                }
+            },
+
+            _ => {}
+        }
+
+        let len = c.len_utf8();
+        output.push_str(&rest[..len]);
+        rest = &rest[len..];
+    }
+}
+
+/// Matches a code span at the start of `source`, returning its contents and how many bytes it
+/// spans. The closing backtick run has to be the same length as the opening one.
+fn code_span(source: &str) -> Option<(&str, usize)> {
+    let ticks = run_of(source, '`');
+    let body = &source[ticks..];
+    let mut index = 0;
+
+    while let Some(offset) = body[index..].find('`') {
+        let start = index + offset;
+        let run = run_of(&body[start..], '`');
+
+        if run == ticks {
+            let mut code = &body[..start];
+
+            // A single leading and trailing space is stripped, so that backticks can be quoted.
+            if code.len() > 2 && code.starts_with(' ') && code.ends_with(' ') {
+                code = &code[1..code.len() - 1];
+            }
+
+            return Some((code, ticks + start + run));
+        }
+
+        index = start + run;
+    }
+
+    None
+}
+
+/// Matches a `[text](url)` link at the start of `source`, returning the text, the URL and how
+/// many bytes it spans. An optional title after the URL is dropped.
+fn link(source: &str) -> Option<(&str, &str, usize)> {
+    let mut depth = 0;
+    let mut escaped = false;
+    let mut close = None;
+
+    for (index, c) in source.char_indices() {
+        if escaped {
+            escaped = false;
+            continue;
+        }
+
+        match c {
+            '\\' => { escaped = true; },
+            '[' => { depth += 1; },
+
+            ']' => {
+                depth -= 1;
+
+                if depth == 0 {
+                    close = Some(index);
+                    break;
+                }
+            },
+
+            _ => {}
+        }
+    }
+
+    let close = close?;
+    let destination = &source[close + 1..];
+
+    if !destination.starts_with('(') {
+        return None;
+    }
+
+    // Destinations can contain balanced parentheses (e.g, Wikipedia URLs), so the link ends at
+    // the `)` that closes the opening one rather than the first `)` we see.
+    let mut depth = 0;
+    let mut escaped = false;
+    let mut end = None;
+
+    for (index, c) in destination.char_indices() {
+        if escaped {
+            escaped = false;
+            continue;
+        }
+
+        match c {
+            '\\' => { escaped = true; },
+            '(' => { depth += 1; },
+
+            ')' => {
+                depth -= 1;
+
+                if depth == 0 {
+                    end = Some(index);
+                    break;
+                }
+            },
+
+            _ => {}
+        }
+    }
+
+    let end = end?;
+    let url = destination[1..end].split_whitespace().next().unwrap_or("");
+    let url = url.trim_start_matches('<').trim_end_matches('>');
+
+    Some((&source[1..close], url, close + 1 + end + 1))
+}
+
+/// Matches emphasis at the start of `source` - `**strong**` or `*emphasized*` (or the same with
+/// underscores). Returns the inner text, how many bytes it spans, and whether it's strong.
+///
+/// `previous` is the character before the opening delimiter; underscores in the middle of a word
+/// (e.g, `snake_case_name`) don't count.
+fn emphasis(source: &str, previous: Option<char>) -> Option<(&str, usize, bool)> {
+    let delimiter = source.chars().next()?;
+
+    if delimiter == '_' && previous.map_or(false, |c| c.is_alphanumeric()) {
+        return None;
+    }
+
+    let widths: &[usize] = match run_of(source, delimiter) >= 2 {
+        true => &[2, 1],
+        false => &[1]
+    };
+
+    for &width in widths {
+        let body = &source[width..];
+
+        if body.is_empty() || body.starts_with(char::is_whitespace) {
+            continue;
+        }
+
+        if let Some(close) = find_closer(body, delimiter, width) {
+            return Some((&body[..close], width + close + width, width == 2));
+        }
+    }
+
+    None
+}
+
+/// Finds the closing delimiter for emphasis of the given width in `body`, skipping over code
+/// spans and escapes. A run of two never closes single emphasis (it's strong emphasis nested
+/// inside), and vice versa.
+fn find_closer(body: &str, delimiter: char, width: usize) -> Option<usize> {
+    let mut index = 0;
+
+    while let Some(c) = body[index..].chars().next() {
+        if c == '\\' {
+            index += 1 + body[index + 1..].chars().next().map_or(0, |c| c.len_utf8());
+            continue;
+        }
+
+        if c == '`' {
+            if let Some((_, consumed)) = code_span(&body[index..]) {
+                index += consumed;
+                continue;
+            }
+        }
+
+        if c == delimiter {
+            let run = run_of(&body[index..], delimiter);
+            let previous = body[..index].chars().last();
+            let next = body[index + run..].chars().next();
+
+            let closes = index > 0
+                && (run == width || run > 2)
+                && !previous.map_or(true, |c| c.is_whitespace())
+                && !(delimiter == '_' && next.map_or(false, |c| c.is_alphanumeric()));
+
+            if closes {
+                return Some(index + run - width);
+            }
+
+            index += run;
+            continue;
+        }
+
+        index += c.len_utf8();
+    }
+
+    None
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    /// Returns the text each attribute matching `pick` covers, in order.
+    fn spans<F>(output: &AttributedString, pick: F) -> Vec<&str>
+    where
+        F: Fn(&Attribute) -> bool
+    {
+        output.attributes().iter()
+            .filter(|(_, attribute)| pick(attribute))
+            .map(|(range, _)| &output.as_str()[range.clone()])
+            .collect()
+    }
+
+    fn bold(output: &AttributedString) -> Vec<&str> {
+        spans(output, |attribute| match attribute {
+            Attribute::Weight(FontWeight::Bold) => true,
+            _ => false
+        })
+    }
+
+    fn italic(output: &AttributedString) -> Vec<&str> {
+        spans(output, |attribute| match attribute {
+            Attribute::Italic => true,
+            _ => false
+        })
+    }
+
+    fn monospace(output: &AttributedString) -> Vec<&str> {
+        spans(output, |attribute| match attribute {
+            Attribute::Monospace => true,
+            _ => false
+        })
+    }
+
+    fn links(output: &AttributedString) -> Vec<(&str, String)> {
+        output.attributes().iter()
+            .filter_map(|(range, attribute)| match attribute {
+                Attribute::Link(url) => Some((&output.as_str()[range.clone()], url.clone())),
+                _ => None
+            })
+            .collect()
+    }
+
+    fn paragraphs(output: &AttributedString) -> Vec<(&str, ParagraphStyle)> {
+        output.attributes().iter()
+            .filter_map(|(range, attribute)| match attribute {
+                Attribute::Paragraph(style) => Some((&output.as_str()[range.clone()], *style)),
+                _ => None
+            })
+            .collect()
+    }
+
+    #[test]
+    fn plain_text_has_no_attributes() {
+        let output = parse("Just some text.");
+        assert_eq!(output.as_str(), "Just some text.");
+        assert!(output.attributes().is_empty());
+    }
+
+    #[test]
+    fn bold_and_italic() {
+        let output = parse("**bold** and *italic*, __bold__ and _italic_");
+        assert_eq!(output.as_str(), "bold and italic, bold and italic");
+        assert_eq!(bold(&output), vec!["bold", "bold"]);
+        assert_eq!(italic(&output), vec!["italic", "italic"]);
+    }
+
+    #[test]
+    fn emphasis_nests() {
+        let output = parse("*a **b** c*");
+        assert_eq!(output.as_str(), "a b c");
+        assert_eq!(bold(&output), vec!["b"]);
+        assert_eq!(italic(&output), vec!["a b c"]);
+
+        let output = parse("***both***");
+        assert_eq!(output.as_str(), "both");
+        assert_eq!(bold(&output), vec!["both"]);
+        assert_eq!(italic(&output), vec!["both"]);
+    }
+
+    #[test]
+    fn underscores_inside_words_are_literal() {
+        let output = parse("call snake_case_name here");
+        assert_eq!(output.as_str(), "call snake_case_name here");
+        assert!(output.attributes().is_empty());
+    }
+
+    #[test]
+    fn unclosed_emphasis_is_literal() {
+        let output = parse("*unclosed and ** spaced **");
+        assert_eq!(output.as_str(), "*unclosed and ** spaced **");
+        assert!(output.attributes().is_empty());
+    }
+
+    #[test]
+    fn code_spans_are_not_parsed() {
+        let output = parse("run `co*de*` now");
+        assert_eq!(output.as_str(), "run co*de* now");
+        assert_eq!(monospace(&output), vec!["co*de*"]);
+        assert!(italic(&output).is_empty());
+    }
+
+    #[test]
+    fn code_spans_can_quote_backticks() {
+        let output = parse("``a`b`` and `` `tick` ``");
+        assert_eq!(output.as_str(), "a`b and `tick`");
+        assert_eq!(monospace(&output), vec!["a`b", "`tick`"]);
+    }
+
+    #[test]
+    fn unmatched_backticks_are_literal() {
+        let output = parse("a ``b` c");
+        assert_eq!(output.as_str(), "a ``b` c");
+        assert!(output.attributes().is_empty());
+    }
+
+    #[test]
+    fn escapes_are_literal() {
+        let output = parse("\\*not italic\\* and \\`not code\\` and \\[x\\](y) and a\\b");
+        assert_eq!(output.as_str(), "*not italic* and `not code` and [x](y) and a\\b");
+        assert!(output.attributes().is_empty());
+    }
+
+    #[test]
+    fn links_carry_their_url() {
+        let output = parse("see [the *docs*](https://example.com/docs \"Title\") now");
+        assert_eq!(output.as_str(), "see the docs now");
+        assert_eq!(links(&output), vec![("the docs", "https://example.com/docs".to_string())]);
+        assert_eq!(italic(&output), vec!["docs"]);
+
+        let output = parse("[angled](<https://example.com>)");
+        assert_eq!(links(&output), vec![("angled", "https://example.com".to_string())]);
+    }
+
+    #[test]
+    fn link_destinations_can_contain_parentheses() {
+        let output = parse("[x](https://en.wikipedia.org/wiki/Foo_(bar)) after");
+        assert_eq!(output.as_str(), "x after");
+        assert_eq!(links(&output), vec![("x", "https://en.wikipedia.org/wiki/Foo_(bar)".to_string())]);
+
+        let output = parse("[x](a\\)b)");
+        assert_eq!(links(&output), vec![("x", "a\\)b".to_string())]);
+    }
+
+    #[test]
+    fn incomplete_links_are_literal() {
+        let output = parse("[x](y and [z] and [w](v");
+        assert_eq!(output.as_str(), "[x](y and [z] and [w](v");
+        assert!(output.attributes().is_empty());
+    }
+
+    #[test]
+    fn paragraphs_join_soft_breaks() {
+        let output = parse("one\ntwo\n\nthree");
+        assert_eq!(output.as_str(), "one two\n\nthree");
+    }
+
+    #[test]
+    fn lists() {
+        let output = parse("- one\n  continued\n- **two**\n  1. nested\n\nafter");
+        assert_eq!(output.as_str(), "\u{2022}\tone continued\n\u{2022}\ttwo\n1.\tnested\n\nafter");
+        assert_eq!(bold(&output), vec!["two"]);
+
+        let styles = paragraphs(&output);
+        assert_eq!(styles.len(), 3);
+        assert_eq!(styles[0].0, "\u{2022}\tone continued");
+        assert_eq!(styles[0].1.first_line_head_indent, Some(0.));
+        assert_eq!(styles[0].1.head_indent, Some(LIST_INDENT));
+        assert_eq!(styles[2].0, "1.\tnested");
+        assert_eq!(styles[2].1.first_line_head_indent, Some(LIST_INDENT));
+        assert_eq!(styles[2].1.head_indent, Some(LIST_INDENT * 2.));
+    }
+
+    #[test]
+    fn fenced_code_is_verbatim() {
+        let output = parse("before\n```rust\nlet *x* = 1;\n\n  indented\n```\nafter");
+        assert_eq!(output.as_str(), "before\n\nlet *x* = 1;\n\n  indented\n\nafter");
+        assert_eq!(monospace(&output), vec!["let *x* = 1;\n\n  indented"]);
+        assert!(italic(&output).is_empty());
+    }
+
+    #[test]
+    fn multibyte_text_keeps_char_boundaries() {
+        let output = parse("ünï *cødé* ✓");
+        assert_eq!(output.as_str(), "ünï cødé ✓");
+        assert_eq!(italic(&output), vec!["cødé"]);
+    }
+}
@@ -0,0 +1,313 @@
+//! `AttributedString` is a Rust-side model of styled text: a `String`, plus a list of
+//! attributes (font, weight, color, links and so on) applied to ranges of it.
+//!
+//! Keeping the model on the Rust side means it can be built up, parsed from Markdown and
+//! inspected without touching the Objective-C runtime; it's only converted to an
+//! `NSAttributedString` when it's handed to a control.
+//!
+//! ```rust,no_run
+//! use cacao::color::Color;
+//! use cacao::text::{AttributedString, Attribute, FontWeight, Label};
+//!
+//! let text = AttributedString::builder()
+//!     .text("Build ")
+//!     .styled("failed", &[Attribute::Weight(FontWeight::Bold), Attribute::Color(Color::SystemRed)])
+//!     .text(" - see the ")
+//!     .link("logs", "https://example.com/logs")
+//!     .build();
+//!
+//! let label = Label::new();
+//! label.set_attributed_text(&text);
+//!
+//! // Or, for simple cases:
+//! label.set_attributed_text(&AttributedString::from_markdown("Build **failed** - see the [logs](https://example.com/logs)"));
+//! ```
+//!
+//! Ranges here are byte ranges into the Rust `String`, and must fall on `char` boundaries. They're
+//! converted to UTF-16 offsets (which is what AppKit works in) when bridging.
+
+use std::fmt;
+use std::ops::Range;
+
+use core_graphics::base::CGFloat;
+
+use crate::color::Color;
+use crate::text::{Font, FontWeight, LineBreakMode, TextAlign};
+
+mod convert;
+
+mod markdown;
+
+/// How to draw an underline or strikethrough.
+#[derive(Copy, Clone, Debug, PartialEq)]
+pub enum UnderlineStyle {
+    /// A single, thin line.
+    Single,
+
+    /// A single, thick line.
+    Thick,
+
+    /// Two thin lines.
+    Double
+}
+
+/// Paragraph-level formatting. Any field left as `None` uses the system default.
+#[derive(Copy, Clone, Debug, Default, PartialEq)]
+pub struct ParagraphStyle {
+    /// How lines are aligned.
+    pub alignment: Option<TextAlign>,
+
+    /// How lines are broken (or truncated).
+    pub line_break_mode: Option<LineBreakMode>,
+
+    /// Extra space between lines, in points.
+    pub line_spacing: Option<CGFloat>,
+
+    /// Extra space after the paragraph, in points.
+    pub paragraph_spacing: Option<CGFloat>,
+
+    /// The indentation of the first line, in points.
+    pub first_line_head_indent: Option<CGFloat>,
+
+    /// The indentation of lines other than the first, in points. This also sets a tab stop, so
+    /// that a "marker\ttext" line (as used for lists) lines up with the lines that follow it.
+    pub head_indent: Option<CGFloat>
+}
+
+/// An attribute that can be applied to a range of an `AttributedString`.
+///
+/// Font-related attributes compose: a range that's both `Weight(Bold)` and `Italic` gets a bold
+/// italic version of whatever font applies there (an explicit `Font`, or the system font).
+#[derive(Clone, Debug)]
+pub enum Attribute {
+    /// Use a specific font.
+    Font(Font),
+
+    /// Use a specific weight of the font.
+    Weight(FontWeight),
+
+    /// Italicize the font.
+    Italic,
+
+    /// Use a monospaced font. With no explicit `Font`, this is the monospaced system font.
+    Monospace,
+
+    /// The color of the text.
+    Color(Color),
+
+    /// The color behind the text.
+    BackgroundColor(Color),
+
+    /// Underline the text.
+    Underline(UnderlineStyle),
+
+    /// Strike through the text.
+    Strikethrough(UnderlineStyle),
+
+    /// Make the text a link to the given URL.
+    Link(String),
+
+    /// Paragraph-level formatting. This should cover whole paragraphs; if more than one applies,
+    /// the last one added wins.
+    Paragraph(ParagraphStyle)
+}
+
+/// Styled text: a string, plus attributes applied to ranges of it.
+#[derive(Clone, Debug, Default)]
+pub struct AttributedString {
+    text: String,
+    attributes: Vec<(Range<usize>, Attribute)>
+}
+
+impl AttributedString {
+    /// Returns a new attributed string with no attributes set.
+    pub fn new(text: &str) -> Self {
+        AttributedString {
+            text: text.to_string(),
+            attributes: Vec::new()
+        }
+    }
+
+    /// Returns a builder, for piecing together a string one styled run at a time.
+    pub fn builder() -> AttributedStringBuilder {
+        AttributedStringBuilder::default()
+    }
+
+    /// Parses a subset of CommonMark into an attributed string: paragraphs, `**bold**`,
+    /// `*italic*`, `` `code` `` (and fenced code blocks), `[links](https://...)`, and bulleted or
+    /// numbered lists. Anything else is passed through as plain text.
+    pub fn from_markdown(source: &str) -> Self {
+        markdown::parse(source)
+    }
+
+    /// Returns the plain text.
+    pub fn as_str(&self) -> &str {
+        &self.text
+    }
+
+    /// Returns the length of the text, in bytes.
+    pub fn len(&self) -> usize {
+        self.text.len()
+    }
+
+    /// Returns whether the text is empty.
+    pub fn is_empty(&self) -> bool {
+        self.text.is_empty()
+    }
+
+    /// Returns the attributes, in the order they were added, along with the (byte) ranges they
+    /// apply to.
+    pub fn attributes(&self) -> &[(Range<usize>, Attribute)] {
+        &self.attributes
+    }
+
+    /// Returns the attributes that apply at the given byte offset.
+    pub fn attributes_at(&self, index: usize) -> Vec<&Attribute> {
+        self.attributes.iter()
+            .filter(|(range, _)| range.contains(&index))
+            .map(|(_, attribute)| attribute)
+            .collect()
+    }
+
+    /// Applies an attribute to a byte range of the text.
+    ///
+    /// # Panics
+    ///
+    /// Panics if the range is out of bounds, or doesn't fall on `char` boundaries.
+    pub fn add_attribute(&mut self, range: Range<usize>, attribute: Attribute) {
+        assert!(
+            range.start <= range.end && self.text.get(range.clone()).is_some(),
+            "AttributedString: range {:?} is not valid for text of length {}", range, self.text.len()
+        );
+
+        if range.start < range.end {
+            self.attributes.push((range, attribute));
+        }
+    }
+
+    /// Applies an attribute to the whole text, underneath every existing attribute - so that,
+    /// e.g, a base `Font` only applies where no other font has been set.
+    pub(crate) fn with_base_attribute(mut self, attribute: Attribute) -> Self {
+        if !self.text.is_empty() {
+            self.attributes.insert(0, (0..self.text.len(), attribute));
+        }
+
+        self
+    }
+
+    /// Removes every attribute, leaving plain text.
+    pub fn clear_attributes(&mut self) {
+        self.attributes.clear();
+    }
+
+    /// Appends plain (unattributed) text.
+    pub fn push_str(&mut self, text: &str) {
+        self.text.push_str(text);
+    }
+
+    /// Appends text with the given attributes applied to it.
+    pub fn push_styled(&mut self, text: &str, attributes: &[Attribute]) {
+        let start = self.text.len();
+        self.text.push_str(text);
+
+        for attribute in attributes {
+            self.add_attribute(start..self.text.len(), attribute.clone());
+        }
+    }
+
+    /// Appends another attributed string, keeping its attributes.
+    pub fn append(&mut self, other: &AttributedString) {
+        let offset = self.text.len();
+        self.text.push_str(&other.text);
+
+        for (range, attribute) in &other.attributes {
+            self.attributes.push(((range.start + offset)..(range.end + offset), attribute.clone()));
+        }
+    }
+}
+
+impl From<&str> for AttributedString {
+    fn from(text: &str) -> Self {
+        AttributedString::new(text)
+    }
+}
+
+impl From<String> for AttributedString {
+    fn from(text: String) -> Self {
+        AttributedString {
+            text: text,
+            attributes: Vec::new()
+        }
+    }
+}
+
+impl fmt::Display for AttributedString {
+    /// Writes the plain text, without attributes.
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(f, "{}", self.text)
+    }
+}
+
+/// Builds up an `AttributedString` one run at a time. Obtain one via `AttributedString::builder()`.
+#[derive(Debug, Default)]
+pub struct AttributedStringBuilder {
+    string: AttributedString
+}
+
+impl AttributedStringBuilder {
+    /// Appends plain text.
+    pub fn text(mut self, text: &str) -> Self {
+        self.string.push_str(text);
+        self
+    }
+
+    /// Appends text with the given attributes.
+    pub fn styled(mut self, text: &str, attributes: &[Attribute]) -> Self {
+        self.string.push_styled(text, attributes);
+        self
+    }
+
+    /// Appends bold text.
+    pub fn bold(self, text: &str) -> Self {
+        self.styled(text, &[Attribute::Weight(FontWeight::Bold)])
+    }
+
+    /// Appends italic text.
+    pub fn italic(self, text: &str) -> Self {
+        self.styled(text, &[Attribute::Italic])
+    }
+
+    /// Appends monospaced text.
+    pub fn code(self, text: &str) -> Self {
+        self.styled(text, &[Attribute::Monospace])
+    }
+
+    /// Appends text that links to the given URL.
+    pub fn link(self, text: &str, url: &str) -> Self {
+        self.styled(text, &[Attribute::Link(url.to_string())])
+    }
+
+    /// Appends a line break.
+    pub fn newline(self) -> Self {
+        self.text("\n")
+    }
+
+    /// Appends another attributed string, keeping its attributes.
+    pub fn append(mut self, other: &AttributedString) -> Self {
+        self.string.append(other);
+        self
+    }
+
+    /// Applies an attribute to everything appended so far - e.g, a `Paragraph` style or a text
+    /// `Color`.
+    pub fn apply(mut self, attribute: Attribute) -> Self {
+        let len = self.string.len();
+        self.string.add_attribute(0..len, attribute);
+        self
+    }
+
+    /// Returns the finished string.
+    pub fn build(self) -> AttributedString {
+        self.string
+    }
+}
@@ -1,6 +1,7 @@
 
 use crate::foundation::{NSInteger, NSUInteger};
 
+#[derive(Copy, Clone, Debug, PartialEq)]
 pub enum TextAlign {
     Left,
     Right,
@@ -22,6 +23,7 @@ impl From<TextAlign> for NSInteger {
 }
 
 /// Instructs text controls how to optimize line breaks.
+#[derive(Copy, Clone, Debug, PartialEq)]
 pub enum LineBreakMode {
     /// Wrap at word boundaries (the default)
     WrapWords,
@@ -0,0 +1,175 @@
+//! Wraps `NSFontDescriptor`, which describes a font by its attributes (family, traits, weight,
+//! typographic features) rather than by name. Descriptors are handy for asking for variants of a
+//! font, or for turning on OpenType features like tabular numbers.
+
+use core_graphics::base::CGFloat;
+
+use objc_id::ShareId;
+use objc::runtime::Object;
+use objc::{class, msg_send, sel, sel_impl};
+
+use crate::foundation::{id, nil, NSInteger, NSString};
+use crate::text::font::{Font, FontTrait, FontWeight};
+
+extern "C" {
+    static NSFontFamilyAttribute: id;
+    static NSFontTraitsAttribute: id;
+    static NSFontWeightTrait: id;
+    static NSFontFeatureSettingsAttribute: id;
+    static NSFontFeatureTypeIdentifierKey: id;
+    static NSFontFeatureSelectorIdentifierKey: id;
+}
+
+/// An OpenType/AAT font feature, identified by a feature type and a selector within that type.
+/// The values come from Apple's font feature registry (`SFNTLayoutTypes.h`); a few common ones
+/// have constructors here.
+#[derive(Copy, Clone, Debug, PartialEq)]
+pub struct FontFeature {
+    /// The feature type, e.g `kNumberSpacingType`.
+    pub feature_type: NSInteger,
+
+    /// The selector within the feature type, e.g `kMonospacedNumbersSelector`.
+    pub selector: NSInteger
+}
+
+impl FontFeature {
+    /// Creates a feature from raw type and selector identifiers.
+    pub fn new(feature_type: NSInteger, selector: NSInteger) -> Self {
+        FontFeature {
+            feature_type: feature_type,
+            selector: selector
+        }
+    }
+
+    /// Digits all have the same width, so columns of numbers line up.
+    pub fn monospaced_numbers() -> Self {
+        FontFeature::new(6, 0)
+    }
+
+    /// Digits have their natural widths.
+    pub fn proportional_numbers() -> Self {
+        FontFeature::new(6, 1)
+    }
+
+    /// Lowercase letters are drawn as small capitals.
+    pub fn small_caps() -> Self {
+        FontFeature::new(37, 1)
+    }
+
+    /// Zero is drawn with a slash through it.
+    pub fn slashed_zero() -> Self {
+        FontFeature::new(14, 4)
+    }
+}
+
+/// A description of a font, which can be refined and then turned into a `Font`.
+#[derive(Clone, Debug)]
+pub struct FontDescriptor {
+    pub objc: ShareId<Object>
+}
+
+impl FontDescriptor {
+    /// Describes fonts in the given family, e.g "Helvetica Neue".
+    pub fn family(family: &str) -> Self {
+        let family = NSString::new(family);
+
+        FontDescriptor {
+            objc: unsafe {
+                let attributes: id = msg_send![class!(NSMutableDictionary), dictionary];
+                let _: () = msg_send![attributes, setObject:family.into_inner() forKey:NSFontFamilyAttribute];
+                ShareId::from_ptr(msg_send![class!(NSFontDescriptor), fontDescriptorWithFontAttributes:attributes])
+            }
+        }
+    }
+
+    /// Describes the font with the given PostScript name, e.g "HelveticaNeue-Bold".
+    pub fn named(name: &str) -> Self {
+        let name = NSString::new(name);
+        let size: CGFloat = 0.;
+
+        FontDescriptor {
+            objc: unsafe {
+                ShareId::from_ptr(msg_send![class!(NSFontDescriptor), fontDescriptorWithName:name.into_inner() size:size])
+            }
+        }
+    }
+
+    /// Returns a copy of this descriptor with the given symbolic traits added to any it already
+    /// has.
+    pub fn with_traits(&self, traits: &[FontTrait]) -> Self {
+        let mut mask: u32 = 0;
+
+        for font_trait in traits {
+            let bit: u32 = font_trait.into();
+            mask = mask | bit;
+        }
+
+        FontDescriptor {
+            objc: unsafe {
+                let existing: u32 = msg_send![&*self.objc, symbolicTraits];
+                ShareId::from_ptr(msg_send![&*self.objc, fontDescriptorWithSymbolicTraits:(existing | mask)])
+            }
+        }
+    }
+
+    /// Returns a copy of this descriptor asking for the given weight. Not every family has every
+    /// weight; the closest one available is used.
+    pub fn with_weight(&self, weight: FontWeight) -> Self {
+        let weight: CGFloat = weight.into();
+
+        FontDescriptor {
+            objc: unsafe {
+                // The weight lives alongside the symbolic traits, so we build on what's there.
+                let existing: id = msg_send![&*self.objc, objectForKey:NSFontTraitsAttribute];
+                let traits: id = match existing == nil {
+                    true => msg_send![class!(NSMutableDictionary), dictionary],
+                    false => {
+                        let copy: id = msg_send![existing, mutableCopy];
+                        msg_send![copy, autorelease]
+                    }
+                };
+
+                let number: id = msg_send![class!(NSNumber), numberWithDouble:weight as f64];
+                let _: () = msg_send![traits, setObject:number forKey:NSFontWeightTrait];
+
+                let attributes: id = msg_send![class!(NSDictionary), dictionaryWithObject:traits forKey:NSFontTraitsAttribute];
+                ShareId::from_ptr(msg_send![&*self.objc, fontDescriptorByAddingAttributes:attributes])
+            }
+        }
+    }
+
+    /// Returns a copy of this descriptor with the given typographic features turned on.
+    pub fn with_features(&self, features: &[FontFeature]) -> Self {
+        FontDescriptor {
+            objc: unsafe {
+                let settings: id = msg_send![class!(NSMutableArray), array];
+
+                for feature in features {
+                    let feature_type: id = msg_send![class!(NSNumber), numberWithInteger:feature.feature_type];
+                    let selector: id = msg_send![class!(NSNumber), numberWithInteger:feature.selector];
+
+                    let setting: id = msg_send![class!(NSMutableDictionary), dictionary];
+                    let _: () = msg_send![setting, setObject:feature_type forKey:NSFontFeatureTypeIdentifierKey];
+                    let _: () = msg_send![setting, setObject:selector forKey:NSFontFeatureSelectorIdentifierKey];
+                    let _: () = msg_send![settings, addObject:setting];
+                }
+
+                let attributes: id = msg_send![class!(NSDictionary), dictionaryWithObject:settings forKey:NSFontFeatureSettingsAttribute];
+                ShareId::from_ptr(msg_send![&*self.objc, fontDescriptorByAddingAttributes:attributes])
+            }
+        }
+    }
+
+    /// Returns whether this descriptor has the given symbolic trait.
+    pub fn has_trait(&self, font_trait: FontTrait) -> bool {
+        let bit: u32 = font_trait.into();
+        let traits: u32 = unsafe { msg_send![&*self.objc, symbolicTraits] };
+        traits & bit != 0
+    }
+
+    /// Returns the font that best matches this descriptor, at the given size. Returns `None` if
+    /// nothing installed matches.
+    pub fn to_font(&self, size: CGFloat) -> Option<Font> {
+        Font::with_descriptor(self, size)
+    }
+}
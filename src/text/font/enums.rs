@@ -0,0 +1,171 @@
+//! Enums used when picking and describing fonts.
+
+use core_graphics::base::CGFloat;
+
+use crate::foundation::id;
+
+/// The weight (stroke thickness) of a font, from lightest to heaviest.
+#[derive(Copy, Clone, Debug, PartialEq)]
+pub enum FontWeight {
+    UltraLight,
+    Thin,
+    Light,
+    Regular,
+    Medium,
+    Semibold,
+    Bold,
+    Heavy,
+    Black
+}
+
+impl FontWeight {
+    /// Whether this weight should pick up the bold trait when applied to a font that doesn't
+    /// support arbitrary weights.
+    pub(crate) fn is_bold(&self) -> bool {
+        match self {
+            FontWeight::Semibold | FontWeight::Bold | FontWeight::Heavy | FontWeight::Black => true,
+            _ => false
+        }
+    }
+}
+
+impl From<FontWeight> for CGFloat {
+    /// Maps to the `NSFontWeight` constants.
+    fn from(weight: FontWeight) -> Self {
+        match weight {
+            FontWeight::UltraLight => -0.8,
+            FontWeight::Thin => -0.6,
+            FontWeight::Light => -0.4,
+            FontWeight::Regular => 0.,
+            FontWeight::Medium => 0.23,
+            FontWeight::Semibold => 0.3,
+            FontWeight::Bold => 0.4,
+            FontWeight::Heavy => 0.56,
+            FontWeight::Black => 0.62
+        }
+    }
+}
+
+/// Symbolic traits a font can have, used when asking for variants of a font (e.g, the bold
+/// version) or describing one to search for.
+#[derive(Copy, Clone, Debug, PartialEq)]
+pub enum FontTrait {
+    /// An italic or oblique style.
+    Italic,
+
+    /// A bold style.
+    Bold,
+
+    /// A wider-than-normal style.
+    Expanded,
+
+    /// A narrower-than-normal style.
+    Condensed,
+
+    /// Every glyph has the same width.
+    Monospace,
+
+    /// Glyphs are laid out for vertical text.
+    Vertical,
+
+    /// Tweaked for display in user interfaces.
+    UIOptimized,
+
+    /// Tighter line spacing than normal.
+    TightLeading,
+
+    /// Looser line spacing than normal.
+    LooseLeading
+}
+
+impl From<FontTrait> for u32 {
+    /// Maps to the `NSFontDescriptorSymbolicTraits` values.
+    fn from(font_trait: FontTrait) -> Self {
+        match font_trait {
+            FontTrait::Italic => 1 << 0,
+            FontTrait::Bold => 1 << 1,
+            FontTrait::Expanded => 1 << 5,
+            FontTrait::Condensed => 1 << 6,
+            FontTrait::Monospace => 1 << 10,
+            FontTrait::Vertical => 1 << 11,
+            FontTrait::UIOptimized => 1 << 12,
+            FontTrait::TightLeading => 1 << 15,
+            FontTrait::LooseLeading => 1 << 16
+        }
+    }
+}
+
+impl From<&FontTrait> for u32 {
+    fn from(font_trait: &FontTrait) -> Self {
+        (*font_trait).into()
+    }
+}
+
+extern "C" {
+    static NSFontTextStyleLargeTitle: id;
+    static NSFontTextStyleTitle1: id;
+    static NSFontTextStyleTitle2: id;
+    static NSFontTextStyleTitle3: id;
+    static NSFontTextStyleHeadline: id;
+    static NSFontTextStyleSubheadline: id;
+    static NSFontTextStyleBody: id;
+    static NSFontTextStyleCallout: id;
+    static NSFontTextStyleFootnote: id;
+    static NSFontTextStyleCaption1: id;
+    static NSFontTextStyleCaption2: id;
+}
+
+/// Semantic text styles, which map to the fonts the system uses for those roles.
+#[derive(Copy, Clone, Debug, PartialEq)]
+pub enum TextStyle {
+    LargeTitle,
+    Title1,
+    Title2,
+    Title3,
+    Headline,
+    Subheadline,
+    Body,
+    Callout,
+    Footnote,
+    Caption1,
+    Caption2
+}
+
+impl TextStyle {
+    /// Returns the `NSFontTextStyle` constant for this style. These require macOS 11.
+    pub(crate) fn to_objc(&self) -> id {
+        unsafe {
+            match self {
+                TextStyle::LargeTitle => NSFontTextStyleLargeTitle,
+                TextStyle::Title1 => NSFontTextStyleTitle1,
+                TextStyle::Title2 => NSFontTextStyleTitle2,
+                TextStyle::Title3 => NSFontTextStyleTitle3,
+                TextStyle::Headline => NSFontTextStyleHeadline,
+                TextStyle::Subheadline => NSFontTextStyleSubheadline,
+                TextStyle::Body => NSFontTextStyleBody,
+                TextStyle::Callout => NSFontTextStyleCallout,
+                TextStyle::Footnote => NSFontTextStyleFootnote,
+                TextStyle::Caption1 => NSFontTextStyleCaption1,
+                TextStyle::Caption2 => NSFontTextStyleCaption2
+            }
+        }
+    }
+
+    /// The size and weight the system uses for this style, for systems that predate text
+    /// styles.
+    pub(crate) fn fallback(&self) -> (CGFloat, FontWeight) {
+        match self {
+            TextStyle::LargeTitle => (26., FontWeight::Regular),
+            TextStyle::Title1 => (22., FontWeight::Regular),
+            TextStyle::Title2 => (17., FontWeight::Regular),
+            TextStyle::Title3 => (15., FontWeight::Regular),
+            TextStyle::Headline => (13., FontWeight::Bold),
+            TextStyle::Subheadline => (11., FontWeight::Regular),
+            TextStyle::Body => (13., FontWeight::Regular),
+            TextStyle::Callout => (12., FontWeight::Regular),
+            TextStyle::Footnote => (10., FontWeight::Regular),
+            TextStyle::Caption1 => (10., FontWeight::Regular),
+            TextStyle::Caption2 => (10., FontWeight::Medium)
+        }
+    }
+}
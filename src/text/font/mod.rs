@@ -0,0 +1,267 @@
+//! Implements `Font`, a wrapper around `NSFont` on macOS and `UIFont` on iOS.
+//!
+//! Beyond the system font, you can ask for specific weights, monospaced variants, semantic
+//! styles (`TextStyle::Body`, `TextStyle::Headline`...) or fonts by name, and read metrics
+//! (ascender, descender, cap height...) for aligning text to a baseline.
+//!
+//! ```rust,no_run
+//! use cacao::text::{Font, FontFeature, FontWeight, TextStyle};
+//!
+//! let title = Font::system_with_weight(20., FontWeight::Semibold);
+//! let body = Font::preferred(TextStyle::Body);
+//! let emphasis = body.italic();
+//! let price = Font::monospaced_digits(13., FontWeight::Regular);
+//! let brand = Font::named("Avenir Next", 15.).unwrap_or_else(|| Font::system(15.));
+//!
+//! let tabular = brand.descriptor().with_features(&[FontFeature::monospaced_numbers()]).to_font(15.);
+//! ```
+
+use core_graphics::base::CGFloat;
+
+use objc_id::ShareId;
+use objc::runtime::{Object, BOOL};
+use objc::{class, msg_send, sel, sel_impl};
+
+use crate::foundation::{id, nil, to_bool, NSInteger, NSString, NSUInteger};
+use crate::utils::os;
+
+mod descriptor;
+pub use descriptor::{FontDescriptor, FontFeature};
+
+mod enums;
+pub use enums::{FontTrait, FontWeight, TextStyle};
+
+mod registration;
+
+/// A font, at a given size.
+#[derive(Clone, Debug)]
+pub struct Font {
+    pub objc: ShareId<Object>
+}
+
+impl Default for Font {
+    /// The font the system uses for labels, at the default size.
+    fn default() -> Self {
+        Font {
+            objc: unsafe {
+                let cls = class!(NSFont);
+                let default_size: CGFloat = msg_send![cls, labelFontSize];
+                ShareId::from_ptr(msg_send![cls, labelFontOfSize:default_size])
+            }
+        }
+    }
+}
+
+impl Font {
+    /// Wraps a font vended by the system, returning `None` if there isn't one.
+    pub(crate) fn maybe_wrap(font: id) -> Option<Self> {
+        match font == nil {
+            true => None,
+            false => Some(Font {
+                objc: unsafe { ShareId::from_ptr(font) }
+            })
+        }
+    }
+
+    /// The system font, at the given size.
+    pub fn system(size: CGFloat) -> Self {
+        Font {
+            objc: unsafe {
+                ShareId::from_ptr(msg_send![class!(NSFont), systemFontOfSize:size])
+            }
+        }
+    }
+
+    /// The system font, at the given size and weight.
+    pub fn system_with_weight(size: CGFloat, weight: FontWeight) -> Self {
+        let weight: CGFloat = weight.into();
+
+        Font {
+            objc: unsafe {
+                ShareId::from_ptr(msg_send![class!(NSFont), systemFontOfSize:size weight:weight])
+            }
+        }
+    }
+
+    /// The bold system font, at the given size.
+    pub fn bold_system(size: CGFloat) -> Self {
+        Font {
+            objc: unsafe {
+                ShareId::from_ptr(msg_send![class!(NSFont), boldSystemFontOfSize:size])
+            }
+        }
+    }
+
+    /// The monospaced system font (SF Mono), at the given size and weight. Prior to macOS 10.15
+    /// this falls back to the user's fixed-pitch font, ignoring the weight.
+    pub fn monospaced_system(size: CGFloat, weight: FontWeight) -> Self {
+        let weight: CGFloat = weight.into();
+
+        Font {
+            objc: unsafe {
+                let cls = class!(NSFont);
+                let available: BOOL = msg_send![cls, respondsToSelector:sel!(monospacedSystemFontOfSize:weight:)];
+
+                ShareId::from_ptr(match to_bool(available) {
+                    true => msg_send![cls, monospacedSystemFontOfSize:size weight:weight],
+                    false => msg_send![cls, userFixedPitchFontOfSize:size]
+                })
+            }
+        }
+    }
+
+    /// The system font, with digits that all share the same width (but proportional letters).
+    /// Useful for numbers that update in place, like timers and counters.
+    pub fn monospaced_digits(size: CGFloat, weight: FontWeight) -> Self {
+        let weight: CGFloat = weight.into();
+
+        Font {
+            objc: unsafe {
+                ShareId::from_ptr(msg_send![class!(NSFont), monospacedDigitSystemFontOfSize:size weight:weight])
+            }
+        }
+    }
+
+    /// The font the system uses for the given semantic style, e.g body text or headlines. Prior
+    /// to macOS 11, this approximates the style with the system font.
+    pub fn preferred(style: TextStyle) -> Self {
+        if os::is_minimum_version(11) {
+            return Font {
+                objc: unsafe {
+                    let options: id = msg_send![class!(NSDictionary), dictionary];
+                    ShareId::from_ptr(msg_send![class!(NSFont), preferredFontForTextStyle:style.to_objc() options:options])
+                }
+            };
+        }
+
+        let (size, weight) = style.fallback();
+        Font::system_with_weight(size, weight)
+    }
+
+    /// Looks up an installed (or registered) font by name, at the given size. This accepts
+    /// PostScript names ("HelveticaNeue-Bold"), full names ("Helvetica Neue Bold") and family
+    /// names ("Helvetica Neue", which picks the regular face). Returns `None` if there's no such
+    /// font.
+    pub fn named(name: &str, size: CGFloat) -> Option<Self> {
+        let name = NSString::new(name).into_inner();
+
+        unsafe {
+            let font: id = msg_send![class!(NSFont), fontWithName:name size:size];
+
+            if font != nil {
+                return Font::maybe_wrap(font);
+            }
+
+            // A weight of 5 is "regular", in NSFontManager's 0-15 scale.
+            let manager: id = msg_send![class!(NSFontManager), sharedFontManager];
+            Font::maybe_wrap(msg_send![manager, fontWithFamily:name traits:0 as NSUInteger weight:5 as NSInteger size:size])
+        }
+    }
+
+    /// Returns the font best matching the given descriptor, at the given size. Returns `None` if
+    /// nothing installed matches.
+    pub fn with_descriptor(descriptor: &FontDescriptor, size: CGFloat) -> Option<Self> {
+        Font::maybe_wrap(unsafe {
+            msg_send![class!(NSFont), fontWithDescriptor:&*descriptor.objc size:size]
+        })
+    }
+
+    /// Returns this font's descriptor, for building variants of it.
+    pub fn descriptor(&self) -> FontDescriptor {
+        FontDescriptor {
+            objc: unsafe {
+                ShareId::from_ptr(msg_send![&*self.objc, fontDescriptor])
+            }
+        }
+    }
+
+    /// Returns this font at a different size.
+    pub fn with_size(&self, size: CGFloat) -> Self {
+        Font::with_descriptor(&self.descriptor(), size).unwrap_or_else(|| self.clone())
+    }
+
+    /// Returns a variant of this font with the given traits (e.g, bold italic). If the family has
+    /// no such variant, you get this font back unchanged.
+    pub fn with_traits(&self, traits: &[FontTrait]) -> Self {
+        let descriptor = self.descriptor().with_traits(traits);
+        Font::with_descriptor(&descriptor, self.point_size()).unwrap_or_else(|| self.clone())
+    }
+
+    /// Returns the bold variant of this font, or this font if there isn't one.
+    pub fn bold(&self) -> Self {
+        self.with_traits(&[FontTrait::Bold])
+    }
+
+    /// Returns the italic variant of this font, or this font if there isn't one.
+    pub fn italic(&self) -> Self {
+        self.with_traits(&[FontTrait::Italic])
+    }
+
+    /// Returns the PostScript name of this font, e.g "HelveticaNeue-Bold". This round-trips
+    /// through `Font::named()`.
+    pub fn name(&self) -> String {
+        NSString::wrap(unsafe {
+            msg_send![&*self.objc, fontName]
+        }).to_string()
+    }
+
+    /// Returns the family name of this font, e.g "Helvetica Neue".
+    pub fn family_name(&self) -> Option<String> {
+        unsafe {
+            let family: id = msg_send![&*self.objc, familyName];
+
+            match family == nil {
+                true => None,
+                false => Some(NSString::wrap(family).to_string())
+            }
+        }
+    }
+
+    /// Returns the localized, human-readable name of this font, e.g "Helvetica Neue Bold".
+    pub fn display_name(&self) -> Option<String> {
+        unsafe {
+            let name: id = msg_send![&*self.objc, displayName];
+
+            match name == nil {
+                true => None,
+                false => Some(NSString::wrap(name).to_string())
+            }
+        }
+    }
+
+    /// The size of this font, in points.
+    pub fn point_size(&self) -> CGFloat {
+        unsafe { msg_send![&*self.objc, pointSize] }
+    }
+
+    /// The distance from the baseline to the top of the tallest glyphs.
+    pub fn ascender(&self) -> CGFloat {
+        unsafe { msg_send![&*self.objc, ascender] }
+    }
+
+    /// The distance from the baseline to the bottom of the lowest glyphs. This is negative, as
+    /// it's below the baseline.
+    pub fn descender(&self) -> CGFloat {
+        unsafe { msg_send![&*self.objc, descender] }
+    }
+
+    /// The extra spacing the font asks for between lines.
+    pub fn leading(&self) -> CGFloat {
+        unsafe { msg_send![&*self.objc, leading] }
+    }
+
+    /// The height of capital letters, from the baseline.
+    pub fn cap_height(&self) -> CGFloat {
+        unsafe { msg_send![&*self.objc, capHeight] }
+    }
+
+    /// The height of lowercase letters (specifically, "x"), from the baseline.
+    pub fn x_height(&self) -> CGFloat {
+        unsafe { msg_send![&*self.objc, xHeight] }
+    }
+
+    /// The height of a line of text in this font: ascender, descender and leading combined.
+    pub fn line_height(&self) -> CGFloat {
+        self.ascender() - self.descender() + self.leading()
+    }
+}
@@ -0,0 +1,211 @@
+//! Registers fonts with Core Text for the running process, so that they can be used by name
+//! without being installed - e.g, fonts embedded in the binary with `include_bytes!()`, for apps
+//! that aren't run from a bundle.
+//!
+//! ```rust,no_run
+//! use cacao::text::Font;
+//!
+//! // e.g, `include_bytes!("../assets/Brand-Regular.ttf")`
+//! fn brand_font(bytes: &[u8]) -> Font {
+//!     let names = Font::register_from_bytes(bytes).expect("Font should register");
+//!     Font::named(&names[0], 15.).unwrap()
+//! }
+//! ```
+
+use std::collections::HashMap;
+use std::os::raw::c_void;
+use std::path::Path;
+use std::ptr;
+use std::sync::Mutex;
+
+use core_foundation::base::{CFRelease, TCFType};
+use core_foundation::data::{CFData, CFDataRef};
+use core_foundation::error::CFErrorRef;
+use core_graphics::base::CGFloat;
+use lazy_static::lazy_static;
+use objc::{class, msg_send, sel, sel_impl};
+
+use crate::error::Error;
+use crate::foundation::{id, nil, NSArray, NSString};
+use crate::text::Font;
+
+/// `kCTFontManagerScopeProcess`: registrations last until the process exits.
+const SCOPE_PROCESS: u32 = 1;
+
+extern "C" {
+    static NSFontNameAttribute: id;
+    static NSFontFamilyAttribute: id;
+
+    fn CGDataProviderCreateWithCFData(data: CFDataRef) -> *const c_void;
+    fn CGFontCreateWithDataProvider(provider: *const c_void) -> *const c_void;
+
+    fn CTFontCreateWithGraphicsFont(font: *const c_void, size: CGFloat, matrix: *const c_void, attributes: *const c_void) -> id;
+    fn CTFontManagerRegisterGraphicsFont(font: *const c_void, error: *mut CFErrorRef) -> bool;
+    fn CTFontManagerUnregisterGraphicsFont(font: *const c_void, error: *mut CFErrorRef) -> bool;
+    fn CTFontManagerRegisterFontsForURL(url: id, scope: u32, error: *mut CFErrorRef) -> bool;
+    fn CTFontManagerUnregisterFontsForURL(url: id, scope: u32, error: *mut CFErrorRef) -> bool;
+    fn CTFontManagerCreateFontDescriptorsFromURL(url: id) -> id;
+}
+
+lazy_static! {
+    /// Fonts registered from bytes, keyed by PostScript name. Unregistering needs the same
+    /// `CGFont` that was registered, so we hold on to them (as `usize`, which is `Send`) here.
+    static ref REGISTERED: Mutex<HashMap<String, usize>> = Mutex::new(HashMap::new());
+}
+
+/// Turns a Core Foundation error (which is toll-free bridged to `NSError`) into an `Error`,
+/// releasing it.
+unsafe fn take_error(error: CFErrorRef) -> Error {
+    if error.is_null() {
+        return invalid_font("Core Text did not report an error.");
+    }
+
+    let result = Error::new(error as id);
+    CFRelease(error as *const c_void);
+    result
+}
+
+/// Builds the `Error` returned when a font can't be read at all.
+fn invalid_font(description: &str) -> Error {
+    Error {
+        code: 0,
+        domain: "com.cacao-rs.font".to_string(),
+        description: description.to_string()
+    }
+}
+
+/// Adds a name to the list, unless it's already there.
+fn push_name(names: &mut Vec<String>, name: id) {
+    if name != nil {
+        let name = NSString::wrap(name).to_string();
+
+        if !names.contains(&name) {
+            names.push(name);
+        }
+    }
+}
+
+/// Returns a file URL for the given path. The returned object is autoreleased.
+fn file_url(path: &Path) -> id {
+    let path = NSString::new(&path.to_string_lossy());
+
+    unsafe {
+        msg_send![class!(NSURL), fileURLWithPath:path.into_inner()]
+    }
+}
+
+impl Font {
+    /// Registers a font (`.ttf` or `.otf` data) for use in this process. On success, returns the
+    /// font's PostScript name followed by its family name; either can be passed to
+    /// `Font::named()`.
+    ///
+    /// Registering the same font twice is an error. To undo this, pass the PostScript name to
+    /// `Font::unregister()`.
+    pub fn register_from_bytes(bytes: &[u8]) -> Result<Vec<String>, Error> {
+        let data = CFData::from_buffer(bytes);
+
+        unsafe {
+            let provider = CGDataProviderCreateWithCFData(data.as_concrete_TypeRef());
+            if provider.is_null() {
+                return Err(invalid_font("Could not read the font data."));
+            }
+
+            let font = CGFontCreateWithDataProvider(provider);
+            CFRelease(provider);
+
+            if font.is_null() {
+                return Err(invalid_font("The data is not a font Core Text can read."));
+            }
+
+            let mut error: CFErrorRef = ptr::null_mut();
+            if !CTFontManagerRegisterGraphicsFont(font, &mut error) {
+                CFRelease(font);
+                return Err(take_error(error));
+            }
+
+            // A Core Text font is toll-free bridged to NSFont, so we can read the names off of it.
+            let ct_font = CTFontCreateWithGraphicsFont(font, 0., ptr::null(), ptr::null());
+            let mut names = Vec::new();
+            push_name(&mut names, msg_send![ct_font, fontName]);
+            push_name(&mut names, msg_send![ct_font, familyName]);
+            CFRelease(ct_font as *const c_void);
+
+            if let Some(name) = names.first() {
+                let mut registered = REGISTERED.lock().unwrap();
+
+                if let Some(previous) = registered.insert(name.clone(), font as usize) {
+                    CFRelease(previous as *const c_void);
+                }
+            }
+
+            Ok(names)
+        }
+    }
+
+    /// Registers the font(s) in a file for use in this process. On success, returns the
+    /// PostScript and family names of every font in the file; any of them can be passed to
+    /// `Font::named()`.
+    pub fn register_from_path<P: AsRef<Path>>(path: P) -> Result<Vec<String>, Error> {
+        let url = file_url(path.as_ref());
+
+        unsafe {
+            let mut error: CFErrorRef = ptr::null_mut();
+            if !CTFontManagerRegisterFontsForURL(url, SCOPE_PROCESS, &mut error) {
+                return Err(take_error(error));
+            }
+
+            let descriptors = CTFontManagerCreateFontDescriptorsFromURL(url);
+            let mut names = Vec::new();
+
+            if descriptors != nil {
+                // Core Text descriptors are toll-free bridged to NSFontDescriptor.
+                let found: Vec<(id, id)> = NSArray::wrap(descriptors).map(|descriptor| {
+                    (msg_send![descriptor, objectForKey:NSFontNameAttribute], msg_send![descriptor, objectForKey:NSFontFamilyAttribute])
+                });
+
+                for (name, family) in found {
+                    push_name(&mut names, name);
+                    push_name(&mut names, family);
+                }
+
+                CFRelease(descriptors as *const c_void);
+            }
+
+            Ok(names)
+        }
+    }
+
+    /// Unregisters a font registered with `Font::register_from_bytes()`, given its PostScript
+    /// name. Existing `Font`s keep working, but new lookups by name will fail.
+    pub fn unregister(postscript_name: &str) -> Result<(), Error> {
+        let font = match REGISTERED.lock().unwrap().remove(postscript_name) {
+            Some(font) => font as *const c_void,
+            None => { return Err(invalid_font("No font with that name was registered from bytes.")); }
+        };
+
+        unsafe {
+            let mut error: CFErrorRef = ptr::null_mut();
+            let unregistered = CTFontManagerUnregisterGraphicsFont(font, &mut error);
+            CFRelease(font);
+
+            match unregistered {
+                true => Ok(()),
+                false => Err(take_error(error))
+            }
+        }
+    }
+
+    /// Unregisters the font(s) in a file registered with `Font::register_from_path()`.
+    pub fn unregister_path<P: AsRef<Path>>(path: P) -> Result<(), Error> {
+        let url = file_url(path.as_ref());
+
+        unsafe {
+            let mut error: CFErrorRef = ptr::null_mut();
+
+            match CTFontManagerUnregisterFontsForURL(url, SCOPE_PROCESS, &mut error) {
+                true => Ok(()),
+                false => Err(take_error(error))
+            }
+        }
+    }
+}
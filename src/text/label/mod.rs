@@ -40,14 +40,15 @@
 //!
 //! For more information on Autolayout, view the module or check out the examples folder.
 
+use core_graphics::base::CGFloat;
 use objc_id::ShareId;
 use objc::runtime::{Class, Object};
 use objc::{msg_send, sel, sel_impl};
 
-use crate::foundation::{id, nil, YES, NO, NSArray, NSInteger, NSUInteger, NSString};
+use crate::foundation::{id, nil, YES, NO, NSArray, NSInteger, NSRect, NSSize, NSUInteger, NSString};
 use crate::color::Color;
 use crate::layout::{Layout, LayoutAnchorX, LayoutAnchorY, LayoutAnchorDimension};
-use crate::text::{Font, TextAlign, LineBreakMode};
+use crate::text::{AttributedString, Font, TextAlign, LineBreakMode};
 
 #[cfg(target_os = "macos")]
 mod macos;
@@ -234,6 +235,18 @@ impl<T> Label<T> {
         }
     }
 
+    /// Call this to set styled text for the label. To make links clickable, the label needs to
+    /// be selectable.
+    pub fn set_attributed_text(&self, text: &AttributedString) {
+        unsafe {
+            #[cfg(target_os = "macos")]
+            let _: () = msg_send![&*self.objc, setAttributedStringValue:text.to_objc()];
+
+            #[cfg(target_os = "ios")]
+            let _: () = msg_send![&*self.objc, setAttributedText:text.to_objc()];
+        }
+    }
+
     /// Retrieve the text currently held in the label.
     pub fn text(&self) -> String {
         let s = NSString::wrap(unsafe {
@@ -256,6 +269,31 @@ impl<T> Label<T> {
         }
     }
 
+    /// Returns the smallest size that fits the label's content. For labels that wrap, see
+    /// `fitting_size_for_width()`.
+    pub fn fitting_size(&self) -> NSSize {
+        unsafe {
+            msg_send![&*self.objc, fittingSize]
+        }
+    }
+
+    /// Returns the size the label's content needs if it's laid out (wrapping as per its line
+    /// break mode) in the given width.
+    pub fn fitting_size_for_width(&self, width: CGFloat) -> NSSize {
+        unsafe {
+            #[cfg(target_os = "macos")]
+            let size: NSSize = {
+                let cell: id = msg_send![&*self.objc, cell];
+                msg_send![cell, cellSizeForBounds:NSRect::new(0., 0., width, CGFloat::MAX)]
+            };
+
+            #[cfg(target_os = "ios")]
+            let size: NSSize = msg_send![&*self.objc, sizeThatFits:NSSize::new(width, CGFloat::MAX)];
+
+            size
+        }
+    }
+
     pub fn set_line_break_mode(&self, mode: LineBreakMode) {
         #[cfg(target_os = "macos")]
         unsafe {
@@ -0,0 +1,143 @@
+//! Measures text, for sizing cells and controls ahead of time, or for laying out custom-drawn
+//! text. This runs the same layout machinery (`NSLayoutManager`) that text views and labels use,
+//! so the numbers match what you'll see on screen.
+//!
+//! ```rust,no_run
+//! use cacao::text::{measure, truncate, Font, LineBreakMode};
+//!
+//! let font = Font::system(13.);
+//! let size = measure("A long line of text that wraps", &font, Some(120.), LineBreakMode::WrapWords);
+//! println!("{}x{} over {} lines", size.width, size.height, size.line_count);
+//!
+//! let title = truncate("A very long title for a small space", &font, 100., LineBreakMode::TruncateMiddle);
+//! ```
+
+use core_graphics::base::CGFloat;
+use objc::{class, msg_send, sel, sel_impl};
+
+use crate::foundation::{id, nil, NSRange, NSRect, NSSize, NSUInteger};
+use crate::text::{AttributedString, Attribute, Font, LineBreakMode, ParagraphStyle};
+
+/// The space some text takes up, once laid out.
+#[derive(Copy, Clone, Debug, Default, PartialEq)]
+pub struct TextSize {
+    /// The width of the widest line, rounded up to a whole point.
+    pub width: CGFloat,
+
+    /// The height of all lines, rounded up to a whole point.
+    pub height: CGFloat,
+
+    /// How many lines the text was laid out over.
+    pub line_count: usize
+}
+
+/// Measures how much space `text` takes up in `font` (which applies wherever the text doesn't set
+/// its own font). With a `max_width`, the text is wrapped (or truncated) to fit according to
+/// `mode`; with `None`, only explicit line breaks start new lines.
+pub fn measure<T: Into<AttributedString>>(text: T, font: &Font, max_width: Option<CGFloat>, mode: LineBreakMode) -> TextSize {
+    let text: AttributedString = text.into();
+    let text = text
+        .with_base_attribute(Attribute::Paragraph(ParagraphStyle {
+            line_break_mode: Some(mode),
+            ..ParagraphStyle::default()
+        }))
+        .with_base_attribute(Attribute::Font(font.clone()));
+
+    let width = max_width.unwrap_or(CGFloat::MAX);
+
+    unsafe {
+        let alloc: id = msg_send![class!(NSTextStorage), alloc];
+        let storage: id = msg_send![alloc, initWithAttributedString:text.to_objc()];
+
+        let alloc: id = msg_send![class!(NSTextContainer), alloc];
+        let container: id = msg_send![alloc, initWithSize:NSSize::new(width, CGFloat::MAX)];
+        let padding: CGFloat = 0.;
+        let _: () = msg_send![container, setLineFragmentPadding:padding];
+
+        let layout_manager: id = msg_send![class!(NSLayoutManager), new];
+        let _: () = msg_send![layout_manager, addTextContainer:container];
+        let _: () = msg_send![storage, addLayoutManager:layout_manager];
+        let _: () = msg_send![layout_manager, ensureLayoutForTextContainer:container];
+
+        let used: NSRect = msg_send![layout_manager, usedRectForTextContainer:container];
+
+        let glyph_count: NSUInteger = msg_send![layout_manager, numberOfGlyphs];
+        let mut line_count = 0;
+        let mut index: NSUInteger = 0;
+
+        while index < glyph_count {
+            let mut range = NSRange::default();
+            let _: NSRect = msg_send![layout_manager, lineFragmentRectForGlyphAtIndex:index effectiveRange:&mut range];
+            index = std::cmp::max(index + 1, range.location + range.length);
+            line_count += 1;
+        }
+
+        // A trailing newline leaves an empty line at the end, which has no glyphs.
+        let extra: id = msg_send![layout_manager, extraLineFragmentTextContainer];
+        if extra != nil {
+            line_count += 1;
+        }
+
+        let _: () = msg_send![storage, release];
+        let _: () = msg_send![container, release];
+        let _: () = msg_send![layout_manager, release];
+
+        TextSize {
+            width: used.size.width.ceil(),
+            height: used.size.height.ceil(),
+            line_count: line_count
+        }
+    }
+}
+
+/// Returns the widest version of `text` that fits on one line in `width` points, in `font`.
+/// If it already fits, you get it back as-is; otherwise it's cut down according to `mode`:
+///
+/// - `TruncateHead`, `TruncateTail` and `TruncateMiddle` replace the cut characters with an
+/// ellipsis, at the start, end or middle respectively.
+/// - `Clip` drops characters from the end, with no ellipsis.
+/// - `WrapWords` and `WrapChars` don't truncate, and return the text unchanged.
+pub fn truncate(text: &str, font: &Font, width: CGFloat, mode: LineBreakMode) -> String {
+    let fits = |candidate: &str| {
+        measure(candidate, font, None, LineBreakMode::Clip).width <= width
+    };
+
+    match mode {
+        LineBreakMode::WrapWords | LineBreakMode::WrapChars => { return text.to_string(); },
+        _ if fits(text) => { return text.to_string(); },
+        _ => {}
+    }
+
+    let chars: Vec<char> = text.chars().collect();
+
+    // Builds the candidate that keeps `kept` of the original characters.
+    let candidate = |kept: usize| -> String {
+        let head: String = chars[..kept].iter().collect();
+        let tail: String = chars[chars.len() - kept..].iter().collect();
+
+        match mode {
+            LineBreakMode::TruncateHead => format!("\u{2026}{}", tail),
+            LineBreakMode::TruncateMiddle => {
+                let front: String = chars[..(kept + 1) / 2].iter().collect();
+                let back: String = chars[chars.len() - kept / 2..].iter().collect();
+                format!("{}\u{2026}{}", front, back)
+            },
+            LineBreakMode::Clip => head,
+            _ => format!("{}\u{2026}", head)
+        }
+    };
+
+    // Find the most characters we can keep; fewer kept characters is always narrower.
+    let (mut low, mut high) = (0, chars.len());
+
+    while low < high {
+        let middle = (low + high + 1) / 2;
+
+        match fits(&candidate(middle)) {
+            true => { low = middle; },
+            false => { high = middle - 1; }
+        }
+    }
+
+    candidate(low)
+}
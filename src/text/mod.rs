@@ -8,4 +8,10 @@ pub mod enums;
 pub use enums::{LineBreakMode, TextAlign};
 
 pub mod font;
-pub use font::Font;
+pub use font::{Font, FontDescriptor, FontFeature, FontTrait, FontWeight, TextStyle};
+
+pub mod attributed_string;
+pub use attributed_string::{AttributedString, AttributedStringBuilder, Attribute, ParagraphStyle, UnderlineStyle};
+
+mod measure;
+pub use measure::{measure, truncate, TextSize};
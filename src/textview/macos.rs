@@ -0,0 +1,55 @@
+//! Registers the `NSTextView` subclasses that back `TextView`, and forwards text and selection
+//! changes on to the delegate.
+
+use std::sync::Once;
+
+use objc::declare::ClassDecl;
+use objc::runtime::{Class, Object, Sel};
+use objc::{class, sel, sel_impl, msg_send};
+
+use crate::foundation::{load_or_register_class, id, NSRange, NSString};
+use crate::textview::{TEXTVIEW_DELEGATE_PTR, TextViewDelegate};
+use crate::utils::load;
+use crate::panic::AddGuardedMethod;
+
+/// Called when the text changes.
+fn text_did_change<T: TextViewDelegate>(this: &Object, _: Sel, _notification: id) {
+    let value = NSString::wrap(unsafe { msg_send![this, string] });
+    let view = load::<T>(this, TEXTVIEW_DELEGATE_PTR);
+    view.text_did_change(value.to_str());
+}
+
+/// Called when the selection changes.
+fn selection_did_change<T: TextViewDelegate>(this: &Object, _: Sel, _notification: id) {
+    let range: NSRange = unsafe { msg_send![this, selectedRange] };
+    let text = NSString::wrap(unsafe { msg_send![this, string] });
+    let view = load::<T>(this, TEXTVIEW_DELEGATE_PTR);
+    view.selection_did_change(range.to_byte_range(text.to_str()));
+}
+
+/// Injects an `NSTextView` subclass. This is used for the default views that don't use delegates.
+pub(crate) fn register_textview_class() -> *const Class {
+    static mut VIEW_CLASS: *const Class = 0 as *const Class;
+    static INIT: Once = Once::new();
+
+    INIT.call_once(|| unsafe {
+        let superclass = class!(NSTextView);
+        let decl = ClassDecl::new("RSTTextView", superclass).unwrap();
+        VIEW_CLASS = decl.register();
+    });
+
+    unsafe {
+        VIEW_CLASS
+    }
+}
+
+/// Injects an `NSTextView` subclass that acts as its own delegate, with a pointer ivar for the
+/// Rust side.
+pub(crate) fn register_textview_class_with_delegate<T: TextViewDelegate>(instance: &T) -> *const Class {
+    load_or_register_class("NSTextView", instance.subclass_name(), |decl| unsafe {
+        decl.add_ivar::<usize>(TEXTVIEW_DELEGATE_PTR);
+
+        decl.add_guarded_method(sel!(textDidChange:), text_did_change::<T>);
+        decl.add_guarded_method(sel!(textViewDidChangeSelection:), selection_did_change::<T>);
+    })
+}
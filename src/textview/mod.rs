@@ -0,0 +1,511 @@
+//! Wraps `NSTextView`, a multi-line text editor, inside of a `ScrollView`.
+//!
+//! `TextView` supports both plain and rich text (the latter via `AttributedString`), and hooks
+//! into the standard undo machinery - edits made by the user, as well as those made through
+//! `insert_text()` and `replace_text()`, can be undone and redone.
+//!
+//! ```rust,no_run
+//! use cacao::layout::{Layout, LayoutConstraint};
+//! use cacao::textview::{TextView, TextViewDelegate};
+//! use cacao::view::View;
+//!
+//! #[derive(Default)]
+//! struct Notes;
+//!
+//! impl TextViewDelegate for Notes {
+//!     const NAME: &'static str = "NotesTextView";
+//!
+//!     fn text_did_change(&self, value: &str) {
+//!         println!("{} characters", value.chars().count());
+//!     }
+//! }
+//!
+//! let content = View::new();
+//! let editor = TextView::with(Notes::default());
+//! editor.set_text("Hello!");
+//! content.add_subview(&editor);
+//!
+//! LayoutConstraint::activate(&[
+//!     editor.top.constraint_equal_to(&content.top),
+//!     editor.leading.constraint_equal_to(&content.leading),
+//!     editor.trailing.constraint_equal_to(&content.trailing),
+//!     editor.bottom.constraint_equal_to(&content.bottom)
+//! ]);
+//! ```
+//!
+//! Ranges (e.g, for selection) are byte ranges into the text returned by `get_text()`, just like
+//! `AttributedString` - so they can be used to slice it directly. They're converted to the UTF-16
+//! offsets AppKit works in when bridging.
+
+use std::ops::Range;
+
+use core_graphics::base::CGFloat;
+use objc_id::ShareId;
+use objc::runtime::{Class, Object, BOOL};
+use objc::{msg_send, sel, sel_impl};
+
+use crate::foundation::{id, nil, to_bool, YES, NO, NSRange, NSRect, NSSize, NSString, NSUInteger};
+use crate::color::Color;
+use crate::layout::{Layout, LayoutAnchorX, LayoutAnchorY, LayoutAnchorDimension};
+use crate::scrollview::ScrollView;
+use crate::text::{AttributedString, Font};
+
+mod macos;
+use macos::{register_textview_class, register_textview_class_with_delegate};
+
+mod traits;
+pub use traits::TextViewDelegate;
+
+pub(crate) static TEXTVIEW_DELEGATE_PTR: &str = "rstTextViewDelegatePtr";
+
+/// A helper method for instantiating the text view and configuring it to grow vertically inside
+/// of a scroll view, while tracking the scroll view's width.
+fn allocate_view(class: *const Class) -> id {
+    unsafe {
+        let alloc: id = msg_send![class, alloc];
+        let view: id = msg_send![alloc, initWithFrame:NSRect::new(0., 0., 0., 0.)];
+
+        let _: () = msg_send![view, setVerticallyResizable:YES];
+        let _: () = msg_send![view, setHorizontallyResizable:NO];
+        let _: () = msg_send![view, setMinSize:NSSize::new(0., 0.)];
+        let _: () = msg_send![view, setMaxSize:NSSize::new(CGFloat::MAX, CGFloat::MAX)];
+
+        // NSViewWidthSizable
+        let _: () = msg_send![view, setAutoresizingMask:2 as NSUInteger];
+
+        let container: id = msg_send![view, textContainer];
+        let _: () = msg_send![container, setWidthTracksTextView:YES];
+        let _: () = msg_send![container, setContainerSize:NSSize::new(CGFloat::MAX, CGFloat::MAX)];
+
+        let _: () = msg_send![view, setAllowsUndo:YES];
+
+        view
+    }
+}
+
+/// Wraps the text view in a `ScrollView`, which is what actually sits in the view heirarchy.
+fn wrap_in_scrollview(view: id) -> ScrollView {
+    let scrollview = ScrollView::new();
+
+    unsafe {
+        let _: () = msg_send![&*scrollview.objc, setDocumentView:view];
+    }
+
+    scrollview
+}
+
+/// A clone-able handler to an `NSTextView` reference in the Objective-C runtime.
+#[derive(Debug)]
+pub struct TextView<T = ()> {
+    /// A pointer to the Objective-C runtime text view.
+    pub objc: ShareId<Object>,
+
+    /// The `NSScrollView` that hosts the text view. This is what gets added to the view
+    /// heirarchy, and what the layout anchors point to.
+    pub scrollview: ScrollView,
+
+    /// A pointer to the delegate for this view.
+    pub delegate: Option<Box<T>>,
+
+    /// A pointer to the Objective-C runtime top layout constraint.
+    pub top: LayoutAnchorY,
+
+    /// A pointer to the Objective-C runtime leading layout constraint.
+    pub leading: LayoutAnchorX,
+
+    /// A pointer to the Objective-C runtime trailing layout constraint.
+    pub trailing: LayoutAnchorX,
+
+    /// A pointer to the Objective-C runtime bottom layout constraint.
+    pub bottom: LayoutAnchorY,
+
+    /// A pointer to the Objective-C runtime width layout constraint.
+    pub width: LayoutAnchorDimension,
+
+    /// A pointer to the Objective-C runtime height layout constraint.
+    pub height: LayoutAnchorDimension,
+
+    /// A pointer to the Objective-C runtime center X layout constraint.
+    pub center_x: LayoutAnchorX,
+
+    /// A pointer to the Objective-C runtime center Y layout constraint.
+    pub center_y: LayoutAnchorY
+}
+
+impl Default for TextView {
+    fn default() -> Self {
+        TextView::new()
+    }
+}
+
+impl TextView {
+    /// Returns a default, editable, plain-text `TextView`.
+    pub fn new() -> Self {
+        let view = allocate_view(register_textview_class());
+        let scrollview = wrap_in_scrollview(view);
+
+        TextView {
+            delegate: None,
+            top: scrollview.top.clone(),
+            leading: scrollview.leading.clone(),
+            trailing: scrollview.trailing.clone(),
+            bottom: scrollview.bottom.clone(),
+            width: scrollview.width.clone(),
+            height: scrollview.height.clone(),
+            center_x: scrollview.center_x.clone(),
+            center_y: scrollview.center_y.clone(),
+            objc: unsafe { ShareId::from_retained_ptr(view) },
+            scrollview: scrollview
+        }
+    }
+}
+
+impl<T> TextView<T> where T: TextViewDelegate + 'static {
+    /// Initializes a new TextView with a given `TextViewDelegate`. This enables you to respond to
+    /// text and selection changes.
+    pub fn with(delegate: T) -> TextView<T> {
+        let class = register_textview_class_with_delegate::<T>(&delegate);
+        let mut delegate = Box::new(delegate);
+
+        let view = allocate_view(class);
+        unsafe {
+            let ptr: *const T = &*delegate;
+            (&mut *view).set_ivar(TEXTVIEW_DELEGATE_PTR, ptr as usize);
+            let _: () = msg_send![view, setDelegate:view];
+        }
+
+        let scrollview = wrap_in_scrollview(view);
+
+        let mut view = TextView {
+            delegate: None,
+            top: scrollview.top.clone(),
+            leading: scrollview.leading.clone(),
+            trailing: scrollview.trailing.clone(),
+            bottom: scrollview.bottom.clone(),
+            width: scrollview.width.clone(),
+            height: scrollview.height.clone(),
+            center_x: scrollview.center_x.clone(),
+            center_y: scrollview.center_y.clone(),
+            objc: unsafe { ShareId::from_retained_ptr(view) },
+            scrollview: scrollview
+        };
+
+        (&mut delegate).did_load(view.clone_as_handle());
+        view.delegate = Some(delegate);
+        view
+    }
+}
+
+impl<T> TextView<T> {
+    /// An internal method that returns a clone of this object, sans references to the delegate or
+    /// callback pointer. We use this in calling `did_load()` - implementing delegates get a way to
+    /// reference, customize and use the view but without the trickery of holding pieces of the
+    /// delegate - the `TextView` is the only true holder of those.
+    pub(crate) fn clone_as_handle(&self) -> TextView {
+        TextView {
+            delegate: None,
+            top: self.top.clone(),
+            leading: self.leading.clone(),
+            trailing: self.trailing.clone(),
+            bottom: self.bottom.clone(),
+            width: self.width.clone(),
+            height: self.height.clone(),
+            center_x: self.center_x.clone(),
+            center_y: self.center_y.clone(),
+            objc: self.objc.clone(),
+            scrollview: self.scrollview.clone_as_handle()
+        }
+    }
+
+    /// Returns the text, sans any attributes.
+    pub fn get_text(&self) -> String {
+        NSString::wrap(unsafe {
+            msg_send![&*self.objc, string]
+        }).to_string()
+    }
+
+    /// Replaces the text. In rich text mode, the new text picks up the typing attributes.
+    ///
+    /// This is not undoable, and clears any existing undo actions.
+    pub fn set_text(&self, text: &str) {
+        let s = NSString::new(text);
+
+        unsafe {
+            let _: () = msg_send![&*self.objc, setString:s.into_inner()];
+            self.remove_all_undo_actions();
+        }
+    }
+
+    /// Returns a copy of the text, with its attributes.
+    pub fn get_attributed_text(&self) -> AttributedString {
+        AttributedString::from_objc(unsafe {
+            msg_send![&*self.objc, textStorage]
+        })
+    }
+
+    /// Replaces the text with an attributed string. Attributes are only displayed in rich text
+    /// mode.
+    ///
+    /// This is not undoable, and clears any existing undo actions.
+    pub fn set_attributed_text(&self, text: &AttributedString) {
+        unsafe {
+            let storage: id = msg_send![&*self.objc, textStorage];
+            let _: () = msg_send![storage, setAttributedString:text.to_objc()];
+            self.remove_all_undo_actions();
+        }
+    }
+
+    /// Toggles rich text mode. In plain text mode, the user can't apply styling, and pasted text
+    /// has its attributes stripped. Text views default to plain text.
+    pub fn set_rich_text(&self, rich: bool) {
+        unsafe {
+            let _: () = msg_send![&*self.objc, setRichText:match rich {
+                true => YES,
+                false => NO
+            }];
+        }
+    }
+
+    /// Returns whether this is in rich text mode.
+    pub fn is_rich_text(&self) -> bool {
+        to_bool(unsafe { msg_send![&*self.objc, isRichText] })
+    }
+
+    /// Returns the selected range, as a byte range into `get_text()`. If nothing is selected,
+    /// this is an empty range at the insertion point.
+    pub fn selected_range(&self) -> Range<usize> {
+        let range: NSRange = unsafe { msg_send![&*self.objc, selectedRange] };
+        range.to_byte_range(&self.get_text())
+    }
+
+    /// Selects the given byte range of the text. Pass an empty range to move the insertion point.
+    ///
+    /// # Panics
+    ///
+    /// Panics if the range is out of bounds, or doesn't fall on `char` boundaries.
+    pub fn set_selected_range(&self, range: Range<usize>) {
+        let range = NSRange::from_byte_range(&self.get_text(), range);
+
+        unsafe {
+            let _: () = msg_send![&*self.objc, setSelectedRange:range];
+        }
+    }
+
+    /// Returns the selected text.
+    pub fn get_selected_text(&self) -> String {
+        NSString::wrap(unsafe {
+            let range: NSRange = msg_send![&*self.objc, selectedRange];
+            let string: id = msg_send![&*self.objc, string];
+            msg_send![string, substringWithRange:range]
+        }).to_string()
+    }
+
+    /// Scrolls the given byte range of the text into view.
+    ///
+    /// # Panics
+    ///
+    /// Panics if the range is out of bounds, or doesn't fall on `char` boundaries.
+    pub fn scroll_to_range(&self, range: Range<usize>) {
+        let range = NSRange::from_byte_range(&self.get_text(), range);
+
+        unsafe {
+            let _: () = msg_send![&*self.objc, scrollRangeToVisible:range];
+        }
+    }
+
+    /// Inserts text at the insertion point, replacing the selection if there is one. This
+    /// registers with the undo manager, just as if the user had typed it.
+    pub fn insert_text(&self, text: &str) -> bool {
+        self.replace_text(self.selected_range(), text)
+    }
+
+    /// Replaces the text in the given byte range. This registers with the undo manager, just as
+    /// if the user had typed it, and notifies the delegate. Returns `false` if the change was
+    /// refused (e.g, the view isn't editable).
+    ///
+    /// # Panics
+    ///
+    /// Panics if the range is out of bounds, or doesn't fall on `char` boundaries.
+    pub fn replace_text(&self, range: Range<usize>, text: &str) -> bool {
+        let range = NSRange::from_byte_range(&self.get_text(), range);
+        let s = NSString::new(text);
+
+        unsafe {
+            let string = s.into_inner();
+            let allowed: BOOL = msg_send![&*self.objc, shouldChangeTextInRange:range replacementString:string];
+
+            if !to_bool(allowed) {
+                return false;
+            }
+
+            let storage: id = msg_send![&*self.objc, textStorage];
+            let _: () = msg_send![storage, replaceCharactersInRange:range withString:string];
+            let _: () = msg_send![&*self.objc, didChangeText];
+        }
+
+        true
+    }
+
+    /// Sets whether the user can edit the text.
+    pub fn set_editable(&self, editable: bool) {
+        unsafe {
+            let _: () = msg_send![&*self.objc, setEditable:match editable {
+                true => YES,
+                false => NO
+            }];
+        }
+    }
+
+    /// Returns whether the user can edit the text.
+    pub fn is_editable(&self) -> bool {
+        to_bool(unsafe { msg_send![&*self.objc, isEditable] })
+    }
+
+    /// Sets whether the user can select the text. Turning this off also makes the view
+    /// non-editable.
+    pub fn set_selectable(&self, selectable: bool) {
+        unsafe {
+            let _: () = msg_send![&*self.objc, setSelectable:match selectable {
+                true => YES,
+                false => NO
+            }];
+        }
+    }
+
+    /// Returns whether the user can select the text.
+    pub fn is_selectable(&self) -> bool {
+        to_bool(unsafe { msg_send![&*self.objc, isSelectable] })
+    }
+
+    /// Sets whether edits are registered with the undo manager. This defaults to `true`.
+    pub fn set_allows_undo(&self, allows: bool) {
+        unsafe {
+            let _: () = msg_send![&*self.objc, setAllowsUndo:match allows {
+                true => YES,
+                false => NO
+            }];
+        }
+    }
+
+    /// Returns the undo manager in use, if any. The text view borrows the window's undo
+    /// manager, so this is `nil` until the view is in a window.
+    fn undo_manager(&self) -> id {
+        unsafe {
+            msg_send![&*self.objc, undoManager]
+        }
+    }
+
+    /// Clears any pending undo and redo actions.
+    unsafe fn remove_all_undo_actions(&self) {
+        let manager = self.undo_manager();
+
+        if manager != nil {
+            let _: () = msg_send![manager, removeAllActionsWithTarget:&*self.objc];
+        }
+    }
+
+    /// Returns whether there's an edit to undo.
+    pub fn can_undo(&self) -> bool {
+        let manager = self.undo_manager();
+
+        match manager == nil {
+            true => false,
+            false => to_bool(unsafe { msg_send![manager, canUndo] })
+        }
+    }
+
+    /// Returns whether there's an edit to redo.
+    pub fn can_redo(&self) -> bool {
+        let manager = self.undo_manager();
+
+        match manager == nil {
+            true => false,
+            false => to_bool(unsafe { msg_send![manager, canRedo] })
+        }
+    }
+
+    /// Undoes the last edit, if there is one.
+    pub fn undo(&self) {
+        if self.can_undo() {
+            unsafe {
+                let _: () = msg_send![self.undo_manager(), undo];
+            }
+        }
+    }
+
+    /// Redoes the last undone edit, if there is one.
+    pub fn redo(&self) {
+        if self.can_redo() {
+            unsafe {
+                let _: () = msg_send![self.undo_manager(), redo];
+            }
+        }
+    }
+
+    /// Sets the font, for the whole text in plain text mode, or for newly typed text in rich
+    /// text mode.
+    pub fn set_font(&self, font: &Font) {
+        unsafe {
+            let _: () = msg_send![&*self.objc, setFont:&*font.objc];
+        }
+    }
+
+    /// Sets the text color.
+    pub fn set_text_color(&self, color: Color) {
+        let color = color.into_platform_specific_color();
+
+        unsafe {
+            let _: () = msg_send![&*self.objc, setTextColor:color];
+        }
+    }
+
+    /// Sets the background color. By default, this is the system text background color.
+    pub fn set_background_color(&self, color: Color) {
+        let color = color.into_platform_specific_color();
+
+        unsafe {
+            let _: () = msg_send![&*self.objc, setBackgroundColor:color];
+            let _: () = msg_send![&*self.objc, setDrawsBackground:YES];
+        }
+    }
+
+    /// Sets the inset between the edges of the view and the text.
+    pub fn set_text_inset(&self, width: CGFloat, height: CGFloat) {
+        unsafe {
+            let _: () = msg_send![&*self.objc, setTextContainerInset:NSSize::new(width, height)];
+        }
+    }
+}
+
+impl<T> Layout for TextView<T> {
+    /// This returns the NSScrollView, not the NSTextView.
+    fn get_backing_node(&self) -> ShareId<Object> {
+        self.scrollview.objc.clone()
+    }
+
+    fn add_subview<V: Layout>(&self, view: &V) {
+        let backing_node = view.get_backing_node();
+
+        unsafe {
+            let _: () = msg_send![&*self.scrollview.objc, addSubview:backing_node];
+        }
+    }
+}
+
+impl<T> Drop for TextView<T> {
+    /// A bit of extra cleanup for delegate callback pointers. If the originating `TextView` is
+    /// being dropped, we remove the scroll view from its superview (if it's in the heirarchy),
+    /// and break the delegate link so AppKit doesn't call back into a freed delegate.
+    fn drop(&mut self) {
+        if self.delegate.is_some() {
+            unsafe {
+                let _: () = msg_send![&*self.objc, setDelegate:nil];
+
+                let superview: id = msg_send![&*self.scrollview.objc, superview];
+                if superview != nil {
+                    let _: () = msg_send![&*self.scrollview.objc, removeFromSuperview];
+                }
+            }
+        }
+    }
+}
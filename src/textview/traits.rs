@@ -0,0 +1,31 @@
+//! Various traits used for TextViews.
+
+use std::ops::Range;
+
+use crate::textview::TextView;
+
+#[allow(unused_variables)]
+pub trait TextViewDelegate {
+    /// Used to cache subclass creations on the Objective-C side.
+    /// You can just set this to be the name of your view type. This
+    /// value *must* be unique per-type.
+    const NAME: &'static str;
+
+    /// You should rarely (read: probably never) need to implement this yourself.
+    /// It simply acts as a getter for the associated `NAME` const on this trait.
+    fn subclass_name(&self) -> &'static str {
+        Self::NAME
+    }
+
+    /// Called when the TextView is ready to work with. You're passed a `TextView` - this is safe
+    /// to store and use repeatedly, but it's not thread safe - any UI calls must be made from
+    /// the main thread!
+    fn did_load(&mut self, view: TextView) {}
+
+    /// Called whenever the text changes, with the new (plain) text.
+    fn text_did_change(&self, value: &str) {}
+
+    /// Called whenever the selection (or insertion point) moves. The range is a byte range into
+    /// the text, matching `TextView::selected_range()`.
+    fn selection_did_change(&self, range: Range<usize>) {}
+}
@@ -0,0 +1,165 @@
+//! A small abstraction over the current time (and over waiting for it to pass), so that
+//! `Debouncer` and `Throttler` can be driven by something other than the wall clock and the run
+//! loop (e.g, in tests).
+
+use std::time::{Duration, Instant};
+
+use crate::timer::Timer;
+
+/// Provides the current time to `Debouncer` and `Throttler`, and schedules their delayed calls.
+/// You generally won't need to implement this yourself; it exists so that timing logic can be
+/// driven by a fake clock.
+pub trait Clock {
+    /// A call scheduled with `schedule()`. Dropping it cancels the call, if it hasn't run yet.
+    type Scheduled;
+
+    /// Returns the current instant.
+    fn now(&self) -> Instant;
+
+    /// Arranges for `handler` to run once `after` has elapsed.
+    fn schedule<F: FnOnce() + 'static>(&self, after: Duration, handler: F) -> Self::Scheduled;
+}
+
+/// The default `Clock`, which defers to `Instant::now()`, and schedules calls with a `Timer` on
+/// the main run loop.
+#[derive(Copy, Clone, Debug, Default)]
+pub struct SystemClock;
+
+impl Clock for SystemClock {
+    type Scheduled = Timer;
+
+    fn now(&self) -> Instant {
+        Instant::now()
+    }
+
+    fn schedule<F: FnOnce() + 'static>(&self, after: Duration, handler: F) -> Timer {
+        Timer::once(after, handler)
+    }
+}
+
+#[cfg(test)]
+pub(crate) use fake::FakeClock;
+
+#[cfg(test)]
+mod fake {
+    use std::cell::RefCell;
+    use std::rc::{Rc, Weak};
+    use std::time::{Duration, Instant};
+
+    use super::Clock;
+
+    struct Alarm {
+        id: usize,
+        deadline: Instant,
+        handler: Box<dyn FnOnce()>
+    }
+
+    struct State {
+        now: Instant,
+        next_id: usize,
+        alarms: Vec<Alarm>
+    }
+
+    /// A `Clock` for tests, which only moves when told to. Scheduled calls run as `advance()`
+    /// passes their deadlines, in order. Clones share the same time.
+    #[derive(Clone)]
+    pub(crate) struct FakeClock(Rc<RefCell<State>>);
+
+    /// A call scheduled on a `FakeClock`. Dropping it cancels the call.
+    pub(crate) struct FakeAlarm {
+        id: usize,
+        state: Weak<RefCell<State>>
+    }
+
+    impl FakeClock {
+        pub(crate) fn new() -> Self {
+            FakeClock(Rc::new(RefCell::new(State {
+                now: Instant::now(),
+                next_id: 0,
+                alarms: Vec::new()
+            })))
+        }
+
+        /// Moves the clock forward by the given number of milliseconds, running any calls that
+        /// come due on the way (at their deadline, so they see the time they'd have fired at).
+        pub(crate) fn advance(&self, millis: u64) {
+            let target = self.0.borrow().now + Duration::from_millis(millis);
+
+            loop {
+                // The borrow is released before the handler runs, as it'll likely schedule again.
+                let due = {
+                    let mut state = self.0.borrow_mut();
+                    let next = state.alarms.iter()
+                        .enumerate()
+                        .filter(|(_, alarm)| alarm.deadline <= target)
+                        .min_by_key(|(_, alarm)| (alarm.deadline, alarm.id))
+                        .map(|(index, _)| index);
+
+                    match next {
+                        Some(index) => {
+                            let alarm = state.alarms.remove(index);
+                            state.now = state.now.max(alarm.deadline);
+                            Some(alarm)
+                        },
+
+                        None => {
+                            state.now = target;
+                            None
+                        }
+                    }
+                };
+
+                match due {
+                    Some(alarm) => (alarm.handler)(),
+                    None => break
+                }
+            }
+        }
+
+        /// Returns how many calls are waiting to run.
+        pub(crate) fn scheduled(&self) -> usize {
+            self.0.borrow().alarms.len()
+        }
+    }
+
+    impl Clock for FakeClock {
+        type Scheduled = FakeAlarm;
+
+        fn now(&self) -> Instant {
+            self.0.borrow().now
+        }
+
+        fn schedule<F: FnOnce() + 'static>(&self, after: Duration, handler: F) -> FakeAlarm {
+            let mut state = self.0.borrow_mut();
+            let id = state.next_id;
+            let deadline = state.now + after;
+
+            state.next_id += 1;
+            state.alarms.push(Alarm {
+                id: id,
+                deadline: deadline,
+                handler: Box::new(handler)
+            });
+
+            FakeAlarm {
+                id: id,
+                state: Rc::downgrade(&self.0)
+            }
+        }
+    }
+
+    impl Drop for FakeAlarm {
+        fn drop(&mut self) {
+            if let Some(state) = self.state.upgrade() {
+                // Drop the handler after the borrow ends, in case dropping it drops another alarm.
+                let cancelled = {
+                    let mut state = state.borrow_mut();
+                    let index = state.alarms.iter().position(|alarm| alarm.id == self.id);
+                    index.map(|index| state.alarms.remove(index))
+                };
+
+                drop(cancelled);
+            }
+        }
+    }
+}
@@ -0,0 +1,348 @@
+//! Implements `Debouncer`, which collapses a burst of calls into a single call once things have
+//! gone quiet.
+
+use std::cell::RefCell;
+use std::rc::{Rc, Weak};
+use std::time::{Duration, Instant};
+
+use crate::timer::{Clock, SystemClock};
+
+/// The timing logic behind a `Debouncer`, kept separate from any run loop concerns.
+#[derive(Debug)]
+struct DebounceState {
+    delay: Duration,
+    last_call: Option<Instant>
+}
+
+impl DebounceState {
+    /// Notes that a call came in at `now`, pushing the deadline back.
+    fn record(&mut self, now: Instant) {
+        self.last_call = Some(now);
+    }
+
+    /// Returns how long until the pending call should run, or `None` if nothing is pending.
+    fn remaining(&self, now: Instant) -> Option<Duration> {
+        self.last_call.map(|last_call| (last_call + self.delay).saturating_duration_since(now))
+    }
+
+    /// Clears any pending call.
+    fn clear(&mut self) {
+        self.last_call = None;
+    }
+}
+
+struct Inner<C: Clock> {
+    clock: C,
+    state: RefCell<DebounceState>,
+    pending: RefCell<Option<Box<dyn FnOnce()>>>,
+    timer: RefCell<Option<C::Scheduled>>
+}
+
+/// Delays running a handler until calls have stopped coming in for a given period. Each call to
+/// `call()` replaces the pending handler and restarts the wait, so only the most recent one runs.
+///
+/// This is what you want for search-as-you-type: rather than querying on every keystroke, query
+/// once the user has paused.
+///
+/// ```rust,no_run
+/// use std::time::Duration;
+/// use cacao::timer::Debouncer;
+///
+/// let debouncer = Debouncer::new(Duration::from_millis(300));
+///
+/// // e.g, from a `TextField` change callback:
+/// let query = String::from("cacao");
+/// debouncer.call(move || {
+///     println!("Searching for {}", query);
+/// });
+/// ```
+///
+/// Handlers run on the main run loop, so this must be created and used on the main thread.
+/// Dropping the `Debouncer` cancels any pending call.
+pub struct Debouncer<C: Clock = SystemClock> {
+    inner: Rc<Inner<C>>
+}
+
+impl Debouncer<SystemClock> {
+    /// Creates a new `Debouncer` that waits for `delay` of quiet before running.
+    pub fn new(delay: Duration) -> Self {
+        Debouncer::with_clock(delay, SystemClock)
+    }
+}
+
+impl<C: Clock + 'static> Debouncer<C> {
+    /// Creates a new `Debouncer` that reads the time from (and schedules its calls with) `clock`,
+    /// rather than the system clock.
+    pub fn with_clock(delay: Duration, clock: C) -> Self {
+        Debouncer {
+            inner: Rc::new(Inner {
+                clock: clock,
+                state: RefCell::new(DebounceState {
+                    delay: delay,
+                    last_call: None
+                }),
+                pending: RefCell::new(None),
+                timer: RefCell::new(None)
+            })
+        }
+    }
+
+    /// Schedules `handler` to run once `delay` has passed without another call. Any handler
+    /// previously passed in that hasn't run yet is discarded.
+    pub fn call<F: FnOnce() + 'static>(&self, handler: F) {
+        let now = self.inner.clock.now();
+        self.inner.state.borrow_mut().record(now);
+        *self.inner.pending.borrow_mut() = Some(Box::new(handler));
+
+        // Rather than recreating the timer on every call, let the existing one fire and check
+        // whether it's been pushed back in the meantime.
+        let is_scheduled = self.inner.timer.borrow().is_some();
+        if !is_scheduled {
+            let delay = self.inner.state.borrow().delay;
+            schedule(&self.inner, delay);
+        }
+    }
+
+    /// Returns how long until the pending handler runs, according to the clock, or `None` if
+    /// nothing is pending.
+    pub fn remaining(&self) -> Option<Duration> {
+        let now = self.inner.clock.now();
+        self.inner.state.borrow().remaining(now)
+    }
+
+    /// Returns whether there's a handler waiting to run.
+    pub fn is_pending(&self) -> bool {
+        self.inner.pending.borrow().is_some()
+    }
+
+    /// Runs the pending handler (if any) immediately.
+    pub fn flush(&self) {
+        run_pending(&self.inner);
+    }
+
+    /// Discards the pending handler (if any) without running it.
+    pub fn cancel(&self) {
+        self.inner.timer.borrow_mut().take();
+        self.inner.pending.borrow_mut().take();
+        self.inner.state.borrow_mut().clear();
+    }
+}
+
+/// Schedules a check (on the main run loop, for the system clock) after `after` has elapsed.
+fn schedule<C: Clock + 'static>(inner: &Rc<Inner<C>>, after: Duration) {
+    let weak: Weak<Inner<C>> = Rc::downgrade(inner);
+
+    let timer = inner.clock.schedule(after, move || {
+        if let Some(inner) = weak.upgrade() {
+            fire(&inner);
+        }
+    });
+
+    *inner.timer.borrow_mut() = Some(timer);
+}
+
+/// Called when the timer fires. If calls came in after the timer was scheduled, this simply
+/// waits out the remainder; otherwise, the pending handler is run.
+fn fire<C: Clock + 'static>(inner: &Rc<Inner<C>>) {
+    let now = inner.clock.now();
+    let remaining = inner.state.borrow().remaining(now);
+
+    match remaining {
+        Some(remaining) if remaining > Duration::from_secs(0) => {
+            schedule(inner, remaining);
+        },
+
+        _ => {
+            run_pending(inner);
+        }
+    }
+}
+
+/// Clears all state and runs the pending handler, if there is one. Borrows are released before
+/// the handler runs, so it's free to call back into the `Debouncer`.
+fn run_pending<C: Clock>(inner: &Inner<C>) {
+    inner.timer.borrow_mut().take();
+    inner.state.borrow_mut().clear();
+    let pending = inner.pending.borrow_mut().take();
+
+    if let Some(handler) = pending {
+        handler();
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use std::cell::RefCell;
+    use std::rc::Rc;
+    use std::time::Duration;
+
+    use crate::timer::Clock;
+    use crate::timer::clock::FakeClock;
+    use super::{DebounceState, Debouncer};
+
+    fn millis(millis: u64) -> Option<Duration> {
+        Some(Duration::from_millis(millis))
+    }
+
+    type Runs = Rc<RefCell<Vec<&'static str>>>;
+
+    /// Returns a list of the handlers that have run, and a way to make handlers that add to it.
+    fn recorder() -> (Runs, impl Fn(&'static str) -> Box<dyn FnOnce()>) {
+        let runs = Rc::new(RefCell::new(Vec::new()));
+        let handle = runs.clone();
+
+        (runs, move |name| {
+            let runs = handle.clone();
+            Box::new(move || runs.borrow_mut().push(name))
+        })
+    }
+
+    #[test]
+    fn nothing_is_pending_before_a_call() {
+        let clock = FakeClock::new();
+        let debouncer = Debouncer::with_clock(Duration::from_millis(300), clock.clone());
+
+        assert!(!debouncer.is_pending());
+        assert_eq!(debouncer.remaining(), None);
+        assert_eq!(clock.scheduled(), 0);
+    }
+
+    #[test]
+    fn state_never_underflows() {
+        let clock = FakeClock::new();
+        let mut state = DebounceState { delay: Duration::from_millis(300), last_call: None };
+
+        state.record(clock.now());
+        clock.advance(350);
+        assert_eq!(state.remaining(clock.now()), millis(0));
+    }
+
+    #[test]
+    fn runs_once_on_the_trailing_edge_of_a_burst() {
+        let clock = FakeClock::new();
+        let debouncer = Debouncer::with_clock(Duration::from_millis(300), clock.clone());
+        let (runs, handler) = recorder();
+
+        debouncer.call(handler("first"));
+        clock.advance(200);
+        assert_eq!(debouncer.remaining(), millis(100));
+
+        // Another call inside the window replaces the handler, and pushes the deadline back to a
+        // full delay from now.
+        debouncer.call(handler("second"));
+        assert_eq!(debouncer.remaining(), millis(300));
+
+        // The original timer fires at 300ms, finds it's been pushed back, and waits out the rest.
+        clock.advance(299);
+        assert!(runs.borrow().is_empty());
+        assert_eq!(clock.scheduled(), 1);
+
+        clock.advance(1);
+        assert_eq!(*runs.borrow(), vec!["second"]);
+        assert!(!debouncer.is_pending());
+        assert_eq!(debouncer.remaining(), None);
+        assert_eq!(clock.scheduled(), 0);
+    }
+
+    #[test]
+    fn a_burst_only_schedules_one_timer() {
+        let clock = FakeClock::new();
+        let debouncer = Debouncer::with_clock(Duration::from_millis(300), clock.clone());
+        let (runs, handler) = recorder();
+
+        for _ in 0..10 {
+            debouncer.call(handler("call"));
+            clock.advance(10);
+            assert_eq!(clock.scheduled(), 1);
+        }
+
+        clock.advance(1000);
+        assert_eq!(runs.borrow().len(), 1);
+    }
+
+    #[test]
+    fn calls_after_a_run_start_a_new_wait() {
+        let clock = FakeClock::new();
+        let debouncer = Debouncer::with_clock(Duration::from_millis(300), clock.clone());
+        let (runs, handler) = recorder();
+
+        debouncer.call(handler("first"));
+        clock.advance(300);
+        debouncer.call(handler("second"));
+        clock.advance(299);
+        assert_eq!(*runs.borrow(), vec!["first"]);
+
+        clock.advance(1);
+        assert_eq!(*runs.borrow(), vec!["first", "second"]);
+    }
+
+    #[test]
+    fn cancel_discards_the_pending_handler() {
+        let clock = FakeClock::new();
+        let debouncer = Debouncer::with_clock(Duration::from_millis(300), clock.clone());
+        let (runs, handler) = recorder();
+
+        debouncer.call(handler("cancelled"));
+        assert!(debouncer.is_pending());
+
+        debouncer.cancel();
+        assert!(!debouncer.is_pending());
+        assert_eq!(debouncer.remaining(), None);
+        assert_eq!(clock.scheduled(), 0);
+
+        clock.advance(1000);
+        debouncer.flush();
+        assert!(runs.borrow().is_empty());
+    }
+
+    #[test]
+    fn flush_runs_the_pending_handler_once() {
+        let clock = FakeClock::new();
+        let debouncer = Debouncer::with_clock(Duration::from_millis(300), clock.clone());
+        let (runs, handler) = recorder();
+
+        debouncer.call(handler("flushed"));
+        debouncer.flush();
+        debouncer.flush();
+        clock.advance(1000);
+
+        assert_eq!(*runs.borrow(), vec!["flushed"]);
+        assert!(!debouncer.is_pending());
+    }
+
+    #[test]
+    fn handlers_can_call_back_in() {
+        let clock = FakeClock::new();
+        let debouncer = Rc::new(Debouncer::with_clock(Duration::from_millis(300), clock.clone()));
+        let (runs, handler) = recorder();
+
+        let again = Rc::downgrade(&debouncer);
+        let second = handler("second");
+        let first = handler("first");
+        debouncer.call(move || {
+            first();
+            again.upgrade().unwrap().call(second);
+        });
+
+        clock.advance(300);
+        assert_eq!(*runs.borrow(), vec!["first"]);
+        assert!(debouncer.is_pending());
+
+        clock.advance(300);
+        assert_eq!(*runs.borrow(), vec!["first", "second"]);
+    }
+
+    #[test]
+    fn dropping_cancels_the_pending_handler() {
+        let clock = FakeClock::new();
+        let debouncer = Debouncer::with_clock(Duration::from_millis(300), clock.clone());
+        let (runs, handler) = recorder();
+
+        debouncer.call(handler("dropped"));
+        drop(debouncer);
+
+        assert_eq!(clock.scheduled(), 0);
+        clock.advance(1000);
+        assert!(runs.borrow().is_empty());
+    }
+}
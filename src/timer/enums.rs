@@ -0,0 +1,33 @@
+//! Enums used when scheduling timers.
+
+use crate::foundation::NSString;
+
+/// Run loop modes that a `Timer` can be scheduled in. A timer only fires while the run loop is
+/// running in one of the modes it's been added to.
+#[derive(Copy, Clone, Debug, PartialEq)]
+pub enum RunLoopMode {
+    /// The standard mode. Timers in this mode will _not_ fire while the user is, say, scrolling
+    /// or dragging a window - use `Common` if you need that.
+    Default,
+
+    /// The set of "common" modes, which includes `Default`, `EventTracking` and `ModalPanel`.
+    Common,
+
+    /// The mode the run loop runs in while tracking events, e.g during scrolling, resizing, or
+    /// mouse-dragging.
+    EventTracking,
+
+    /// The mode the run loop runs in while a modal panel (e.g, an open or save panel) is up.
+    ModalPanel
+}
+
+impl From<RunLoopMode> for NSString {
+    fn from(mode: RunLoopMode) -> Self {
+        NSString::new(match mode {
+            RunLoopMode::Default => "kCFRunLoopDefaultMode",
+            RunLoopMode::Common => "kCFRunLoopCommonModes",
+            RunLoopMode::EventTracking => "NSEventTrackingRunLoopMode",
+            RunLoopMode::ModalPanel => "NSModalPanelRunLoopMode"
+        })
+    }
+}
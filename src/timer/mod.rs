@@ -0,0 +1,152 @@
+//! Timers that fire on the main run loop, backed by `NSTimer`.
+//!
+//! A `Timer` is cancelled when it's dropped, so hold on to it for as long as you want it to fire:
+//!
+//! ```rust,no_run
+//! use std::time::Duration;
+//! use cacao::timer::{RunLoopMode, Timer};
+//!
+//! let timer = Timer::repeating(Duration::from_secs(1), || {
+//!     println!("Tick");
+//! });
+//!
+//! // Allow the system some leeway in when this fires, and keep it firing while scrolling.
+//! timer.set_tolerance(Duration::from_millis(100));
+//! timer.add_to_run_loop_mode(RunLoopMode::EventTracking);
+//! ```
+//!
+//! This module also provides `Debouncer` and `Throttler`, which are built on top of `Timer` and
+//! are handy for things like search-as-you-type.
+//!
+//! Timers must be created on the main thread.
+
+use std::cell::RefCell;
+use std::time::Duration;
+
+use block::ConcreteBlock;
+
+use objc::{class, msg_send, sel, sel_impl};
+use objc::runtime::{Object, YES, NO};
+use objc_id::ShareId;
+
+use crate::foundation::{id, to_bool, NSString};
+use crate::panic::guard;
+
+mod clock;
+pub use clock::{Clock, SystemClock};
+
+mod debounce;
+pub use debounce::Debouncer;
+
+mod enums;
+pub use enums::RunLoopMode;
+
+mod throttle;
+pub use throttle::Throttler;
+
+/// A wrapper for `NSTimer`. The timer is scheduled on the main run loop in the default mode when
+/// it's created, and is invalidated when this is dropped.
+#[derive(Debug)]
+pub struct Timer {
+    pub objc: ShareId<Object>
+}
+
+impl Timer {
+    /// Creates a timer that calls `handler` once, after `delay` has elapsed.
+    pub fn once<F>(delay: Duration, handler: F) -> Self
+    where
+        F: FnOnce() + 'static
+    {
+        let handler = RefCell::new(Some(handler));
+
+        Timer::new(delay, false, move || {
+            if let Some(handler) = handler.borrow_mut().take() {
+                handler();
+            }
+        })
+    }
+
+    /// Creates a timer that calls `handler` every `interval`, starting one `interval` from now.
+    pub fn repeating<F>(interval: Duration, handler: F) -> Self
+    where
+        F: Fn() + 'static
+    {
+        Timer::new(interval, true, handler)
+    }
+
+    /// Builds the underlying `NSTimer` and schedules it on the main run loop.
+    fn new<F>(interval: Duration, repeats: bool, handler: F) -> Self
+    where
+        F: Fn() + 'static
+    {
+        let block = ConcreteBlock::new(move |_timer: id| {
+            guard(|| handler());
+        });
+        let block = block.copy();
+
+        let timer = unsafe {
+            let timer: id = msg_send![class!(NSTimer), timerWithTimeInterval:interval.as_secs_f64()
+                repeats:match repeats {
+                    true => YES,
+                    false => NO
+                }
+                block:&*block
+            ];
+
+            ShareId::from_ptr(timer)
+        };
+
+        let timer = Timer { objc: timer };
+        timer.add_to_run_loop_mode(RunLoopMode::Default);
+        timer
+    }
+
+    /// Sets how late the timer may fire after its scheduled time. Giving the system some leeway
+    /// here lets it coalesce timers, which can noticeably help with power usage.
+    pub fn set_tolerance(&self, tolerance: Duration) {
+        unsafe {
+            let _: () = msg_send![&*self.objc, setTolerance:tolerance.as_secs_f64()];
+        }
+    }
+
+    /// Additionally schedules this timer in the given run loop mode. Timers start out in the
+    /// default mode only, which means they won't fire during e.g scrolling or window resizing.
+    pub fn add_to_run_loop_mode(&self, mode: RunLoopMode) {
+        let mode: NSString = mode.into();
+
+        unsafe {
+            let run_loop: id = msg_send![class!(NSRunLoop), mainRunLoop];
+            let _: () = msg_send![run_loop, addTimer:&*self.objc forMode:mode.into_inner()];
+        }
+    }
+
+    /// Fires the timer immediately. For a repeating timer, this does not affect the regular
+    /// schedule; a one-shot timer is invalidated afterwards.
+    pub fn fire(&self) {
+        unsafe {
+            let _: () = msg_send![&*self.objc, fire];
+        }
+    }
+
+    /// Stops the timer from ever firing again. This is called automatically on drop.
+    pub fn cancel(&self) {
+        unsafe {
+            let _: () = msg_send![&*self.objc, invalidate];
+        }
+    }
+
+    /// Returns whether the timer is still scheduled - i.e, it hasn't been cancelled, and (if it's
+    /// a one-shot timer) hasn't fired yet.
+    pub fn is_valid(&self) -> bool {
+        to_bool(unsafe {
+            msg_send![&*self.objc, isValid]
+        })
+    }
+}
+
+impl Drop for Timer {
+    /// Invalidates the timer, which removes it from the run loop and releases the handler.
+    fn drop(&mut self) {
+        self.cancel();
+    }
+}
@@ -0,0 +1,302 @@
+//! Implements `Throttler`, which limits how often a handler can run.
+
+use std::cell::RefCell;
+use std::rc::{Rc, Weak};
+use std::time::{Duration, Instant};
+
+use crate::timer::{Clock, SystemClock};
+
+/// The timing logic behind a `Throttler`, kept separate from any run loop concerns.
+#[derive(Debug)]
+struct ThrottleState {
+    interval: Duration,
+    last_run: Option<Instant>
+}
+
+impl ThrottleState {
+    /// Notes that the handler ran at `now`.
+    fn record(&mut self, now: Instant) {
+        self.last_run = Some(now);
+    }
+
+    /// Returns how long until the handler is allowed to run again. A zero duration means it can
+    /// run right away.
+    fn remaining(&self, now: Instant) -> Duration {
+        match self.last_run {
+            Some(last_run) => (last_run + self.interval).saturating_duration_since(now),
+            None => Duration::from_secs(0)
+        }
+    }
+}
+
+struct Inner<C: Clock> {
+    clock: C,
+    state: RefCell<ThrottleState>,
+    pending: RefCell<Option<Box<dyn FnOnce()>>>,
+    timer: RefCell<Option<C::Scheduled>>
+}
+
+/// Ensures a handler runs at most once per interval. The first call runs immediately; calls that
+/// come in before the interval has passed are collapsed into a single trailing call (using the
+/// most recent handler), which runs as soon as the interval is up.
+///
+/// This is useful for work that should track a rapidly changing value without running on every
+/// change - e.g, live-updating a preview while the user types or drags a slider.
+///
+/// ```rust,no_run
+/// use std::time::Duration;
+/// use cacao::timer::Throttler;
+///
+/// let throttler = Throttler::new(Duration::from_millis(100));
+///
+/// throttler.call(|| {
+///     println!("Updating preview");
+/// });
+/// ```
+///
+/// Handlers run on the main run loop, so this must be created and used on the main thread.
+/// Dropping the `Throttler` cancels any pending trailing call.
+pub struct Throttler<C: Clock = SystemClock> {
+    inner: Rc<Inner<C>>
+}
+
+impl Throttler<SystemClock> {
+    /// Creates a new `Throttler` that runs at most once per `interval`.
+    pub fn new(interval: Duration) -> Self {
+        Throttler::with_clock(interval, SystemClock)
+    }
+}
+
+impl<C: Clock + 'static> Throttler<C> {
+    /// Creates a new `Throttler` that reads the time from (and schedules its calls with) `clock`,
+    /// rather than the system clock.
+    pub fn with_clock(interval: Duration, clock: C) -> Self {
+        Throttler {
+            inner: Rc::new(Inner {
+                clock: clock,
+                state: RefCell::new(ThrottleState {
+                    interval: interval,
+                    last_run: None
+                }),
+                pending: RefCell::new(None),
+                timer: RefCell::new(None)
+            })
+        }
+    }
+
+    /// Runs `handler` now if the interval has passed since the last run; otherwise, stores it to
+    /// run once the interval is up, replacing any handler already waiting.
+    pub fn call<F: FnOnce() + 'static>(&self, handler: F) {
+        let is_scheduled = self.inner.timer.borrow().is_some();
+        if is_scheduled {
+            *self.inner.pending.borrow_mut() = Some(Box::new(handler));
+            return;
+        }
+
+        let now = self.inner.clock.now();
+        let remaining = self.inner.state.borrow().remaining(now);
+
+        if remaining == Duration::from_secs(0) {
+            self.inner.state.borrow_mut().record(now);
+            handler();
+        } else {
+            *self.inner.pending.borrow_mut() = Some(Box::new(handler));
+            schedule(&self.inner, remaining);
+        }
+    }
+
+    /// Returns how long until a handler is allowed to run again, according to the clock.
+    pub fn remaining(&self) -> Duration {
+        let now = self.inner.clock.now();
+        self.inner.state.borrow().remaining(now)
+    }
+
+    /// Returns whether there's a trailing handler waiting to run.
+    pub fn is_pending(&self) -> bool {
+        self.inner.pending.borrow().is_some()
+    }
+
+    /// Discards the trailing handler (if any) without running it.
+    pub fn cancel(&self) {
+        self.inner.timer.borrow_mut().take();
+        self.inner.pending.borrow_mut().take();
+    }
+}
+
+/// Schedules the trailing call (on the main run loop, for the system clock) after `after` has
+/// elapsed.
+fn schedule<C: Clock + 'static>(inner: &Rc<Inner<C>>, after: Duration) {
+    let weak: Weak<Inner<C>> = Rc::downgrade(inner);
+
+    let timer = inner.clock.schedule(after, move || {
+        if let Some(inner) = weak.upgrade() {
+            fire(&inner);
+        }
+    });
+
+    *inner.timer.borrow_mut() = Some(timer);
+}
+
+/// Called when the timer fires; runs the trailing handler, if there is one. Borrows are released
+/// before the handler runs, so it's free to call back into the `Throttler`.
+fn fire<C: Clock>(inner: &Inner<C>) {
+    inner.timer.borrow_mut().take();
+    let pending = inner.pending.borrow_mut().take();
+
+    if let Some(handler) = pending {
+        let now = inner.clock.now();
+        inner.state.borrow_mut().record(now);
+        handler();
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use std::cell::RefCell;
+    use std::rc::Rc;
+    use std::time::Duration;
+
+    use crate::timer::Clock;
+    use crate::timer::clock::FakeClock;
+    use super::{ThrottleState, Throttler};
+
+    fn millis(millis: u64) -> Duration {
+        Duration::from_millis(millis)
+    }
+
+    type Runs = Rc<RefCell<Vec<&'static str>>>;
+
+    /// Returns a list of the handlers that have run, and a way to make handlers that add to it.
+    fn recorder() -> (Runs, impl Fn(&'static str) -> Box<dyn FnOnce()>) {
+        let runs = Rc::new(RefCell::new(Vec::new()));
+        let handle = runs.clone();
+
+        (runs, move |name| {
+            let runs = handle.clone();
+            Box::new(move || runs.borrow_mut().push(name))
+        })
+    }
+
+    #[test]
+    fn state_never_underflows() {
+        let clock = FakeClock::new();
+        let mut state = ThrottleState { interval: millis(100), last_run: None };
+        assert_eq!(state.remaining(clock.now()), millis(0));
+
+        state.record(clock.now());
+        clock.advance(150);
+        assert_eq!(state.remaining(clock.now()), millis(0));
+    }
+
+    #[test]
+    fn leading_call_runs_immediately() {
+        let clock = FakeClock::new();
+        let throttler = Throttler::with_clock(millis(100), clock.clone());
+        let (runs, handler) = recorder();
+
+        throttler.call(handler("leading"));
+
+        assert_eq!(*runs.borrow(), vec!["leading"]);
+        assert!(!throttler.is_pending());
+        assert_eq!(throttler.remaining(), millis(100));
+        assert_eq!(clock.scheduled(), 0);
+    }
+
+    #[test]
+    fn calls_in_separate_windows_each_run() {
+        let clock = FakeClock::new();
+        let throttler = Throttler::with_clock(millis(100), clock.clone());
+        let (runs, handler) = recorder();
+
+        throttler.call(handler("first"));
+        clock.advance(100);
+        throttler.call(handler("second"));
+        clock.advance(250);
+        throttler.call(handler("third"));
+
+        assert_eq!(*runs.borrow(), vec!["first", "second", "third"]);
+        assert_eq!(clock.scheduled(), 0);
+    }
+
+    #[test]
+    fn calls_inside_the_window_collapse_into_one_trailing_call() {
+        let clock = FakeClock::new();
+        let throttler = Throttler::with_clock(millis(100), clock.clone());
+        let (runs, handler) = recorder();
+
+        throttler.call(handler("leading"));
+        clock.advance(20);
+        throttler.call(handler("dropped"));
+        clock.advance(20);
+        throttler.call(handler("trailing"));
+
+        assert!(throttler.is_pending());
+        assert_eq!(clock.scheduled(), 1);
+
+        clock.advance(59);
+        assert_eq!(*runs.borrow(), vec!["leading"]);
+
+        clock.advance(1);
+        assert_eq!(*runs.borrow(), vec!["leading", "trailing"]);
+        assert!(!throttler.is_pending());
+    }
+
+    #[test]
+    fn trailing_call_starts_a_new_window() {
+        let clock = FakeClock::new();
+        let throttler = Throttler::with_clock(millis(100), clock.clone());
+        let (runs, handler) = recorder();
+
+        throttler.call(handler("leading"));
+        clock.advance(50);
+        throttler.call(handler("trailing"));
+        clock.advance(50);
+        assert_eq!(throttler.remaining(), millis(100));
+
+        // Inside the window the trailing call opened, so this waits for it to close.
+        clock.advance(50);
+        throttler.call(handler("next"));
+        assert_eq!(*runs.borrow(), vec!["leading", "trailing"]);
+
+        clock.advance(50);
+        assert_eq!(*runs.borrow(), vec!["leading", "trailing", "next"]);
+    }
+
+    #[test]
+    fn cancel_discards_the_trailing_call() {
+        let clock = FakeClock::new();
+        let throttler = Throttler::with_clock(millis(100), clock.clone());
+        let (runs, handler) = recorder();
+
+        throttler.call(handler("leading"));
+        throttler.call(handler("cancelled"));
+        assert!(throttler.is_pending());
+
+        throttler.cancel();
+        assert!(!throttler.is_pending());
+        assert_eq!(clock.scheduled(), 0);
+
+        clock.advance(200);
+        assert_eq!(*runs.borrow(), vec!["leading"]);
+
+        // Cancelling doesn't reset the window from the last real run.
+        assert_eq!(throttler.remaining(), millis(0));
+        throttler.call(handler("after"));
+        assert_eq!(*runs.borrow(), vec!["leading", "after"]);
+    }
+
+    #[test]
+    fn dropping_cancels_the_trailing_call() {
+        let clock = FakeClock::new();
+        let throttler = Throttler::with_clock(millis(100), clock.clone());
+        let (runs, handler) = recorder();
+
+        throttler.call(handler("leading"));
+        throttler.call(handler("dropped"));
+        drop(throttler);
+
+        assert_eq!(clock.scheduled(), 0);
+        clock.advance(200);
+        assert_eq!(*runs.borrow(), vec!["leading"]);
+    }
+}
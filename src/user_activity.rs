@@ -1,9 +1,11 @@
 //! A module wrapping `NSUserActivity`.
 
+use objc::{msg_send, sel, sel_impl};
 use objc::runtime::Object;
 use objc_id::ShareId;
+use url::Url;
 
-use crate::foundation::id;
+use crate::foundation::{id, nil, NSString};
 
 /// Represents an `NSUserActivity`, which acts as a lightweight method to capture the state of your
 /// app. 
@@ -18,4 +20,18 @@ impl UserActivity {
             inner: unsafe { ShareId::from_ptr(object) }
         }
     }
+
+    /// Returns the webpage URL for this activity, if there is one. For activities that come in via
+    /// Universal Links, this is the link that was opened.
+    pub fn webpage_url(&self) -> Option<Url> {
+        unsafe {
+            let url: id = msg_send![&*self.inner, webpageURL];
+            if url == nil {
+                return None;
+            }
+
+            let uri = NSString::wrap(msg_send![url, absoluteString]);
+            Url::parse(uri.to_str()).ok()
+        }
+    }
 }
@@ -9,9 +9,10 @@ use objc::{class, msg_send, sel, sel_impl};
 use crate::foundation::load_or_register_class;
 use crate::view::{VIEW_DELEGATE_PTR, ViewDelegate};
 use crate::utils::load;
+use crate::panic::AddGuardedMethod;
 
 /// Called when the view controller receives a `viewWillAppear` message.
-extern fn will_appear<T: ViewDelegate>(this: &mut Object, _: Sel) {
+fn will_appear<T: ViewDelegate>(this: &mut Object, _: Sel) {
     unsafe {
         let _: () = msg_send![super(this, class!(NSViewController)), viewWillAppear];
     }
@@ -21,31 +22,31 @@ extern fn will_appear<T: ViewDelegate>(this: &mut Object, _: Sel) {
 }
 
 /// Called when the view controller receives a `viewDidAppear` message.
-extern fn did_appear<T: ViewDelegate>(this: &mut Object, _: Sel) {
+fn did_appear<T: ViewDelegate>(this: &mut Object, _: Sel) {
     unsafe {
         let _: () = msg_send![super(this, class!(NSViewController)), viewDidAppear];
     }
-    
+
     let controller = load::<T>(this, VIEW_DELEGATE_PTR);
     controller.did_appear(false);
 }
 
 /// Called when the view controller receives a `viewWillDisappear` message.
-extern fn will_disappear<T: ViewDelegate>(this: &mut Object, _: Sel) {
+fn will_disappear<T: ViewDelegate>(this: &mut Object, _: Sel) {
     unsafe {
         let _: () = msg_send![super(this, class!(NSViewController)), viewWillDisappear];
     }
-    
+
     let controller = load::<T>(this, VIEW_DELEGATE_PTR);
     controller.will_disappear(false);
 }
 
 /// Called when the view controller receives a `viewDidDisappear` message.
-extern fn did_disappear<T: ViewDelegate>(this: &mut Object, _: Sel) {
+fn did_disappear<T: ViewDelegate>(this: &mut Object, _: Sel) {
     unsafe {
         let _: () = msg_send![super(this, class!(NSViewController)), viewDidDisappear];
     }
-    
+
     let controller = load::<T>(this, VIEW_DELEGATE_PTR);
     controller.did_disappear(false);
 }
@@ -55,9 +56,9 @@ pub(crate) fn register_view_controller_class<T: ViewDelegate + 'static>(instance
     load_or_register_class("NSViewController", instance.subclass_name(), |decl| unsafe {
         decl.add_ivar::<usize>(VIEW_DELEGATE_PTR);
 
-        decl.add_method(sel!(viewWillAppear), will_appear::<T> as extern fn(&mut Object, _));
-        decl.add_method(sel!(viewDidAppear), did_appear::<T> as extern fn(&mut Object, _));
-        decl.add_method(sel!(viewWillDisappear), will_disappear::<T> as extern fn(&mut Object, _));
-        decl.add_method(sel!(viewDidDisappear), did_disappear::<T> as extern fn(&mut Object, _));
+        decl.add_guarded_method(sel!(viewWillAppear), will_appear::<T>);
+        decl.add_guarded_method(sel!(viewDidAppear), did_appear::<T>);
+        decl.add_guarded_method(sel!(viewWillDisappear), will_disappear::<T>);
+        decl.add_guarded_method(sel!(viewDidDisappear), did_disappear::<T>);
     })
 }
@@ -18,14 +18,15 @@ use crate::foundation::{load_or_register_class, id, nil, YES, NO, NSUInteger};
 use crate::dragdrop::DragInfo;
 use crate::view::{VIEW_DELEGATE_PTR, BACKGROUND_COLOR, ViewDelegate};
 use crate::utils::load;
+use crate::panic::AddGuardedMethod;
 
 /// Enforces normalcy, or: a needlessly cruel method in terms of the name. You get the idea though.
-extern fn enforce_normalcy(_: &Object, _: Sel) -> BOOL {
+fn enforce_normalcy(_: &Object, _: Sel) -> BOOL {
     return YES;
 }
 
 /// Called when a drag/drop operation has entered this view.
-extern fn dragging_entered<T: ViewDelegate>(this: &mut Object, _: Sel, info: id) -> NSUInteger {
+fn dragging_entered<T: ViewDelegate>(this: &mut Object, _: Sel, info: id) -> NSUInteger {
     let view = load::<T>(this, VIEW_DELEGATE_PTR);
     view.dragging_entered(DragInfo {
         info: unsafe { Id::from_ptr(info) }
@@ -33,9 +34,9 @@ extern fn dragging_entered<T: ViewDelegate>(this: &mut Object, _: Sel, info: id)
 }
 
 /// Called when a drag/drop operation has entered this view.
-extern fn prepare_for_drag_operation<T: ViewDelegate>(this: &mut Object, _: Sel, info: id) -> BOOL {
+fn prepare_for_drag_operation<T: ViewDelegate>(this: &mut Object, _: Sel, info: id) -> BOOL {
     let view = load::<T>(this, VIEW_DELEGATE_PTR);
-    
+
     match view.prepare_for_drag_operation(DragInfo {
         info: unsafe { Id::from_ptr(info) }
     }) {
@@ -45,9 +46,9 @@ extern fn prepare_for_drag_operation<T: ViewDelegate>(this: &mut Object, _: Sel,
 }
 
 /// Called when a drag/drop operation has entered this view.
-extern fn perform_drag_operation<T: ViewDelegate>(this: &mut Object, _: Sel, info: id) -> BOOL {
+fn perform_drag_operation<T: ViewDelegate>(this: &mut Object, _: Sel, info: id) -> BOOL {
     let view = load::<T>(this, VIEW_DELEGATE_PTR);
-        
+
     match view.perform_drag_operation(DragInfo {
         info: unsafe { Id::from_ptr(info) }
     }) {
@@ -57,25 +58,25 @@ extern fn perform_drag_operation<T: ViewDelegate>(this: &mut Object, _: Sel, inf
 }
 
 /// Called when a drag/drop operation has entered this view.
-extern fn conclude_drag_operation<T: ViewDelegate>(this: &mut Object, _: Sel, info: id) {
+fn conclude_drag_operation<T: ViewDelegate>(this: &mut Object, _: Sel, info: id) {
     let view = load::<T>(this, VIEW_DELEGATE_PTR);
-    
+
     view.conclude_drag_operation(DragInfo {
         info: unsafe { Id::from_ptr(info) }
     });           
 }
 
 /// Called when a drag/drop operation has entered this view.
-extern fn dragging_exited<T: ViewDelegate>(this: &mut Object, _: Sel, info: id) {
+fn dragging_exited<T: ViewDelegate>(this: &mut Object, _: Sel, info: id) {
     let view = load::<T>(this, VIEW_DELEGATE_PTR);
-        
+
     view.dragging_exited(DragInfo {
         info: unsafe { Id::from_ptr(info) }
     });
 }
 
 /// Called for layer updates.
-extern fn update_layer(this: &Object, _: Sel) {
+fn update_layer(this: &Object, _: Sel) {
     unsafe {
         let background_color: id = *this.get_ivar(BACKGROUND_COLOR);
 
@@ -98,9 +99,9 @@ pub(crate) fn register_view_class() -> *const Class {
         let superclass = class!(NSView);
         let mut decl = ClassDecl::new("RSTView", superclass).unwrap();
 
-        decl.add_method(sel!(isFlipped), enforce_normalcy as extern fn(&Object, _) -> BOOL);
-        decl.add_method(sel!(updateLayer), update_layer as extern fn(&Object, _));
-        decl.add_method(sel!(wantsUpdateLayer), enforce_normalcy as extern fn(&Object, _) -> BOOL);
+        decl.add_guarded_method(sel!(isFlipped), enforce_normalcy);
+        decl.add_guarded_method(sel!(updateLayer), update_layer);
+        decl.add_guarded_method(sel!(wantsUpdateLayer), enforce_normalcy);
 
         decl.add_ivar::<id>(BACKGROUND_COLOR);
     
@@ -119,45 +120,45 @@ pub(crate) fn register_view_class_with_delegate<T: ViewDelegate>(instance: &T) -
         decl.add_ivar::<usize>(VIEW_DELEGATE_PTR);
         decl.add_ivar::<id>(BACKGROUND_COLOR);
         
-        decl.add_method(
+        decl.add_guarded_method(
             sel!(updateLayer),
-            update_layer as extern fn(&Object, _)
+            update_layer
         );
 
-        decl.add_method(
+        decl.add_guarded_method(
             sel!(wantsUpdateLayer),
-            enforce_normalcy as extern fn(&Object, _) -> BOOL
+            enforce_normalcy
         );
 
-        decl.add_method(
+        decl.add_guarded_method(
             sel!(isFlipped),
-            enforce_normalcy as extern fn(&Object, _) -> BOOL
+            enforce_normalcy
         );
 
         // Drag and drop operations (e.g, accepting files)
-        decl.add_method(
+        decl.add_guarded_method(
             sel!(draggingEntered:),
-            dragging_entered::<T> as extern fn (&mut Object, _, _) -> NSUInteger
+            dragging_entered::<T>
         );
 
-        decl.add_method(
+        decl.add_guarded_method(
             sel!(prepareForDragOperation:),
-            prepare_for_drag_operation::<T> as extern fn (&mut Object, _, _) -> BOOL
+            prepare_for_drag_operation::<T>
         );
 
-        decl.add_method(
+        decl.add_guarded_method(
             sel!(performDragOperation:),
-            perform_drag_operation::<T> as extern fn (&mut Object, _, _) -> BOOL
+            perform_drag_operation::<T>
         );
 
-        decl.add_method(
+        decl.add_guarded_method(
             sel!(concludeDragOperation:),
-            conclude_drag_operation::<T> as extern fn (&mut Object, _, _)
+            conclude_drag_operation::<T>
         );
 
-        decl.add_method(
+        decl.add_guarded_method(
             sel!(draggingExited:),
-            dragging_exited::<T> as extern fn (&mut Object, _, _)
+            dragging_exited::<T>
         );
     })
 }
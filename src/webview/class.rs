@@ -16,10 +16,11 @@ use crate::webview::{WEBVIEW_DELEGATE_PTR, WebViewDelegate};
 use crate::webview::actions::{NavigationAction, NavigationResponse};//, OpenPanelParameters};
 //use crate::webview::enums::{NavigationPolicy, NavigationResponsePolicy};
 use crate::utils::load;
+use crate::panic::AddGuardedMethod;
 
 /// Called when an `alert()` from the underlying `WKWebView` is fired. Will call over to your
 /// `WebViewController`, where you should handle the event.
-extern fn alert<T: WebViewDelegate>(_: &Object, _: Sel, _: id, _: id, _: id, complete: id) {
+fn alert<T: WebViewDelegate>(_: &Object, _: Sel, _: id, _: id, _: id, complete: id) {
     //let alert = NSString::wrap(s).to_str();
 
     // @TODO: This is technically (I think?) a private method, and there's some other dance that
@@ -43,7 +44,7 @@ extern fn alert<T: WebViewDelegate>(_: &Object, _: Sel, _: id, _: id, _: id, com
 }
 
 /// Fires when a message has been passed from the underlying `WKWebView`.
-extern fn on_message<T: WebViewDelegate>(this: &Object, _: Sel, _: id, script_message: id) {
+fn on_message<T: WebViewDelegate>(this: &Object, _: Sel, _: id, script_message: id) {
     let delegate = load::<T>(this, WEBVIEW_DELEGATE_PTR);
 
     unsafe {
@@ -54,11 +55,11 @@ extern fn on_message<T: WebViewDelegate>(this: &Object, _: Sel, _: id, script_me
 }
 
 /// Fires when deciding a navigation policy - i.e, should something be allowed or not.
-extern fn decide_policy_for_action<T: WebViewDelegate>(this: &Object, _: Sel, _: id, action: id, handler: usize) {
+fn decide_policy_for_action<T: WebViewDelegate>(this: &Object, _: Sel, _: id, action: id, handler: usize) {
     let delegate = load::<T>(this, WEBVIEW_DELEGATE_PTR);
 
     let action = NavigationAction::new(action);
-    
+
     delegate.policy_for_navigation_action(action, |policy| unsafe {
         let handler = handler as *const Block<(NSInteger,), c_void>;
         (*handler).call((policy.into(),));
@@ -66,7 +67,7 @@ extern fn decide_policy_for_action<T: WebViewDelegate>(this: &Object, _: Sel, _:
 }
 
 /// Fires when deciding a navigation policy - i.e, should something be allowed or not.
-extern fn decide_policy_for_response<T: WebViewDelegate>(this: &Object, _: Sel, _: id, response: id, handler: usize) {
+fn decide_policy_for_response<T: WebViewDelegate>(this: &Object, _: Sel, _: id, response: id, handler: usize) {
     let delegate = load::<T>(this, WEBVIEW_DELEGATE_PTR);
 
     let response = NavigationResponse::new(response);
@@ -78,7 +79,7 @@ extern fn decide_policy_for_response<T: WebViewDelegate>(this: &Object, _: Sel,
 }
 
 /// Fires when deciding a navigation policy - i.e, should something be allowed or not.
-extern fn run_open_panel<T: WebViewDelegate>(this: &Object, _: Sel, _: id, params: id, _: id, handler: usize) {
+fn run_open_panel<T: WebViewDelegate>(this: &Object, _: Sel, _: id, params: id, _: id, handler: usize) {
     let delegate = load::<T>(this, WEBVIEW_DELEGATE_PTR);
 
     delegate.run_open_panel(params.into(), move |urls| unsafe {
@@ -103,7 +104,7 @@ extern fn run_open_panel<T: WebViewDelegate>(this: &Object, _: Sel, _: id, param
 /// response is upgraded to BecomeDownload. Only called when explicitly linked since it's a private
 /// API.
 #[cfg(feature = "webview-downloading")]
-extern fn handle_download<T: WebViewDelegate>(this: &Object, _: Sel, download: id, suggested_filename: id, handler: usize) {
+fn handle_download<T: WebViewDelegate>(this: &Object, _: Sel, download: id, suggested_filename: id, handler: usize) {
     let delegate = load::<T>(this, WEBVIEW_DELEGATE_PTR);
 
     let handler = handler as *const Block<(objc::runtime::BOOL, id), c_void>; 
@@ -115,7 +116,7 @@ extern fn handle_download<T: WebViewDelegate>(this: &Object, _: Sel, download: i
         }
 
         let path = NSString::new(&path.unwrap());
-        
+
         (*handler).call((match can_overwrite {
             true => YES,
             false => NO
@@ -153,21 +154,21 @@ pub fn register_webview_delegate_class<T: WebViewDelegate>() -> *const Class {
         decl.add_ivar::<usize>(WEBVIEW_DELEGATE_PTR);
 
         // WKNavigationDelegate
-        decl.add_method(sel!(webView:decidePolicyForNavigationAction:decisionHandler:), decide_policy_for_action::<T> as extern fn(&Object, _, _, id, usize));
-        decl.add_method(sel!(webView:decidePolicyForNavigationResponse:decisionHandler:), decide_policy_for_response::<T> as extern fn(&Object, _, _, id, usize));
+        decl.add_guarded_method(sel!(webView:decidePolicyForNavigationAction:decisionHandler:), decide_policy_for_action::<T>);
+        decl.add_guarded_method(sel!(webView:decidePolicyForNavigationResponse:decisionHandler:), decide_policy_for_response::<T>);
 
         // WKScriptMessageHandler
-        decl.add_method(sel!(userContentController:didReceiveScriptMessage:), on_message::<T> as extern fn(&Object, _, _, id));
+        decl.add_guarded_method(sel!(userContentController:didReceiveScriptMessage:), on_message::<T>);
  
         // WKUIDelegate
-        decl.add_method(sel!(webView:runJavaScriptAlertPanelWithMessage:initiatedByFrame:completionHandler:), alert::<T> as extern fn(&Object, _, _, id, _, _));
-        decl.add_method(sel!(webView:runOpenPanelWithParameters:initiatedByFrame:completionHandler:), run_open_panel::<T> as extern fn(&Object, _, _, id, _, usize));
+        decl.add_guarded_method(sel!(webView:runJavaScriptAlertPanelWithMessage:initiatedByFrame:completionHandler:), alert::<T>);
+        decl.add_guarded_method(sel!(webView:runOpenPanelWithParameters:initiatedByFrame:completionHandler:), run_open_panel::<T>);
         
         // WKDownloadDelegate is a private class on macOS that handles downloading (saving) files.
         // It's absurd that this is still private in 2020. This probably couldn't get into the app
         // store, so... screw it, feature-gate it.
         #[cfg(feature = "webview-downloading")]
-        decl.add_method(sel!(_download:decideDestinationWithSuggestedFilename:completionHandler:), handle_download::<T> as extern fn(&Object, _, id, id, usize));
+        decl.add_guarded_method(sel!(_download:decideDestinationWithSuggestedFilename:completionHandler:), handle_download::<T>);
 
         VIEW_CLASS = decl.register();
     });